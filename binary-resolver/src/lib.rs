@@ -0,0 +1,253 @@
+mod trigger;
+use trigger::{encode_cli_output, encode_trigger_output, Codec, Destination};
+use wavs_wasi_utils::{
+    evm::alloy_primitives::{hex, keccak256},
+    http::http_request_post_json,
+};
+pub mod bindings; // Never edit bindings.rs!
+use crate::bindings::{export, Guest, TriggerAction, WasmResponse};
+use serde::{Deserialize, Serialize};
+use wavs_component_common::{
+    decode_abi_string_input, dry_run, envelope, llm,
+    output::{CliOutput, EthereumOutput},
+    replay, run, selector, timing, versioned,
+};
+use wstd::{http::HeaderValue, runtime::block_on};
+
+const SYSTEM_PROMPT: &str = "You resolve prediction market questions. Reply with exactly one \
+word: YES, NO or UNRESOLVED. Reply UNRESOLVED if the question is ambiguous, cannot be decided \
+yet, or you do not have enough information to decide it.";
+const DEFAULT_MODEL: &str = "gpt-4o";
+const DEFAULT_SAMPLES: u8 = 5;
+const DEFAULT_QUORUM: u8 = 4;
+const MAX_SAMPLES: u8 = 10;
+const DEFAULT_SEED: u64 = 42;
+const OPENAI_CHAT_URL: &str = "https://api.openai.com/v1/chat/completions";
+// One of YES, NO or UNRESOLVED
+const MAX_ANSWER_TOKENS: u32 = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Outcome {
+    Unresolved = 0,
+    Yes = 1,
+    No = 2,
+}
+
+impl Outcome {
+    // Strict protocol: the whole reply must be one of the three words, ignoring case and a
+    // trailing period
+    fn parse(reply: &str) -> Option<Outcome> {
+        match reply.trim().trim_end_matches('.').to_ascii_uppercase().as_str() {
+            "YES" => Some(Outcome::Yes),
+            "NO" => Some(Outcome::No),
+            "UNRESOLVED" => Some(Outcome::Unresolved),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ResolutionData {
+    question: String,
+    question_hash: String,
+    outcome: Outcome,
+    outcome_code: u8,
+    confidence_bps: u16,
+    samples: u8,
+    quorum: u8,
+    model: String,
+    // raw replies, in call order; replies outside the protocol count as no vote
+    votes: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct Message {
+    role: String,
+    content: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct ChatRequest {
+    model: String,
+    messages: Vec<Message>,
+    temperature: f32,
+    seed: u64,
+    max_tokens: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+struct ChatResponse {
+    choices: Vec<Choice>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+struct Choice {
+    message: Option<Message>,
+}
+
+struct Component;
+export!(Component with_types_in bindings);
+
+impl Guest for Component {
+    fn run(action: TriggerAction) -> std::result::Result<Option<WasmResponse>, String> {
+        run::run(Codec, action.data, process)
+    }
+}
+
+fn process(
+    trigger_id: u64,
+    req: Vec<u8>,
+    dest: Destination,
+    options: envelope::Options,
+) -> Result<Option<WasmResponse>, String> {
+    options.check_supported(&["dry_run"])?;
+
+    // Hex text, resolve calldata or a bare ABI string
+    let question = decode_abi_string_input(
+        &req,
+        &[selector::Expected::of::<trigger::solidity::resolveCall>()],
+    )?;
+    let question = question.trim().to_string();
+    if question.is_empty() {
+        return Err("Question must not be empty".to_string());
+    }
+
+    let config = config()?;
+
+    timing::end_phase("decode");
+    if options.dry_run {
+        // Every sample is its own chat request, answered in at most MAX_ANSWER_TOKENS
+        let prompt_tokens = llm::estimate_tokens(SYSTEM_PROMPT) + llm::estimate_tokens(&question);
+        let encoded = trigger::solidity::Resolution {
+            questionHash: keccak256(question.as_bytes()),
+            outcome: u8::MAX,
+            confidenceBps: u16::MAX,
+        };
+        let plan = dry_run::Plan::new(
+            env!("CARGO_PKG_NAME"),
+            &serde_json::json!({ "question": question }),
+        )?
+        .llm(
+            OPENAI_CHAT_URL,
+            &config.model,
+            config.samples.into(),
+            prompt_tokens as u64,
+            Some(MAX_ANSWER_TOKENS.into()),
+        )?
+        .submission(&encode_trigger_output(trigger_id, EthereumOutput::abi(&encoded)).payload);
+        let payload = plan.output(matches!(dest, Destination::Ethereum), options.json_format())?;
+        return payload.map(encode_cli_output).transpose();
+    }
+    let resolution = block_on(async move { resolve(&question, config).await })?;
+    timing::end_phase("upstream");
+
+    let encoded = trigger::solidity::Resolution {
+        questionHash: keccak256(resolution.question.as_bytes()),
+        outcome: resolution.outcome_code,
+        confidenceBps: resolution.confidence_bps,
+    };
+    run::respond(Codec, trigger_id, EthereumOutput::abi(&encoded).traced(), dest, &options, || {
+        Ok(CliOutput::new(versioned!(&resolution)))
+    })
+}
+
+struct Config {
+    samples: u8,
+    quorum: u8,
+    model: String,
+}
+
+fn config() -> Result<Config, String> {
+    let samples = env_u8("WAVS_ENV_RESOLVER_SAMPLES", DEFAULT_SAMPLES);
+    let quorum = env_u8("WAVS_ENV_RESOLVER_QUORUM", DEFAULT_QUORUM);
+    if samples == 0 || samples > MAX_SAMPLES {
+        return Err(format!("WAVS_ENV_RESOLVER_SAMPLES must be between 1 and {}", MAX_SAMPLES));
+    }
+    // A majority, so two outcomes can never both reach it
+    if quorum <= samples / 2 || quorum > samples {
+        return Err(format!(
+            "WAVS_ENV_RESOLVER_QUORUM must be between {} and {}",
+            samples / 2 + 1,
+            samples
+        ));
+    }
+    let model =
+        std::env::var("WAVS_ENV_RESOLVER_MODEL").unwrap_or_else(|_| DEFAULT_MODEL.to_string());
+    Ok(Config { samples, quorum, model })
+}
+
+// Asks the same question N times with deterministic sampling and settles only when at least
+// M replies agree; anything short of the quorum resolves to UNRESOLVED
+async fn resolve(question: &str, config: Config) -> Result<ResolutionData, String> {
+    let Config { samples, quorum, model } = config;
+    let mut votes = Vec::with_capacity(samples as usize);
+    for _ in 0..samples {
+        votes.push(ask(question, &model).await?);
+    }
+
+    let outcomes: Vec<Outcome> = votes.iter().filter_map(|v| Outcome::parse(v)).collect();
+    let (top, top_count) = [Outcome::Yes, Outcome::No, Outcome::Unresolved]
+        .into_iter()
+        .map(|o| (o, outcomes.iter().filter(|v| **v == o).count()))
+        .max_by_key(|(_, count)| *count)
+        .unwrap_or((Outcome::Unresolved, 0));
+    let outcome = if top_count >= quorum as usize { top } else { Outcome::Unresolved };
+    let confidence_bps = (top_count * 10_000 / samples as usize) as u16;
+
+    Ok(ResolutionData {
+        question: question.to_string(),
+        question_hash: format!("0x{}", hex::encode(keccak256(question.as_bytes()))),
+        outcome,
+        outcome_code: outcome as u8,
+        confidence_bps,
+        samples,
+        quorum,
+        model,
+        votes,
+    })
+}
+
+async fn ask(question: &str, model: &str) -> Result<String, String> {
+    let api_key = std::env::var("WAVS_ENV_OPENAI_KEY")
+        .map_err(|_| "Failed to get OPENAI_KEY from environment variables".to_string())?;
+
+    let request = ChatRequest {
+        model: model.to_string(),
+        messages: vec![
+            Message { role: "system".to_string(), content: SYSTEM_PROMPT.to_string() },
+            Message { role: "user".to_string(), content: question.to_string() },
+        ],
+        temperature: 0.0,
+        seed: env_u64("WAVS_ENV_RESOLVER_SEED", DEFAULT_SEED),
+        max_tokens: MAX_ANSWER_TOKENS,
+    };
+
+    let mut req = http_request_post_json(OPENAI_CHAT_URL, &request)
+        .map_err(|e| format!("Failed to create request: {}", e))?;
+    req.headers_mut().insert(
+        "Authorization",
+        HeaderValue::from_str(&format!("Bearer {}", api_key))
+            .map_err(|e| format!("Failed to create Authorization header: {}", e))?,
+    );
+    req.headers_mut().insert("Content-Type", HeaderValue::from_static("application/json"));
+
+    let response: ChatResponse =
+        replay::fetch_json(req).await.map_err(|e| format!("Failed to fetch data: {}", e))?;
+    response
+        .choices
+        .first()
+        .and_then(|choice| choice.message.as_ref())
+        .map(|msg| msg.content.trim().to_string())
+        .ok_or_else(|| "No response received from OpenAI".to_string())
+}
+
+fn env_u8(name: &str, default: u8) -> u8 {
+    std::env::var(name).ok().and_then(|v| v.parse::<u8>().ok()).unwrap_or(default)
+}
+
+fn env_u64(name: &str, default: u64) -> u64 {
+    std::env::var(name).ok().and_then(|v| v.parse::<u64>().ok()).unwrap_or(default)
+}
@@ -0,0 +1,119 @@
+// Blob fee market reads for a window of blocks ending at a pinned block. eth_feeHistory returns
+// each block's blob base fee and blob gas used ratio, plus the fees of the block after the
+// window; the newest block's header adds its exact blob gas figures. Fees are wei per unit of
+// (blob) gas, kept as integers so no float reaches the output.
+use alloy_network::Ethereum;
+use alloy_primitives::B256;
+use alloy_provider::{Provider, RootProvider};
+use alloy_rpc_types::BlockNumberOrTag;
+use wavs_component_common::rpc;
+
+// Blob gas one blob consumes (EIP-4844's GAS_PER_BLOB)
+pub const GAS_PER_BLOB: u64 = 131_072;
+// eth_feeHistory's usual cap on the block count
+pub const MAX_BLOCK_COUNT: u16 = 1_024;
+
+pub struct BlockFees {
+    pub number: u64,
+    pub blob_base_fee: u128,
+    // blob gas used over the block's blob gas limit
+    pub blob_gas_used_bps: u16,
+}
+
+pub struct FeeWindow {
+    pub blocks: Vec<BlockFees>,
+    // fees of the block after the newest one
+    pub next_blob_base_fee: u128,
+    pub next_base_fee: u128,
+    pub newest_hash: B256,
+    pub newest_blob_gas_used: u64,
+    pub newest_excess_blob_gas: u64,
+}
+
+impl FeeWindow {
+    pub fn min_blob_base_fee(&self) -> u128 {
+        self.blocks.iter().map(|b| b.blob_base_fee).min().unwrap_or_default()
+    }
+
+    pub fn max_blob_base_fee(&self) -> u128 {
+        self.blocks.iter().map(|b| b.blob_base_fee).max().unwrap_or_default()
+    }
+
+    // Rounded down; blob base fees stay far below u128::MAX / MAX_BLOCK_COUNT
+    pub fn mean_blob_base_fee(&self) -> u128 {
+        let total: u128 = self.blocks.iter().map(|b| b.blob_base_fee).sum();
+        total / self.blocks.len().max(1) as u128
+    }
+
+    pub fn mean_blob_gas_used_bps(&self) -> u16 {
+        let total: u64 = self.blocks.iter().map(|b| u64::from(b.blob_gas_used_bps)).sum();
+        (total / self.blocks.len().max(1) as u64) as u16
+    }
+
+    pub fn blocks_with_blobs(&self) -> usize {
+        self.blocks.iter().filter(|b| b.blob_gas_used_bps > 0).count()
+    }
+}
+
+pub async fn read_window(
+    provider: &RootProvider<Ethereum>,
+    newest: u64,
+    block_count: u16,
+) -> Result<FeeWindow, String> {
+    let header = provider
+        .get_block_by_number(BlockNumberOrTag::Number(newest))
+        .await
+        .map_err(|e| rpc::describe(&format!("Failed to fetch block {}", newest), &e))?
+        .ok_or_else(|| format!("Block {} not found", newest))?
+        .header;
+    let (Some(blob_gas_used), Some(excess_blob_gas)) =
+        (header.blob_gas_used, header.excess_blob_gas)
+    else {
+        return Err(format!("Block {} has no blob gas fields; it predates EIP-4844", newest));
+    };
+
+    let history = provider
+        .get_fee_history(u64::from(block_count), BlockNumberOrTag::Number(newest), &[])
+        .await
+        .map_err(|e| rpc::describe("eth_feeHistory failed", &e))?;
+    // One entry per block, plus the blob base fee of the block after the window
+    let count = history.blob_gas_used_ratio.len();
+    if count == 0 || history.base_fee_per_blob_gas.len() != count + 1 {
+        return Err(
+            "eth_feeHistory returned no blob fees; does the node support EIP-4844?".to_string()
+        );
+    }
+    if history.oldest_block + count as u64 != newest + 1 {
+        return Err(format!(
+            "eth_feeHistory returned blocks {}..{}, expected a window ending at {}",
+            history.oldest_block,
+            history.oldest_block + count as u64,
+            newest
+        ));
+    }
+
+    let blocks = history
+        .blob_gas_used_ratio
+        .iter()
+        .zip(&history.base_fee_per_blob_gas)
+        .enumerate()
+        .map(|(i, (ratio, fee))| BlockFees {
+            number: history.oldest_block + i as u64,
+            blob_base_fee: *fee,
+            blob_gas_used_bps: ratio_bps(*ratio),
+        })
+        .collect();
+    Ok(FeeWindow {
+        blocks,
+        next_blob_base_fee: history.base_fee_per_blob_gas[count],
+        next_base_fee: history.base_fee_per_gas.get(count).copied().unwrap_or_default(),
+        newest_hash: header.hash,
+        newest_blob_gas_used: blob_gas_used,
+        newest_excess_blob_gas: excess_blob_gas,
+    })
+}
+
+// The ratio is the one float eth_feeHistory reports; basis points keep it out of the output
+fn ratio_bps(ratio: f64) -> u16 {
+    (ratio * 10_000.0).round().clamp(0.0, 10_000.0) as u16
+}
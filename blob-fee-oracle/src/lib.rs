@@ -0,0 +1,218 @@
+mod fees;
+mod trigger;
+use trigger::{encode_cli_output, encode_trigger_output, Codec, Destination};
+pub mod bindings;
+use crate::bindings::host::get_evm_chain_config;
+use crate::bindings::{export, Guest, TriggerAction, WasmResponse};
+use alloy_primitives::U256;
+use alloy_sol_types::SolCall;
+use serde::{Deserialize, Serialize};
+use wavs_component_common::{
+    decode, dry_run, envelope, freshness, info,
+    output::{CliOutput, EthereumOutput},
+    reorg::{self, BlockInfo, PinnedBlock},
+    replay, rpc, run, selector, timestamp, timing, versioned,
+};
+use wstd::runtime::block_on;
+
+const DEFAULT_BLOCK_COUNT: u16 = 20;
+// The blob base fee can move by about 12.5% per block, so a reading is stale within minutes
+const DEFAULT_RESULT_TTL_SECS: u64 = 60;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BlockBlobFees {
+    number: u64,
+    blob_base_fee: String,
+    blob_gas_used_bps: u16,
+}
+
+// Wei amounts are decimal strings, as elsewhere in this repository
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BlobFeeResult {
+    oldest_block: u64,
+    block_count: u16,
+    // what a blob transaction included in the next block pays, per blob gas and per blob
+    blob_base_fee: String,
+    blob_cost_wei: String,
+    blob_base_fee_min: String,
+    blob_base_fee_max: String,
+    blob_base_fee_mean: String,
+    // window mean of blob gas used over the blob gas limit
+    blob_gas_used_bps: u16,
+    blocks_with_blobs: usize,
+    // header fields of the newest block
+    blob_gas_used: u64,
+    excess_blob_gas: u64,
+    // execution base fee of the next block, which a blob transaction pays as well
+    base_fee: String,
+    blocks: Vec<BlockBlobFees>,
+    expires_at: u64,
+    // the newest block, its confirmations and reorg risk
+    #[serde(flatten)]
+    block: BlockInfo,
+}
+
+struct Component;
+export!(Component with_types_in bindings);
+
+impl Guest for Component {
+    fn run(action: TriggerAction) -> std::result::Result<Option<WasmResponse>, String> {
+        run::run(Codec, action.data, process)
+    }
+}
+
+fn process(
+    trigger_id: u64,
+    req: Vec<u8>,
+    dest: Destination,
+    options: envelope::Options,
+) -> Result<Option<WasmResponse>, String> {
+    options.check_supported(&["rpc_url", "dry_run"])?;
+
+    // A bare ABI string is the block count, or empty for the default window at the head
+    let (block_number, block_count) = {
+        let hex_data = decode::input_bytes(&req)?;
+        selector::check(
+            &hex_data,
+            &[
+                selector::Expected::of::<trigger::solidity::checkBlobFeesCall>(),
+                selector::Expected::of::<trigger::solidity::checkBlobFeesAtCall>(),
+            ],
+        )?;
+        if let Ok(call) = trigger::solidity::checkBlobFeesAtCall::abi_decode(&hex_data) {
+            (Some(call.blockNumber), call.blockCount)
+        } else if let Ok(call) = trigger::solidity::checkBlobFeesCall::abi_decode(&hex_data) {
+            (None, call.blockCount)
+        } else {
+            let input = decode::abi_string(&hex_data)?;
+            match input.trim() {
+                "" | "latest" => (None, 0),
+                count => (
+                    None,
+                    count
+                        .parse::<u16>()
+                        .map_err(|e| format!("Invalid block count '{}': {}", count, e))?,
+                ),
+            }
+        }
+    };
+    let block_count = match block_count {
+        0 => DEFAULT_BLOCK_COUNT,
+        count if count > fees::MAX_BLOCK_COUNT => {
+            return Err(format!("At most {} blocks per request", fees::MAX_BLOCK_COUNT));
+        }
+        count => count,
+    };
+
+    let rpc_url = options.rpc_url.clone();
+    timing::end_phase("decode");
+    if options.dry_run {
+        let plan = plan(trigger_id, block_number, block_count, rpc_url.as_deref())?;
+        let payload = plan.output(matches!(dest, Destination::Ethereum), options.json_format())?;
+        return payload.map(encode_cli_output).transpose();
+    }
+    let (result, encoded) =
+        block_on(async move { blob_fees(block_number, block_count, rpc_url.as_deref()).await })?;
+    timing::end_phase("upstream");
+
+    run::respond(Codec, trigger_id, EthereumOutput::abi(&encoded).traced(), dest, &options, || {
+        Ok(CliOutput::new(versioned!(&result)))
+    })
+}
+
+// The head when no block is named, the newest block and its fee history, and the reorg check's
+// two. Gas is estimated for the widest words.
+fn plan(
+    trigger_id: u64,
+    block_number: Option<u64>,
+    block_count: u16,
+    rpc_url: Option<&str>,
+) -> Result<dry_run::Plan, String> {
+    let widest = trigger::solidity::BlobFeeStats {
+        blockNumber: u64::MAX,
+        blockCount: block_count,
+        blobBaseFee: U256::MAX,
+        blobBaseFeeMin: U256::MAX,
+        blobBaseFeeMax: U256::MAX,
+        blobBaseFeeMean: U256::MAX,
+        blobGasUsedBps: u16::MAX,
+        excessBlobGas: u64::MAX,
+        baseFee: U256::MAX,
+    };
+    let input = serde_json::json!({ "block_number": block_number, "block_count": block_count });
+    let calls = block_number.is_none() as u64 + 4 + timestamp::trigger_block_requests("ethereum");
+    Ok(dry_run::Plan::new(env!("CARGO_PKG_NAME"), &input)?
+        .rpc(&ethereum_endpoint(rpc_url)?, calls)
+        .submission(&encode_trigger_output(trigger_id, EthereumOutput::abi(&widest)).payload))
+}
+
+fn ethereum_endpoint(rpc_url: Option<&str>) -> Result<String, String> {
+    let chain_config = get_evm_chain_config("ethereum")
+        .ok_or_else(|| "Failed to get Ethereum chain config".to_string())?;
+    rpc::resolve_endpoint(rpc_url, chain_config.http_endpoint)
+}
+
+// Without a block number the window ends at the latest block, which operators running a few
+// seconds apart can see differently; contracts aggregating submissions name the block instead
+async fn blob_fees(
+    block_number: Option<u64>,
+    block_count: u16,
+    rpc_url: Option<&str>,
+) -> Result<(BlobFeeResult, trigger::solidity::BlobFeeStats), String> {
+    let provider = replay::evm_provider(ethereum_endpoint(rpc_url)?);
+    timestamp::record_trigger_block(&provider, "ethereum").await;
+
+    let newest = match block_number {
+        Some(number) => number,
+        None => reorg::pin_latest(&provider).await?.number,
+    };
+    let window = fees::read_window(&provider, newest, block_count).await?;
+    let block =
+        reorg::assess(&provider, PinnedBlock { number: newest, hash: window.newest_hash }).await?;
+    info!(
+        "Blob base fee after block {}: {} wei ({} bps of blob gas used over {} blocks)",
+        newest,
+        window.next_blob_base_fee,
+        window.mean_blob_gas_used_bps(),
+        window.blocks.len()
+    );
+
+    let encoded = trigger::solidity::BlobFeeStats {
+        blockNumber: newest,
+        blockCount: window.blocks.len() as u16,
+        blobBaseFee: U256::from(window.next_blob_base_fee),
+        blobBaseFeeMin: U256::from(window.min_blob_base_fee()),
+        blobBaseFeeMax: U256::from(window.max_blob_base_fee()),
+        blobBaseFeeMean: U256::from(window.mean_blob_base_fee()),
+        blobGasUsedBps: window.mean_blob_gas_used_bps(),
+        excessBlobGas: window.newest_excess_blob_gas,
+        baseFee: U256::from(window.next_base_fee),
+    };
+    let result = BlobFeeResult {
+        oldest_block: window.blocks.first().map(|b| b.number).unwrap_or(newest),
+        block_count: window.blocks.len() as u16,
+        blob_base_fee: window.next_blob_base_fee.to_string(),
+        blob_cost_wei: (U256::from(window.next_blob_base_fee) * U256::from(fees::GAS_PER_BLOB))
+            .to_string(),
+        blob_base_fee_min: window.min_blob_base_fee().to_string(),
+        blob_base_fee_max: window.max_blob_base_fee().to_string(),
+        blob_base_fee_mean: window.mean_blob_base_fee().to_string(),
+        blob_gas_used_bps: window.mean_blob_gas_used_bps(),
+        blocks_with_blobs: window.blocks_with_blobs(),
+        blob_gas_used: window.newest_blob_gas_used,
+        excess_blob_gas: window.newest_excess_blob_gas,
+        base_fee: window.next_base_fee.to_string(),
+        blocks: window
+            .blocks
+            .iter()
+            .map(|b| BlockBlobFees {
+                number: b.number,
+                blob_base_fee: b.blob_base_fee.to_string(),
+                blob_gas_used_bps: b.blob_gas_used_bps,
+            })
+            .collect(),
+        expires_at: freshness::expires_at(timestamp::run_secs(), DEFAULT_RESULT_TTL_SECS),
+        block,
+    };
+    Ok((result, encoded))
+}
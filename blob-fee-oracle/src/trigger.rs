@@ -0,0 +1,109 @@
+use crate::bindings::host::get_evm_chain_config;
+use crate::bindings::wavs::worker::layer_types::{
+    TriggerData, TriggerDataEvmContractEvent, WasmResponse,
+};
+use alloy_sol_types::SolValue;
+use anyhow::Result;
+use serde::Serialize;
+use wavs_component_common::origin::TriggerOrigin;
+use wavs_component_common::output::{CliOutput, EthereumOutput};
+use wavs_wasi_utils::decode_event_log_data;
+
+pub use wavs_component_common::run::Destination;
+
+pub fn decode_trigger_event(
+    trigger_data: TriggerData,
+) -> Result<(u64, Vec<u8>, Destination, Option<TriggerOrigin>)> {
+    match trigger_data {
+        TriggerData::EvmContractEvent(TriggerDataEvmContractEvent {
+            log,
+            contract_address,
+            chain_name,
+            block_height,
+        }) => {
+            let event: solidity::NewTrigger = decode_event_log_data!(log)?;
+            let trigger_info =
+                <solidity::TriggerInfo as SolValue>::abi_decode(&event._triggerInfo)?;
+            let chain_id =
+                get_evm_chain_config(&chain_name).and_then(|config| config.chain_id.parse().ok());
+            let origin = TriggerOrigin::new(chain_name, &contract_address.raw_bytes, block_height)
+                .with_trigger(trigger_info.triggerId, chain_id);
+            Ok((
+                trigger_info.triggerId,
+                trigger_info.data.to_vec(),
+                Destination::Ethereum,
+                Some(origin),
+            ))
+        }
+        TriggerData::Raw(data) => Ok((0, data.clone(), Destination::CliOutput, None)),
+        _ => Err(anyhow::anyhow!("Unsupported trigger data type")),
+    }
+}
+
+pub fn encode_trigger_output<S>(trigger_id: u64, output: EthereumOutput<S>) -> WasmResponse {
+    WasmResponse {
+        payload: solidity::DataWithId { triggerId: trigger_id, data: output.into_data().into() }
+            .abi_encode(),
+        ordering: None,
+    }
+}
+
+pub fn encode_cli_output<T: Serialize>(output: CliOutput<T>) -> Result<WasmResponse, String> {
+    Ok(WasmResponse { payload: output.to_vec()?, ordering: None })
+}
+
+// Decodes and encodes this component's bindings for run::run and run::respond
+pub struct Codec;
+
+impl wavs_component_common::run::Codec for Codec {
+    const NAME: &'static str = env!("CARGO_PKG_NAME");
+    const VERSION: &'static str = env!("CARGO_PKG_VERSION");
+    type TriggerData = TriggerData;
+    type Response = WasmResponse;
+
+    fn decode(
+        &self,
+        data: TriggerData,
+    ) -> Result<(u64, Vec<u8>, Destination, Option<TriggerOrigin>), String> {
+        decode_trigger_event(data).map_err(|e| e.to_string())
+    }
+
+    fn submission<S>(&self, trigger_id: u64, output: EthereumOutput<S>) -> WasmResponse {
+        encode_trigger_output(trigger_id, output)
+    }
+
+    fn cli<T: Serialize>(&self, output: CliOutput<T>) -> Result<WasmResponse, String> {
+        encode_cli_output(output)
+    }
+
+    fn payload<'a>(&self, response: &'a WasmResponse) -> &'a [u8] {
+        &response.payload
+    }
+}
+
+pub mod solidity {
+    use alloy_sol_macro::sol;
+    pub use ITypes::*;
+    sol!("../../src/interfaces/ITypes.sol");
+    // trigger contract functions; blockCount 0 asks for the default window of 20 blocks
+    sol! {
+        function checkBlobFees(uint16 blockCount) external;
+        function checkBlobFeesAt(uint64 blockNumber, uint16 blockCount) external;
+    }
+
+    // Fees are wei per unit of gas. blobBaseFee and baseFee are those of the block after
+    // blockNumber, i.e. what a transaction sent now pays; the min/max/mean cover the window.
+    sol! {
+        struct BlobFeeStats {
+            uint64 blockNumber;
+            uint16 blockCount;
+            uint256 blobBaseFee;
+            uint256 blobBaseFeeMin;
+            uint256 blobBaseFeeMax;
+            uint256 blobBaseFeeMean;
+            uint16 blobGasUsedBps;
+            uint64 excessBlobGas;
+            uint256 baseFee;
+        }
+    }
+}
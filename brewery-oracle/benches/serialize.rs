@@ -0,0 +1,72 @@
+// Output cost of a large zip lookup: canonical JSON for 1000 breweries, with and without the
+// version tag, plus the filter option applied to the same rows. Run with
+// `cargo bench -p brewery-oracle`.
+use brewery_oracle::{Brewery, ZipResult};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use wavs_component_common::{canonical_json, filter::Filter, versioned};
+
+const TYPES: [&str; 4] = ["micro", "brewpub", "regional", "large"];
+
+fn breweries(count: usize) -> Vec<Brewery> {
+    (0..count)
+        .map(|i| Brewery {
+            id: Some(format!("b54b16e1-ac3b-4bff-a11f-{:012x}", i)),
+            name: Some(format!("Example Brewing Company {}", i)),
+            brewery_type: Some(TYPES[i % TYPES.len()].to_string()),
+            address_1: Some(format!("{} Larimer St", 1000 + i)),
+            city: Some(if i % 3 == 0 { "Portland" } else { "Denver" }.to_string()),
+            state_province: Some("Colorado".to_string()),
+            postal_code: Some("80205-1234".to_string()),
+            country: Some("United States".to_string()),
+            longitude: Some(-104.98 + i as f64 * 1e-4),
+            latitude: Some(39.75 + i as f64 * 1e-4),
+            phone: Some("+13035551234".to_string()),
+            website_url: Some(format!("https://example-{}.com", i)),
+            state: Some("Colorado".to_string()),
+            street: Some(format!("{} Larimer St", 1000 + i)),
+            ..Default::default()
+        })
+        .collect()
+}
+
+fn zip_result(count: usize) -> ZipResult {
+    ZipResult {
+        zip_code: "80205".to_string(),
+        brewery_count: count,
+        breweries: breweries(count),
+        cached: false,
+        stale: false,
+        expires_at: Some(1_700_086_400),
+        opening_hours: None,
+    }
+}
+
+fn serialize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("serialize");
+    for count in [20, 200, 1000] {
+        let result = zip_result(count);
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(BenchmarkId::new("canonical", count), &result, |b, result| {
+            b.iter(|| canonical_json::to_vec(black_box(result)).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("versioned", count), &result, |b, result| {
+            b.iter(|| canonical_json::to_vec(&versioned!(black_box(result))).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn filter(c: &mut Criterion) {
+    let rows = breweries(1000);
+    let filter = Filter::parse("brewery_type == 'micro' && city != 'Portland'").unwrap();
+    c.bench_function("filter/1000", |b| {
+        b.iter(|| {
+            let mut rows = rows.clone();
+            filter.retain(&mut rows).unwrap();
+            rows
+        })
+    });
+}
+
+criterion_group!(benches, serialize, filter);
+criterion_main!(benches);
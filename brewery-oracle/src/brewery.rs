@@ -0,0 +1,160 @@
+use crate::moderation::ModerationFlag;
+use crate::region::{self, Region};
+use crate::website::WebsiteStatus;
+use anyhow::Result;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::cell::{Cell, RefCell};
+use wavs_component_common::{contact, dry_run::Plan, replay, warn};
+use wavs_wasi_utils::http::http_request_get;
+use wstd::{future::FutureExt, http::HeaderValue, time::Duration};
+
+pub const API_URL: &str = "https://api.openbrewerydb.org/v1/breweries";
+const DEFAULT_API_TIMEOUT_SECS: u64 = 10;
+
+thread_local! {
+    // position in api_urls() of the mirror that answered last; earlier ones failed this run
+    static PREFERRED: Cell<usize> = const { Cell::new(0) };
+    static USED: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct Brewery {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub brewery_type: Option<String>,
+    pub address_1: Option<String>,
+    pub address_2: Option<String>,
+    pub address_3: Option<String>,
+    pub city: Option<String>,
+    pub state_province: Option<String>,
+    pub postal_code: Option<String>,
+    pub country: Option<String>,
+    pub longitude: Option<f64>,
+    pub latitude: Option<f64>,
+    pub phone: Option<String>,
+    pub website_url: Option<String>,
+    pub state: Option<String>,
+    pub street: Option<String>,
+    pub invalid_fields: Vec<String>,
+    // ISO codes and names for country and state_province; absent when the country is unknown
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<Region>,
+    // set when WAVS_ENV_BREWERY_MODERATION=flag and the listing was flagged
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moderation: Option<ModerationFlag>,
+    // set when WAVS_ENV_BREWERY_WEBSITE_CHECK=true and the listing has a website
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub website_status: Option<WebsiteStatus>,
+}
+
+impl Brewery {
+    // Contracts and frontends render contact fields verbatim, so values that can't be
+    // normalized are dropped and listed in invalid_fields. Phone numbers are read with the
+    // resolved country, which also knows "USA" or "England".
+    pub fn normalize(&mut self) {
+        let state_province = self.state_province.as_deref().or(self.state.as_deref());
+        self.region = region::resolve(self.country.as_deref(), state_province);
+        let country = self.region.as_ref().map(|r| r.country.as_str()).or(self.country.as_deref());
+        if let Some(phone) = self.phone.take().filter(|p| !p.trim().is_empty()) {
+            self.phone = contact::normalize_phone(&phone, country);
+            if self.phone.is_none() {
+                self.invalid_fields.push("phone".to_string());
+            }
+        }
+        if let Some(website) = self.website_url.take().filter(|w| !w.trim().is_empty()) {
+            self.website_url = contact::normalize_website(&website);
+            if self.website_url.is_none() {
+                self.invalid_fields.push("website_url".to_string());
+            }
+        }
+    }
+}
+
+// OpenBreweryDB base URLs in the order they are tried: WAVS_ENV_BREWERY_API_URLS, a
+// comma-separated list that may name self-hosted mirrors, or just the public API
+fn api_urls() -> Vec<String> {
+    let configured = std::env::var("WAVS_ENV_BREWERY_API_URLS").unwrap_or_default();
+    let urls: Vec<String> = configured
+        .split(',')
+        .map(|url| url.trim().trim_end_matches('/'))
+        .filter(|url| !url.is_empty())
+        .map(str::to_string)
+        .collect();
+    if urls.is_empty() {
+        vec![API_URL.to_string()]
+    } else {
+        urls
+    }
+}
+
+fn api_timeout_secs() -> u64 {
+    std::env::var("WAVS_ENV_BREWERY_API_TIMEOUT_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .filter(|secs| *secs > 0)
+        .unwrap_or(DEFAULT_API_TIMEOUT_SECS)
+}
+
+// GETs `path` (e.g. "/search?query=...") from the first mirror that answers within the
+// timeout. Failover sticks for the rest of the run, so pages of one query don't keep waiting
+// on a mirror that is down.
+pub async fn get_json<T: DeserializeOwned>(path: &str) -> Result<T> {
+    let urls = api_urls();
+    let timeout_secs = api_timeout_secs();
+    let first = PREFERRED.with(Cell::get).min(urls.len() - 1);
+    let mut failures = Vec::new();
+    for (index, base) in urls.iter().enumerate().skip(first) {
+        let url = format!("{}{}", base, path);
+        let failure = match fetch_json(&url).timeout(Duration::from_secs(timeout_secs)).await {
+            Ok(Ok(value)) => {
+                PREFERRED.with(|preferred| preferred.set(index));
+                USED.with(|used| *used.borrow_mut() = Some(base.clone()));
+                return Ok(value);
+            }
+            Ok(Err(e)) => e.to_string(),
+            Err(_) => format!("no response within {}s", timeout_secs),
+        };
+        if index + 1 < urls.len() {
+            warn!("Brewery API {} failed, trying the next mirror: {}", base, failure);
+        }
+        failures.push(format!("{}: {}", base, failure));
+    }
+    Err(anyhow::anyhow!("Every brewery API endpoint failed ({})", failures.join("; ")))
+}
+
+// A dry run's `count` API requests. A mirror is only asked when the ones before it failed, so
+// each one is counted for all of them: the most a run that fails over makes.
+pub fn planned(mut plan: Plan, count: u64) -> Plan {
+    for url in api_urls() {
+        plan = plan.http(&url, count);
+    }
+    plan
+}
+
+// The base URL that served the run's latest API response; None when nothing was fetched,
+// e.g. for results served from cache
+pub fn used_endpoint() -> Option<String> {
+    USED.with(|used| used.borrow().clone())
+}
+
+async fn fetch_json<T: DeserializeOwned>(url: &str) -> Result<T> {
+    let mut req = http_request_get(url)?;
+    req.headers_mut().insert("Accept", HeaderValue::from_static("application/json"));
+    req.headers_mut().insert("Content-Type", HeaderValue::from_static("application/json"));
+    req.headers_mut().insert("User-Agent", HeaderValue::from_static("Mozilla/5.0"));
+    replay::fetch_json(req).await
+}
+
+pub fn encode_query_param(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            b' ' => "+".to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
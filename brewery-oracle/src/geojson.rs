@@ -0,0 +1,61 @@
+// GeoJSON (RFC 7946) output for CLI runs, so mapping frontends can load a result directly: a
+// FeatureCollection with one Point feature per brewery, the brewery's other fields as its
+// properties, and the result's own fields (counts, expiry, version) as foreign members.
+// Coordinates stay JSON numbers, which is why this doesn't go through canonical JSON; on-chain
+// payloads are canonical JSON as always.
+use serde_json::{Map, Value};
+use wavs_component_common::envelope;
+
+// Selected with the envelope's output_format option; None means plain JSON
+pub fn requested(options: &envelope::Options) -> Result<bool, String> {
+    match options.output_format.as_deref().map(str::trim) {
+        None | Some("json") => Ok(false),
+        Some("geojson") => Ok(true),
+        Some(other) => Err(format!("Unknown output_format '{}'; expected json or geojson", other)),
+    }
+}
+
+// `result` is a serialized zip, search or country result, with its breweries under `rows_key`
+pub fn feature_collection(result: Value, rows_key: &str) -> Result<Value, String> {
+    let Value::Object(mut members) = result else {
+        return Err("GeoJSON output needs a JSON object result".to_string());
+    };
+    let rows = match members.remove(rows_key) {
+        Some(Value::Array(rows)) => rows,
+        _ => return Err(format!("Result has no {} to map", rows_key)),
+    };
+    let features = rows.into_iter().map(feature).collect::<Result<Vec<_>, _>>()?;
+    members.insert("type".to_string(), Value::from("FeatureCollection"));
+    members.insert("features".to_string(), Value::Array(features));
+    Ok(Value::Object(members))
+}
+
+// Breweries without usable coordinates keep their feature with a null geometry, which RFC 7946
+// allows for unlocated features, so the count still matches the result's
+fn feature(row: Value) -> Result<Value, String> {
+    let Value::Object(mut properties) = row else {
+        return Err("Brewery rows must be JSON objects".to_string());
+    };
+    let longitude = properties.remove("longitude").and_then(|v| v.as_f64());
+    let latitude = properties.remove("latitude").and_then(|v| v.as_f64());
+    let geometry = match (longitude, latitude) {
+        (Some(lon), Some(lat))
+            if (-180.0..=180.0).contains(&lon) && (-90.0..=90.0).contains(&lat) =>
+        {
+            let mut point = Map::new();
+            point.insert("type".to_string(), Value::from("Point"));
+            // GeoJSON positions are [longitude, latitude]
+            point.insert("coordinates".to_string(), Value::from(vec![lon, lat]));
+            Value::Object(point)
+        }
+        _ => Value::Null,
+    };
+    let mut feature = Map::new();
+    feature.insert("type".to_string(), Value::from("Feature"));
+    if let Some(id) = properties.get("id").filter(|id| id.is_string()) {
+        feature.insert("id".to_string(), id.clone());
+    }
+    feature.insert("geometry".to_string(), geometry);
+    feature.insert("properties".to_string(), Value::Object(properties));
+    Ok(Value::Object(feature))
+}
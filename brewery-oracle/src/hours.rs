@@ -0,0 +1,184 @@
+// Opening-hours enrichment: the top result's website is fetched and the model extracts the
+// weekly hours into a fixed schema. Listings from OpenBreweryDB carry no hours at all.
+use crate::brewery::Brewery;
+use serde::{Deserialize, Serialize};
+use wavs_component_common::{info, llm, replay};
+use wavs_wasi_utils::http::http_request_get;
+use wstd::http::HeaderValue;
+
+const DEFAULT_MODEL: &str = "gpt-4o-mini";
+// Keeps the prompt small; opening hours are almost always in the first screens of text
+const MAX_PAGE_CHARS: usize = 12_000;
+const DAYS: [&str; 7] =
+    ["monday", "tuesday", "wednesday", "thursday", "friday", "saturday", "sunday"];
+const INSTRUCTION: &str = "You extract the regular weekly opening hours of a brewery from the text of its website. Report only hours stated on the page; never guess. Use 24-hour HH:MM times. Set found to false when the page does not state opening hours, and leave days out that the page does not mention. A day with several openings gets one entry per opening.";
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DayHours {
+    day: String,
+    // both null when the page says the brewery is closed that day
+    opens: Option<String>,
+    closes: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OpeningHours {
+    brewery_id: Option<String>,
+    source_url: String,
+    found: bool,
+    hours: Vec<DayHours>,
+    // free-form remarks from the page, e.g. seasonal or holiday hours
+    notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Extraction {
+    found: bool,
+    hours: Vec<DayHours>,
+    notes: Option<String>,
+}
+
+pub fn enabled() -> bool {
+    matches!(std::env::var("WAVS_ENV_BREWERY_HOURS_ENRICHMENT").as_deref(), Ok("1") | Ok("true"))
+}
+
+// Enriches the first brewery that has a website
+pub async fn top_result_hours(breweries: &[Brewery]) -> Result<Option<OpeningHours>, String> {
+    let Some((brewery, url)) =
+        breweries.iter().find_map(|b| b.website_url.as_ref().map(|url| (b, url)))
+    else {
+        return Ok(None);
+    };
+    info!("Extracting opening hours from {}", url);
+
+    let mut req = http_request_get(url).map_err(|e| format!("Failed to create request: {}", e))?;
+    req.headers_mut().insert("Accept", HeaderValue::from_static("text/html"));
+    req.headers_mut().insert("User-Agent", HeaderValue::from_static("Mozilla/5.0"));
+    let html = replay::fetch_string(req)
+        .await
+        .map_err(|e| format!("Failed to fetch brewery website {}: {}", url, e))?;
+    let text = page_text(&html);
+
+    let model =
+        llm::resolve_model(std::env::var("WAVS_ENV_BREWERY_HOURS_MODEL").ok(), DEFAULT_MODEL)?;
+    let name = brewery.name.as_deref().unwrap_or("the brewery");
+    let messages = [
+        llm::Message::system(INSTRUCTION),
+        llm::Message::user(format!("Website of {}:\n\n{}", name, text)),
+    ];
+    let value = llm::chat_json(&model, &messages, "opening_hours", schema()).await?;
+    let extraction: Extraction = serde_json::from_value(value)
+        .map_err(|e| format!("Invalid opening hours extraction: {}", e))?;
+
+    Ok(Some(OpeningHours {
+        brewery_id: brewery.id.clone(),
+        source_url: url.clone(),
+        found: extraction.found && !extraction.hours.is_empty(),
+        hours: validated(extraction.hours)?,
+        notes: extraction.notes.filter(|n| !n.trim().is_empty()),
+    }))
+}
+
+fn schema() -> serde_json::Value {
+    serde_json::json!({
+        "type": "object",
+        "properties": {
+            "found": { "type": "boolean" },
+            "hours": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "day": { "type": "string", "enum": DAYS },
+                        "opens": { "type": ["string", "null"] },
+                        "closes": { "type": ["string", "null"] }
+                    },
+                    "required": ["day", "opens", "closes"],
+                    "additionalProperties": false
+                }
+            },
+            "notes": { "type": ["string", "null"] }
+        },
+        "required": ["found", "hours", "notes"],
+        "additionalProperties": false
+    })
+}
+
+// The schema fixes the shape but not the time format, so malformed times are rejected here
+// rather than passed on to consumers
+fn validated(mut hours: Vec<DayHours>) -> Result<Vec<DayHours>, String> {
+    for entry in &hours {
+        let valid = match (&entry.opens, &entry.closes) {
+            (Some(opens), Some(closes)) => is_time(opens) && is_time(closes),
+            (None, None) => true,
+            _ => false,
+        };
+        if !valid || !DAYS.contains(&entry.day.as_str()) {
+            return Err(format!("Invalid opening hours for {}", entry.day));
+        }
+    }
+    hours.sort_by_key(|h| (DAYS.iter().position(|d| *d == h.day), h.opens.clone()));
+    Ok(hours)
+}
+
+fn is_time(value: &str) -> bool {
+    let Some((hours, minutes)) = value.split_once(':') else {
+        return false;
+    };
+    let in_range = |part: &str, max: u32| {
+        part.len() == 2 && part.parse::<u32>().map(|v| v <= max).unwrap_or(false)
+    };
+    // 24:00 is allowed as a closing time
+    (in_range(hours, 23) && in_range(minutes, 59)) || value == "24:00"
+}
+
+// Visible text of an HTML page: scripts, styles and tags removed, whitespace collapsed
+fn page_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        text.push(' ');
+        let tag = &rest[start..];
+        let lower = tag.get(..8).unwrap_or(tag).to_ascii_lowercase();
+        let skip_until = if lower.starts_with("<script") {
+            Some("</script>")
+        } else if lower.starts_with("<style") {
+            Some("</style>")
+        } else {
+            None
+        };
+        let end = match skip_until {
+            Some(closing) => find_ignore_case(tag, closing).map(|i| i + closing.len()),
+            None => tag.find('>').map(|i| i + 1),
+        };
+        match end {
+            Some(end) => rest = &tag[end..],
+            None => {
+                rest = "";
+                break;
+            }
+        }
+    }
+    text.push_str(rest);
+
+    let text = decode_entities(&text);
+    let collapsed = text.split_whitespace().collect::<Vec<&str>>().join(" ");
+    collapsed.chars().take(MAX_PAGE_CHARS).collect()
+}
+
+fn find_ignore_case(haystack: &str, needle: &str) -> Option<usize> {
+    haystack.to_ascii_lowercase().find(needle)
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&nbsp;", " ")
+        .replace("&ndash;", "-")
+        .replace("&mdash;", "-")
+        .replace("&#8211;", "-")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
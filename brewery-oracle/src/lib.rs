@@ -0,0 +1,334 @@
+mod brewery;
+mod country;
+mod geojson;
+mod hours;
+mod itinerary;
+mod mode;
+mod moderation;
+mod nearest;
+mod region;
+mod search;
+mod stats;
+mod trigger;
+mod website;
+mod zip;
+use trigger::{encode_cli_output, encode_trigger_output, Codec, Destination};
+pub mod bindings; // Never edit bindings.rs!
+use crate::bindings::{export, Guest, TriggerAction, WasmResponse};
+use alloy_sol_types::SolCall;
+use anyhow::Result;
+use wavs_component_common::{
+    batch, commit_reveal, decode, dry_run, envelope,
+    filter::Filter,
+    info, json_patch,
+    output::{CliOutput, EthereumOutput},
+    run, selector, timing, truncation, versioned, warn,
+};
+use wstd::runtime::block_on;
+
+pub use brewery::Brewery;
+pub use country::CountryResult;
+pub use nearest::NearestResult;
+pub use region::Region;
+pub use search::{SearchMatch, SearchResult};
+pub use zip::ZipResult;
+
+// Brewery listings change rarely, so results stay valid for a day
+const DEFAULT_RESULT_TTL_SECS: u64 = 86_400;
+
+enum Query {
+    Zip(String),
+    ZipBatch(Vec<String>),
+    Itinerary { zip_code: String, preferences: String, max_stops: u8 },
+    Nearest { origins: Vec<String>, per_origin: u32 },
+    Search { query: String, max_results: u32 },
+    Country { code: String, max_results: u32 },
+    Aggregate { state: Option<String>, postal_prefix: Option<String> },
+}
+
+struct Component;
+export!(Component with_types_in bindings);
+
+impl Guest for Component {
+    fn run(action: TriggerAction) -> std::result::Result<Option<WasmResponse>, String> {
+        run::run(Codec, action.data, process)
+    }
+}
+
+fn process(
+    trigger_id: u64,
+    req: Vec<u8>,
+    dest: Destination,
+    options: envelope::Options,
+) -> Result<Option<WasmResponse>, String> {
+    options.check_supported(&[
+        "filter",
+        "output_format",
+        "encrypt_to",
+        "commit",
+        "reveal",
+        "diff",
+        "dry_run",
+    ])?;
+    let on_chain = matches!(dest, Destination::Ethereum);
+    if let Some(reveal) = commit_reveal::reveal(&options, mode::COMPONENT_NAME, on_chain)? {
+        return Ok(Some(encode_trigger_output(trigger_id, reveal)));
+    }
+    let filter = options.row_filter()?;
+    let query = decode_query(&req, mode::input()?)?;
+
+    // Aggregates have no rows left to filter or map by the time they are returned
+    let rows_key = match query {
+        Query::Zip(_) => zip::ROWS_KEY,
+        // each item keeps its own breweries, so there is no single row list to map
+        Query::ZipBatch(_) => "",
+        // stops carry no coordinates, and the listings they came from aren't returned
+        Query::Itinerary { .. } => "",
+        // each origin keeps its own nearest breweries
+        Query::Nearest { .. } => "",
+        Query::Search { .. } | Query::Country { .. } => "results",
+        Query::Aggregate { .. } => {
+            options.check_supported(&["encrypt_to", "commit", "reveal", "diff", "dry_run"])?;
+            ""
+        }
+    };
+    let as_geojson = geojson::requested(&options)?;
+    if as_geojson && matches!(dest, Destination::Ethereum) {
+        return Err("GeoJSON output is only available for CLI runs".to_string());
+    }
+    if as_geojson && rows_key.is_empty() {
+        return Err("GeoJSON output is not available for this query".to_string());
+    }
+    if as_geojson && options.diff {
+        return Err("GeoJSON output can't be combined with diff".to_string());
+    }
+    // The list a payload limit cuts, most relevant entries first
+    let list_key = match query {
+        Query::ZipBatch(_) => "items",
+        Query::Nearest { .. } => "origins",
+        _ => rows_key,
+    };
+
+    timing::end_phase("decode");
+    if options.dry_run {
+        let payload = plan(&query, filter.as_ref())?.output(on_chain, options.json_format())?;
+        return payload.map(encode_cli_output).transpose();
+    }
+    let mut value = block_on(async move {
+        match query {
+            Query::Zip(zip_code) => zip_lookup(&zip_code, filter.as_ref()).await,
+            Query::ZipBatch(zip_codes) => {
+                let filter = filter.as_ref();
+                let batch =
+                    batch::run(
+                        zip_codes,
+                        |zip_code| async move { zip_lookup(&zip_code, filter).await },
+                    )
+                    .await?;
+                serde_json::to_value(&batch).map_err(|e| e.to_string())
+            }
+            Query::Itinerary { zip_code, preferences, max_stops } => {
+                let result =
+                    itinerary::plan(&zip_code, &preferences, max_stops, filter.as_ref()).await?;
+                serde_json::to_value(&result).map_err(|e| e.to_string())
+            }
+            Query::Nearest { origins, per_origin } => {
+                let result =
+                    nearest::nearest_breweries(&origins, per_origin, filter.as_ref()).await?;
+                serde_json::to_value(&result).map_err(|e| e.to_string())
+            }
+            Query::Search { query, max_results } => {
+                let mut result =
+                    search::search_breweries(&query, max_results, filter.as_ref()).await?;
+                moderation::moderate(&mut result.results, |m| &mut m.brewery).await?;
+                website::check(&mut result.results, |m| &mut m.brewery).await;
+                result.result_count = result.results.len();
+                serde_json::to_value(&result).map_err(|e| e.to_string())
+            }
+            Query::Country { code, max_results } => {
+                let mut result =
+                    country::country_breweries(&code, max_results, filter.as_ref()).await?;
+                moderation::moderate(&mut result.results, |b| b).await?;
+                website::check(&mut result.results, |b| b).await;
+                result.result_count = result.results.len();
+                serde_json::to_value(&result).map_err(|e| e.to_string())
+            }
+            Query::Aggregate { state, postal_prefix } => {
+                info!(
+                    "Aggregating breweries for state {:?}, postal prefix {:?}",
+                    state, postal_prefix
+                );
+                let stats = stats::aggregate(state, postal_prefix).await?;
+                serde_json::to_value(&stats).map_err(|e| e.to_string())
+            }
+        }
+    })?;
+    // Which mirror answered; it only differs between operators when one of them failed over
+    if let (Some(endpoint), Some(result)) = (brewery::used_endpoint(), value.as_object_mut()) {
+        result.insert("api_endpoint".to_string(), endpoint.into());
+    }
+    let mut value = json_patch::apply(&options, mode::COMPONENT_NAME, &req, value)?;
+    truncation::apply(&mut value, list_key)?;
+    timing::end_phase("upstream");
+
+    let data = EthereumOutput::json(&versioned!(mode::COMPONENT_NAME, &value))?;
+    zip::annotate(&mut value);
+    run::respond(Codec, trigger_id, data, dest, &options, || {
+        let result = serde_json::to_value(versioned!(mode::COMPONENT_NAME, &value))
+            .map_err(|e| e.to_string())?;
+        if !as_geojson {
+            return Ok(CliOutput::new(result));
+        }
+        let collection = geojson::feature_collection(result, rows_key)?;
+        Ok(CliOutput::new(collection).plain_json())
+    })
+}
+
+// The API requests each query makes. Results hold however many listings the API returns, so
+// there is no gas estimate.
+fn plan(query: &Query, filter: Option<&Filter>) -> Result<dry_run::Plan, String> {
+    let new = |input: serde_json::Value| dry_run::Plan::new(mode::COMPONENT_NAME, &input);
+    match query {
+        Query::Zip(zip_code) => zip_plan(new(serde_json::json!({ "zip_code": zip_code }))?, 1),
+        Query::ZipBatch(zip_codes) => {
+            batch::check_len(zip_codes.len())?;
+            let plan = new(serde_json::json!({ "zip_codes": zip_codes }))?;
+            zip_plan(plan, zip_codes.len() as u64)
+        }
+        Query::Itinerary { zip_code, preferences, max_stops } => {
+            let plan = new(serde_json::json!({
+                "zip_code": zip_code,
+                "preferences": preferences,
+                "max_stops": max_stops,
+            }))?;
+            itinerary::planned(plan, preferences, *max_stops)
+        }
+        Query::Nearest { origins, per_origin } => {
+            let plan = new(serde_json::json!({ "origins": origins, "per_origin": per_origin }))?;
+            moderation::planned(nearest::planned(plan, origins)?)
+        }
+        Query::Search { query, max_results } => {
+            let plan = new(serde_json::json!({ "query": query, "max_results": max_results }))?;
+            moderation::planned(website::planned(search::planned(plan, query)?))
+        }
+        Query::Country { code, max_results } => {
+            let plan = new(serde_json::json!({ "country": code, "max_results": max_results }))?;
+            moderation::planned(website::planned(country::planned(
+                plan,
+                code,
+                *max_results,
+                filter,
+            )?))
+        }
+        Query::Aggregate { state, postal_prefix } => {
+            let plan = new(serde_json::json!({ "state": state, "postal_prefix": postal_prefix }))?;
+            stats::planned(plan, state.as_deref(), postal_prefix.as_deref())
+        }
+    }
+}
+
+// One listing request per zip code, the most it takes: fresh cache entries need none
+fn zip_plan(plan: dry_run::Plan, lookups: u64) -> Result<dry_run::Plan, String> {
+    let plan = moderation::planned(website::planned(brewery::planned(plan, lookups)))?;
+    if !hours::enabled() {
+        return Ok(plan);
+    }
+    Ok(plan.note("Opening hours enrichment fetches the top brewery's website and asks the model"))
+}
+
+async fn zip_lookup(zip_code: &str, filter: Option<&Filter>) -> Result<serde_json::Value, String> {
+    info!("Looking up breweries for zip code: {}", zip_code);
+    let mut result = zip::find_breweries(zip_code).await?;
+    if let Some(filter) = filter {
+        filter.retain(&mut result.breweries)?;
+    }
+    // Before enrichment, so a stripped brewery's website is never fetched
+    moderation::moderate(&mut result.breweries, |b| b).await?;
+    website::check(&mut result.breweries, |b| b).await;
+    result.brewery_count = result.breweries.len();
+    if hours::enabled() {
+        // Hours are a best-effort extra; the listings are still worth submitting
+        result.opening_hours = match hours::top_result_hours(&result.breweries).await {
+            Ok(hours) => hours,
+            Err(e) => {
+                warn!("Opening hours enrichment failed: {}", e);
+                None
+            }
+        };
+    }
+    serde_json::to_value(&result).map_err(|e| e.to_string())
+}
+
+// Calldata decode_call accepts, for WAVS_ENV_STRICT_SELECTORS
+const TRIGGER_CALLS: [selector::Expected; 8] = {
+    use trigger::solidity::{
+        aggregateBreweriesCall, byCountryCall, findBreweriesBatchCall, findBreweriesCall,
+        lookupCall, nearestBreweriesCall, planItineraryCall, searchCall,
+    };
+    [
+        selector::Expected::of::<findBreweriesCall>(),
+        selector::Expected::of::<findBreweriesBatchCall>(),
+        selector::Expected::of::<lookupCall>(),
+        selector::Expected::of::<planItineraryCall>(),
+        selector::Expected::of::<nearestBreweriesCall>(),
+        selector::Expected::of::<searchCall>(),
+        selector::Expected::of::<byCountryCall>(),
+        selector::Expected::of::<aggregateBreweriesCall>(),
+    ]
+};
+
+// Decode trigger data inline - handles hex string input
+fn decode_query(req: &[u8], input: mode::Input) -> Result<Query, String> {
+    let hex_data = decode::input_bytes(req)?;
+    if input == mode::Input::String && selector::strict() {
+        return Err(
+            "WAVS_ENV_BREWERY_INPUT=string takes no calldata, so it can't be combined with \
+                    WAVS_ENV_STRICT_SELECTORS"
+                .to_string(),
+        );
+    }
+    selector::check(&hex_data, &TRIGGER_CALLS)?;
+    if input != mode::Input::String {
+        if let Some(query) = decode_call(&hex_data) {
+            return Ok(query);
+        }
+        if input == mode::Input::Call {
+            return Err("Input is not a call to one of the brewery trigger functions".to_string());
+        }
+    }
+    let zip_code = decode::abi_string(&hex_data)?;
+    Ok(Query::Zip(zip_code))
+}
+
+fn decode_call(data: &[u8]) -> Option<Query> {
+    use trigger::solidity::{
+        aggregateBreweriesCall, byCountryCall, findBreweriesBatchCall, findBreweriesCall,
+        lookupCall, nearestBreweriesCall, planItineraryCall, searchCall,
+    };
+    if let Ok(call) = findBreweriesCall::abi_decode(data) {
+        Some(Query::Zip(call.zipCode))
+    } else if let Ok(call) = findBreweriesBatchCall::abi_decode(data) {
+        Some(Query::ZipBatch(call.zipCodes))
+    } else if let Ok(call) = lookupCall::abi_decode(data) {
+        Some(Query::Zip(call.zip))
+    } else if let Ok(call) = planItineraryCall::abi_decode(data) {
+        Some(Query::Itinerary {
+            zip_code: call.zipCode,
+            preferences: call.preferences,
+            max_stops: call.maxStops,
+        })
+    } else if let Ok(call) = nearestBreweriesCall::abi_decode(data) {
+        Some(Query::Nearest { origins: call.origins, per_origin: call.perOrigin })
+    } else if let Ok(call) = searchCall::abi_decode(data) {
+        Some(Query::Search { query: call.query, max_results: call.maxResults })
+    } else if let Ok(call) = byCountryCall::abi_decode(data) {
+        Some(Query::Country { code: call.country, max_results: call.maxResults })
+    } else if let Ok(call) = aggregateBreweriesCall::abi_decode(data) {
+        Some(Query::Aggregate {
+            state: Some(call.state).filter(|s| !s.trim().is_empty()),
+            postal_prefix: Some(call.postalPrefix).filter(|p| !p.trim().is_empty()),
+        })
+    } else {
+        None
+    }
+}
@@ -0,0 +1,73 @@
+// Mode flags, read from the environment so one build covers what used to be separate brewery
+// components. The defaults behave like brewery-finder; the `lookup-compat` feature, which the
+// brewery-lookup crate enables, keeps that component's three-brewery zip lookups and its zip
+// output shape (see zip.rs), and `finder-compat` keeps brewery-finder's name.
+#[cfg(not(feature = "lookup-compat"))]
+const DEFAULT_ZIP_RESULTS: u32 = 20;
+#[cfg(feature = "lookup-compat")]
+const DEFAULT_ZIP_RESULTS: u32 = 3;
+// Reported as `component_name` and used to key commitments, dead letters and diffs, so
+// brewery-finder and brewery-lookup deployments keep the name they already report. If feature
+// unification turns on both features, lookup-compat wins, as it does for the zip output shape.
+#[cfg(not(any(feature = "lookup-compat", feature = "finder-compat")))]
+pub const COMPONENT_NAME: &str = env!("CARGO_PKG_NAME");
+#[cfg(all(feature = "finder-compat", not(feature = "lookup-compat")))]
+pub const COMPONENT_NAME: &str = "brewery-finder";
+#[cfg(feature = "lookup-compat")]
+pub const COMPONENT_NAME: &str = "brewery-lookup";
+// Largest page size OpenBreweryDB serves
+pub const MAX_PAGE_SIZE: u32 = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Input {
+    // function calls first, then a bare ABI string as a zip code
+    Auto,
+    // only a bare ABI string zip code, for triggers that never send calldata
+    String,
+    // only the trigger functions' calldata; zip codes come as findBreweries or lookup calls
+    Call,
+}
+
+pub fn input() -> Result<Input, String> {
+    match std::env::var("WAVS_ENV_BREWERY_INPUT").as_deref().map(str::trim) {
+        Err(_) | Ok("") | Ok("auto") => Ok(Input::Auto),
+        Ok("string") => Ok(Input::String),
+        Ok("call") => Ok(Input::Call),
+        Ok(other) => {
+            Err(format!("WAVS_ENV_BREWERY_INPUT must be auto, string or call, not '{}'", other))
+        }
+    }
+}
+
+// Breweries requested per zip code lookup
+pub fn zip_results() -> u32 {
+    std::env::var("WAVS_ENV_BREWERY_MAX_RESULTS")
+        .ok()
+        .and_then(|v| v.parse::<u32>().ok())
+        .unwrap_or(DEFAULT_ZIP_RESULTS)
+        .clamp(1, MAX_PAGE_SIZE)
+}
+
+// expires_at for data fetched at `fetched_at`, or None when WAVS_ENV_BREWERY_INCLUDE_TIMESTAMPS
+// is off. Operators fetch at slightly different times, so leaving it out is what lets their
+// payloads for the same query match byte for byte.
+pub fn expires_at(fetched_at: u64, default_ttl_secs: u64) -> Option<u64> {
+    let include = !matches!(
+        std::env::var("WAVS_ENV_BREWERY_INCLUDE_TIMESTAMPS").as_deref(),
+        Ok("0") | Ok("false")
+    );
+    include.then(|| wavs_component_common::freshness::expires_at(fetched_at, default_ttl_secs))
+}
+
+// 0 selects the default cap; anything above the hard maximum (which `env` can lower) is clamped
+pub fn result_cap(max_results: u32, default: u32, hard_max: u32, env: &str) -> u32 {
+    let max = std::env::var(env)
+        .ok()
+        .and_then(|v| v.parse::<u32>().ok())
+        .unwrap_or(hard_max)
+        .clamp(1, hard_max);
+    match max_results {
+        0 => default.min(max),
+        n => n.min(max),
+    }
+}
@@ -0,0 +1,258 @@
+// Content moderation for listings before they are submitted, since on-chain data can't be taken
+// back. Rows are checked against an embedded word list (data/moderation_words.txt); with
+// WAVS_ENV_BREWERY_MODERATION_LLM the rows it lets through are reviewed by the model as well, in
+// one batched request. WAVS_ENV_BREWERY_MODERATION picks what happens to a flagged row: `flag`
+// keeps it and attaches a `moderation` object, `strip` drops it.
+use crate::brewery::Brewery;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use wavs_component_common::{dry_run::Plan, info, llm};
+
+const WORD_LIST: &str = include_str!("../data/moderation_words.txt");
+const SUFFIXES: [&str; 7] = ["s", "es", "ed", "er", "ers", "ing", "in"];
+const DEFAULT_MODEL: &str = "gpt-4o-mini";
+const CHARS_PER_TOKEN: usize = 4;
+const FIELDS: [&str; 6] = ["name", "street", "address_1", "address_2", "address_3", "city"];
+const CATEGORIES: [&str; 5] = ["profanity", "slur", "sexual", "harassment", "hate"];
+const INSTRUCTION: &str = "You review brewery listings before they are published permanently. Each listing has an index and some text fields. Flag a field only when it contains profanity, a slur, sexual content, harassment or hateful content. Brewery names are often playful: puns, mild innuendo and words like hell, damn, devil or bastard are acceptable. Return an empty list when nothing should be flagged.";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mode {
+    Off,
+    Flag,
+    Strip,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ModerationFlag {
+    // word_list or llm
+    pub source: String,
+    pub fields: Vec<String>,
+    // one of CATEGORIES for LLM flags; the word list doesn't tell them apart. The matched text
+    // itself is never repeated here.
+    pub category: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Review {
+    flagged: Vec<Flagged>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Flagged {
+    index: usize,
+    field: String,
+    category: String,
+}
+
+pub fn mode() -> Result<Mode, String> {
+    match std::env::var("WAVS_ENV_BREWERY_MODERATION").as_deref().map(str::trim) {
+        Err(_) | Ok("") | Ok("off") => Ok(Mode::Off),
+        Ok("flag") => Ok(Mode::Flag),
+        Ok("strip") => Ok(Mode::Strip),
+        Ok(other) => {
+            Err(format!("WAVS_ENV_BREWERY_MODERATION must be off, flag or strip, not '{}'", other))
+        }
+    }
+}
+
+fn llm_enabled() -> bool {
+    matches!(std::env::var("WAVS_ENV_BREWERY_MODERATION_LLM").as_deref(), Ok("1") | Ok("true"))
+}
+
+// The word list needs no request; the model's review depends on how many rows come back
+pub fn planned(plan: Plan) -> Result<Plan, String> {
+    if mode()? == Mode::Off || !llm_enabled() {
+        return Ok(plan);
+    }
+    Ok(plan.note("Listings that pass the word list are also reviewed by the model, in batches"))
+}
+
+// `brewery` picks the listing out of a result row. A failed LLM review fails the run rather
+// than letting unreviewed rows through.
+pub async fn moderate<T>(
+    rows: &mut Vec<T>,
+    brewery: fn(&mut T) -> &mut Brewery,
+) -> Result<(), String> {
+    let mode = mode()?;
+    if mode == Mode::Off || rows.is_empty() {
+        return Ok(());
+    }
+
+    let terms = listed_terms();
+    let mut flags: Vec<Option<ModerationFlag>> =
+        rows.iter_mut().map(|row| word_list_flag(brewery(row), &terms)).collect();
+
+    if llm_enabled() {
+        let listings: Vec<Value> = rows
+            .iter_mut()
+            .enumerate()
+            .filter(|(i, _)| flags[*i].is_none())
+            .map(|(i, row)| listing(i, brewery(row)))
+            .collect();
+        for batch in batches(listings)? {
+            for (index, flag) in review(&batch).await? {
+                if let Some(slot @ None) = flags.get_mut(index) {
+                    *slot = Some(flag);
+                }
+            }
+        }
+    }
+
+    let flagged = flags.iter().filter(|f| f.is_some()).count();
+    if flagged > 0 {
+        info!("Moderation flagged {} of {} breweries", flagged, rows.len());
+    }
+    match mode {
+        Mode::Strip => {
+            let mut flags = flags.into_iter();
+            rows.retain(|_| flags.next().flatten().is_none());
+        }
+        _ => {
+            for (row, flag) in rows.iter_mut().zip(flags) {
+                brewery(row).moderation = flag;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn text_fields(brewery: &Brewery) -> [(&'static str, Option<&str>); 6] {
+    [
+        ("name", brewery.name.as_deref()),
+        ("street", brewery.street.as_deref()),
+        ("address_1", brewery.address_1.as_deref()),
+        ("address_2", brewery.address_2.as_deref()),
+        ("address_3", brewery.address_3.as_deref()),
+        ("city", brewery.city.as_deref()),
+    ]
+}
+
+fn listed_terms() -> Vec<String> {
+    let extra = std::env::var("WAVS_ENV_BREWERY_MODERATION_WORDS").unwrap_or_default();
+    WORD_LIST
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .chain(extra.split(','))
+        .map(|term| term.trim().to_lowercase())
+        .filter(|term| !term.is_empty())
+        .collect()
+}
+
+fn word_list_flag(brewery: &Brewery, terms: &[String]) -> Option<ModerationFlag> {
+    let fields: Vec<String> = text_fields(brewery)
+        .iter()
+        .filter(|(_, text)| text.is_some_and(|text| contains_listed(text, terms)))
+        .map(|(field, _)| field.to_string())
+        .collect();
+    (!fields.is_empty()).then(|| ModerationFlag {
+        source: "word_list".to_string(),
+        fields,
+        category: None,
+    })
+}
+
+// Whole-word match after undoing common character swaps (sh1t, a$$), so terms inside longer
+// words don't match; compounds are left to the LLM pass
+fn contains_listed(text: &str, terms: &[String]) -> bool {
+    let normalized: String = text
+        .to_lowercase()
+        .chars()
+        .map(|c| match c {
+            '0' => 'o',
+            '1' => 'i',
+            '3' => 'e',
+            '4' | '@' => 'a',
+            '5' | '$' => 's',
+            '7' => 't',
+            c => c,
+        })
+        .collect();
+    normalized.split(|c: char| !c.is_alphanumeric()).any(|word| {
+        terms.iter().any(|term| {
+            word.strip_prefix(term.as_str())
+                .is_some_and(|rest| rest.is_empty() || SUFFIXES.contains(&rest))
+        })
+    })
+}
+
+fn listing(index: usize, brewery: &Brewery) -> Value {
+    let mut listing = serde_json::Map::new();
+    listing.insert("index".to_string(), index.into());
+    for (field, text) in text_fields(brewery) {
+        if let Some(text) = text.filter(|t| !t.trim().is_empty()) {
+            listing.insert(field.to_string(), text.into());
+        }
+    }
+    Value::Object(listing)
+}
+
+// Listings normally go out in one request. On providers with a smaller context window
+// (llm::Capabilities) they are split so each request stays within half of it,
+// leaving room for the instruction and the answer.
+fn batches(listings: Vec<Value>) -> Result<Vec<Vec<Value>>, String> {
+    let max_chars = llm::capabilities()?.max_context_tokens / 2 * CHARS_PER_TOKEN;
+    let mut batches: Vec<Vec<Value>> = Vec::new();
+    let mut batch_chars = 0;
+    for listing in listings {
+        let chars = listing.to_string().len();
+        match batches.last_mut() {
+            Some(batch) if batch_chars + chars <= max_chars => batch.push(listing),
+            _ => {
+                batches.push(vec![listing]);
+                batch_chars = 0;
+            }
+        }
+        batch_chars += chars;
+    }
+    Ok(batches)
+}
+
+async fn review(listings: &[Value]) -> Result<Vec<(usize, ModerationFlag)>, String> {
+    let model =
+        llm::resolve_model(std::env::var("WAVS_ENV_BREWERY_MODERATION_MODEL").ok(), DEFAULT_MODEL)?;
+    let listings = serde_json::to_string(listings).map_err(|e| e.to_string())?;
+    let messages = [llm::Message::system(INSTRUCTION), llm::Message::user(listings)];
+    let value = llm::chat_json(&model, &messages, "moderation", schema())
+        .await
+        .map_err(|e| format!("Moderation review failed: {}", e))?;
+    let review: Review =
+        serde_json::from_value(value).map_err(|e| format!("Invalid moderation review: {}", e))?;
+
+    let mut flags: BTreeMap<usize, ModerationFlag> = BTreeMap::new();
+    for flagged in review.flagged {
+        let flag = flags.entry(flagged.index).or_insert_with(|| ModerationFlag {
+            source: "llm".to_string(),
+            fields: Vec::new(),
+            category: Some(flagged.category),
+        });
+        if !flag.fields.contains(&flagged.field) {
+            flag.fields.push(flagged.field);
+        }
+    }
+    Ok(flags.into_iter().collect())
+}
+
+fn schema() -> Value {
+    serde_json::json!({
+        "type": "object",
+        "properties": {
+            "flagged": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "index": { "type": "integer" },
+                        "field": { "type": "string", "enum": FIELDS },
+                        "category": { "type": "string", "enum": CATEGORIES }
+                    },
+                    "required": ["index", "field", "category"],
+                    "additionalProperties": false
+                }
+            }
+        },
+        "required": ["flagged"],
+        "additionalProperties": false
+    })
+}
@@ -0,0 +1,160 @@
+// Distance matrix mode: several origins, e.g. the stops of a delivery route, each get their K
+// nearest breweries from a single API request. OpenBreweryDB's `by_dist` sorts by distance from
+// one point, so the page is fetched around the origins' centroid and every origin's distances
+// are then computed here. Origins far apart from each other can be closer to breweries outside
+// that page than to the ones it holds; `candidate_count` shows how many there were to rank.
+use crate::brewery::{self, get_json, Brewery};
+use crate::{mode, moderation, DEFAULT_RESULT_TTL_SECS};
+use serde::{Deserialize, Serialize};
+use wavs_component_common::{dry_run::Plan, filter::Filter, freshness};
+
+const MAX_ORIGINS: usize = 25;
+const DEFAULT_PER_ORIGIN: u32 = 5;
+const MAX_PER_ORIGIN: u32 = 50;
+// Mean Earth radius of the haversine formula
+const EARTH_RADIUS_M: f64 = 6_371_008.8;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NearbyBrewery {
+    // great-circle distance from the origin, rounded to the metre
+    pub distance_m: u64,
+    #[serde(flatten)]
+    pub brewery: Brewery,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OriginResult {
+    // the origin as the trigger gave it
+    pub origin: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub nearest: Vec<NearbyBrewery>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NearestResult {
+    pub per_origin: u32,
+    // breweries with coordinates in the fetched page, after filtering and moderation
+    pub candidate_count: usize,
+    pub origins: Vec<OriginResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
+}
+
+struct Origin {
+    text: String,
+    latitude: f64,
+    longitude: f64,
+}
+
+pub async fn nearest_breweries(
+    origins: &[String],
+    per_origin: u32,
+    filter: Option<&Filter>,
+) -> Result<NearestResult, String> {
+    let origins = parsed_origins(origins)?;
+    let per_origin = per_origin_cap(per_origin);
+
+    let (latitude, longitude) = centroid(&origins);
+    let url = format!("?by_dist={:.6},{:.6}&per_page={}", latitude, longitude, mode::MAX_PAGE_SIZE);
+    let mut breweries: Vec<Brewery> =
+        get_json(&url).await.map_err(|e| format!("Failed to fetch nearby breweries: {}", e))?;
+    breweries.iter_mut().for_each(Brewery::normalize);
+    breweries.retain(|b| coordinates(b).is_some());
+    if let Some(filter) = filter {
+        filter.retain(&mut breweries)?;
+    }
+    moderation::moderate(&mut breweries, |b| b).await?;
+
+    let origins = origins
+        .into_iter()
+        .map(|origin| OriginResult {
+            nearest: nearest(&origin, &breweries, per_origin),
+            origin: origin.text,
+            latitude: origin.latitude,
+            longitude: origin.longitude,
+        })
+        .collect();
+    Ok(NearestResult {
+        per_origin,
+        candidate_count: breweries.len(),
+        origins,
+        expires_at: mode::expires_at(freshness::now_secs(), DEFAULT_RESULT_TTL_SECS),
+    })
+}
+
+// The one `by_dist` request, however many origins there are
+pub fn planned(plan: Plan, origins: &[String]) -> Result<Plan, String> {
+    parsed_origins(origins)?;
+    Ok(brewery::planned(plan, 1))
+}
+
+fn per_origin_cap(per_origin: u32) -> u32 {
+    match per_origin {
+        0 => DEFAULT_PER_ORIGIN,
+        n => n.min(MAX_PER_ORIGIN),
+    }
+}
+
+// Origins are "latitude,longitude" in decimal degrees, e.g. "39.7392,-104.9903"
+fn parsed_origins(origins: &[String]) -> Result<Vec<Origin>, String> {
+    if origins.is_empty() || origins.len() > MAX_ORIGINS {
+        return Err(format!(
+            "Between 1 and {} origins are required, got {}",
+            MAX_ORIGINS,
+            origins.len()
+        ));
+    }
+    origins
+        .iter()
+        .map(|text| {
+            let invalid = || format!("Invalid origin '{}', expected \"latitude,longitude\"", text);
+            let (latitude, longitude) = text.split_once(',').ok_or_else(invalid)?;
+            let latitude: f64 = latitude.trim().parse().map_err(|_| invalid())?;
+            let longitude: f64 = longitude.trim().parse().map_err(|_| invalid())?;
+            if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+                return Err(format!("Origin '{}' is out of range", text));
+            }
+            Ok(Origin { text: text.trim().to_string(), latitude, longitude })
+        })
+        .collect()
+}
+
+// The mean of the origins' coordinates; routes are local, so the antimeridian isn't handled
+fn centroid(origins: &[Origin]) -> (f64, f64) {
+    let count = origins.len() as f64;
+    let latitude = origins.iter().map(|o| o.latitude).sum::<f64>() / count;
+    let longitude = origins.iter().map(|o| o.longitude).sum::<f64>() / count;
+    (latitude, longitude)
+}
+
+fn coordinates(brewery: &Brewery) -> Option<(f64, f64)> {
+    let (latitude, longitude) = (brewery.latitude?, brewery.longitude?);
+    let in_range = (-90.0..=90.0).contains(&latitude) && (-180.0..=180.0).contains(&longitude);
+    in_range.then_some((latitude, longitude))
+}
+
+// Ties are broken by id, so every operator ranks the same page the same way
+fn nearest(origin: &Origin, breweries: &[Brewery], per_origin: u32) -> Vec<NearbyBrewery> {
+    let mut ranked: Vec<NearbyBrewery> = breweries
+        .iter()
+        .filter_map(|brewery| {
+            let (latitude, longitude) = coordinates(brewery)?;
+            let distance = haversine_m(origin.latitude, origin.longitude, latitude, longitude);
+            Some(NearbyBrewery { distance_m: distance.round() as u64, brewery: brewery.clone() })
+        })
+        .collect();
+    ranked.sort_by(|a, b| {
+        a.distance_m.cmp(&b.distance_m).then_with(|| a.brewery.id.cmp(&b.brewery.id))
+    });
+    ranked.truncate(per_origin as usize);
+    ranked
+}
+
+fn haversine_m(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+    let half_dlat = (lat2 - lat1) / 2.0;
+    let half_dlon = (lon2 - lon1).to_radians() / 2.0;
+    let a = half_dlat.sin().powi(2) + lat1.cos() * lat2.cos() * half_dlon.sin().powi(2);
+    2.0 * EARTH_RADIUS_M * a.sqrt().min(1.0).asin()
+}
@@ -0,0 +1,323 @@
+// ISO 3166 region fields for listings. OpenBreweryDB's `country` and `state_province` are free
+// text and not consistent: the same state shows up as "California", "CA" or "Californa", UK
+// breweries are filed under "England" or "Scotland", and codes sit next to names. Every listing
+// gains a `region` with the ISO 3166-1 country and, where the tables below cover the country, the
+// ISO 3166-2 subdivision, so consumers can aggregate by code. The source fields are kept as
+// fetched.
+//
+// Values are compared case-, accent- and punctuation-insensitively, and names of five letters or
+// more may be one typo off (two from nine letters on) when no other entry is as close.
+use crate::country::{self, Country, COUNTRIES};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct Region {
+    // ISO 3166-1 alpha-2
+    pub country_code: String,
+    pub country: String,
+    // ISO 3166-2, e.g. "US-CA"; null when the subdivision isn't known
+    pub subdivision_code: Option<String>,
+    pub subdivision: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Subdivision {
+    pub code: &'static str,
+    pub name: &'static str,
+}
+
+// None when the country can't be resolved
+pub fn resolve(country: Option<&str>, state_province: Option<&str>) -> Option<Region> {
+    let (country, implied) = resolve_country(country?)?;
+    let subdivision =
+        implied.or_else(|| state_province.and_then(|s| resolve_subdivision(country.alpha2, s)));
+    Some(Region {
+        country_code: country.alpha2.to_string(),
+        country: country.name.to_string(),
+        subdivision_code: subdivision.map(|s| s.code.to_string()),
+        subdivision: subdivision.map(|s| s.name.to_string()),
+    })
+}
+
+// The country, plus the subdivision the name implies for UK constituent countries
+fn resolve_country(text: &str) -> Option<(&'static Country, Option<&'static Subdivision>)> {
+    let key = fold(text);
+    if let Ok(country) = country::lookup(&key) {
+        return Some((country, None));
+    }
+    let names = COUNTRIES.iter().map(|c| (c.name, (c.alpha2, None)));
+    let aliases = COUNTRY_ALIASES.iter().map(|(alias, alpha2, sub)| (*alias, (*alpha2, *sub)));
+    let (alpha2, subdivision) = best_match(&key, names.chain(aliases))?;
+    let country = country::lookup(alpha2).ok()?;
+    Some((country, subdivision.and_then(subdivision_by_code)))
+}
+
+fn resolve_subdivision(alpha2: &str, text: &str) -> Option<&'static Subdivision> {
+    let key = fold(text);
+    let key = ["county ", "co "].iter().find_map(|prefix| key.strip_prefix(prefix)).unwrap_or(&key);
+    let prefix = format!("{}-", alpha2);
+    let prefix = prefix.as_str();
+    let in_country = || SUBDIVISIONS.iter().filter(move |s| s.code.starts_with(prefix));
+    // "CA", "US-CA" and "us ca" all name California
+    let code = key.strip_prefix(&format!("{} ", alpha2.to_lowercase())).unwrap_or(key);
+    if let Some(found) = in_country().find(|s| s.code[prefix.len()..].eq_ignore_ascii_case(code)) {
+        return Some(found);
+    }
+    let names = in_country().map(|s| (s.name, s.code));
+    let aliases = SUBDIVISION_ALIASES
+        .iter()
+        .filter(|(_, code)| code.starts_with(prefix))
+        .map(|(alias, code)| (*alias, *code));
+    best_match(key, names.chain(aliases)).and_then(subdivision_by_code)
+}
+
+fn subdivision_by_code(code: &str) -> Option<&'static Subdivision> {
+    SUBDIVISIONS.iter().find(|s| s.code == code)
+}
+
+// An exact match, or else the closest candidate within the typo allowance; candidates as close
+// as each other that resolve differently cancel out
+fn best_match<T: Copy + PartialEq>(
+    key: &str,
+    candidates: impl Iterator<Item = (&'static str, T)>,
+) -> Option<T> {
+    let allowance = match key.chars().count() {
+        0..=4 => 0,
+        5..=8 => 1,
+        _ => 2,
+    };
+    let mut best: Option<(usize, Option<T>)> = None;
+    for (name, value) in candidates {
+        let name = fold(name);
+        if name == key {
+            return Some(value);
+        }
+        let distance = edit_distance(key, &name);
+        if distance > allowance {
+            continue;
+        }
+        best = match best {
+            Some((closest, _)) if distance > closest => best,
+            Some((closest, found)) if distance == closest => {
+                Some((closest, found.filter(|found| *found == value)))
+            }
+            _ => Some((distance, Some(value))),
+        };
+    }
+    best.and_then(|(_, value)| value)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+// Lowercase ASCII words separated by single spaces: "Baden-Württemberg" becomes
+// "baden wurttemberg" and "U.S.A." becomes "usa"
+fn fold(text: &str) -> String {
+    let mut folded = String::with_capacity(text.len());
+    for c in text.chars().flat_map(char::to_lowercase) {
+        match c {
+            '.' | '\'' | '’' => {}
+            '&' => folded.push_str(" and "),
+            'ß' => folded.push_str("ss"),
+            'à'..='å' => folded.push('a'),
+            'ç' => folded.push('c'),
+            'è'..='ë' => folded.push('e'),
+            'ì'..='ï' => folded.push('i'),
+            'ł' => folded.push('l'),
+            'ñ' => folded.push('n'),
+            'ò'..='ö' | 'ø' => folded.push('o'),
+            'ù'..='ü' => folded.push('u'),
+            'ý' | 'ÿ' => folded.push('y'),
+            c if c.is_alphanumeric() => folded.push(c),
+            _ => folded.push(' '),
+        }
+    }
+    folded.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// Other names for countries in COUNTRIES, with the subdivision a name implies
+const COUNTRY_ALIASES: &[(&str, &str, Option<&str>)] = &[
+    ("United States of America", "US", None),
+    ("America", "US", None),
+    ("UK", "GB", None),
+    ("Great Britain", "GB", None),
+    ("Britain", "GB", None),
+    ("England", "GB", Some("GB-ENG")),
+    ("Scotland", "GB", Some("GB-SCT")),
+    ("Wales", "GB", Some("GB-WLS")),
+    ("Northern Ireland", "GB", Some("GB-NIR")),
+    ("Republic of Ireland", "IE", None),
+    ("Korea", "KR", None),
+    ("Republic of Korea", "KR", None),
+    ("Russia", "RU", None),
+    ("Czech Republic", "CZ", None),
+    ("Turkey", "TR", None),
+    ("The Netherlands", "NL", None),
+    ("Holland", "NL", None),
+    ("Viet Nam", "VN", None),
+    ("Ivory Coast", "CI", None),
+    ("Macau", "MO", None),
+    ("Swaziland", "SZ", None),
+    ("Macedonia", "MK", None),
+    ("Cape Verde", "CV", None),
+    ("Brunei", "BN", None),
+    ("Vatican City", "VA", None),
+    ("Deutschland", "DE", None),
+    ("Österreich", "AT", None),
+    ("Schweiz", "CH", None),
+    ("España", "ES", None),
+    ("Italia", "IT", None),
+    ("Polska", "PL", None),
+];
+
+// Subdivisions of the countries with the most listings; codes and English names from ISO 3166-2
+#[rustfmt::skip]
+const SUBDIVISIONS: &[Subdivision] = &[
+    Subdivision { code: "US-AL", name: "Alabama" },
+    Subdivision { code: "US-AK", name: "Alaska" },
+    Subdivision { code: "US-AZ", name: "Arizona" },
+    Subdivision { code: "US-AR", name: "Arkansas" },
+    Subdivision { code: "US-CA", name: "California" },
+    Subdivision { code: "US-CO", name: "Colorado" },
+    Subdivision { code: "US-CT", name: "Connecticut" },
+    Subdivision { code: "US-DE", name: "Delaware" },
+    Subdivision { code: "US-DC", name: "District of Columbia" },
+    Subdivision { code: "US-FL", name: "Florida" },
+    Subdivision { code: "US-GA", name: "Georgia" },
+    Subdivision { code: "US-HI", name: "Hawaii" },
+    Subdivision { code: "US-ID", name: "Idaho" },
+    Subdivision { code: "US-IL", name: "Illinois" },
+    Subdivision { code: "US-IN", name: "Indiana" },
+    Subdivision { code: "US-IA", name: "Iowa" },
+    Subdivision { code: "US-KS", name: "Kansas" },
+    Subdivision { code: "US-KY", name: "Kentucky" },
+    Subdivision { code: "US-LA", name: "Louisiana" },
+    Subdivision { code: "US-ME", name: "Maine" },
+    Subdivision { code: "US-MD", name: "Maryland" },
+    Subdivision { code: "US-MA", name: "Massachusetts" },
+    Subdivision { code: "US-MI", name: "Michigan" },
+    Subdivision { code: "US-MN", name: "Minnesota" },
+    Subdivision { code: "US-MS", name: "Mississippi" },
+    Subdivision { code: "US-MO", name: "Missouri" },
+    Subdivision { code: "US-MT", name: "Montana" },
+    Subdivision { code: "US-NE", name: "Nebraska" },
+    Subdivision { code: "US-NV", name: "Nevada" },
+    Subdivision { code: "US-NH", name: "New Hampshire" },
+    Subdivision { code: "US-NJ", name: "New Jersey" },
+    Subdivision { code: "US-NM", name: "New Mexico" },
+    Subdivision { code: "US-NY", name: "New York" },
+    Subdivision { code: "US-NC", name: "North Carolina" },
+    Subdivision { code: "US-ND", name: "North Dakota" },
+    Subdivision { code: "US-OH", name: "Ohio" },
+    Subdivision { code: "US-OK", name: "Oklahoma" },
+    Subdivision { code: "US-OR", name: "Oregon" },
+    Subdivision { code: "US-PA", name: "Pennsylvania" },
+    Subdivision { code: "US-RI", name: "Rhode Island" },
+    Subdivision { code: "US-SC", name: "South Carolina" },
+    Subdivision { code: "US-SD", name: "South Dakota" },
+    Subdivision { code: "US-TN", name: "Tennessee" },
+    Subdivision { code: "US-TX", name: "Texas" },
+    Subdivision { code: "US-UT", name: "Utah" },
+    Subdivision { code: "US-VT", name: "Vermont" },
+    Subdivision { code: "US-VA", name: "Virginia" },
+    Subdivision { code: "US-WA", name: "Washington" },
+    Subdivision { code: "US-WV", name: "West Virginia" },
+    Subdivision { code: "US-WI", name: "Wisconsin" },
+    Subdivision { code: "US-WY", name: "Wyoming" },
+    Subdivision { code: "CA-AB", name: "Alberta" },
+    Subdivision { code: "CA-BC", name: "British Columbia" },
+    Subdivision { code: "CA-MB", name: "Manitoba" },
+    Subdivision { code: "CA-NB", name: "New Brunswick" },
+    Subdivision { code: "CA-NL", name: "Newfoundland and Labrador" },
+    Subdivision { code: "CA-NS", name: "Nova Scotia" },
+    Subdivision { code: "CA-NT", name: "Northwest Territories" },
+    Subdivision { code: "CA-NU", name: "Nunavut" },
+    Subdivision { code: "CA-ON", name: "Ontario" },
+    Subdivision { code: "CA-PE", name: "Prince Edward Island" },
+    Subdivision { code: "CA-QC", name: "Quebec" },
+    Subdivision { code: "CA-SK", name: "Saskatchewan" },
+    Subdivision { code: "CA-YT", name: "Yukon" },
+    Subdivision { code: "AU-ACT", name: "Australian Capital Territory" },
+    Subdivision { code: "AU-NSW", name: "New South Wales" },
+    Subdivision { code: "AU-NT", name: "Northern Territory" },
+    Subdivision { code: "AU-QLD", name: "Queensland" },
+    Subdivision { code: "AU-SA", name: "South Australia" },
+    Subdivision { code: "AU-TAS", name: "Tasmania" },
+    Subdivision { code: "AU-VIC", name: "Victoria" },
+    Subdivision { code: "AU-WA", name: "Western Australia" },
+    Subdivision { code: "GB-ENG", name: "England" },
+    Subdivision { code: "GB-NIR", name: "Northern Ireland" },
+    Subdivision { code: "GB-SCT", name: "Scotland" },
+    Subdivision { code: "GB-WLS", name: "Wales" },
+    Subdivision { code: "IE-CW", name: "Carlow" },
+    Subdivision { code: "IE-CN", name: "Cavan" },
+    Subdivision { code: "IE-CE", name: "Clare" },
+    Subdivision { code: "IE-CO", name: "Cork" },
+    Subdivision { code: "IE-DL", name: "Donegal" },
+    Subdivision { code: "IE-D", name: "Dublin" },
+    Subdivision { code: "IE-G", name: "Galway" },
+    Subdivision { code: "IE-KY", name: "Kerry" },
+    Subdivision { code: "IE-KE", name: "Kildare" },
+    Subdivision { code: "IE-KK", name: "Kilkenny" },
+    Subdivision { code: "IE-LS", name: "Laois" },
+    Subdivision { code: "IE-LM", name: "Leitrim" },
+    Subdivision { code: "IE-LK", name: "Limerick" },
+    Subdivision { code: "IE-LD", name: "Longford" },
+    Subdivision { code: "IE-LH", name: "Louth" },
+    Subdivision { code: "IE-MO", name: "Mayo" },
+    Subdivision { code: "IE-MH", name: "Meath" },
+    Subdivision { code: "IE-MN", name: "Monaghan" },
+    Subdivision { code: "IE-OY", name: "Offaly" },
+    Subdivision { code: "IE-RN", name: "Roscommon" },
+    Subdivision { code: "IE-SO", name: "Sligo" },
+    Subdivision { code: "IE-TA", name: "Tipperary" },
+    Subdivision { code: "IE-WD", name: "Waterford" },
+    Subdivision { code: "IE-WH", name: "Westmeath" },
+    Subdivision { code: "IE-WX", name: "Wexford" },
+    Subdivision { code: "IE-WW", name: "Wicklow" },
+    Subdivision { code: "DE-BW", name: "Baden-Württemberg" },
+    Subdivision { code: "DE-BY", name: "Bavaria" },
+    Subdivision { code: "DE-BE", name: "Berlin" },
+    Subdivision { code: "DE-BB", name: "Brandenburg" },
+    Subdivision { code: "DE-HB", name: "Bremen" },
+    Subdivision { code: "DE-HH", name: "Hamburg" },
+    Subdivision { code: "DE-HE", name: "Hesse" },
+    Subdivision { code: "DE-NI", name: "Lower Saxony" },
+    Subdivision { code: "DE-MV", name: "Mecklenburg-Vorpommern" },
+    Subdivision { code: "DE-NW", name: "North Rhine-Westphalia" },
+    Subdivision { code: "DE-RP", name: "Rhineland-Palatinate" },
+    Subdivision { code: "DE-SL", name: "Saarland" },
+    Subdivision { code: "DE-SN", name: "Saxony" },
+    Subdivision { code: "DE-ST", name: "Saxony-Anhalt" },
+    Subdivision { code: "DE-SH", name: "Schleswig-Holstein" },
+    Subdivision { code: "DE-TH", name: "Thuringia" },
+];
+
+// Other names for subdivisions in SUBDIVISIONS, including the German names of the Länder
+const SUBDIVISION_ALIASES: &[(&str, &str)] = &[
+    ("Washington DC", "US-DC"),
+    ("Newfoundland", "CA-NL"),
+    ("PEI", "CA-PE"),
+    ("Yukon Territory", "CA-YT"),
+    ("Bayern", "DE-BY"),
+    ("Hessen", "DE-HE"),
+    ("Niedersachsen", "DE-NI"),
+    ("Mecklenburg-Western Pomerania", "DE-MV"),
+    ("Nordrhein-Westfalen", "DE-NW"),
+    ("Rheinland-Pfalz", "DE-RP"),
+    ("Sachsen", "DE-SN"),
+    ("Sachsen-Anhalt", "DE-ST"),
+    ("Thüringen", "DE-TH"),
+];
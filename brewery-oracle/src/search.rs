@@ -0,0 +1,112 @@
+use crate::brewery::{self, encode_query_param, get_json, Brewery};
+use crate::{mode, DEFAULT_RESULT_TTL_SECS};
+use serde::{Deserialize, Serialize};
+use wavs_component_common::{dry_run::Plan, filter::Filter, freshness};
+
+const DEFAULT_SEARCH_RESULTS: u32 = 10;
+const MAX_SEARCH_RESULTS: u32 = 50;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SearchMatch {
+    pub matched_on: String,
+    #[serde(flatten)]
+    pub brewery: Brewery,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SearchResult {
+    pub query: String,
+    pub endpoint: String,
+    pub result_count: usize,
+    pub results: Vec<SearchMatch>,
+    // unix seconds after which consumers should treat the results as stale
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
+}
+
+// Fuzzy name search: the search endpoint returns full records, autocomplete is used as a
+// fallback when search finds nothing since it matches partial names more leniently
+pub async fn search_breweries(
+    query: &str,
+    max_results: u32,
+    filter: Option<&Filter>,
+) -> Result<SearchResult, String> {
+    let query = checked_query(query)?;
+    let limit = mode::result_cap(
+        max_results,
+        DEFAULT_SEARCH_RESULTS,
+        MAX_SEARCH_RESULTS,
+        "WAVS_ENV_BREWERY_SEARCH_MAX_RESULTS",
+    );
+    let encoded = encode_query_param(query);
+
+    let url = format!("/search?query={}&per_page={}", encoded, limit);
+    let mut breweries: Vec<Brewery> =
+        get_json(&url).await.map_err(|e| format!("Failed to search breweries: {}", e))?;
+    let mut endpoint = "search";
+
+    if breweries.is_empty() {
+        let url = format!("/autocomplete?query={}", encoded);
+        breweries = get_json(&url)
+            .await
+            .map_err(|e| format!("Failed to autocomplete brewery names: {}", e))?;
+        endpoint = "autocomplete";
+    }
+    breweries.truncate(limit as usize);
+    breweries.iter_mut().for_each(Brewery::normalize);
+
+    let mut results: Vec<SearchMatch> = breweries
+        .into_iter()
+        .map(|brewery| SearchMatch { matched_on: matched_field(&brewery, query), brewery })
+        .collect();
+    if let Some(filter) = filter {
+        filter.retain(&mut results)?;
+    }
+
+    Ok(SearchResult {
+        query: query.to_string(),
+        endpoint: endpoint.to_string(),
+        result_count: results.len(),
+        results,
+        expires_at: mode::expires_at(freshness::now_secs(), DEFAULT_RESULT_TTL_SECS),
+    })
+}
+
+// The search request, and the autocomplete one it falls back to
+pub fn planned(plan: Plan, query: &str) -> Result<Plan, String> {
+    checked_query(query)?;
+    Ok(brewery::planned(plan, 2).note("Autocomplete is only asked when search finds nothing"))
+}
+
+fn checked_query(query: &str) -> Result<&str, String> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Err("Search query must not be empty".to_string());
+    }
+    Ok(query)
+}
+
+// Reports the first field containing one of the query words, case-insensitively.
+// Records returned only because of upstream fuzzy matching are reported as "fuzzy".
+fn matched_field(brewery: &Brewery, query: &str) -> String {
+    let words: Vec<String> = query.split_whitespace().map(|w| w.to_lowercase()).collect();
+    let fields = [
+        ("name", &brewery.name),
+        ("city", &brewery.city),
+        ("state_province", &brewery.state_province),
+        ("country", &brewery.country),
+        ("postal_code", &brewery.postal_code),
+        ("brewery_type", &brewery.brewery_type),
+        ("street", &brewery.street),
+    ];
+    fields
+        .iter()
+        .find(|(_, value)| {
+            value.as_ref().is_some_and(|v| {
+                let v = v.to_lowercase();
+                words.iter().any(|w| v.contains(w.as_str()))
+            })
+        })
+        .map(|(field, _)| field.to_string())
+        .unwrap_or_else(|| "fuzzy".to_string())
+}
@@ -0,0 +1,237 @@
+// Aggregate mode: counts by type and city plus contact coverage for a state or postal prefix,
+// for analytics consumers that don't want the full record lists
+use crate::brewery::{self, Brewery};
+use crate::mode;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use wavs_component_common::{
+    dry_run::Plan,
+    freshness,
+    partial::{self, StepError},
+    privacy::{Laplace, Privacy},
+    warn,
+};
+
+const PAGE_SIZE: u64 = 200;
+// Bounds the number of list requests; larger areas are aggregated over the first pages only
+const MAX_PAGES: u64 = 10;
+const UNKNOWN: &str = "unknown";
+// Aggregates change about as slowly as the listings themselves
+const DEFAULT_RESULT_TTL_SECS: u64 = 86_400;
+// One brewery adds 1 to total, records_scanned, a by_type and a by_city bucket, and at most 1
+// each to the website and phone counts the shares are computed from
+const SENSITIVITY: u64 = 6;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BreweryStats {
+    state: Option<String>,
+    postal_prefix: Option<String>,
+    // total matching breweries, from the metadata endpoint when it answers
+    total: u64,
+    records_scanned: u64,
+    // true when only the first MAX_PAGES pages were scanned; by_city and the shares then
+    // describe that sample
+    truncated: bool,
+    // true when a list page failed after its retries; the figures then describe the pages
+    // before it, and truncated is set as well
+    partial: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    failure: Option<partial::Failure>,
+    by_type: BTreeMap<String, u64>,
+    // "metadata" when by_type comes from /meta, "records" when counted from the scanned records
+    by_type_source: String,
+    by_city: BTreeMap<String, u64>,
+    website_share_bps: u16,
+    phone_share_bps: u16,
+    // set when the counts are noised (see privacy.rs in wavs-component-common)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    privacy: Option<Privacy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expires_at: Option<u64>,
+}
+
+// /meta reports counts as numbers or numeric strings depending on the API version
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+struct Meta {
+    total: Option<Value>,
+    by_type: Option<BTreeMap<String, Value>>,
+}
+
+pub async fn aggregate(
+    state: Option<String>,
+    postal_prefix: Option<String>,
+) -> Result<BreweryStats, String> {
+    let filter = query_filter(state.as_deref(), postal_prefix.as_deref())?;
+    let laplace = noise(&filter)?;
+
+    // The metadata endpoint is optional: without it totals are counted from the records
+    let meta = match get::<Meta>(&format!("/meta?{}", filter)).await {
+        Ok(meta) => Some(meta),
+        Err(e) => {
+            warn!("Brewery metadata unavailable, counting records instead: {}", e);
+            None
+        }
+    };
+
+    let mut records = Vec::new();
+    let mut truncated = true;
+    let mut failure = None;
+    for page in 1..=MAX_PAGES {
+        let url = format!("?{}&per_page={}&page={}", filter, PAGE_SIZE, page);
+        let fetched = partial::retry(&format!("page {}", page), || async {
+            get::<Vec<Brewery>>(&url).await.map_err(StepError::http)
+        })
+        .await;
+        let mut breweries = match fetched {
+            Ok(breweries) => breweries,
+            Err(f) if page == 1 => return Err(f.into_error()),
+            Err(f) => {
+                warn!("Aggregating the {} pages fetched before {} failed", page - 1, f.step);
+                failure = Some(f);
+                break;
+            }
+        };
+        breweries.iter_mut().for_each(Brewery::normalize);
+        let last_page = (breweries.len() as u64) < PAGE_SIZE;
+        records.extend(breweries);
+        if last_page {
+            truncated = false;
+            break;
+        }
+    }
+    let scanned = records.len() as u64;
+
+    let total = meta.as_ref().and_then(|m| m.total.as_ref()).and_then(count).unwrap_or(scanned);
+    let meta_by_type = meta.and_then(|m| m.by_type).map(|by_type| {
+        by_type.into_iter().filter_map(|(k, v)| count(&v).map(|c| (k, c))).collect()
+    });
+    let (by_type, by_type_source) = match meta_by_type {
+        Some(by_type) => (by_type, "metadata"),
+        None => (tally(records.iter().map(|b| b.brewery_type.as_deref())), "records"),
+    };
+    let counts = Counts {
+        total,
+        scanned,
+        by_type,
+        by_city: tally(records.iter().map(|b| b.city.as_deref())),
+        websites: records.iter().filter(|b| b.website_url.is_some()).count() as u64,
+        phones: records.iter().filter(|b| b.phone.is_some()).count() as u64,
+    };
+    let (counts, privacy) = match laplace {
+        Some(laplace) => (counts.noised(&laplace), Some(laplace.metadata())),
+        None => (counts, None),
+    };
+
+    Ok(BreweryStats {
+        state,
+        postal_prefix,
+        total: counts.total,
+        records_scanned: counts.scanned,
+        truncated: truncated || scanned < total,
+        partial: failure.is_some(),
+        failure,
+        by_type: counts.by_type,
+        by_type_source: by_type_source.to_string(),
+        by_city: counts.by_city,
+        website_share_bps: share_bps(counts.websites, counts.scanned),
+        phone_share_bps: share_bps(counts.phones, counts.scanned),
+        privacy,
+        expires_at: mode::expires_at(freshness::now_secs(), DEFAULT_RESULT_TTL_SECS),
+    })
+}
+
+struct Counts {
+    total: u64,
+    scanned: u64,
+    by_type: BTreeMap<String, u64>,
+    by_city: BTreeMap<String, u64>,
+    websites: u64,
+    phones: u64,
+}
+
+impl Counts {
+    // Buckets whose noisy count rounds to zero are dropped rather than listed with a 0
+    fn noised(self, laplace: &Laplace) -> Counts {
+        let buckets = |name: &str, counts: BTreeMap<String, u64>| {
+            counts
+                .into_iter()
+                .map(|(key, count)| {
+                    let count = laplace.count(&format!("{}/{}", name, key), count);
+                    (key, count)
+                })
+                .filter(|(_, count)| *count > 0)
+                .collect()
+        };
+        Counts {
+            total: laplace.count("total", self.total),
+            scanned: laplace.count("records_scanned", self.scanned),
+            by_type: buckets("by_type", self.by_type),
+            by_city: buckets("by_city", self.by_city),
+            websites: laplace.count("websites", self.websites),
+            phones: laplace.count("phones", self.phones),
+        }
+    }
+}
+
+// The metadata request and at most MAX_PAGES list requests, each with its retries
+pub fn planned(
+    plan: Plan,
+    state: Option<&str>,
+    postal_prefix: Option<&str>,
+) -> Result<Plan, String> {
+    let filter = query_filter(state, postal_prefix)?;
+    let plan = brewery::planned(plan, 1 + MAX_PAGES * u64::from(partial::attempts()));
+    Ok(match noise(&filter)? {
+        Some(laplace) => plan
+            .note(format!("Counts get Laplace noise with epsilon {}", laplace.metadata().epsilon)),
+        None => plan,
+    })
+}
+
+// Keyed on the filter, so each area draws its own noise
+fn noise(filter: &str) -> Result<Option<Laplace>, String> {
+    Laplace::from_env(SENSITIVITY, &format!("{}\n{}", mode::COMPONENT_NAME, filter))
+}
+
+fn query_filter(state: Option<&str>, postal_prefix: Option<&str>) -> Result<String, String> {
+    let mut filters = Vec::new();
+    if let Some(state) = state {
+        let state = state.trim().replace(' ', "_");
+        filters.push(format!("by_state={}", brewery::encode_query_param(&state)));
+    }
+    if let Some(prefix) = postal_prefix {
+        // by_postal matches on prefix, so "921" covers 92101, 92102, ...
+        filters.push(format!("by_postal={}", brewery::encode_query_param(prefix.trim())));
+    }
+    if filters.is_empty() {
+        return Err("Aggregate mode needs a state or a postal prefix".to_string());
+    }
+    Ok(filters.join("&"))
+}
+
+fn tally<'a>(values: impl Iterator<Item = Option<&'a str>>) -> BTreeMap<String, u64> {
+    let mut counts = BTreeMap::new();
+    for value in values {
+        let key = value.map(str::trim).filter(|v| !v.is_empty()).unwrap_or(UNKNOWN);
+        *counts.entry(key.to_string()).or_insert(0) += 1;
+    }
+    counts
+}
+
+// Noisy counts can put more matches than records scanned
+fn share_bps(matching: u64, scanned: u64) -> u16 {
+    if scanned == 0 {
+        return 0;
+    }
+    (matching.min(scanned) * 10_000 / scanned) as u16
+}
+
+fn count(value: &Value) -> Option<u64> {
+    value.as_u64().or_else(|| value.as_str().and_then(|s| s.parse().ok()))
+}
+
+async fn get<T: serde::de::DeserializeOwned>(path: &str) -> Result<T, String> {
+    brewery::get_json(path).await.map_err(|e| format!("Failed to fetch {}: {}", path, e))
+}
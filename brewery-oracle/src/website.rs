@@ -0,0 +1,155 @@
+// Website liveness checks, so consumers don't surface dead links that were minted into a record.
+// With WAVS_ENV_BREWERY_WEBSITE_CHECK=true each returned listing's website_url gets a HEAD
+// request, redirects are followed up to MAX_REDIRECTS hops, and the listing gains
+//
+//   "website_status": {"state": "live", "status_code": 200, "redirect_url": "https://..."}
+//
+// `state` is live for a 2xx answer, and for 405 and 501 from servers that refuse HEAD but did
+// answer; broken for any other status; unreachable when nothing answered within
+// WAVS_ENV_BREWERY_WEBSITE_TIMEOUT_SECS, the redirects didn't end or led somewhere that isn't an
+// http(s) URL. `redirect_url` is where the redirects ended, absent when there were none. Why a
+// site was unreachable is only logged, since operators' error messages don't match.
+use crate::brewery::Brewery;
+use serde::{Deserialize, Serialize};
+use std::{cell::RefCell, collections::HashMap};
+use wavs_component_common::{contact, dry_run::Plan, info, replay, warn};
+use wavs_wasi_utils::http::http_request_get;
+use wstd::{future::FutureExt, http::HeaderValue, time::Duration};
+
+const MAX_REDIRECTS: usize = 5;
+const DEFAULT_TIMEOUT_SECS: u64 = 5;
+
+thread_local! {
+    // chains list one website for many breweries; each is checked once per run
+    static CHECKED: RefCell<HashMap<String, WebsiteStatus>> = RefCell::new(HashMap::new());
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WebsiteStatus {
+    // live, broken or unreachable
+    pub state: String,
+    // of the last answer, after redirects
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_url: Option<String>,
+}
+
+impl WebsiteStatus {
+    fn unreachable() -> WebsiteStatus {
+        WebsiteStatus { state: "unreachable".to_string(), status_code: None, redirect_url: None }
+    }
+}
+
+pub fn enabled() -> bool {
+    matches!(std::env::var("WAVS_ENV_BREWERY_WEBSITE_CHECK").as_deref(), Ok("1") | Ok("true"))
+}
+
+fn timeout_secs() -> u64 {
+    std::env::var("WAVS_ENV_BREWERY_WEBSITE_TIMEOUT_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .filter(|secs| *secs > 0)
+        .unwrap_or(DEFAULT_TIMEOUT_SECS)
+}
+
+// One HEAD request per distinct website, plus one per redirect, neither known ahead of the run
+pub fn planned(plan: Plan) -> Plan {
+    if !enabled() {
+        return plan;
+    }
+    plan.note("Website checks send a HEAD request to each listed website and follow its redirects")
+}
+
+// `brewery` picks the listing out of a result row, as in moderation::moderate
+pub async fn check<T>(rows: &mut [T], brewery: fn(&mut T) -> &mut Brewery) {
+    if !enabled() {
+        return;
+    }
+    for row in rows {
+        let brewery = brewery(row);
+        brewery.website_status = status(brewery.website_url.as_deref()).await;
+    }
+}
+
+// None when checks are off or there is no website to check
+pub async fn status(url: Option<&str>) -> Option<WebsiteStatus> {
+    let url = url.filter(|_| enabled())?;
+    if let Some(status) = CHECKED.with(|checked| checked.borrow().get(url).cloned()) {
+        return Some(status);
+    }
+    let status = follow(url).await;
+    info!("Website {} is {}", url, status.state);
+    CHECKED.with(|checked| checked.borrow_mut().insert(url.to_string(), status.clone()));
+    Some(status)
+}
+
+async fn follow(url: &str) -> WebsiteStatus {
+    let timeout_secs = timeout_secs();
+    let mut current = url.to_string();
+    for _ in 0..=MAX_REDIRECTS {
+        let head = match head(&current).timeout(Duration::from_secs(timeout_secs)).await {
+            Ok(Ok(head)) => head,
+            Ok(Err(e)) => {
+                warn!("Website check of {} failed: {}", current, e);
+                return WebsiteStatus::unreachable();
+            }
+            Err(_) => {
+                warn!("Website {} didn't answer within {}s", current, timeout_secs);
+                return WebsiteStatus::unreachable();
+            }
+        };
+        let redirect = matches!(head.status, 301 | 302 | 303 | 307 | 308);
+        if let (true, Some(location)) = (redirect, head.location.as_deref()) {
+            let Some(next) = resolve(&current, location) else {
+                warn!("Website {} redirects to '{}', which isn't checked", current, location);
+                return WebsiteStatus::unreachable();
+            };
+            current = next;
+            continue;
+        }
+        let state = match head.status {
+            200..=299 | 405 | 501 => "live",
+            _ => "broken",
+        };
+        return WebsiteStatus {
+            state: state.to_string(),
+            status_code: Some(head.status),
+            redirect_url: Some(current).filter(|current| current != url),
+        };
+    }
+    warn!("Website {} redirects more than {} times", url, MAX_REDIRECTS);
+    WebsiteStatus::unreachable()
+}
+
+async fn head(url: &str) -> Result<replay::HeadInfo, String> {
+    let mut req = http_request_get(url).map_err(|e| format!("Failed to create request: {}", e))?;
+    req.headers_mut().insert("User-Agent", HeaderValue::from_static("Mozilla/5.0"));
+    replay::fetch_head(req).await.map_err(|e| e.to_string())
+}
+
+// A Location header against the URL that sent it: absolute, scheme-relative, root-relative or
+// relative to the path (RFC 3986 section 5.2, without dot segments). Only http(s) targets that
+// pass the listing rules of contact::normalize_website are followed.
+fn resolve(base: &str, location: &str) -> Option<String> {
+    let location = location.trim();
+    let (scheme, rest) = base.split_once("://")?;
+    let authority_end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+    let origin = format!("{}://{}", scheme, &rest[..authority_end]);
+    let lower = location.to_ascii_lowercase();
+    let target = if lower.starts_with("http://") || lower.starts_with("https://") {
+        location.to_string()
+    } else if let Some(scheme_relative) = location.strip_prefix("//") {
+        format!("{}://{}", scheme, scheme_relative)
+    } else if location.starts_with('/') {
+        format!("{}{}", origin, location)
+    } else if location.split(['/', '?', '#']).next().is_some_and(|first| first.contains(':')) {
+        // another scheme, e.g. mailto:
+        return None;
+    } else {
+        let path = rest[authority_end..].split(['?', '#']).next().unwrap_or_default();
+        let directory = path.rsplit_once('/').map_or("", |(directory, _)| directory);
+        format!("{}{}/{}", origin, directory, location)
+    };
+    contact::normalize_website(&target)
+}
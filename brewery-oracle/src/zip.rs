@@ -0,0 +1,146 @@
+use crate::brewery::{encode_query_param, get_json, Brewery};
+use crate::{hours, mode, DEFAULT_RESULT_TTL_SECS};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use wavs_component_common::{cache, freshness, info, warn};
+use wavs_wasi_utils::evm::alloy_primitives::{hex, keccak256};
+
+const DEFAULT_CACHE_TTL_SECS: u64 = 300;
+const DEFAULT_CACHE_MAX_STALE_SECS: u64 = 86_400;
+
+// The array of breweries in a zip result
+#[cfg(not(feature = "lookup-compat"))]
+pub const ROWS_KEY: &str = "breweries";
+#[cfg(feature = "lookup-compat")]
+pub const ROWS_KEY: &str = "results";
+
+thread_local! {
+    // How each zip code looked up this run was served, for annotate
+    static SERVED: RefCell<HashMap<String, Served>> = RefCell::new(HashMap::new());
+}
+
+// With `lookup-compat`, serialized as brewery-lookup's `{zip_code, result_count, results}`.
+// Whether the listings came from this operator's cache, and so when they expire, differs between
+// operators, so those fields stay out of submitted results; annotate adds them to CLI output.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ZipResult {
+    pub zip_code: String,
+    #[cfg_attr(feature = "lookup-compat", serde(rename = "result_count"))]
+    pub brewery_count: usize,
+    #[cfg_attr(feature = "lookup-compat", serde(rename = "results"))]
+    pub breweries: Vec<Brewery>,
+    // served from cache instead of a fresh upstream response
+    #[serde(skip)]
+    pub cached: bool,
+    // served from an entry past its TTL because the upstream request failed
+    #[serde(skip)]
+    pub stale: bool,
+    // unix seconds after which consumers should treat the data as stale, counted from when
+    // it was fetched from OpenBreweryDB
+    #[serde(skip)]
+    pub expires_at: Option<u64>,
+    // present when WAVS_ENV_BREWERY_HOURS_ENRICHMENT is enabled and the top result has a website
+    #[cfg_attr(feature = "lookup-compat", serde(skip))]
+    pub opening_hours: Option<hours::OpeningHours>,
+}
+
+pub async fn find_breweries(zip_code: &str) -> Result<ZipResult, String> {
+    let per_page = mode::zip_results();
+    // Stale-while-revalidate: fresh entries are served directly, expired ones are kept as a
+    // fallback so a transient OpenBreweryDB outage doesn't fail the round
+    let cache_key = hex::encode(keccak256(format!("brewery-oracle\n{}\n{}", per_page, zip_code)));
+    let ttl = env_secs("WAVS_ENV_BREWERY_CACHE_TTL_SECS", DEFAULT_CACHE_TTL_SECS);
+    let max_stale = env_secs("WAVS_ENV_BREWERY_CACHE_MAX_STALE_SECS", DEFAULT_CACHE_MAX_STALE_SECS);
+    let cached = cache::get::<Vec<Brewery>>(&cache_key);
+    if let Some(entry) = &cached {
+        if entry.age_secs() <= ttl {
+            info!("Serving cached breweries ({}s old)", entry.age_secs());
+            return Ok(zip_result(zip_code, entry.value.clone(), entry.stored_at, true, false));
+        }
+    }
+
+    match fetch_breweries(zip_code, per_page).await {
+        Ok(breweries) => {
+            // A failed cache write should not fail the run
+            if let Err(e) = cache::put(&cache_key, &breweries) {
+                warn!("Failed to cache breweries: {}", e);
+            }
+            Ok(zip_result(zip_code, breweries, freshness::now_secs(), false, false))
+        }
+        Err(e) => match cached {
+            Some(entry) if entry.age_secs() <= ttl + max_stale => {
+                warn!("{}; serving stale breweries ({}s old)", e, entry.age_secs());
+                Ok(zip_result(zip_code, entry.value, entry.stored_at, true, true))
+            }
+            _ => Err(e),
+        },
+    }
+}
+
+// Adds the cache state of the zip lookups behind a CLI result: to the result of a zip lookup or
+// an itinerary, and to each item's result of a batch
+pub fn annotate(value: &mut Value) {
+    if cfg!(feature = "lookup-compat") {
+        return;
+    }
+    let Some(object) = value.as_object_mut() else {
+        return;
+    };
+    if let Some(Value::Array(items)) = object.get_mut("items") {
+        items.iter_mut().filter_map(|item| item.get_mut("result")).for_each(annotate);
+        return;
+    }
+    let Some(zip_code) = object.get("zip_code").and_then(Value::as_str) else {
+        return;
+    };
+    let Some(served) = SERVED.with(|served| served.borrow().get(zip_code).cloned()) else {
+        return;
+    };
+    object.insert("cached".to_string(), served.cached.into());
+    object.insert("stale".to_string(), served.stale.into());
+    if let Some(expires_at) = served.expires_at {
+        object.insert("expires_at".to_string(), expires_at.into());
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Served {
+    cached: bool,
+    stale: bool,
+    expires_at: Option<u64>,
+}
+
+async fn fetch_breweries(zip_code: &str, per_page: u32) -> Result<Vec<Brewery>, String> {
+    let url = format!("?by_postal={}&per_page={}", encode_query_param(zip_code), per_page);
+    let mut breweries: Vec<Brewery> =
+        get_json(&url).await.map_err(|e| format!("Failed to fetch breweries: {}", e))?;
+    breweries.iter_mut().for_each(Brewery::normalize);
+    Ok(breweries)
+}
+
+fn zip_result(
+    zip_code: &str,
+    breweries: Vec<Brewery>,
+    fetched_at: u64,
+    cached: bool,
+    stale: bool,
+) -> ZipResult {
+    let expires_at = mode::expires_at(fetched_at, DEFAULT_RESULT_TTL_SECS);
+    let served = Served { cached, stale, expires_at };
+    SERVED.with(|all| all.borrow_mut().insert(zip_code.to_string(), served));
+    ZipResult {
+        zip_code: zip_code.to_string(),
+        brewery_count: breweries.len(),
+        breweries,
+        cached,
+        stale,
+        expires_at,
+        opening_hours: None,
+    }
+}
+
+fn env_secs(name: &str, default: u64) -> u64 {
+    std::env::var(name).ok().and_then(|v| v.parse::<u64>().ok()).unwrap_or(default)
+}
@@ -0,0 +1,79 @@
+// Return types declared by the trigger. The type list is parsed at run time, so any view function
+// can be read without bindings for it; the return data is decoded with the list and encoded
+// again, which both checks that it matches and leaves it in canonical form.
+use alloy_dyn_abi::{DynSolType, DynSolValue};
+use alloy_primitives::hex;
+use serde_json::Value;
+
+const MAX_RETURN_TYPE_CHARS: usize = 512;
+
+// "uint256", "address,uint256" or "(address,uint256)", like a Solidity returns clause. A function
+// returning one struct is declared with its own parentheses: "((address,uint256))".
+pub fn return_types(declared: &str) -> Result<DynSolType, String> {
+    let declared = declared.trim();
+    if declared.is_empty() {
+        return Err("Return type is empty".to_string());
+    }
+    if declared.len() > MAX_RETURN_TYPE_CHARS {
+        return Err(format!("Return type is limited to {} characters", MAX_RETURN_TYPE_CHARS));
+    }
+    let types = match DynSolType::parse(declared) {
+        Ok(DynSolType::Tuple(types)) => types,
+        _ => match DynSolType::parse(&format!("({})", declared)) {
+            Ok(DynSolType::Tuple(types)) => types,
+            Ok(_) | Err(_) => return Err(format!("Invalid return type '{}'", declared)),
+        },
+    };
+    if types.is_empty() {
+        return Err("Return type lists no types".to_string());
+    }
+    Ok(DynSolType::Tuple(types))
+}
+
+pub fn decode(types: &DynSolType, data: &[u8]) -> Result<DynSolValue, String> {
+    types
+        .abi_decode_sequence(data)
+        .map_err(|e| format!("Return data doesn't decode as {}: {}", type_name(types), e))
+}
+
+// "(uint256)" rather than the "(uint256,)" alloy names a one-type tuple with
+pub fn type_name(types: &DynSolType) -> String {
+    match types {
+        DynSolType::Tuple(types) => {
+            let names: Vec<_> = types.iter().map(DynSolType::sol_type_name).collect();
+            format!("({})", names.join(","))
+        }
+        ty => ty.sol_type_name().into_owned(),
+    }
+}
+
+// The encoded size of return data that has no dynamic values, which doesn't depend on the call
+pub fn static_size(types: &DynSolType) -> Option<usize> {
+    match types {
+        DynSolType::Bytes | DynSolType::String | DynSolType::Array(_) => None,
+        DynSolType::FixedArray(ty, len) => static_size(ty).map(|size| size * len),
+        DynSolType::Tuple(types) => types.iter().map(static_size).sum(),
+        _ => Some(32),
+    }
+}
+
+// Integers are decimal strings and byte values 0x-prefixed hex, as elsewhere in this repository
+pub fn to_json(value: &DynSolValue) -> Value {
+    match value {
+        DynSolValue::Bool(b) => Value::Bool(*b),
+        DynSolValue::Int(i, _) => Value::String(i.to_string()),
+        DynSolValue::Uint(u, _) => Value::String(u.to_string()),
+        DynSolValue::FixedBytes(word, size) => Value::String(to_hex(&word[..*size])),
+        DynSolValue::Address(address) => Value::String(address.to_checksum(None)),
+        DynSolValue::Function(function) => Value::String(to_hex(function.as_slice())),
+        DynSolValue::Bytes(bytes) => Value::String(to_hex(bytes)),
+        DynSolValue::String(s) => Value::String(s.clone()),
+        DynSolValue::Array(values)
+        | DynSolValue::FixedArray(values)
+        | DynSolValue::Tuple(values) => Value::Array(values.iter().map(to_json).collect()),
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
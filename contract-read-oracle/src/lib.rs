@@ -0,0 +1,218 @@
+mod abi;
+mod trigger;
+use trigger::{encode_cli_output, encode_trigger_output, Codec, Destination};
+pub mod bindings;
+use crate::bindings::host::get_evm_chain_config;
+use crate::bindings::{export, Guest, TriggerAction, WasmResponse};
+use alloy_dyn_abi::DynSolType;
+use alloy_primitives::{hex, Address, Bytes, TxKind};
+use alloy_provider::Provider;
+use alloy_rpc_types::{BlockId, TransactionInput};
+use alloy_sol_types::SolCall;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use wavs_component_common::{
+    chains, decode, dry_run, envelope, info,
+    output::{CliOutput, EthereumOutput},
+    reorg, replay, rpc, run, selector, timestamp, timing, versioned,
+};
+use wstd::runtime::block_on;
+
+const MAX_CALL_DATA_BYTES: usize = 8_192;
+// Re-encoded return data; larger results cost more to submit than most contracts want to pay
+const MAX_RESULT_BYTES: usize = 16_384;
+
+// The input as a bare ABI string:
+// {"target": "0x...", "call_data": "0x70a08231...", "return_type": "uint256"}
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ReadRequest {
+    target: Address,
+    call_data: Bytes,
+    return_type: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ContractReadResult {
+    // the chain the call was made on, see chains.rs
+    chain: chains::Chain,
+    target: String,
+    selector: String,
+    call_data: String,
+    // the declared types in canonical form, e.g. "(uint256,address[])"
+    return_type: String,
+    block_number: u64,
+    // one JSON value per declared type
+    values: Value,
+    // what is submitted on-chain
+    result: String,
+}
+
+struct Component;
+export!(Component with_types_in bindings);
+
+impl Guest for Component {
+    fn run(action: TriggerAction) -> std::result::Result<Option<WasmResponse>, String> {
+        run::run(Codec, action.data, process)
+    }
+}
+
+fn process(
+    trigger_id: u64,
+    req: Vec<u8>,
+    dest: Destination,
+    options: envelope::Options,
+) -> Result<Option<WasmResponse>, String> {
+    options.check_supported(&["rpc_url", "dry_run"])?;
+
+    // readContract(address,bytes,string) calldata, or a bare ABI string holding the request as JSON
+    let data = decode::input_bytes(&req)?;
+    selector::check(&data, &[selector::Expected::of::<trigger::solidity::readContractCall>()])?;
+    let request = if data.starts_with(&trigger::solidity::readContractCall::SELECTOR) {
+        let call = trigger::solidity::readContractCall::abi_decode(&data).map_err(|e| {
+            format!(
+                "Invalid arguments for {}: {}",
+                trigger::solidity::readContractCall::SIGNATURE,
+                e
+            )
+        })?;
+        ReadRequest { target: call.target, call_data: call.callData, return_type: call.returnType }
+    } else {
+        serde_json::from_str(&decode::abi_string(&data)?)
+            .map_err(|e| format!("Failed to parse read request: {}", e))?
+    };
+    check_target(request.target)?;
+    if request.call_data.len() < 4 {
+        return Err("Call data must start with a 4-byte function selector".to_string());
+    }
+    if request.call_data.len() > MAX_CALL_DATA_BYTES {
+        return Err(format!("Call data is limited to {} bytes", MAX_CALL_DATA_BYTES));
+    }
+    let types = abi::return_types(&request.return_type)?;
+
+    let rpc_url = options.rpc_url.clone();
+    timing::end_phase("decode");
+    if options.dry_run {
+        let plan = plan(trigger_id, &request, &types, rpc_url.as_deref())?;
+        let payload = plan.output(matches!(dest, Destination::Ethereum), options.json_format())?;
+        return payload.map(encode_cli_output).transpose();
+    }
+    let (result, encoded) =
+        block_on(async move { read(request, types, rpc_url.as_deref()).await })?;
+    timing::end_phase("upstream");
+
+    run::respond(Codec, trigger_id, EthereumOutput::abi(&encoded).traced(), dest, &options, || {
+        Ok(CliOutput::new(versioned!(&result)))
+    })
+}
+
+// Operators who only want to serve some contracts list them in WAVS_ENV_CONTRACT_READ_TARGETS;
+// unset, any address can be read
+fn check_target(target: Address) -> Result<(), String> {
+    let Ok(targets) = std::env::var("WAVS_ENV_CONTRACT_READ_TARGETS") else {
+        return Ok(());
+    };
+    let mut allowed = targets.split(',').map(str::trim).filter(|t| !t.is_empty());
+    if allowed.any(|t| t.parse::<Address>().is_ok_and(|t| t == target)) {
+        return Ok(());
+    }
+    Err(format!("{} is not in WAVS_ENV_CONTRACT_READ_TARGETS", target))
+}
+
+// The pinned block and the call. Return data of static types has a known size, so the gas
+// estimate is only given for those.
+fn plan(
+    trigger_id: u64,
+    request: &ReadRequest,
+    types: &DynSolType,
+    rpc_url: Option<&str>,
+) -> Result<dry_run::Plan, String> {
+    let input = serde_json::json!({
+        "target": request.target.to_checksum(None),
+        "call_data": request.call_data,
+        "return_type": abi::type_name(types),
+    });
+    let chain = resolve_chain()?;
+    let calls = 2 + timestamp::trigger_block_requests(&chain.name);
+    let plan =
+        dry_run::Plan::new(env!("CARGO_PKG_NAME"), &input)?.rpc(&chain.endpoint(rpc_url)?, calls);
+    let Some(result_size) = abi::static_size(types) else {
+        return Ok(plan.note("The gas estimate depends on the size of the dynamic return values"));
+    };
+    let widest = trigger::solidity::ContractRead {
+        target: request.target,
+        callData: request.call_data.clone(),
+        returnType: abi::type_name(types),
+        blockNumber: u64::MAX,
+        result: vec![0xff; result_size].into(),
+    };
+    Ok(plan.submission(&encode_trigger_output(trigger_id, EthereumOutput::abi(&widest)).payload))
+}
+
+fn resolve_chain() -> Result<chains::Chain, String> {
+    chains::resolve(|name| get_evm_chain_config(name).map(|c| (c.chain_id, c.http_endpoint)))
+}
+
+// The call is made at a pinned block, so the result names the state it was read from
+async fn read(
+    request: ReadRequest,
+    types: DynSolType,
+    rpc_url: Option<&str>,
+) -> Result<(ContractReadResult, trigger::solidity::ContractRead), String> {
+    let chain = resolve_chain()?;
+    let provider = replay::evm_provider(chain.endpoint(rpc_url)?);
+    timestamp::record_trigger_block(&provider, &chain.name).await;
+    let block_number = reorg::pin_latest(&provider).await?.number;
+
+    let tx = alloy_rpc_types::eth::TransactionRequest {
+        to: Some(TxKind::Call(request.target)),
+        input: TransactionInput { input: Some(request.call_data.clone()), data: None },
+        ..Default::default()
+    };
+    let data = provider
+        .call(tx)
+        .block(BlockId::number(block_number))
+        .await
+        .map_err(|e| rpc::describe(&format!("Call to {} failed", request.target), &e))?;
+    // Accounts without code answer every call with empty data
+    if data.is_empty() {
+        return Err(format!("{} returned no data; is it a contract?", request.target));
+    }
+    let values = abi::decode(&types, &data)?;
+    let result = values.abi_encode_params();
+    if result.len() > MAX_RESULT_BYTES {
+        return Err(format!(
+            "Result is {} bytes, more than the {} allowed",
+            result.len(),
+            MAX_RESULT_BYTES
+        ));
+    }
+    let return_type = abi::type_name(&types);
+    info!(
+        "{} on {} at block {} returned {} bytes of {}",
+        request.target,
+        chain.name,
+        block_number,
+        result.len(),
+        return_type
+    );
+
+    let output = ContractReadResult {
+        chain,
+        target: request.target.to_checksum(None),
+        selector: format!("0x{}", hex::encode(&request.call_data[..4])),
+        call_data: request.call_data.to_string(),
+        return_type: return_type.clone(),
+        block_number,
+        values: abi::to_json(&values),
+        result: format!("0x{}", hex::encode(&result)),
+    };
+    let encoded = trigger::solidity::ContractRead {
+        target: request.target,
+        callData: request.call_data,
+        returnType: return_type,
+        blockNumber: block_number,
+        result: result.into(),
+    };
+    Ok((output, encoded))
+}
@@ -0,0 +1,116 @@
+// The drand networks this component trusts. Each one's group public key is pinned here rather
+// than read from a relay's /info, so a relay can withhold rounds but not forge them: every round
+// is checked against the key before it is reported.
+use sha2::{Digest, Sha256};
+
+pub enum Scheme {
+    // pedersen-bls-chained: signatures on G2 over sha256(previous_signature || round), keys on G1
+    Chained,
+    // bls-unchained-g1-rfc9380: signatures on G1 over sha256(round), keys on G2
+    UnchainedG1,
+}
+
+pub struct Chain {
+    pub name: &'static str,
+    pub hash: &'static str,
+    public_key: &'static str,
+    pub genesis_time: u64,
+    pub period_secs: u64,
+    scheme: Scheme,
+}
+
+pub const CHAINS: [Chain; 2] = [
+    Chain {
+        name: "quicknet",
+        hash: "52db9ba70e0cc0f6eaf7803dd07447a1f5477735fd3f661792ba94600c84e971",
+        public_key: "83cf0f2896adee7eb8b5f01fcad3912212c437e0073e911fb90022d3e760183c8c4b450b6a0a6c3ac6a5776a2d1064510d1fec758c921cc22b0e17e63aaf4bcb5ed66304de9cf809bd274ca73bab4af5a6e9c76a4bc09e76eae8991ef5ece45a",
+        genesis_time: 1_692_803_367,
+        period_secs: 3,
+        scheme: Scheme::UnchainedG1,
+    },
+    Chain {
+        name: "default",
+        hash: "8990e7a9aaed2ffed73dbd7092123d6f289930540d7651336225dc172e51b2ce",
+        public_key: "868f005eb8e6e4ca0a47c8a77ceaa5309a47978a7c71bc5cce96366b5d7a569937c529eeda66c7293784a9402801af31",
+        genesis_time: 1_595_431_050,
+        period_secs: 30,
+        scheme: Scheme::Chained,
+    },
+];
+
+const G1_DST: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
+const G2_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_";
+
+// WAVS_ENV_DRAND_CHAIN names the network, quicknet unless set
+pub fn configured() -> Result<&'static Chain, String> {
+    let name = std::env::var("WAVS_ENV_DRAND_CHAIN").unwrap_or_default();
+    let name = match name.trim() {
+        "" => "quicknet",
+        name => name,
+    };
+    CHAINS.iter().find(|chain| chain.name == name).ok_or_else(|| {
+        let known: Vec<&str> = CHAINS.iter().map(|chain| chain.name).collect();
+        format!("Unknown WAVS_ENV_DRAND_CHAIN '{}'; expected one of {}", name, known.join(", "))
+    })
+}
+
+impl Chain {
+    // The round in progress at `now`; 0 before genesis
+    pub fn current_round(&self, now: u64) -> u64 {
+        match now.checked_sub(self.genesis_time) {
+            Some(elapsed) => elapsed / self.period_secs + 1,
+            None => 0,
+        }
+    }
+
+    pub fn round_time(&self, round: u64) -> u64 {
+        self.genesis_time + round.saturating_sub(1) * self.period_secs
+    }
+
+    pub fn is_chained(&self) -> bool {
+        matches!(self.scheme, Scheme::Chained)
+    }
+
+    // Checks the round's signature against the pinned key and returns its randomness,
+    // sha256(signature)
+    pub fn verify(
+        &self,
+        round: u64,
+        signature: &[u8],
+        previous_signature: Option<&[u8]>,
+    ) -> Result<[u8; 32], String> {
+        let public_key = alloy_primitives::hex::decode(self.public_key)
+            .map_err(|e| format!("Invalid pinned public key: {}", e))?;
+        let mut message = Sha256::new();
+        if self.is_chained() {
+            let previous = previous_signature
+                .ok_or_else(|| format!("Round {} has no previous signature", round))?;
+            message.update(previous);
+        }
+        message.update(round.to_be_bytes());
+        let message = message.finalize();
+
+        let result = match self.scheme {
+            Scheme::UnchainedG1 => {
+                use blst::min_sig::{PublicKey, Signature};
+                let key = PublicKey::from_bytes(&public_key)
+                    .map_err(|e| format!("Invalid pinned public key: {:?}", e))?;
+                let signature = Signature::from_bytes(signature)
+                    .map_err(|e| format!("Invalid signature for round {}: {:?}", round, e))?;
+                signature.verify(true, &message, G1_DST, &[], &key, true)
+            }
+            Scheme::Chained => {
+                use blst::min_pk::{PublicKey, Signature};
+                let key = PublicKey::from_bytes(&public_key)
+                    .map_err(|e| format!("Invalid pinned public key: {:?}", e))?;
+                let signature = Signature::from_bytes(signature)
+                    .map_err(|e| format!("Invalid signature for round {}: {:?}", round, e))?;
+                signature.verify(true, &message, G2_DST, &[], &key, true)
+            }
+        };
+        if result != blst::BLST_ERROR::BLST_SUCCESS {
+            return Err(format!("Signature of round {} does not verify ({:?})", round, result));
+        }
+        Ok(Sha256::digest(signature).into())
+    }
+}
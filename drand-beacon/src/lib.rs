@@ -0,0 +1,128 @@
+mod chain;
+mod relay;
+mod trigger;
+use trigger::{encode_cli_output, encode_trigger_output, Codec, Destination};
+use wavs_wasi_utils::evm::alloy_primitives::hex;
+pub mod bindings;
+use crate::bindings::{export, Guest, TriggerAction, WasmResponse};
+use alloy_primitives::B256;
+use alloy_sol_types::SolCall;
+use serde::{Deserialize, Serialize};
+use wavs_component_common::{
+    decode, dry_run, envelope, freshness, info,
+    output::{CliOutput, EthereumOutput},
+    run, selector, timing, versioned,
+};
+use wstd::runtime::block_on;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RandomnessResult {
+    chain: String,
+    chain_hash: String,
+    round: u64,
+    randomness: String,
+    signature: String,
+    // only set on chained networks, where it is part of the signed message
+    previous_signature: Option<String>,
+    // unix time the round was due
+    round_time: u64,
+    relay: String,
+}
+
+struct Component;
+export!(Component with_types_in bindings);
+
+impl Guest for Component {
+    fn run(action: TriggerAction) -> std::result::Result<Option<WasmResponse>, String> {
+        run::run(Codec, action.data, process)
+    }
+}
+
+fn process(
+    trigger_id: u64,
+    req: Vec<u8>,
+    dest: Destination,
+    options: envelope::Options,
+) -> Result<Option<WasmResponse>, String> {
+    options.check_supported(&["dry_run"])?;
+
+    // Decode trigger data inline - handles hex string input. None asks for the latest round.
+    let round = {
+        let hex_data = decode::input_bytes(&req)?;
+        selector::check(
+            &hex_data,
+            &[
+                selector::Expected::of::<trigger::solidity::latestRandomnessCall>(),
+                selector::Expected::of::<trigger::solidity::randomnessAtCall>(),
+            ],
+        )?;
+        if let Ok(decoded) = trigger::solidity::randomnessAtCall::abi_decode(&hex_data) {
+            Some(decoded.round)
+        } else if trigger::solidity::latestRandomnessCall::abi_decode(&hex_data).is_ok() {
+            None
+        } else {
+            let input = decode::abi_string(&hex_data)?;
+            match input.trim() {
+                "" | "latest" => None,
+                round => Some(
+                    round
+                        .parse::<u64>()
+                        .map_err(|e| format!("Invalid drand round '{}': {}", round, e))?,
+                ),
+            }
+        }
+    };
+
+    let chain = chain::configured()?;
+    if let Some(round) = round {
+        let current = chain.current_round(freshness::now_secs());
+        if round == 0 || round > current {
+            return Err(format!(
+                "Round {} of drand {} is not available; the current round is {}",
+                round, chain.name, current
+            ));
+        }
+    }
+
+    timing::end_phase("decode");
+    if options.dry_run {
+        let input = serde_json::json!({ "chain": chain.name, "round": round });
+        // Relays are tried in order until one answers, so each is asked at most once
+        let plan = relay::relays()
+            .iter()
+            .fold(dry_run::Plan::new(env!("CARGO_PKG_NAME"), &input)?, |plan, url| {
+                plan.http(url, 1)
+            });
+        // The payload is static, so its size is known; all-ones is the costliest calldata
+        let encoded =
+            trigger::solidity::Randomness { round: u64::MAX, randomness: B256::repeat_byte(0xff) };
+        let plan = plan
+            .submission(&encode_trigger_output(trigger_id, EthereumOutput::abi(&encoded)).payload);
+        let payload = plan.output(matches!(dest, Destination::Ethereum), options.json_format())?;
+        return payload.map(encode_cli_output).transpose();
+    }
+    let beacon = block_on(async move { relay::fetch(chain, round).await })?;
+    timing::end_phase("upstream");
+    info!("Verified drand {} round {} from {}", chain.name, beacon.round, beacon.relay);
+
+    let encoded = trigger::solidity::Randomness {
+        round: beacon.round,
+        randomness: B256::from(beacon.randomness),
+    };
+    let result = RandomnessResult {
+        chain: chain.name.to_string(),
+        chain_hash: chain.hash.to_string(),
+        round: beacon.round,
+        randomness: format!("0x{}", hex::encode(beacon.randomness)),
+        signature: format!("0x{}", hex::encode(&beacon.signature)),
+        previous_signature: beacon
+            .previous_signature
+            .map(|previous| format!("0x{}", hex::encode(previous))),
+        round_time: chain.round_time(beacon.round),
+        relay: beacon.relay,
+    };
+    let data = EthereumOutput::abi(&encoded).traced();
+    run::respond(Codec, trigger_id, data, dest, &options, || {
+        Ok(CliOutput::new(versioned!(&result)))
+    })
+}
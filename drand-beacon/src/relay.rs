@@ -0,0 +1,106 @@
+// Fetches beacon rounds from drand HTTP relays. Relays are untrusted transport: a round is only
+// returned once its signature verifies against the chain's pinned key, and a relay serving a
+// bad or unreachable round is skipped in favour of the next one.
+use crate::chain::Chain;
+use alloy_primitives::hex;
+use serde::Deserialize;
+use wavs_component_common::{replay, warn};
+use wavs_wasi_utils::http::http_request_get;
+use wstd::http::HeaderValue;
+
+const DEFAULT_RELAYS: [&str; 3] =
+    ["https://api.drand.sh", "https://api2.drand.sh", "https://drand.cloudflare.com"];
+
+#[derive(Debug, Deserialize)]
+struct BeaconResponse {
+    round: u64,
+    randomness: String,
+    signature: String,
+    #[serde(default)]
+    previous_signature: Option<String>,
+}
+
+pub struct Beacon {
+    pub round: u64,
+    pub randomness: [u8; 32],
+    pub signature: Vec<u8>,
+    pub previous_signature: Option<Vec<u8>>,
+    // the relay that served the round
+    pub relay: String,
+}
+
+// WAVS_ENV_DRAND_URLS, a comma-separated list tried in order, or the League of Entropy's
+// public relays
+pub fn relays() -> Vec<String> {
+    let configured = std::env::var("WAVS_ENV_DRAND_URLS").unwrap_or_default();
+    let urls: Vec<String> = configured
+        .split(',')
+        .map(|url| url.trim().trim_end_matches('/'))
+        .filter(|url| !url.is_empty())
+        .map(str::to_string)
+        .collect();
+    if urls.is_empty() {
+        DEFAULT_RELAYS.iter().map(|url| url.to_string()).collect()
+    } else {
+        urls
+    }
+}
+
+// `round` of None asks for the latest round
+pub async fn fetch(chain: &Chain, round: Option<u64>) -> Result<Beacon, String> {
+    let path = match round {
+        Some(round) => format!("/{}/public/{}", chain.hash, round),
+        None => format!("/{}/public/latest", chain.hash),
+    };
+    let urls = relays();
+    let mut failures = Vec::new();
+    for (index, relay) in urls.iter().enumerate() {
+        let failure = match fetch_verified(chain, relay, &path, round).await {
+            Ok(beacon) => return Ok(beacon),
+            Err(e) => e,
+        };
+        if index + 1 < urls.len() {
+            warn!("drand relay {} failed, trying the next one: {}", relay, failure);
+        }
+        failures.push(format!("{}: {}", relay, failure));
+    }
+    Err(format!("Every drand relay failed ({})", failures.join("; ")))
+}
+
+async fn fetch_verified(
+    chain: &Chain,
+    relay: &str,
+    path: &str,
+    round: Option<u64>,
+) -> Result<Beacon, String> {
+    let url = format!("{}{}", relay, path);
+    let mut req = http_request_get(&url).map_err(|e| format!("Failed to create request: {}", e))?;
+    req.headers_mut().insert("Accept", HeaderValue::from_static("application/json"));
+    let response: BeaconResponse = replay::fetch_json(req).await.map_err(|e| e.to_string())?;
+
+    if round.is_some_and(|round| round != response.round) {
+        return Err(format!("asked for round {:?}, got round {}", round, response.round));
+    }
+    let decode = |field: &str, value: &str| {
+        hex::decode(value).map_err(|e| format!("invalid {} '{}': {}", field, value, e))
+    };
+    let signature = decode("signature", &response.signature)?;
+    let previous_signature = match &response.previous_signature {
+        Some(previous) if chain.is_chained() => Some(decode("previous_signature", previous)?),
+        _ => None,
+    };
+    let randomness = chain.verify(response.round, &signature, previous_signature.as_deref())?;
+    // The relay's own randomness field is only a convenience; it has to match the value derived
+    // from the verified signature
+    if decode("randomness", &response.randomness)? != randomness {
+        return Err(format!("randomness of round {} does not match its signature", response.round));
+    }
+
+    Ok(Beacon {
+        round: response.round,
+        randomness,
+        signature,
+        previous_signature,
+        relay: relay.to_string(),
+    })
+}
@@ -0,0 +1,218 @@
+// The Chainlink ETH / USD aggregators the price is taken from, one per chain, and their latest
+// rounds. Answers are carried as integers with PRICE_DECIMALS digits of fraction so no float
+// reaches the output.
+use crate::bindings::host::get_evm_chain_config;
+use alloy_network::Ethereum;
+use alloy_primitives::{address, aliases::U80, Address, TxKind, U256};
+use alloy_provider::{Provider, RootProvider};
+use alloy_rpc_types::TransactionInput;
+use alloy_sol_types::{sol, SolCall};
+use wavs_component_common::{freshness, rpc};
+
+pub const PRICE_DECIMALS: u8 = 8;
+const DEFAULT_FEEDS: &str = "ethereum,arbitrum,optimism,base";
+const MAX_FEEDS: usize = 16;
+// Grace on top of a feed's heartbeat before its answer counts as stale
+const HEARTBEAT_GRACE_SECS: u64 = 600;
+// Heartbeat assumed for aggregators configured by address
+const DEFAULT_HEARTBEAT_SECS: u64 = 3_600;
+
+sol! {
+    interface AggregatorV3Interface {
+        function decimals() external view returns (uint8);
+        function latestRoundData() external view returns (uint80 roundId, int256 answer, uint256 startedAt, uint256 updatedAt, uint80 answeredInRound);
+    }
+}
+
+struct KnownFeed {
+    chain: &'static str,
+    aggregator: Address,
+    heartbeat_secs: u64,
+}
+
+// Chainlink's ETH / USD aggregators, by the chain names of the WAVS chain config
+const KNOWN_FEEDS: [KnownFeed; 4] = [
+    KnownFeed {
+        chain: "ethereum",
+        aggregator: address!("5f4eC3Df9cbd43714FE2740f5E3616155c5b8419"),
+        heartbeat_secs: 3_600,
+    },
+    KnownFeed {
+        chain: "arbitrum",
+        aggregator: address!("639Fe6ab55C921f74e7fac1ee960C0B6293ba612"),
+        heartbeat_secs: 86_400,
+    },
+    KnownFeed {
+        chain: "optimism",
+        aggregator: address!("13e3Ee699D1909E989722E753853AE30b17e08c5"),
+        heartbeat_secs: 1_200,
+    },
+    KnownFeed {
+        chain: "base",
+        aggregator: address!("71041dddad3595F9CEd3DcCFBe3D1F4b0a16Bb70"),
+        heartbeat_secs: 1_200,
+    },
+];
+
+pub struct Feed {
+    pub chain: String,
+    pub aggregator: Address,
+    heartbeat_secs: u64,
+}
+
+pub struct Round {
+    pub round_id: U80,
+    pub answer: U256,
+    pub updated_at: u64,
+}
+
+// WAVS_ENV_ETH_PRICE_FEEDS lists the chains to read, e.g. `ethereum,base,polygon=0xF968...`.
+// A bare name uses Chainlink's aggregator on that chain; `name=address` names the aggregator,
+// for chains without a known one or to pin another deployment.
+pub fn configured() -> Result<Vec<Feed>, String> {
+    let configured = std::env::var("WAVS_ENV_ETH_PRICE_FEEDS")
+        .ok()
+        .filter(|feeds| !feeds.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_FEEDS.to_string());
+    let mut feeds: Vec<Feed> = Vec::new();
+    for entry in configured.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        let feed = match entry.split_once('=') {
+            Some((chain, aggregator)) => Feed {
+                chain: chain.trim().to_string(),
+                aggregator: aggregator.trim().parse().map_err(|e| {
+                    format!(
+                        "Invalid aggregator in WAVS_ENV_ETH_PRICE_FEEDS entry '{}': {}",
+                        entry, e
+                    )
+                })?,
+                heartbeat_secs: DEFAULT_HEARTBEAT_SECS,
+            },
+            None => {
+                let known = KNOWN_FEEDS.iter().find(|feed| feed.chain == entry).ok_or_else(|| {
+                    let known: Vec<&str> = KNOWN_FEEDS.iter().map(|feed| feed.chain).collect();
+                    format!(
+                        "No known ETH / USD aggregator on '{}' (known: {}); configure it as {}=<address>",
+                        entry,
+                        known.join(", "),
+                        entry
+                    )
+                })?;
+                Feed {
+                    chain: known.chain.to_string(),
+                    aggregator: known.aggregator,
+                    heartbeat_secs: known.heartbeat_secs,
+                }
+            }
+        };
+        if feeds.iter().any(|f| f.chain == feed.chain) {
+            return Err(format!(
+                "Chain '{}' appears more than once in WAVS_ENV_ETH_PRICE_FEEDS",
+                feed.chain
+            ));
+        }
+        feeds.push(feed);
+    }
+    if feeds.is_empty() || feeds.len() > MAX_FEEDS {
+        return Err(format!("Between 1 and {} feeds are required, got {}", MAX_FEEDS, feeds.len()));
+    }
+    Ok(feeds)
+}
+
+// The fresh answers a run needs, WAVS_ENV_ETH_PRICE_MIN_FEEDS or a majority of the feeds
+pub fn min_feeds(feed_count: usize) -> Result<usize, String> {
+    let Ok(configured) = std::env::var("WAVS_ENV_ETH_PRICE_MIN_FEEDS") else {
+        return Ok(feed_count / 2 + 1);
+    };
+    configured
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .filter(|min| (1..=feed_count).contains(min))
+        .ok_or_else(|| {
+            format!(
+                "WAVS_ENV_ETH_PRICE_MIN_FEEDS must be between 1 and {}, the number of feeds, not '{}'",
+                feed_count, configured
+            )
+        })
+}
+
+// A chain missing from the chain config is a configuration error and fails the run, unlike a
+// feed that can't be read
+pub fn endpoint(feed: &Feed) -> Result<String, String> {
+    get_evm_chain_config(&feed.chain)
+        .ok_or_else(|| format!("Failed to get {} chain config", feed.chain))?
+        .http_endpoint
+        .ok_or_else(|| format!("{} chain config has no HTTP endpoint", feed.chain))
+}
+
+pub async fn latest_round(provider: &RootProvider<Ethereum>, feed: &Feed) -> Result<Round, String> {
+    let decimals = aggregator_call(provider, feed, AggregatorV3Interface::decimalsCall {}).await?;
+    let decimals = AggregatorV3Interface::decimalsCall::abi_decode_returns(&decimals)
+        .map_err(|e| format!("Failed to decode Chainlink decimals: {}", e))?;
+    let round =
+        aggregator_call(provider, feed, AggregatorV3Interface::latestRoundDataCall {}).await?;
+    let round = AggregatorV3Interface::latestRoundDataCall::abi_decode_returns(&round)
+        .map_err(|e| format!("Failed to decode Chainlink round data: {}", e))?;
+
+    let max_age = std::env::var("WAVS_ENV_ETH_PRICE_MAX_AGE_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(feed.heartbeat_secs + HEARTBEAT_GRACE_SECS);
+    let updated_at: u64 = round.updatedAt.try_into().unwrap_or(u64::MAX);
+    if freshness::now_secs().saturating_sub(updated_at) > max_age {
+        return Err(format!("Round {} is stale (updated at {})", round.roundId, updated_at));
+    }
+    if !round.answer.is_positive() {
+        return Err(format!("Round {} has a non-positive answer: {}", round.roundId, round.answer));
+    }
+
+    Ok(Round {
+        round_id: round.roundId,
+        answer: rescale(round.answer.into_raw(), decimals),
+        updated_at,
+    })
+}
+
+async fn aggregator_call(
+    provider: &RootProvider<Ethereum>,
+    feed: &Feed,
+    call: impl SolCall,
+) -> Result<Vec<u8>, String> {
+    let tx = alloy_rpc_types::eth::TransactionRequest {
+        to: Some(TxKind::Call(feed.aggregator)),
+        input: TransactionInput { input: Some(call.abi_encode().into()), data: None },
+        ..Default::default()
+    };
+    let result = provider.call(tx).await.map_err(|e| rpc::describe("Chainlink call failed", &e))?;
+    Ok(result.to_vec())
+}
+
+// The middle answer, or the mean of the two middle ones rounded down
+pub fn median(answers: &[U256]) -> U256 {
+    let mut sorted = answers.to_vec();
+    sorted.sort();
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 1 {
+        sorted[mid]
+    } else {
+        (sorted[mid - 1] + sorted[mid]) / U256::from(2)
+    }
+}
+
+fn rescale(value: U256, from: u8) -> U256 {
+    if from >= PRICE_DECIMALS {
+        value / U256::from(10).pow(U256::from(from - PRICE_DECIMALS))
+    } else {
+        value * U256::from(10).pow(U256::from(PRICE_DECIMALS - from))
+    }
+}
+
+pub fn format_price(price: U256) -> String {
+    let scale = U256::from(10).pow(U256::from(PRICE_DECIMALS));
+    let fraction =
+        format!("{:0>width$}", (price % scale).to_string(), width = PRICE_DECIMALS as usize);
+    match fraction.trim_end_matches('0') {
+        "" => (price / scale).to_string(),
+        fraction => format!("{}.{}", price / scale, fraction),
+    }
+}
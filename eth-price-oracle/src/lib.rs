@@ -0,0 +1,222 @@
+mod feeds;
+mod trigger;
+use feeds::{format_price, PRICE_DECIMALS};
+use trigger::{encode_cli_output, encode_trigger_output, Codec, Destination};
+pub mod bindings;
+use crate::bindings::{export, Guest, TriggerAction, WasmResponse};
+use alloy_primitives::{aliases::U80, U256};
+use alloy_sol_types::SolCall;
+use serde::{Deserialize, Serialize};
+use wavs_component_common::{
+    decode, dry_run, envelope, error, freshness, info,
+    output::{CliOutput, EthereumOutput},
+    replay, run, selector, timestamp, timing, versioned, warn,
+};
+use wstd::runtime::block_on;
+
+// ETH moves a percent in minutes on a busy day, so a reading is only trusted briefly
+const DEFAULT_RESULT_TTL_SECS: u64 = 120;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FeedReading {
+    chain: String,
+    aggregator: String,
+    // decimal string: Chainlink round IDs carry the aggregator phase in their top bits
+    round_id: String,
+    answer: String,
+    updated_at: u64,
+    // distance of the answer from the median
+    deviation_bps: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ExcludedFeed {
+    chain: String,
+    aggregator: String,
+    error: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EthPriceResult {
+    // median of the fresh feeds' answers
+    price: String,
+    decimals: u8,
+    feeds: Vec<FeedReading>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    excluded: Vec<ExcludedFeed>,
+    min_feeds: usize,
+    // highest answer over the lowest, in basis points
+    spread_bps: u64,
+    expires_at: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct InsufficientFeeds<'a> {
+    error: &'a str,
+    fresh_feeds: usize,
+    min_feeds: usize,
+    excluded: &'a [ExcludedFeed],
+}
+
+struct Component;
+export!(Component with_types_in bindings);
+
+impl Guest for Component {
+    fn run(action: TriggerAction) -> std::result::Result<Option<WasmResponse>, String> {
+        run::run(Codec, action.data, process)
+    }
+}
+
+fn process(
+    trigger_id: u64,
+    req: Vec<u8>,
+    dest: Destination,
+    options: envelope::Options,
+) -> Result<Option<WasmResponse>, String> {
+    // No rpc_url: one override can't stand in for the endpoints of several chains
+    options.check_supported(&["dry_run"])?;
+
+    // checkEthPrice() calldata, or a bare ABI string naming the pair
+    let data = decode::input_bytes(&req)?;
+    selector::check(&data, &[selector::Expected::of::<trigger::solidity::checkEthPriceCall>()])?;
+    if data.starts_with(&trigger::solidity::checkEthPriceCall::SELECTOR) {
+        trigger::solidity::checkEthPriceCall::abi_decode(&data).map_err(|e| {
+            format!(
+                "Invalid arguments for {}: {}",
+                trigger::solidity::checkEthPriceCall::SIGNATURE,
+                e
+            )
+        })?;
+    } else {
+        let pair = decode::abi_string(&data)?;
+        if !matches!(pair.trim().to_ascii_uppercase().as_str(), "" | "ETH" | "ETH/USD") {
+            return Err(format!("Unsupported pair '{}', expected ETH/USD", pair.trim()));
+        }
+    }
+    let feeds = feeds::configured()?;
+    let min_feeds = feeds::min_feeds(feeds.len())?;
+
+    timing::end_phase("decode");
+    if options.dry_run {
+        let plan = plan(trigger_id, &feeds, min_feeds)?;
+        let payload = plan.output(matches!(dest, Destination::Ethereum), options.json_format())?;
+        return payload.map(encode_cli_output).transpose();
+    }
+    let (result, encoded) = block_on(async move { eth_price(&feeds, min_feeds).await })?;
+    timing::end_phase("upstream");
+
+    run::respond(Codec, trigger_id, EthereumOutput::abi(&encoded).traced(), dest, &options, || {
+        Ok(CliOutput::new(versioned!(&result)))
+    })
+}
+
+// Each feed's decimals and latest round, on its own chain. Gas is estimated with every feed
+// fresh and the widest words.
+fn plan(trigger_id: u64, feeds: &[feeds::Feed], min_feeds: usize) -> Result<dry_run::Plan, String> {
+    let chains: Vec<&str> = feeds.iter().map(|feed| feed.chain.as_str()).collect();
+    let input = serde_json::json!({ "feeds": chains, "min_feeds": min_feeds });
+    let mut plan = dry_run::Plan::new(env!("CARGO_PKG_NAME"), &input)?;
+    for feed in feeds {
+        let calls = 2 + timestamp::trigger_block_requests(&feed.chain);
+        plan = plan.rpc(&feeds::endpoint(feed)?, calls);
+    }
+    let widest = trigger::solidity::EthPrice {
+        price: U256::MAX,
+        decimals: PRICE_DECIMALS,
+        timestamp: U256::MAX,
+        rounds: feeds
+            .iter()
+            .map(|feed| trigger::solidity::FeedRound {
+                chain: feed.chain.clone(),
+                aggregator: feed.aggregator,
+                roundId: U80::MAX,
+                answer: U256::MAX,
+                updatedAt: U256::MAX,
+            })
+            .collect(),
+    };
+    Ok(plan.submission(&encode_trigger_output(trigger_id, EthereumOutput::abi(&widest)).payload))
+}
+
+// A feed that can't be read or is stale is left out rather than failing the run, as long as
+// min_feeds remain. The median only leaves the range of the correct answers when more than half
+// of the answers it is taken from are wrong.
+async fn eth_price(
+    feeds: &[feeds::Feed],
+    min_feeds: usize,
+) -> Result<(EthPriceResult, trigger::solidity::EthPrice), String> {
+    let mut rounds = Vec::new();
+    let mut excluded = Vec::new();
+    for feed in feeds {
+        let provider = replay::evm_provider(feeds::endpoint(feed)?);
+        timestamp::record_trigger_block(&provider, &feed.chain).await;
+        match feeds::latest_round(&provider, feed).await {
+            Ok(round) => rounds.push((feed, round)),
+            Err(e) => {
+                warn!("Leaving out the {} feed: {}", feed.chain, e);
+                excluded.push(ExcludedFeed {
+                    chain: feed.chain.clone(),
+                    aggregator: feed.aggregator.to_string(),
+                    error: e,
+                });
+            }
+        }
+    }
+    if rounds.len() < min_feeds {
+        let error = InsufficientFeeds {
+            error: "insufficient_feeds",
+            fresh_feeds: rounds.len(),
+            min_feeds,
+            excluded: &excluded,
+        };
+        return Err(serde_json::to_string(&error).map_err(|e| e.to_string())?);
+    }
+
+    let answers: Vec<U256> = rounds.iter().map(|(_, round)| round.answer).collect();
+    let price = feeds::median(&answers);
+    let lowest = answers.iter().min().copied().unwrap_or_default();
+    let highest = answers.iter().max().copied().unwrap_or_default();
+    let now = timestamp::run_secs();
+    info!("ETH at {} from {} of {} feeds", format_price(price), rounds.len(), feeds.len());
+
+    let encoded = trigger::solidity::EthPrice {
+        price,
+        decimals: PRICE_DECIMALS,
+        timestamp: U256::from(now),
+        rounds: rounds
+            .iter()
+            .map(|(feed, round)| trigger::solidity::FeedRound {
+                chain: feed.chain.clone(),
+                aggregator: feed.aggregator,
+                roundId: round.round_id,
+                answer: round.answer,
+                updatedAt: U256::from(round.updated_at),
+            })
+            .collect(),
+    };
+    let result = EthPriceResult {
+        price: format_price(price),
+        decimals: PRICE_DECIMALS,
+        feeds: rounds
+            .iter()
+            .map(|(feed, round)| FeedReading {
+                chain: feed.chain.clone(),
+                aggregator: feed.aggregator.to_string(),
+                round_id: round.round_id.to_string(),
+                answer: format_price(round.answer),
+                updated_at: round.updated_at,
+                deviation_bps: bps(round.answer.abs_diff(price), price),
+            })
+            .collect(),
+        excluded,
+        min_feeds,
+        spread_bps: bps(highest - lowest, lowest),
+        expires_at: freshness::expires_at(now, DEFAULT_RESULT_TTL_SECS),
+    };
+    Ok((result, encoded))
+}
+
+// Answers are positive, so `of` is never zero
+fn bps(difference: U256, of: U256) -> u64 {
+    (difference * U256::from(10_000) / of).try_into().unwrap_or(u64::MAX)
+}
@@ -0,0 +1,217 @@
+use serde_json::Value;
+use std::str::FromStr;
+use wavs_component_common::output::{Dynamic, EthereumOutput};
+use wavs_wasi_utils::evm::alloy_primitives::{hex, Address, FixedBytes, Sign, B256, I256, U256};
+
+// Walks a dot-path such as `data.items[0].price`, `data.items.0.price` or `$.data.price`
+pub fn extract_path<'a>(value: &'a Value, path: &str) -> Result<&'a Value, String> {
+    let path = path.trim();
+    let path = path.strip_prefix('$').unwrap_or(path);
+    let path = path.strip_prefix('.').unwrap_or(path);
+
+    let mut current = value;
+    if path.is_empty() {
+        return Ok(current);
+    }
+
+    for segment in path.split('.') {
+        let (key, indexes) = split_indexes(segment)?;
+        if !key.is_empty() {
+            current = match current {
+                Value::Object(map) => {
+                    map.get(key).ok_or_else(|| format!("Key '{}' not found", key))?
+                }
+                Value::Array(items) => {
+                    let index: usize =
+                        key.parse().map_err(|_| format!("Expected array index, got '{}'", key))?;
+                    items.get(index).ok_or_else(|| format!("Index {} out of bounds", index))?
+                }
+                _ => return Err(format!("Cannot read '{}' from a non-container value", key)),
+            };
+        }
+        for index in indexes {
+            current = current
+                .as_array()
+                .ok_or_else(|| format!("Cannot index [{}] into a non-array value", index))?
+                .get(index)
+                .ok_or_else(|| format!("Index {} out of bounds", index))?;
+        }
+    }
+    Ok(current)
+}
+
+// Splits `items[0][1]` into (`items`, [0, 1])
+fn split_indexes(segment: &str) -> Result<(&str, Vec<usize>), String> {
+    let Some(start) = segment.find('[') else {
+        return Ok((segment, Vec::new()));
+    };
+    let key = &segment[..start];
+    let mut indexes = Vec::new();
+    for part in segment[start..].split_inclusive(']') {
+        let inner = part
+            .strip_prefix('[')
+            .and_then(|p| p.strip_suffix(']'))
+            .ok_or_else(|| format!("Malformed index in path segment '{}'", segment))?;
+        indexes
+            .push(inner.parse().map_err(|_| format!("Invalid index '{}' in path segment", inner))?);
+    }
+    Ok((key, indexes))
+}
+
+#[derive(Debug, Clone)]
+pub struct Coerced {
+    pub display: String,
+    pub encoded: EthereumOutput<Dynamic>,
+}
+
+// Coerces a JSON value into the declared Solidity type and ABI-encodes it.
+// Numeric types accept JSON numbers or numeric strings and are scaled by `decimals`.
+pub fn coerce(value: &Value, sol_type: &str, decimals: u8) -> Result<Coerced, String> {
+    let sol_type = sol_type.trim();
+    match sol_type {
+        "bool" => {
+            let b = match value {
+                Value::Bool(b) => *b,
+                Value::String(s) if s.eq_ignore_ascii_case("true") => true,
+                Value::String(s) if s.eq_ignore_ascii_case("false") => false,
+                other => return Err(format!("Cannot coerce {} to bool", other)),
+            };
+            Ok(Coerced { display: b.to_string(), encoded: EthereumOutput::abi(&b).into_dynamic() })
+        }
+        "string" => {
+            let s = match value {
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            Ok(Coerced { display: s.clone(), encoded: EthereumOutput::abi(&s).into_dynamic() })
+        }
+        "address" => {
+            let s = value.as_str().ok_or_else(|| format!("Cannot coerce {} to address", value))?;
+            let address =
+                Address::from_str(s).map_err(|e| format!("Invalid address '{}': {}", s, e))?;
+            Ok(Coerced {
+                display: address.to_checksum(None),
+                encoded: EthereumOutput::abi(&address).into_dynamic(),
+            })
+        }
+        "bytes32" => {
+            let s = value.as_str().ok_or_else(|| format!("Cannot coerce {} to bytes32", value))?;
+            let bytes = hex::decode(s).map_err(|e| format!("Invalid hex '{}': {}", s, e))?;
+            if bytes.len() != 32 {
+                return Err(format!("Expected 32 bytes for bytes32, got {}", bytes.len()));
+            }
+            let word = FixedBytes::<32>::from_slice(&bytes);
+            Ok(Coerced {
+                display: word.to_string(),
+                encoded: EthereumOutput::abi(&word).into_dynamic(),
+            })
+        }
+        t if t.starts_with("uint") => {
+            let bits = type_bits(&t[4..])?;
+            let (negative, magnitude) = parse_decimal(&numeric_string(value)?, decimals)?;
+            if negative && !magnitude.is_zero() {
+                return Err(format!("Negative value cannot be coerced to {}", t));
+            }
+            if bits < 256 && magnitude >= (U256::from(1) << bits) {
+                return Err(format!("Value {} overflows {}", magnitude, t));
+            }
+            Ok(Coerced {
+                display: magnitude.to_string(),
+                encoded: EthereumOutput::abi(&magnitude).into_dynamic(),
+            })
+        }
+        t if t.starts_with("int") => {
+            let bits = type_bits(&t[3..])?;
+            let (negative, magnitude) = parse_decimal(&numeric_string(value)?, decimals)?;
+            let limit = U256::from(1) << (bits - 1);
+            if (!negative && magnitude >= limit) || (negative && magnitude > limit) {
+                return Err(format!("Value {} overflows {}", magnitude, t));
+            }
+            // int256's minimum has no positive counterpart to negate
+            let sign = if negative { Sign::Negative } else { Sign::Positive };
+            let signed = I256::checked_from_sign_and_abs(sign, magnitude)
+                .ok_or_else(|| format!("Value {} overflows {}", magnitude, t))?;
+            Ok(Coerced {
+                display: signed.to_string(),
+                encoded: EthereumOutput::abi(&signed).into_dynamic(),
+            })
+        }
+        other => Err(format!("Unsupported Solidity type '{}'", other)),
+    }
+}
+
+// Checks `sol_type` before anything is fetched, giving the widest encoding of it for dry-run gas
+// estimates. Every supported type but string encodes to one 32-byte word; a string's size
+// depends on the fetched value, so it has None.
+pub fn widest(sol_type: &str) -> Result<Option<EthereumOutput<Dynamic>>, String> {
+    let word = || Some(EthereumOutput::abi(&B256::repeat_byte(0xff)).into_dynamic());
+    match sol_type.trim() {
+        "string" => Ok(None),
+        "bool" | "address" | "bytes32" => Ok(word()),
+        t if t.starts_with("uint") => type_bits(&t[4..]).map(|_| word()),
+        t if t.starts_with("int") => type_bits(&t[3..]).map(|_| word()),
+        other => Err(format!("Unsupported Solidity type '{}'", other)),
+    }
+}
+
+fn type_bits(suffix: &str) -> Result<usize, String> {
+    if suffix.is_empty() {
+        return Ok(256);
+    }
+    let bits: usize = suffix.parse().map_err(|_| format!("Invalid integer width '{}'", suffix))?;
+    if bits == 0 || bits > 256 || bits % 8 != 0 {
+        return Err(format!("Invalid integer width {}", bits));
+    }
+    Ok(bits)
+}
+
+fn numeric_string(value: &Value) -> Result<String, String> {
+    match value {
+        Value::Number(n) => Ok(n.to_string()),
+        Value::String(s) => Ok(s.trim().to_string()),
+        other => Err(format!("Cannot coerce {} to a number", other)),
+    }
+}
+
+// Parses a decimal string (optionally signed, fractional or in exponent notation) and scales
+// it by 10^decimals, truncating any remaining fractional digits. Returns (negative, magnitude).
+fn parse_decimal(input: &str, decimals: u8) -> Result<(bool, U256), String> {
+    let (negative, unsigned) = match input.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, input.strip_prefix('+').unwrap_or(input)),
+    };
+    let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+        Some(pos) => {
+            let exp: i64 = unsigned[pos + 1..]
+                .parse()
+                .map_err(|_| format!("Invalid exponent in '{}'", input))?;
+            (&unsigned[..pos], exp)
+        }
+        None => (unsigned, 0),
+    };
+    let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if int_part.is_empty() && frac_part.is_empty() {
+        return Err(format!("Invalid number '{}'", input));
+    }
+    if !int_part.chars().chain(frac_part.chars()).all(|c| c.is_ascii_digit()) {
+        return Err(format!("Invalid number '{}'", input));
+    }
+
+    // Shift the decimal point right by the exponent plus the requested decimals
+    let digits = format!("{}{}", int_part, frac_part);
+    let point = int_part.len() as i64 + exponent + decimals as i64;
+    let kept = if point <= 0 {
+        String::new()
+    } else if point as usize >= digits.len() {
+        format!("{}{}", digits, "0".repeat(point as usize - digits.len()))
+    } else {
+        digits[..point as usize].to_string()
+    };
+    let kept = kept.trim_start_matches('0');
+    if kept.is_empty() {
+        return Ok((negative, U256::ZERO));
+    }
+    let magnitude = U256::from_str_radix(kept, 10)
+        .map_err(|e| format!("Number '{}' does not fit in 256 bits: {}", input, e))?;
+    Ok((negative, magnitude))
+}
@@ -0,0 +1,193 @@
+mod coerce;
+mod trigger;
+use trigger::{encode_cli_output, encode_trigger_output, Codec, Destination};
+use wavs_wasi_utils::{
+    evm::alloy_primitives::hex,
+    http::{http_request_get, http_request_post_json},
+};
+pub mod bindings;
+use crate::bindings::{export, Guest, TriggerAction, WasmResponse};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use wavs_component_common::{
+    decode_abi_string_input, dry_run, envelope, info, oauth,
+    output::{CliOutput, Dynamic, EthereumOutput},
+    replay, run, selector, timing, versioned,
+};
+use wstd::{http::HeaderValue, runtime::block_on};
+
+// Request spec carried in the trigger as a JSON string
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OracleRequest {
+    url: String,
+    #[serde(default = "default_method")]
+    method: String,
+    path: String,
+    #[serde(rename = "type")]
+    sol_type: String,
+    #[serde(default)]
+    decimals: u8,
+    #[serde(default)]
+    body: Option<Value>,
+}
+
+fn default_method() -> String {
+    "GET".to_string()
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OracleResult {
+    url: String,
+    path: String,
+    #[serde(rename = "type")]
+    sol_type: String,
+    value: String,
+    encoded: String,
+}
+
+struct Component;
+export!(Component with_types_in bindings);
+
+impl Guest for Component {
+    fn run(action: TriggerAction) -> std::result::Result<Option<WasmResponse>, String> {
+        run::run(Codec, action.data, process)
+    }
+}
+
+fn process(
+    trigger_id: u64,
+    req: Vec<u8>,
+    dest: Destination,
+    options: envelope::Options,
+) -> Result<Option<WasmResponse>, String> {
+    options.check_supported(&["dry_run"])?;
+
+    // Hex text, fetchValue calldata or a bare ABI string
+    let request_json = decode_abi_string_input(
+        &req,
+        &[selector::Expected::of::<trigger::solidity::fetchValueCall>()],
+    )?;
+
+    let request: OracleRequest = serde_json::from_str(&request_json)
+        .map_err(|e| format!("Failed to parse oracle request: {}", e))?;
+    check_allowlist(&request.url)?;
+    if !matches!(request.method.to_uppercase().as_str(), "GET" | "POST") {
+        return Err(format!("Unsupported HTTP method '{}'", request.method));
+    }
+    let widest = coerce::widest(&request.sol_type)?;
+    let oauth = oauth_client(&request.url)?;
+
+    timing::end_phase("decode");
+    if options.dry_run {
+        let mut plan = dry_run::Plan::new(env!("CARGO_PKG_NAME"), &request)?;
+        if let Some(oauth) = &oauth {
+            plan = oauth.planned(plan);
+        }
+        plan = plan.http(&request.url, 1);
+        if let Some(widest) = widest {
+            plan = plan.submission(&encode_trigger_output(trigger_id, widest).payload);
+        }
+        let payload = plan.output(matches!(dest, Destination::Ethereum), options.json_format())?;
+        return payload.map(encode_cli_output).transpose();
+    }
+    let (result, encoded) = block_on(async move { fetch_value(&request, oauth.as_ref()).await })?;
+    timing::end_phase("upstream");
+
+    run::respond(Codec, trigger_id, encoded.traced(), dest, &options, || {
+        Ok(CliOutput::new(versioned!(&result)))
+    })
+}
+
+async fn fetch_value(
+    request: &OracleRequest,
+    oauth: Option<&oauth::Client>,
+) -> Result<(OracleResult, EthereumOutput<Dynamic>), String> {
+    info!("Fetching {} {}", request.method, request.url);
+
+    let response: Value = match request.method.to_uppercase().as_str() {
+        "GET" => {
+            let mut req = http_request_get(&request.url)
+                .map_err(|e| format!("Failed to create request: {}", e))?;
+            req.headers_mut().insert("Accept", HeaderValue::from_static("application/json"));
+            req.headers_mut().insert("User-Agent", HeaderValue::from_static("Mozilla/5.0"));
+            if let Some(oauth) = oauth {
+                oauth.authorize(&mut req).await?;
+            }
+            replay::fetch_json(req).await
+        }
+        "POST" => {
+            let body = request.body.clone().unwrap_or(Value::Object(Default::default()));
+            let mut req = http_request_post_json(&request.url, &body)
+                .map_err(|e| format!("Failed to create request: {}", e))?;
+            req.headers_mut().insert("Accept", HeaderValue::from_static("application/json"));
+            req.headers_mut().insert("User-Agent", HeaderValue::from_static("Mozilla/5.0"));
+            if let Some(oauth) = oauth {
+                oauth.authorize(&mut req).await?;
+            }
+            replay::fetch_json(req).await
+        }
+        other => return Err(format!("Unsupported HTTP method '{}'", other)),
+    }
+    .map_err(|e| format!("Failed to fetch data: {}", e))?;
+
+    let value = coerce::extract_path(&response, &request.path)
+        .map_err(|e| format!("Failed to extract '{}': {}", request.path, e))?;
+    let coerced = coerce::coerce(value, &request.sol_type, request.decimals)?;
+
+    let result = OracleResult {
+        url: request.url.clone(),
+        path: request.path.clone(),
+        sol_type: request.sol_type.clone(),
+        value: coerced.display,
+        encoded: format!("0x{}", hex::encode(coerced.encoded.data())),
+    };
+    Ok((result, coerced.encoded))
+}
+
+// URLs must match one of the comma-separated patterns in WAVS_ENV_HTTP_ORACLE_ALLOWLIST,
+// where `*` matches any run of characters. Nothing is allowed when the variable is unset.
+fn check_allowlist(url: &str) -> Result<(), String> {
+    let allowlist = std::env::var("WAVS_ENV_HTTP_ORACLE_ALLOWLIST").map_err(|_| {
+        "WAVS_ENV_HTTP_ORACLE_ALLOWLIST is not set, refusing to fetch any URL".to_string()
+    })?;
+    if allowlist.split(',').map(str::trim).any(|p| !p.is_empty() && glob_match(p, url)) {
+        Ok(())
+    } else {
+        Err(format!("URL '{}' is not in the allowlist", url))
+    }
+}
+
+// APIs behind client-credentials tokens are configured with the HTTP_ORACLE prefix (see
+// oauth.rs). The token is only sent to URLs matching WAVS_ENV_HTTP_ORACLE_OAUTH_URLS, in the
+// allowlist's pattern syntax, so other allowlisted hosts never see it.
+fn oauth_client(url: &str) -> Result<Option<oauth::Client>, String> {
+    let Some(client) = oauth::Client::from_env("HTTP_ORACLE")? else {
+        return Ok(None);
+    };
+    let patterns = std::env::var("WAVS_ENV_HTTP_ORACLE_OAUTH_URLS").map_err(|_| {
+        "WAVS_ENV_HTTP_ORACLE_OAUTH_URLS must list the URLs that get the OAuth token".to_string()
+    })?;
+    let matches = patterns.split(',').map(str::trim).any(|p| !p.is_empty() && glob_match(p, url));
+    Ok(matches.then_some(client))
+}
+
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let parts: Vec<&str> = pattern.split('*').collect();
+    if parts.len() == 1 {
+        return pattern == text;
+    }
+    let first = parts[0];
+    let last = parts[parts.len() - 1];
+    if text.len() < first.len() + last.len() || !text.starts_with(first) || !text.ends_with(last) {
+        return false;
+    }
+    // Both ends matched, so the slice starts and ends on char boundaries
+    let mut rest = &text[first.len()..text.len() - last.len()];
+    for part in &parts[1..parts.len() - 1] {
+        match rest.find(part) {
+            Some(pos) => rest = &rest[pos + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
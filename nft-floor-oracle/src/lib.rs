@@ -0,0 +1,115 @@
+mod reservoir;
+mod trigger;
+use trigger::{encode_cli_output, encode_trigger_output, Codec, Destination};
+pub mod bindings;
+use crate::bindings::{export, Guest, TriggerAction, WasmResponse};
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::SolCall;
+use serde::{Deserialize, Serialize};
+use wavs_component_common::{
+    decode, dry_run, envelope, info,
+    output::{CliOutput, EthereumOutput},
+    run, selector, timestamp, timing, versioned,
+};
+use wstd::runtime::block_on;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FloorPriceResult {
+    collection: String,
+    name: Option<String>,
+    // decimal strings, since wei amounts exceed what JSON numbers hold exactly
+    floor_wei: String,
+    floor_currency: String,
+    // marketplace of the floor listing, e.g. "opensea.io"
+    floor_source: Option<String>,
+    volume_24h_wei: String,
+    api_url: String,
+}
+
+struct Component;
+export!(Component with_types_in bindings);
+
+impl Guest for Component {
+    fn run(action: TriggerAction) -> std::result::Result<Option<WasmResponse>, String> {
+        run::run(Codec, action.data, process)
+    }
+}
+
+fn process(
+    trigger_id: u64,
+    req: Vec<u8>,
+    dest: Destination,
+    options: envelope::Options,
+) -> Result<Option<WasmResponse>, String> {
+    options.check_supported(&["dry_run"])?;
+
+    // Decode trigger data inline - handles hex string input
+    let collection = {
+        let hex_data = decode::input_bytes(&req)?;
+        selector::check(
+            &hex_data,
+            &[selector::Expected::of::<trigger::solidity::checkFloorPriceCall>()],
+        )?;
+        if let Ok(decoded) = trigger::solidity::checkFloorPriceCall::abi_decode(&hex_data) {
+            decoded.collection
+        } else {
+            let input = decode::abi_string(&hex_data)?;
+            input
+                .trim()
+                .parse::<Address>()
+                .map_err(|e| format!("Invalid collection address '{}': {}", input.trim(), e))?
+        }
+    };
+
+    timing::end_phase("decode");
+    if options.dry_run {
+        let payload = plan(trigger_id, collection)?
+            .output(matches!(dest, Destination::Ethereum), options.json_format())?;
+        return payload.map(encode_cli_output).transpose();
+    }
+    let (result, encoded) = block_on(async move { floor_price(collection).await })?;
+    timing::end_phase("upstream");
+
+    run::respond(Codec, trigger_id, EthereumOutput::abi(&encoded).traced(), dest, &options, || {
+        Ok(CliOutput::new(versioned!(&result)))
+    })
+}
+
+// One collections request; gas is estimated for the widest amount and timestamp words
+fn plan(trigger_id: u64, collection: Address) -> Result<dry_run::Plan, String> {
+    let widest = trigger::solidity::FloorPrice {
+        collection,
+        floorWei: U256::MAX,
+        volumeWei: U256::MAX,
+        ts: u64::MAX,
+    };
+    let input = serde_json::json!({ "collection": collection.to_checksum(None) });
+    Ok(dry_run::Plan::new(env!("CARGO_PKG_NAME"), &input)?
+        .http(&reservoir::api_url(), 1)
+        .submission(&encode_trigger_output(trigger_id, EthereumOutput::abi(&widest)).payload))
+}
+
+async fn floor_price(
+    collection: Address,
+) -> Result<(FloorPriceResult, trigger::solidity::FloorPrice), String> {
+    info!("Looking up the floor price of {}", collection);
+    let stats = reservoir::collection_stats(collection).await?;
+    let ts = timestamp::run_secs();
+
+    let encoded = trigger::solidity::FloorPrice {
+        collection,
+        floorWei: stats.floor_wei,
+        volumeWei: stats.volume_wei,
+        ts,
+    };
+    let result = FloorPriceResult {
+        collection: collection.to_checksum(None),
+        name: stats.name,
+        floor_wei: stats.floor_wei.to_string(),
+        floor_currency: stats.floor_currency,
+        floor_source: stats.floor_source,
+        volume_24h_wei: stats.volume_wei.to_string(),
+        api_url: reservoir::api_url(),
+    };
+    Ok((result, encoded))
+}
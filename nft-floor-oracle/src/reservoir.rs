@@ -0,0 +1,170 @@
+// Collection floor price and 24h volume from the Reservoir API (collections/v7). The default host
+// serves Ethereum mainnet; WAVS_ENV_RESERVOIR_API_URL points at another chain's host, and
+// WAVS_ENV_RESERVOIR_API_KEY is sent as x-api-key when set.
+use alloy_primitives::{Address, U256};
+use serde::Deserialize;
+use serde_json::Number;
+use wavs_component_common::replay;
+use wavs_wasi_utils::http::http_request_get;
+use wstd::http::HeaderValue;
+
+const DEFAULT_API_URL: &str = "https://api.reservoir.tools";
+const WEI_DECIMALS: i32 = 18;
+
+#[derive(Debug, Deserialize)]
+struct CollectionsResponse {
+    #[serde(default)]
+    collections: Vec<Collection>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Collection {
+    id: String,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default, rename = "floorAsk")]
+    floor_ask: Option<FloorAsk>,
+    #[serde(default)]
+    volume: Option<Volume>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FloorAsk {
+    #[serde(default)]
+    price: Option<Price>,
+    #[serde(default, rename = "sourceDomain")]
+    source_domain: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Price {
+    currency: Currency,
+    amount: Amount,
+}
+
+#[derive(Debug, Deserialize)]
+struct Currency {
+    contract: Address,
+    #[serde(default)]
+    symbol: Option<String>,
+    #[serde(default)]
+    decimals: Option<u8>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Amount {
+    // in the currency's smallest unit
+    raw: String,
+    // converted to the chain's native currency by Reservoir
+    #[serde(default)]
+    native: Option<Number>,
+}
+
+// Reservoir reports volumes in the chain's native currency
+#[derive(Debug, Deserialize)]
+struct Volume {
+    #[serde(default, rename = "1day")]
+    one_day: Option<Number>,
+}
+
+pub struct CollectionStats {
+    pub name: Option<String>,
+    pub floor_wei: U256,
+    // the currency the floor listing is priced in, e.g. "ETH" or "WETH"
+    pub floor_currency: String,
+    pub floor_source: Option<String>,
+    pub volume_wei: U256,
+}
+
+pub fn api_url() -> String {
+    std::env::var("WAVS_ENV_RESERVOIR_API_URL")
+        .ok()
+        .map(|url| url.trim().trim_end_matches('/').to_string())
+        .filter(|url| !url.is_empty())
+        .unwrap_or_else(|| DEFAULT_API_URL.to_string())
+}
+
+pub async fn collection_stats(collection: Address) -> Result<CollectionStats, String> {
+    let id = collection.to_string().to_lowercase();
+    let url = format!("{}/collections/v7?id={}", api_url(), id);
+    let response: CollectionsResponse = get(&url).await?;
+    // Token-range collections share the contract's address in their id, so only an exact match
+    // is the whole collection
+    let entry = response
+        .collections
+        .into_iter()
+        .find(|entry| entry.id.to_lowercase() == id)
+        .ok_or_else(|| format!("Reservoir has no collection {}", collection))?;
+
+    let floor_ask = entry.floor_ask.unwrap_or(FloorAsk { price: None, source_domain: None });
+    let price =
+        floor_ask.price.ok_or_else(|| format!("Collection {} has no floor listing", collection))?;
+    let floor_wei = if price.currency.contract.is_zero() {
+        if price.currency.decimals.is_some_and(|decimals| i32::from(decimals) != WEI_DECIMALS) {
+            return Err(format!("Unexpected decimals for the native currency of {}", collection));
+        }
+        price
+            .amount
+            .raw
+            .parse::<U256>()
+            .map_err(|e| format!("Invalid floor amount '{}': {}", price.amount.raw, e))?
+    } else {
+        // Listings in another currency (WETH, USDC, ...) are valued at Reservoir's native rate
+        let native = price.amount.native.as_ref().ok_or_else(|| {
+            format!("Floor listing of {} has no native currency value", collection)
+        })?;
+        to_wei(native)?
+    };
+    let volume_wei = match entry.volume.and_then(|volume| volume.one_day) {
+        Some(volume) => to_wei(&volume)?,
+        None => U256::ZERO,
+    };
+
+    Ok(CollectionStats {
+        name: entry.name,
+        floor_wei,
+        floor_currency: price.currency.symbol.unwrap_or_default(),
+        floor_source: floor_ask.source_domain,
+        volume_wei,
+    })
+}
+
+// Native-currency amounts arrive as JSON numbers such as 12.345 or 1.5e-7. Converting from their
+// text rather than through f64 arithmetic gives every operator the same wei value; digits past
+// 18 decimals are truncated.
+fn to_wei(amount: &Number) -> Result<U256, String> {
+    let text = amount.to_string();
+    let invalid = || format!("Invalid native amount {}", text);
+    let (mantissa, exponent) = match text.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i32>().map_err(|_| invalid())?),
+        None => (text.as_str(), 0),
+    };
+    let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = format!("{}{}", whole, fraction);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+
+    let scale = WEI_DECIMALS + exponent - fraction.len() as i32;
+    if scale >= 0 {
+        let factor = U256::from(10).checked_pow(U256::from(scale)).ok_or_else(invalid)?;
+        digits.parse::<U256>().ok().and_then(|v| v.checked_mul(factor)).ok_or_else(invalid)
+    } else {
+        let kept = digits.len().saturating_sub(scale.unsigned_abs() as usize);
+        match &digits[..kept] {
+            "" => Ok(U256::ZERO),
+            kept => kept.parse::<U256>().map_err(|_| invalid()),
+        }
+    }
+}
+
+async fn get<T: serde::de::DeserializeOwned>(url: &str) -> Result<T, String> {
+    let mut req = http_request_get(url).map_err(|e| format!("Failed to create request: {}", e))?;
+    req.headers_mut().insert("Accept", HeaderValue::from_static("application/json"));
+    if let Some(key) = std::env::var("WAVS_ENV_RESERVOIR_API_KEY").ok().filter(|k| !k.is_empty()) {
+        let key = HeaderValue::from_str(key.trim())
+            .map_err(|e| format!("Invalid WAVS_ENV_RESERVOIR_API_KEY: {}", e))?;
+        req.headers_mut().insert("x-api-key", key);
+    }
+    replay::fetch_json(req).await.map_err(|e| format!("Failed to fetch {}: {}", url, e))
+}
@@ -0,0 +1,210 @@
+mod metadata;
+mod trigger;
+mod uri;
+use trigger::{encode_cli_output, Codec, Destination};
+pub mod bindings;
+use crate::bindings::host::get_evm_chain_config;
+use crate::bindings::{export, Guest, TriggerAction, WasmResponse};
+use alloy_network::Ethereum;
+use alloy_primitives::{keccak256, Address, TxKind, U256};
+use alloy_provider::{Provider, RootProvider};
+use alloy_rpc_types::{BlockId, TransactionInput};
+use alloy_sol_types::{sol, SolCall};
+use serde::{Deserialize, Serialize};
+use wavs_component_common::{
+    decode, dry_run, envelope, info,
+    output::{CliOutput, EthereumOutput},
+    reorg, replay, rpc, run, selector, timestamp, timing, versioned,
+};
+use wstd::runtime::block_on;
+
+sol! {
+    interface IERC721Metadata {
+        function tokenURI(uint256 tokenId) external view returns (string);
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MetadataSnapshot {
+    collection: String,
+    // decimal, since token ids often exceed what JSON numbers hold exactly
+    token_id: String,
+    // block tokenURI was read at
+    block_number: u64,
+    token_uri: String,
+    // https, ipfs or data
+    uri_scheme: String,
+    name: Option<String>,
+    description: Option<String>,
+    image: Option<String>,
+    // keccak256 of the metadata document as fetched or decoded, before normalization
+    content_hash: String,
+    content_bytes: usize,
+}
+
+struct Component;
+export!(Component with_types_in bindings);
+
+impl Guest for Component {
+    fn run(action: TriggerAction) -> std::result::Result<Option<WasmResponse>, String> {
+        run::run(Codec, action.data, process)
+    }
+}
+
+fn process(
+    trigger_id: u64,
+    req: Vec<u8>,
+    dest: Destination,
+    options: envelope::Options,
+) -> Result<Option<WasmResponse>, String> {
+    options.check_supported(&["rpc_url", "dry_run"])?;
+
+    let (collection, token_id) = {
+        let hex_data = decode::input_bytes(&req)?;
+        selector::check(
+            &hex_data,
+            &[selector::Expected::of::<trigger::solidity::fetchMetadataCall>()],
+        )?;
+        if let Ok(decoded) = trigger::solidity::fetchMetadataCall::abi_decode(&hex_data) {
+            (decoded.collection, decoded.tokenId)
+        } else {
+            let input = decode::abi_string(&hex_data)?;
+            parse_token_ref(&input)?
+        }
+    };
+
+    let rpc_url = options.rpc_url.clone();
+    timing::end_phase("decode");
+    if options.dry_run {
+        let plan = plan(collection, token_id, rpc_url.as_deref())?;
+        let payload = plan.output(matches!(dest, Destination::Ethereum), options.json_format())?;
+        return payload.map(encode_cli_output).transpose();
+    }
+    let (result, encoded) =
+        block_on(async move { snapshot(collection, token_id, rpc_url.as_deref()).await })?;
+    timing::end_phase("upstream");
+
+    run::respond(Codec, trigger_id, EthereumOutput::abi(&encoded).traced(), dest, &options, || {
+        Ok(CliOutput::new(versioned!(&result)))
+    })
+}
+
+// "<collection>:<tokenId>", with the token id in decimal or 0x-prefixed hex
+fn parse_token_ref(input: &str) -> Result<(Address, U256), String> {
+    let (collection, token_id) = input
+        .trim()
+        .split_once(':')
+        .ok_or_else(|| format!("Expected <collection>:<tokenId>, got '{}'", input))?;
+    let collection = collection
+        .trim()
+        .parse::<Address>()
+        .map_err(|e| format!("Invalid collection address '{}': {}", collection, e))?;
+    let token_id = token_id
+        .trim()
+        .parse::<U256>()
+        .map_err(|e| format!("Invalid token id '{}': {}", token_id, e))?;
+    Ok((collection, token_id))
+}
+
+// The pinned block and the tokenURI read. Where the document comes from, and how large the
+// snapshot gets, depends on the URI, so neither is in the plan.
+fn plan(
+    collection: Address,
+    token_id: U256,
+    rpc_url: Option<&str>,
+) -> Result<dry_run::Plan, String> {
+    let input = serde_json::json!({
+        "collection": collection.to_checksum(None),
+        "token_id": token_id.to_string(),
+    });
+    let calls = 2 + timestamp::trigger_block_requests("ethereum");
+    Ok(dry_run::Plan::new(env!("CARGO_PKG_NAME"), &input)?
+        .rpc(&ethereum_endpoint(rpc_url)?, calls)
+        .note("The metadata comes from the https host or IPFS gateway tokenURI names, if any"))
+}
+
+fn ethereum_endpoint(rpc_url: Option<&str>) -> Result<String, String> {
+    let chain_config = get_evm_chain_config("ethereum")
+        .ok_or_else(|| "Failed to get Ethereum chain config".to_string())?;
+    rpc::resolve_endpoint(rpc_url, chain_config.http_endpoint)
+}
+
+// tokenURI is read at a pinned block so the snapshot can name the state it was taken from;
+// the document behind an https or IPFS URI is whatever it is at fetch time
+async fn snapshot(
+    collection: Address,
+    token_id: U256,
+    rpc_url: Option<&str>,
+) -> Result<(MetadataSnapshot, trigger::solidity::NftMetadata), String> {
+    let provider = replay::evm_provider(ethereum_endpoint(rpc_url)?);
+    timestamp::record_trigger_block(&provider, "ethereum").await;
+    let block_number = reorg::pin_latest(&provider).await?.number;
+
+    let token_uri = read_token_uri(&provider, collection, token_id, block_number).await?;
+    let document = uri::fetch_document(&token_uri).await?;
+    let metadata = metadata::parse(&document.bytes)?;
+    let content_hash = keccak256(&document.bytes);
+    info!(
+        "Token {} of {} at block {}: {} bytes of {} metadata",
+        token_id,
+        collection,
+        block_number,
+        document.bytes.len(),
+        document.scheme
+    );
+
+    let token_uri = uri::reported(&token_uri);
+    let encoded = trigger::solidity::NftMetadata {
+        collection,
+        tokenId: token_id,
+        blockNumber: block_number,
+        tokenUri: token_uri.clone(),
+        name: metadata.name.clone().unwrap_or_default(),
+        description: metadata.description.clone().unwrap_or_default(),
+        image: metadata.image.clone().unwrap_or_default(),
+        contentHash: content_hash,
+    };
+    let result = MetadataSnapshot {
+        collection: collection.to_checksum(None),
+        token_id: token_id.to_string(),
+        block_number,
+        token_uri,
+        uri_scheme: document.scheme.to_string(),
+        name: metadata.name,
+        description: metadata.description,
+        image: metadata.image,
+        content_hash: content_hash.to_string(),
+        content_bytes: document.bytes.len(),
+    };
+    Ok((result, encoded))
+}
+
+async fn read_token_uri(
+    provider: &RootProvider<Ethereum>,
+    collection: Address,
+    token_id: U256,
+    block_number: u64,
+) -> Result<String, String> {
+    let call = IERC721Metadata::tokenURICall { tokenId: token_id };
+    let tx = alloy_rpc_types::eth::TransactionRequest {
+        to: Some(TxKind::Call(collection)),
+        input: TransactionInput { input: Some(call.abi_encode().into()), data: None },
+        ..Default::default()
+    };
+    let result = provider.call(tx).block(BlockId::number(block_number)).await.map_err(|e| {
+        rpc::describe(&format!("tokenURI({}) failed, the token may not exist", token_id), &e)
+    })?;
+    // Accounts without code answer every call with empty data
+    if result.is_empty() {
+        return Err(format!(
+            "{} returned no data for tokenURI; is it an ERC-721 contract?",
+            collection
+        ));
+    }
+    let token_uri = IERC721Metadata::tokenURICall::abi_decode_returns(&result)
+        .map_err(|e| format!("Failed to decode tokenURI: {}", e))?;
+    if token_uri.trim().is_empty() {
+        return Err(format!("Token {} has an empty tokenURI", token_id));
+    }
+    Ok(token_uri)
+}
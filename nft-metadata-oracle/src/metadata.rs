@@ -0,0 +1,74 @@
+// Validation of a metadata document against the ERC-721 metadata JSON schema, which declares
+// `name`, `description` and `image` as strings on a top-level object and requires none of them.
+// Other fields (attributes, external_url, ...) are allowed and ignored.
+use crate::uri;
+use serde_json::{Map, Value};
+
+const FIELDS: [&str; 3] = ["name", "description", "image"];
+
+pub struct Metadata {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub image: Option<String>,
+}
+
+pub fn parse(bytes: &[u8]) -> Result<Metadata, String> {
+    let text = std::str::from_utf8(bytes).map_err(|_| "Metadata is not UTF-8".to_string())?;
+    let value: Value = serde_json::from_str(text.trim_start_matches('\u{feff}'))
+        .map_err(|e| format!("Metadata is not valid JSON: {}", e))?;
+    let Value::Object(document) = value else {
+        return Err("Metadata must be a JSON object".to_string());
+    };
+
+    let violations: Vec<String> = FIELDS
+        .iter()
+        .filter_map(|field| match document.get(*field) {
+            None | Some(Value::Null) | Some(Value::String(_)) => None,
+            Some(other) => Some(format!("{} must be a string, not {}", field, kind(other))),
+        })
+        .collect();
+    if !violations.is_empty() {
+        return Err(format!(
+            "Metadata does not match the ERC-721 metadata schema: {}",
+            violations.join("; ")
+        ));
+    }
+
+    // OpenSea's older `image_url` stands in for a missing `image`
+    let image = text_field(&document, "image", false)
+        .or_else(|| text_field(&document, "image_url", false))
+        .map(|image| uri::canonical_image(&image));
+    let metadata = Metadata {
+        name: text_field(&document, "name", false),
+        description: text_field(&document, "description", true),
+        image,
+    };
+    // An error body like {"error": "not found"} is a valid object too
+    if metadata.name.is_none() && metadata.description.is_none() && metadata.image.is_none() {
+        return Err("Metadata has no name, description or image".to_string());
+    }
+    Ok(metadata)
+}
+
+// Trimmed, without control characters (descriptions keep line breaks and tabs). Null and
+// blank values count as missing, which is how most collections mean them.
+fn text_field(document: &Map<String, Value>, field: &str, multiline: bool) -> Option<String> {
+    let text: String = document
+        .get(field)
+        .and_then(Value::as_str)?
+        .chars()
+        .filter(|c| !c.is_control() || multiline && matches!(c, '\n' | '\t'))
+        .collect();
+    Some(text.trim().to_string()).filter(|text| !text.is_empty())
+}
+
+fn kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
@@ -0,0 +1,149 @@
+// Turns a token URI into the bytes of its metadata document. ERC-721 leaves the scheme open; in
+// practice collections use https, ipfs:// (fetched here through WAVS_ENV_IPFS_GATEWAY) or data:
+// URIs that carry the JSON in the contract itself. Anything else is rejected.
+use base64::Engine;
+use wavs_component_common::replay;
+use wavs_wasi_utils::http::http_request_get;
+use wstd::http::HeaderValue;
+
+const DEFAULT_IPFS_GATEWAY: &str = "https://ipfs.io/ipfs";
+// Token URIs are echoed in errors; data: URIs can be many kilobytes long
+const MAX_ERROR_URI_CHARS: usize = 80;
+
+pub struct Document {
+    // https, ipfs or data
+    pub scheme: &'static str,
+    pub bytes: Vec<u8>,
+}
+
+pub async fn fetch_document(uri: &str) -> Result<Document, String> {
+    let uri = uri.trim();
+    if let Some(path) = ipfs_path(uri) {
+        let bytes = fetch(&format!("{}/{}", gateway(), path), uri).await?;
+        return Ok(Document { scheme: "ipfs", bytes });
+    }
+    if has_scheme(uri, "data:") {
+        return Ok(Document { scheme: "data", bytes: decode_data_uri(uri)? });
+    }
+    if has_scheme(uri, "https://") {
+        return Ok(Document { scheme: "https", bytes: fetch(uri, uri).await? });
+    }
+    Err(format!("Unsupported token URI: {}", preview(uri)))
+}
+
+// Gateways may carry an access token in their URL, so errors name the token URI instead
+async fn fetch(url: &str, uri: &str) -> Result<Vec<u8>, String> {
+    let mut req = http_request_get(url).map_err(|e| format!("Failed to create request: {}", e))?;
+    req.headers_mut().insert("Accept", HeaderValue::from_static("application/json"));
+    replay::fetch_bytes(req)
+        .await
+        .map_err(|e| format!("Failed to fetch metadata for {}: {}", preview(uri), e))
+}
+
+fn gateway() -> String {
+    std::env::var("WAVS_ENV_IPFS_GATEWAY")
+        .ok()
+        .map(|g| g.trim().trim_end_matches('/').to_string())
+        .filter(|g| !g.is_empty())
+        .unwrap_or_else(|| DEFAULT_IPFS_GATEWAY.to_string())
+}
+
+// The CID and path of ipfs://<cid>/<path>, also accepting the older ipfs://ipfs/<cid> form
+fn ipfs_path(uri: &str) -> Option<&str> {
+    let rest = strip_scheme(uri, "ipfs://")?;
+    let rest = rest.strip_prefix("ipfs/").unwrap_or(rest);
+    (!rest.is_empty()).then_some(rest)
+}
+
+// Canonical form of an image URI, so the same image is reported the same way whichever gateway
+// the collection happened to link: ipfs://<cid>/<path> for IPFS content, including https
+// gateway links, and everything else unchanged
+pub fn canonical_image(uri: &str) -> String {
+    let uri = uri.trim();
+    if let Some(path) = ipfs_path(uri) {
+        return format!("ipfs://{}", path);
+    }
+    if let Some(rest) = strip_scheme(uri, "https://") {
+        if let Some((_, path)) = rest.split_once("/ipfs/").filter(|(host, path)| {
+            !host.is_empty() && !host.contains('/') && path.starts_with(['Q', 'b'])
+        }) {
+            return format!("ipfs://{}", path);
+        }
+    }
+    uri.to_string()
+}
+
+// data:[<media type>][;<param>]*[;base64],<payload>. The media type has to be JSON; payloads
+// without ;base64 are percent-decoded.
+fn decode_data_uri(uri: &str) -> Result<Vec<u8>, String> {
+    let rest = &uri["data:".len()..];
+    let (header, payload) =
+        rest.split_once(',').ok_or_else(|| "data: URI has no payload".to_string())?;
+    let mut params = header.split(';').map(str::trim);
+    let media_type = params.next().unwrap_or_default().to_ascii_lowercase();
+    if media_type != "application/json" && !media_type.ends_with("+json") {
+        return Err(format!(
+            "data: URI has media type '{}', expected application/json",
+            media_type
+        ));
+    }
+    if params.any(|p| p.eq_ignore_ascii_case("base64")) {
+        let payload: String = payload.chars().filter(|c| !c.is_ascii_whitespace()).collect();
+        base64::engine::general_purpose::STANDARD
+            .decode(percent_decode(&payload))
+            .map_err(|e| format!("data: URI has invalid base64: {}", e))
+    } else {
+        Ok(percent_decode(payload))
+    }
+}
+
+// A `%` not followed by two hex digits is kept as it is: contracts often embed raw JSON that
+// was never percent-encoded, and `100%` in a description shouldn't fail the run
+fn percent_decode(text: &str) -> Vec<u8> {
+    let bytes = text.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = bytes
+            .get(i + 1..i + 3)
+            .filter(|hex| bytes[i] == b'%' && hex.iter().all(u8::is_ascii_hexdigit))
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                out.push(byte);
+                i += 3;
+            }
+            None => {
+                out.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+fn strip_scheme<'a>(uri: &'a str, scheme: &str) -> Option<&'a str> {
+    has_scheme(uri, scheme).then(|| &uri[scheme.len()..])
+}
+
+fn has_scheme(uri: &str, scheme: &str) -> bool {
+    uri.get(..scheme.len()).is_some_and(|prefix| prefix.eq_ignore_ascii_case(scheme))
+}
+
+pub fn preview(uri: &str) -> String {
+    match uri.char_indices().nth(MAX_ERROR_URI_CHARS) {
+        Some((end, _)) => format!("{}...", &uri[..end]),
+        None => uri.to_string(),
+    }
+}
+
+// How a token URI is reported in outputs: data: URIs are cut to their header, since the
+// payload is what the content hash covers and can be far larger than the rest of the result
+pub fn reported(uri: &str) -> String {
+    let uri = uri.trim();
+    match uri.find(',') {
+        Some(comma) if has_scheme(uri, "data:") => uri[..=comma].to_string(),
+        _ => uri.to_string(),
+    }
+}
@@ -0,0 +1,256 @@
+mod trigger;
+use trigger::{encode_cli_output, encode_trigger_output, Codec, Destination};
+pub mod bindings;
+use crate::bindings::host::get_evm_chain_config;
+use crate::bindings::{export, Guest, TriggerAction, WasmResponse};
+use alloy_network::Ethereum;
+use alloy_primitives::{Address, Bytes, FixedBytes, TxKind, U256};
+use alloy_provider::{Provider, RootProvider};
+use alloy_rpc_types::{BlockId, TransactionInput};
+use alloy_sol_types::{sol, SolCall};
+use serde::{Deserialize, Serialize};
+use wavs_component_common::{
+    decode, dry_run, envelope, info,
+    output::{CliOutput, EthereumOutput},
+    reorg, replay, rpc,
+    rpc::RpcErrorKind,
+    run, selector, timestamp, timing, versioned,
+};
+use wstd::runtime::block_on;
+
+sol! {
+    interface IERC721 {
+        function ownerOf(uint256 tokenId) external view returns (address);
+    }
+    interface IERC1155 {
+        function balanceOf(address account, uint256 id) external view returns (uint256);
+    }
+    interface IERC165 {
+        function supportsInterface(bytes4 interfaceId) external view returns (bool);
+    }
+}
+
+// ERC-165 interface id of ERC-721
+const ERC721_INTERFACE_ID: [u8; 4] = [0x80, 0xac, 0x58, 0xcd];
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OwnershipResult {
+    collection: String,
+    token_id: String,
+    claimed_owner: String,
+    // "erc721" or "erc1155", whichever the collection answered as
+    standard: String,
+    owns: bool,
+    // ERC-721 only; None when the token doesn't exist
+    owner: Option<String>,
+    // ERC-1155 only: the claimed owner's balance of the token
+    balance: Option<String>,
+    block_number: u64,
+}
+
+enum Holding {
+    Erc721 { owner: Option<Address> },
+    Erc1155 { balance: U256 },
+}
+
+struct Component;
+export!(Component with_types_in bindings);
+
+impl Guest for Component {
+    fn run(action: TriggerAction) -> std::result::Result<Option<WasmResponse>, String> {
+        run::run(Codec, action.data, process)
+    }
+}
+
+fn process(
+    trigger_id: u64,
+    req: Vec<u8>,
+    dest: Destination,
+    options: envelope::Options,
+) -> Result<Option<WasmResponse>, String> {
+    options.check_supported(&["rpc_url", "dry_run"])?;
+
+    let (collection, token_id, claimed_owner) = {
+        let hex_data = decode::input_bytes(&req)?;
+        selector::check(
+            &hex_data,
+            &[selector::Expected::of::<trigger::solidity::checkOwnershipCall>()],
+        )?;
+        if let Ok(decoded) = trigger::solidity::checkOwnershipCall::abi_decode(&hex_data) {
+            (decoded.collection, decoded.tokenId, decoded.claimedOwner)
+        } else {
+            let input = decode::abi_string(&hex_data)?;
+            parse_claim(&input)?
+        }
+    };
+    // A burned ERC-721 token can report the zero address as its owner
+    if claimed_owner == Address::ZERO {
+        return Err("The claimed owner can't be the zero address".to_string());
+    }
+
+    let rpc_url = options.rpc_url.clone();
+    timing::end_phase("decode");
+    if options.dry_run {
+        let plan = plan(trigger_id, collection, token_id, claimed_owner, rpc_url.as_deref())?;
+        let payload = plan.output(matches!(dest, Destination::Ethereum), options.json_format())?;
+        return payload.map(encode_cli_output).transpose();
+    }
+    let (result, encoded) = block_on(async move {
+        check_ownership(collection, token_id, claimed_owner, rpc_url.as_deref()).await
+    })?;
+    timing::end_phase("upstream");
+
+    run::respond(Codec, trigger_id, EthereumOutput::abi(&encoded).traced(), dest, &options, || {
+        Ok(CliOutput::new(versioned!(&result)))
+    })
+}
+
+// "<collection>:<tokenId>:<claimedOwner>", with the token id in decimal or 0x-prefixed hex
+fn parse_claim(input: &str) -> Result<(Address, U256, Address), String> {
+    let parts: Vec<&str> = input.trim().split(':').map(str::trim).collect();
+    let [collection, token_id, claimed_owner] = parts[..] else {
+        return Err(format!("Expected <collection>:<tokenId>:<claimedOwner>, got '{}'", input));
+    };
+    let collection = collection
+        .parse::<Address>()
+        .map_err(|e| format!("Invalid collection address '{}': {}", collection, e))?;
+    let token_id =
+        token_id.parse::<U256>().map_err(|e| format!("Invalid token id '{}': {}", token_id, e))?;
+    let claimed_owner = claimed_owner
+        .parse::<Address>()
+        .map_err(|e| format!("Invalid claimed owner '{}': {}", claimed_owner, e))?;
+    Ok((collection, token_id, claimed_owner))
+}
+
+// The pinned block and the ownerOf read an ERC-721 token that exists takes
+fn plan(
+    trigger_id: u64,
+    collection: Address,
+    token_id: U256,
+    claimed_owner: Address,
+    rpc_url: Option<&str>,
+) -> Result<dry_run::Plan, String> {
+    let input = serde_json::json!({
+        "collection": collection.to_checksum(None),
+        "token_id": token_id.to_string(),
+        "claimed_owner": claimed_owner.to_checksum(None),
+    });
+    let calls = 2 + timestamp::trigger_block_requests("ethereum");
+    let widest = trigger::solidity::OwnershipProof {
+        claimedOwner: claimed_owner,
+        owns: true,
+        blockNumber: u64::MAX,
+    };
+    Ok(dry_run::Plan::new(env!("CARGO_PKG_NAME"), &input)?
+        .rpc(&ethereum_endpoint(rpc_url)?, calls)
+        .note("ERC-1155 collections take one more call, ERC-721 tokens that don't exist two more")
+        .submission(&encode_trigger_output(trigger_id, EthereumOutput::abi(&widest)).payload))
+}
+
+fn ethereum_endpoint(rpc_url: Option<&str>) -> Result<String, String> {
+    let chain_config = get_evm_chain_config("ethereum")
+        .ok_or_else(|| "Failed to get Ethereum chain config".to_string())?;
+    rpc::resolve_endpoint(rpc_url, chain_config.http_endpoint)
+}
+
+async fn check_ownership(
+    collection: Address,
+    token_id: U256,
+    claimed_owner: Address,
+    rpc_url: Option<&str>,
+) -> Result<(OwnershipResult, trigger::solidity::OwnershipProof), String> {
+    let provider = replay::evm_provider(ethereum_endpoint(rpc_url)?);
+    timestamp::record_trigger_block(&provider, "ethereum").await;
+    let block_number = reorg::pin_latest(&provider).await?.number;
+
+    let holding =
+        read_holding(&provider, collection, token_id, claimed_owner, block_number).await?;
+    let (standard, owns, owner, balance) = match holding {
+        Holding::Erc721 { owner } => {
+            ("erc721", owner == Some(claimed_owner), owner.map(|o| o.to_checksum(None)), None)
+        }
+        Holding::Erc1155 { balance } => {
+            ("erc1155", !balance.is_zero(), None, Some(balance.to_string()))
+        }
+    };
+    info!(
+        "{} {} token {} of {} at block {}",
+        claimed_owner,
+        if owns { "owns" } else { "doesn't own" },
+        token_id,
+        collection,
+        block_number
+    );
+
+    let result = OwnershipResult {
+        collection: collection.to_checksum(None),
+        token_id: token_id.to_string(),
+        claimed_owner: claimed_owner.to_checksum(None),
+        standard: standard.to_string(),
+        owns,
+        owner,
+        balance,
+        block_number,
+    };
+    let encoded = trigger::solidity::OwnershipProof {
+        claimedOwner: claimed_owner,
+        owns,
+        blockNumber: block_number,
+    };
+    Ok((result, encoded))
+}
+
+// ownerOf first. An ERC-1155 contract has no ownerOf and reverts, so balanceOf(claimedOwner, id)
+// is tried next. An ERC-721 contract reverts on both for a token that doesn't exist, which
+// supportsInterface tells apart from a contract that is neither.
+async fn read_holding(
+    provider: &RootProvider<Ethereum>,
+    collection: Address,
+    token_id: U256,
+    claimed_owner: Address,
+    block_number: u64,
+) -> Result<Holding, String> {
+    let owner_of = IERC721::ownerOfCall { tokenId: token_id };
+    if let Some(data) = call(provider, collection, &owner_of, block_number).await? {
+        if let Ok(owner) = IERC721::ownerOfCall::abi_decode_returns(&data) {
+            return Ok(Holding::Erc721 { owner: Some(owner).filter(|o| !o.is_zero()) });
+        }
+    }
+    let balance_of = IERC1155::balanceOfCall { account: claimed_owner, id: token_id };
+    if let Some(data) = call(provider, collection, &balance_of, block_number).await? {
+        if let Ok(balance) = IERC1155::balanceOfCall::abi_decode_returns(&data) {
+            return Ok(Holding::Erc1155 { balance });
+        }
+    }
+    let supports = IERC165::supportsInterfaceCall { interfaceId: FixedBytes(ERC721_INTERFACE_ID) };
+    if let Some(data) = call(provider, collection, &supports, block_number).await? {
+        if IERC165::supportsInterfaceCall::abi_decode_returns(&data).unwrap_or(false) {
+            return Ok(Holding::Erc721 { owner: None });
+        }
+    }
+    Err(format!(
+        "{} answered neither ownerOf nor ERC-1155 balanceOf for token {}; is it an ERC-721 or ERC-1155 contract?",
+        collection, token_id
+    ))
+}
+
+// None when the call reverts or returns no data, which is what accounts without code do; other
+// failures fail the run, so a flaky endpoint isn't mistaken for a token that isn't owned
+async fn call<C: SolCall>(
+    provider: &RootProvider<Ethereum>,
+    collection: Address,
+    call: &C,
+    block_number: u64,
+) -> Result<Option<Bytes>, String> {
+    let tx = alloy_rpc_types::eth::TransactionRequest {
+        to: Some(TxKind::Call(collection)),
+        input: TransactionInput { input: Some(call.abi_encode().into()), data: None },
+        ..Default::default()
+    };
+    match provider.call(tx).block(BlockId::number(block_number)).await {
+        Ok(data) if data.is_empty() => Ok(None),
+        Ok(data) => Ok(Some(data)),
+        Err(e) if rpc::RpcError::classify(&e).kind == RpcErrorKind::ExecutionReverted => Ok(None),
+        Err(e) => Err(rpc::describe(&format!("{} failed", C::SIGNATURE), &e)),
+    }
+}
@@ -0,0 +1,94 @@
+// A/B comparison: the same prompt goes to two configured models, and the answers come back with
+// their latency and token usage plus an agreement score, the cosine similarity of the two
+// answers' embeddings. Operators use it to judge whether a cheaper model answers like the one
+// their oracle runs today. Comparisons bypass the response cache so latencies are real.
+use crate::{complete, prompt_tokens, OpenAiResponse, DEFAULT_RESULT_TTL_SECS, OPENAI_CHAT_URL};
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+use wavs_component_common::{dry_run, freshness, info, llm};
+
+const DEFAULT_MODEL_A: &str = "gpt-4";
+const DEFAULT_MODEL_B: &str = "gpt-4o-mini";
+const DEFAULT_EMBEDDING_MODEL: &str = "text-embedding-3-small";
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ModelAnswer {
+    model: String,
+    answer: String,
+    latency_ms: u64,
+    prompt_tokens: Option<u64>,
+    completion_tokens: Option<u64>,
+    total_tokens: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Comparison {
+    prompt: String,
+    a: ModelAnswer,
+    b: ModelAnswer,
+    // cosine similarity of the answer embeddings, rounded to 4 decimals; 1 means identical
+    agreement: f64,
+    embedding_model: String,
+    expires_at: u64,
+}
+
+fn env_model(name: &str, default: &str) -> String {
+    std::env::var(name).ok().filter(|m| !m.trim().is_empty()).unwrap_or_else(|| default.to_string())
+}
+
+// Both models are asked directly, then both answers are embedded in one request
+pub fn plan(plan: dry_run::Plan, prompt: &str) -> Result<dry_run::Plan, String> {
+    let model_a = env_model("WAVS_ENV_COMPARE_MODEL_A", DEFAULT_MODEL_A);
+    let model_b = env_model("WAVS_ENV_COMPARE_MODEL_B", DEFAULT_MODEL_B);
+    plan.llm(OPENAI_CHAT_URL, &model_a, 1, prompt_tokens(prompt), None)?
+        .llm(OPENAI_CHAT_URL, &model_b, 1, prompt_tokens(prompt), None)?
+        .embeddings(1)
+}
+
+pub async fn compare(prompt: &str) -> Result<Comparison, String> {
+    let model_a = env_model("WAVS_ENV_COMPARE_MODEL_A", DEFAULT_MODEL_A);
+    let model_b = env_model("WAVS_ENV_COMPARE_MODEL_B", DEFAULT_MODEL_B);
+    let embedding_model = env_model("WAVS_ENV_COMPARE_EMBEDDING_MODEL", DEFAULT_EMBEDDING_MODEL);
+    info!("Comparing {} and {}", model_a, model_b);
+
+    let a = ask(&model_a, prompt).await?;
+    let b = ask(&model_b, prompt).await?;
+
+    let embeddings = llm::embed(&embedding_model, &[&a.answer, &b.answer]).await?;
+    let similarity = llm::cosine_similarity(&embeddings[0], &embeddings[1])?;
+
+    Ok(Comparison {
+        prompt: prompt.to_string(),
+        a,
+        b,
+        agreement: (similarity * 10_000.0).round() / 10_000.0,
+        embedding_model,
+        expires_at: freshness::expires_at(freshness::now_secs(), DEFAULT_RESULT_TTL_SECS),
+    })
+}
+
+async fn ask(model: &str, prompt: &str) -> Result<ModelAnswer, String> {
+    let started = Instant::now();
+    let response: OpenAiResponse = complete(model, prompt).await?;
+    let latency_ms = started.elapsed().as_millis() as u64;
+
+    let answer = response
+        .choices
+        .as_ref()
+        .and_then(|choices| choices.first())
+        .and_then(|choice| choice.message.as_ref())
+        .and_then(|message| message.content.clone())
+        .filter(|answer| !answer.trim().is_empty())
+        .ok_or_else(|| format!("{} returned no answer", model))?;
+    let usage = response.usage.unwrap_or_default();
+
+    Ok(ModelAnswer {
+        // the snapshot that actually answered, e.g. gpt-4-0613
+        model: response.model.unwrap_or_else(|| model.to_string()),
+        answer,
+        latency_ms,
+        prompt_tokens: usage.prompt_tokens,
+        completion_tokens: usage.completion_tokens,
+        total_tokens: usage.total_tokens,
+    })
+}
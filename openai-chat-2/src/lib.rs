@@ -1,14 +1,22 @@
+mod compare;
 mod trigger;
-use trigger::{decode_trigger_event, encode_trigger_output, Destination};
+use trigger::{encode_cli_output, encode_trigger_output, Codec, Destination};
 use wavs_wasi_utils::{
-    evm::alloy_primitives::hex,
-    http::{fetch_json, http_request_post_json},
+    evm::alloy_primitives::{hex, keccak256},
+    http::http_request_post_json,
 };
 pub mod bindings;
 use crate::bindings::{export, Guest, TriggerAction, WasmResponse};
-use alloy_sol_types::SolValue;
+use alloy_sol_types::SolCall;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use wavs_component_common::{
+    cache, commit_reveal, debug, decode, dry_run, envelope, freshness, info, llm,
+    output::{CliOutput, EthereumOutput},
+    replay, run,
+    sampling::{self, Sampling},
+    selector, spend, timing, versioned, warn,
+};
 use wstd::{http::HeaderValue, runtime::block_on};
 
 // API Response Structures
@@ -54,11 +62,27 @@ pub struct Usage {
     total_tokens: Option<u64>,
 }
 
+// Output Structure - the OpenAI response plus its expiry
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ChatOutput {
+    #[serde(flatten)]
+    response: OpenAiResponse,
+    // whether this operator served the answer from its own cache; that differs between
+    // operators, so it is only added to CLI output
+    #[serde(skip)]
+    cached: bool,
+    // unix seconds after which the answer should not be used; LLM answers are immediate-only
+    // by default
+    expires_at: u64,
+}
+
 // Request Structure
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct OpenAiRequest {
     model: String,
     messages: Vec<Message>,
+    #[serde(flatten)]
+    sampling: Sampling,
 }
 
 // Component Implementation
@@ -67,65 +91,162 @@ export!(Component with_types_in bindings);
 
 impl Guest for Component {
     fn run(action: TriggerAction) -> std::result::Result<Option<WasmResponse>, String> {
-        let (trigger_id, req, dest) =
-            decode_trigger_event(action.data).map_err(|e| e.to_string())?;
-
-        // Decode trigger data inline - handles hex string input
-        let prompt = {
-            // First, convert the input bytes to a string to check if it's a hex string
-            let input_str = String::from_utf8(req.clone())
-                .map_err(|e| format!("Input is not valid UTF-8: {}", e))?;
-
-            // Check if it's a hex string (starts with "0x")
-            let hex_data = if input_str.starts_with("0x") {
-                // Decode the hex string to bytes
-                hex::decode(&input_str[2..])
-                    .map_err(|e| format!("Failed to decode hex string: {}", e))?
-            } else {
-                // If it's not a hex string, assume the input is already binary data
-                req.clone()
-            };
+        run::run(Codec, action.data, process)
+    }
+}
+
+fn process(
+    trigger_id: u64,
+    req: Vec<u8>,
+    dest: Destination,
+    options: envelope::Options,
+) -> Result<Option<WasmResponse>, String> {
+    options.check_supported(&["encrypt_to", "commit", "reveal", "dry_run"])?;
+    let on_chain = matches!(dest, Destination::Ethereum);
+    if let Some(reveal) = commit_reveal::reveal(&options, env!("CARGO_PKG_NAME"), on_chain)? {
+        return Ok(Some(encode_trigger_output(trigger_id, reveal)));
+    }
+    let sampling = sampling::select(matches!(dest, Destination::Ethereum))?;
+    debug!("Sampling: {:?}", sampling);
 
+    // Decode trigger data inline - handles hex string input
+    let (prompt, compare_models) = {
+        let hex_data = decode::input_bytes(&req)?;
+        selector::check(
+            &hex_data,
+            &[
+                selector::Expected::of::<trigger::solidity::sendPromptCall>(),
+                selector::Expected::of::<trigger::solidity::compareModelsCall>(),
+            ],
+        )?;
+
+        if let Ok(call) = trigger::solidity::compareModelsCall::abi_decode(&hex_data) {
+            (call.prompt, true)
+        } else if let Ok(call) = trigger::solidity::sendPromptCall::abi_decode(&hex_data) {
+            (call.prompt, false)
+        } else {
             // Now ABI decode the binary data as a string parameter
-            <String as SolValue>::abi_decode(&hex_data)
-                .map_err(|e| format!("Failed to decode input as ABI string: {}", e))?
-        };
-
-        // Process the prompt with OpenAI
-        let res = block_on(async move {
-            let response = call_openai(&prompt).await?;
-            serde_json::to_vec(&response).map_err(|e| e.to_string())
-        })?;
-
-        // Return result based on destination
-        let output = match dest {
-            Destination::Ethereum => Some(encode_trigger_output(trigger_id, &res)),
-            Destination::CliOutput => Some(WasmResponse { payload: res.into(), ordering: None }),
-        };
-        Ok(output)
+            let prompt = decode::abi_string(&hex_data)?;
+            (prompt, false)
+        }
+    };
+    timing::end_phase("decode");
+    if options.dry_run {
+        let input = serde_json::json!({ "prompt": prompt, "compare_models": compare_models });
+        let plan = dry_run::Plan::new(env!("CARGO_PKG_NAME"), &input)?;
+        let plan =
+            if compare_models { compare::plan(plan, &prompt)? } else { plan_chat(plan, &prompt)? };
+        let payload = plan.output(on_chain, options.json_format())?;
+        return payload.map(encode_cli_output).transpose();
+    }
+
+    // Process the prompt with OpenAI
+    let (response, cached) = block_on(async move {
+        if compare_models {
+            let comparison = compare::compare(&prompt).await?;
+            let response = serde_json::to_value(&comparison).map_err(|e| e.to_string())?;
+            Ok::<_, String>((response, None))
+        } else {
+            let output = call_openai(&prompt).await?;
+            let response = serde_json::to_value(&output).map_err(|e| e.to_string())?;
+            Ok((response, Some(output.cached)))
+        }
+    })?;
+    timing::end_phase("upstream");
+
+    // Return result based on destination
+    let data = EthereumOutput::json(&versioned!(&response))?;
+    let mut response = response;
+    if let (Some(cached), Some(fields)) = (cached, response.as_object_mut()) {
+        fields.insert("cached".to_string(), cached.into());
     }
+    run::respond(Codec, trigger_id, data, dest, &options, || {
+        Ok(CliOutput::new(versioned!(&response)))
+    })
+}
+
+const MODEL: &str = "gpt-4";
+const OPENAI_CHAT_URL: &str = "https://api.openai.com/v1/chat/completions";
+const SYSTEM_PROMPT: &str = "You are a helpful assistant.";
+const DEFAULT_CACHE_TTL_SECS: u64 = 3600;
+// Answers are only meant for the submission they are produced for, cached or not; five blocks
+// leave it time to land
+const DEFAULT_RESULT_TTL_SECS: u64 = 60;
+
+// Identical prompts are served from cache while the entry is within the TTL. The key covers the
+// sampling too, so an answer sampled for a CLI run is never served to an on-chain one.
+fn cache_key(prompt: &str) -> Result<String, String> {
+    let sampling = serde_json::to_string(&sampling::current()).map_err(|e| e.to_string())?;
+    Ok(hex::encode(keccak256(format!("{}\n{}\n{}", MODEL, sampling, prompt))))
 }
 
-async fn call_openai(prompt: &str) -> Result<OpenAiResponse, String> {
+fn cached(cache_key: &str) -> Option<cache::CacheEntry<OpenAiResponse>> {
+    let ttl = std::env::var("WAVS_ENV_OPENAI_CACHE_TTL_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_CACHE_TTL_SECS);
+    cache::get::<OpenAiResponse>(cache_key).filter(|entry| entry.age_secs() <= ttl)
+}
+
+// Reading the cache has no side effects, so a dry run can tell whether a request would be made
+fn plan_chat(plan: dry_run::Plan, prompt: &str) -> Result<dry_run::Plan, String> {
+    if let Some(entry) = cached(&cache_key(prompt)?) {
+        return Ok(
+            plan.note(format!("A cached answer ({}s old) would be served", entry.age_secs()))
+        );
+    }
+    plan.llm(OPENAI_CHAT_URL, MODEL, 1, prompt_tokens(prompt), None)
+}
+
+fn prompt_tokens(prompt: &str) -> u64 {
+    (llm::estimate_tokens(SYSTEM_PROMPT) + llm::estimate_tokens(prompt)) as u64
+}
+
+async fn call_openai(prompt: &str) -> Result<ChatOutput, String> {
+    let cache_key = cache_key(prompt)?;
+    if let Some(entry) = cached(&cache_key) {
+        info!("Serving cached response ({}s old)", entry.age_secs());
+        return Ok(ChatOutput {
+            response: entry.value,
+            cached: true,
+            expires_at: freshness::expires_at(freshness::now_secs(), DEFAULT_RESULT_TTL_SECS),
+        });
+    }
+
+    let response = complete(MODEL, prompt).await?;
+
+    // A failed cache write should not fail the run
+    if let Err(e) = cache::put(&cache_key, &response) {
+        warn!("Failed to cache response: {}", e);
+    }
+
+    Ok(ChatOutput {
+        response,
+        cached: false,
+        expires_at: freshness::expires_at(freshness::now_secs(), DEFAULT_RESULT_TTL_SECS),
+    })
+}
+
+// Sends the prompt to one model, uncached, unless it would take the run over its spend ceiling
+async fn complete(model: &str, prompt: &str) -> Result<OpenAiResponse, String> {
     // Get API key from environment
     let api_key = std::env::var("WAVS_ENV_OPENAI_KEY")
         .map_err(|_| "Failed to get OPENAI_KEY from environment variables".to_string())?;
 
     // Create request
     let request = OpenAiRequest {
-        model: "gpt-4".to_string(),
+        model: model.to_string(),
         messages: vec![
-            Message {
-                role: Some("system".to_string()),
-                content: Some("You are a helpful assistant.".to_string()),
-            },
+            Message { role: Some("system".to_string()), content: Some(SYSTEM_PROMPT.to_string()) },
             Message { role: Some("user".to_string()), content: Some(prompt.to_string()) },
         ],
+        sampling: sampling::current(),
     };
+    let prompt_tokens = prompt_tokens(prompt);
+    spend::check(model, prompt_tokens)?;
 
     // Create HTTP request
-    let url = "https://api.openai.com/v1/chat/completions";
-    let mut req = http_request_post_json(url, &request)
+    let mut req = http_request_post_json(OPENAI_CHAT_URL, &request)
         .map_err(|e| format!("Failed to create request: {}", e))?;
 
     // Add headers
@@ -138,7 +259,12 @@ async fn call_openai(prompt: &str) -> Result<OpenAiResponse, String> {
 
     // Make request
     let response: OpenAiResponse =
-        fetch_json(req).await.map_err(|e| format!("Failed to fetch data: {}", e))?;
-
+        replay::fetch_json(req).await.map_err(|e| format!("Failed to fetch data: {}", e))?;
+    let usage = response.usage.clone().unwrap_or_default();
+    let completion_tokens = usage.completion_tokens.unwrap_or_else(|| {
+        let answer = response.choices.iter().flatten().filter_map(|c| c.message.as_ref());
+        answer.filter_map(|m| m.content.as_deref()).map(llm::estimate_tokens).sum::<usize>() as u64
+    });
+    spend::record(model, usage.prompt_tokens.unwrap_or(prompt_tokens), completion_tokens);
     Ok(response)
 }
@@ -0,0 +1,147 @@
+// Source grounding: context documents (inline text or URLs to fetch) are numbered and injected
+// into the prompt, and the answer is scanned for the [n] markers the model is asked to cite with.
+use serde::{Deserialize, Serialize};
+use wavs_component_common::replay;
+use wavs_wasi_utils::{
+    evm::alloy_primitives::{hex, keccak256},
+    http::http_request_get,
+};
+use wstd::http::HeaderValue;
+
+pub const MAX_SOURCES: usize = 5;
+// Per-source character budget so a large page cannot crowd out the question
+const MAX_SOURCE_CHARS: usize = 8_000;
+// Reply the model is told to give when the sources do not answer the question
+pub const NO_ANSWER: &str = "NO_ANSWER";
+
+pub struct Source {
+    pub url: Option<String>,
+    pub content: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Citation {
+    index: usize,
+    url: Option<String>,
+    // keccak256 of the text that was injected, so the grounding can be audited
+    content_hash: String,
+}
+
+pub fn check_count(entries: &[String]) -> Result<(), String> {
+    if entries.len() > MAX_SOURCES {
+        return Err(format!(
+            "{} sources provided, at most {} are allowed",
+            entries.len(),
+            MAX_SOURCES
+        ));
+    }
+    Ok(())
+}
+
+// Entries starting with http:// or https:// are fetched; anything else is used as-is
+pub async fn load_sources(entries: &[String]) -> Result<Vec<Source>, String> {
+    check_count(entries)?;
+
+    let mut sources = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let entry = entry.trim();
+        let source = if is_url(entry) {
+            let mut req = http_request_get(entry)
+                .map_err(|e| format!("Failed to create request for {}: {}", entry, e))?;
+            req.headers_mut()
+                .insert("User-Agent", HeaderValue::from_static("WAVS-OpenAI-Component/1.0"));
+            let body = replay::fetch_string(req)
+                .await
+                .map_err(|e| format!("Failed to fetch source {}: {}", entry, e))?;
+            Source { url: Some(entry.to_string()), content: truncate(&body) }
+        } else {
+            Source { url: None, content: truncate(entry) }
+        };
+        if source.content.trim().is_empty() {
+            return Err(format!("Source {} is empty", sources.len() + 1));
+        }
+        sources.push(source);
+    }
+    Ok(sources)
+}
+
+// The sources of a dry run: inline entries as they would be injected, and URLs, which aren't
+// fetched, as stand-ins of the length a fetched page is cut to
+pub fn planned_sources(entries: &[String]) -> Result<Vec<Source>, String> {
+    check_count(entries)?;
+    entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let entry = entry.trim();
+            let source = if is_url(entry) {
+                Source { url: Some(entry.to_string()), content: "x".repeat(MAX_SOURCE_CHARS) }
+            } else {
+                Source { url: None, content: truncate(entry) }
+            };
+            if source.content.trim().is_empty() {
+                return Err(format!("Source {} is empty", i + 1));
+            }
+            Ok(source)
+        })
+        .collect()
+}
+
+pub fn is_url(entry: &str) -> bool {
+    entry.starts_with("http://") || entry.starts_with("https://")
+}
+
+pub fn system_prompt() -> String {
+    format!(
+        "You are a helpful assistant. Answer using only the numbered sources provided by the \
+         user. Cite every claim with the number of its source in square brackets, e.g. [1]. \
+         If the sources do not contain the answer, reply with exactly {}.",
+        NO_ANSWER
+    )
+}
+
+pub fn user_prompt(prompt: &str, sources: &[Source]) -> String {
+    let mut content = String::from("Sources:\n");
+    for (i, source) in sources.iter().enumerate() {
+        content.push_str(&format!("\n[{}]", i + 1));
+        if let Some(url) = &source.url {
+            content.push_str(&format!(" ({})", url));
+        }
+        content.push_str(&format!("\n{}\n", source.content));
+    }
+    content.push_str(&format!("\nQuestion: {}", prompt));
+    content
+}
+
+// Distinct [n] markers in the answer that refer to a provided source, in order of appearance
+pub fn extract_citations(answer: &str, sources: &[Source]) -> Vec<Citation> {
+    let mut cited: Vec<usize> = Vec::new();
+    for part in answer.split('[').skip(1) {
+        let Some((number, _)) = part.split_once(']') else {
+            continue;
+        };
+        for n in number.split(',') {
+            if let Ok(index) = n.trim().parse::<usize>() {
+                if (1..=sources.len()).contains(&index) && !cited.contains(&index) {
+                    cited.push(index);
+                }
+            }
+        }
+    }
+
+    cited
+        .into_iter()
+        .map(|index| {
+            let source = &sources[index - 1];
+            Citation {
+                index,
+                url: source.url.clone(),
+                content_hash: format!("0x{}", hex::encode(keccak256(source.content.as_bytes()))),
+            }
+        })
+        .collect()
+}
+
+fn truncate(text: &str) -> String {
+    text.chars().take(MAX_SOURCE_CHARS).collect()
+}
@@ -0,0 +1,209 @@
+// Reply-language enforcement. Detection is a lightweight heuristic: the dominant script decides
+// for non-Latin languages, and Latin-script languages are told apart by stopword hits. It is
+// only meant to catch a model answering in the wrong language, not to classify arbitrary text.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Script {
+    Latin,
+    Cyrillic,
+    Greek,
+    Arabic,
+    Hebrew,
+    Devanagari,
+    Thai,
+    Hangul,
+    Kana,
+    Han,
+}
+
+pub struct Language {
+    pub code: &'static str,
+    pub name: &'static str,
+    script: Script,
+    stopwords: &'static [&'static str],
+}
+
+const LANGUAGES: &[Language] = &[
+    Language {
+        code: "en",
+        name: "English",
+        script: Script::Latin,
+        stopwords: &[
+            "the", "and", "is", "are", "of", "to", "in", "that", "it", "for", "with", "was",
+            "this", "you", "not",
+        ],
+    },
+    Language {
+        code: "es",
+        name: "Spanish",
+        script: Script::Latin,
+        stopwords: &[
+            "el", "la", "los", "las", "de", "que", "y", "en", "es", "por", "para", "con", "una",
+            "del", "no",
+        ],
+    },
+    Language {
+        code: "fr",
+        name: "French",
+        script: Script::Latin,
+        stopwords: &[
+            "le", "la", "les", "des", "et", "est", "que", "pour", "dans", "une", "pas", "sur",
+            "avec", "du", "ce",
+        ],
+    },
+    Language {
+        code: "de",
+        name: "German",
+        script: Script::Latin,
+        stopwords: &[
+            "der", "die", "das", "und", "ist", "nicht", "ein", "eine", "zu", "mit", "den", "von",
+            "auf", "für", "sich",
+        ],
+    },
+    Language {
+        code: "it",
+        name: "Italian",
+        script: Script::Latin,
+        stopwords: &[
+            "il", "la", "che", "di", "e", "è", "per", "non", "una", "con", "del", "sono", "gli",
+            "della", "più",
+        ],
+    },
+    Language {
+        code: "pt",
+        name: "Portuguese",
+        script: Script::Latin,
+        stopwords: &[
+            "o", "a", "os", "as", "de", "que", "e", "é", "não", "para", "com", "uma", "do", "da",
+            "em",
+        ],
+    },
+    Language {
+        code: "nl",
+        name: "Dutch",
+        script: Script::Latin,
+        stopwords: &[
+            "de", "het", "een", "en", "van", "is", "dat", "niet", "op", "te", "voor", "met",
+            "zijn", "er", "ook",
+        ],
+    },
+    Language { code: "ru", name: "Russian", script: Script::Cyrillic, stopwords: &[] },
+    Language { code: "uk", name: "Ukrainian", script: Script::Cyrillic, stopwords: &[] },
+    Language { code: "el", name: "Greek", script: Script::Greek, stopwords: &[] },
+    Language { code: "ar", name: "Arabic", script: Script::Arabic, stopwords: &[] },
+    Language { code: "he", name: "Hebrew", script: Script::Hebrew, stopwords: &[] },
+    Language { code: "hi", name: "Hindi", script: Script::Devanagari, stopwords: &[] },
+    Language { code: "th", name: "Thai", script: Script::Thai, stopwords: &[] },
+    Language { code: "ko", name: "Korean", script: Script::Hangul, stopwords: &[] },
+    Language { code: "ja", name: "Japanese", script: Script::Kana, stopwords: &[] },
+    Language { code: "zh", name: "Chinese", script: Script::Han, stopwords: &[] },
+];
+
+// Looks up an ISO 639-1 code, e.g. "fr"
+pub fn lookup(code: &str) -> Result<&'static Language, String> {
+    let code = code.trim().to_lowercase();
+    LANGUAGES.iter().find(|l| l.code == code).ok_or_else(|| {
+        let supported: Vec<&str> = LANGUAGES.iter().map(|l| l.code).collect();
+        format!("Unsupported language '{}', expected one of {}", code, supported.join(", "))
+    })
+}
+
+pub fn instruction(language: &Language) -> String {
+    format!(
+        "Always reply in {} ({}), regardless of the language of the question or any sources.",
+        language.name, language.code
+    )
+}
+
+pub fn retry_instruction(language: &Language) -> String {
+    format!(
+        "Your previous answer was not in {}. Answer again, only in {}.",
+        language.name, language.name
+    )
+}
+
+// False only when the text is confidently detected as some other language; inconclusive text
+// (numbers, code, very short replies) is accepted
+pub fn matches(text: &str, language: &Language) -> bool {
+    match detect(text) {
+        Some(detected) => detected.code == language.code,
+        None => true,
+    }
+}
+
+fn detect(text: &str) -> Option<&'static Language> {
+    let mut counts: Vec<(Script, usize)> = Vec::new();
+    for c in text.chars().filter(|c| c.is_alphabetic()) {
+        let Some(script) = script_of(c) else {
+            continue;
+        };
+        match counts.iter_mut().find(|(s, _)| *s == script) {
+            Some((_, n)) => *n += 1,
+            None => counts.push((script, 1)),
+        }
+    }
+    let total: usize = counts.iter().map(|(_, n)| n).sum();
+    if total < 8 {
+        return None;
+    }
+    let count = |script: Script| counts.iter().find(|(s, _)| *s == script).map_or(0, |(_, n)| *n);
+
+    // Japanese mixes kana with Han characters, so any meaningful share of kana decides it
+    let cjk = count(Script::Kana) + count(Script::Han);
+    let dominant = if cjk * 2 > total {
+        if count(Script::Kana) * 10 >= cjk {
+            Script::Kana
+        } else {
+            Script::Han
+        }
+    } else {
+        counts.iter().max_by_key(|(_, n)| *n).map(|(s, _)| *s)?
+    };
+
+    match dominant {
+        Script::Latin => detect_latin(text),
+        Script::Cyrillic => {
+            // Letters used in Ukrainian but not in Russian
+            let code =
+                if text.chars().any(|c| "іїєґІЇЄҐ".contains(c)) { "uk" } else { "ru" };
+            LANGUAGES.iter().find(|l| l.code == code)
+        }
+        script => LANGUAGES.iter().find(|l| l.script == script),
+    }
+}
+
+fn detect_latin(text: &str) -> Option<&'static Language> {
+    let lowered = text.to_lowercase();
+    let words: Vec<&str> =
+        lowered.split(|c: char| !c.is_alphabetic()).filter(|w| !w.is_empty()).collect();
+
+    let mut scores: Vec<(&'static Language, usize)> = LANGUAGES
+        .iter()
+        .filter(|l| l.script == Script::Latin)
+        .map(|l| (l, words.iter().filter(|w| l.stopwords.contains(w)).count()))
+        .collect();
+    scores.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+
+    // Require a clear winner so closely related languages don't flip on one word
+    match scores.as_slice() {
+        [(best, top), (_, second), ..] if *top >= 2 && *top > *second => Some(best),
+        _ => None,
+    }
+}
+
+fn script_of(c: char) -> Option<Script> {
+    let script = match c as u32 {
+        0x0041..=0x024F => Script::Latin,
+        0x0370..=0x03FF => Script::Greek,
+        0x0400..=0x04FF => Script::Cyrillic,
+        0x0590..=0x05FF => Script::Hebrew,
+        0x0600..=0x06FF => Script::Arabic,
+        0x0900..=0x097F => Script::Devanagari,
+        0x0E00..=0x0E7F => Script::Thai,
+        0x1100..=0x11FF | 0xAC00..=0xD7AF => Script::Hangul,
+        0x3040..=0x30FF => Script::Kana,
+        0x4E00..=0x9FFF | 0x3400..=0x4DBF => Script::Han,
+        _ => return None,
+    };
+    Some(script)
+}
@@ -1,79 +1,261 @@
+mod grounding;
+mod language;
 mod trigger;
-use trigger::{decode_trigger_event, encode_trigger_output, Destination};
+mod vision;
+use grounding::{Citation, Source, NO_ANSWER};
+use language::Language;
+use trigger::{encode_cli_output, Codec, Destination};
+use vision::Content;
 use wavs_wasi_utils::{
-    evm::alloy_primitives::hex,
-    http::{fetch_json, http_request_post_json},
+    evm::alloy_primitives::{hex, keccak256, B256},
+    http::http_request_post_json,
 };
 pub mod bindings;
 use crate::bindings::{export, Guest, TriggerAction, WasmResponse};
-use alloy_sol_types::SolValue;
+use alloy_sol_types::{SolCall, SolValue};
 use serde::{Deserialize, Serialize};
+use wavs_component_common::{
+    bls, debug, decode, dry_run, eip712, envelope, freshness, info, llm,
+    output::{CliOutput, EthereumOutput},
+    replay, run,
+    sampling::{self, Sampling},
+    selector, spend, timing, versioned, warn,
+};
 use wstd::{http::HeaderValue, runtime::block_on};
 
+const MODEL: &str = "gpt-4o";
+const OPENAI_CHAT_URL: &str = "https://api.openai.com/v1/chat/completions";
+// LLM answers are meant for the submission they were produced for; five blocks leave it time
+// to land
+const DEFAULT_RESULT_TTL_SECS: u64 = 60;
+
 struct Component;
 export!(Component with_types_in bindings);
 
 impl Guest for Component {
     fn run(action: TriggerAction) -> std::result::Result<Option<WasmResponse>, String> {
-        let (trigger_id, req, dest) =
-            decode_trigger_event(action.data).map_err(|e| e.to_string())?;
-
-        // Decode trigger data inline - handles hex string input
-        let prompt = {
-            // First, convert the input bytes to a string to check if it's a hex string
-            let input_str = String::from_utf8(req.clone())
-                .map_err(|e| format!("Input is not valid UTF-8: {}", e))?;
-
-            // Check if it's a hex string (starts with "0x")
-            let hex_data = if input_str.starts_with("0x") {
-                // Decode the hex string to bytes
-                hex::decode(&input_str[2..])
-                    .map_err(|e| format!("Failed to decode hex string: {}", e))?
-            } else {
-                // If it's not a hex string, assume the input is already binary data
-                req.clone()
-            };
+        run::run(Codec, action.data, process)
+    }
+}
+
+fn process(
+    trigger_id: u64,
+    req: Vec<u8>,
+    dest: Destination,
+    options: envelope::Options,
+) -> Result<Option<WasmResponse>, String> {
+    options.check_supported(&["dry_run"])?;
+    let sampling = sampling::select(matches!(dest, Destination::Ethereum))?;
+    debug!("Sampling: {:?}", sampling);
+
+    // Decode trigger data inline - handles hex string input
+    let (prompt, source_entries, require_citations, language_code, image_urls) = {
+        let hex_data = decode::input_bytes(&req)?;
+        selector::check(
+            &hex_data,
+            &[
+                selector::Expected::of::<trigger::solidity::sendPromptCall>(),
+                selector::Expected::of::<trigger::solidity::askWithSourcesCall>(),
+                selector::Expected::of::<trigger::solidity::askInLanguageCall>(),
+                selector::Expected::of::<trigger::solidity::askAboutImagesCall>(),
+            ],
+        )?;
 
+        if let Ok(call) = trigger::solidity::askWithSourcesCall::abi_decode(&hex_data) {
+            (call.prompt, call.sources, call.requireCitations, None, Vec::new())
+        } else if let Ok(call) = trigger::solidity::askInLanguageCall::abi_decode(&hex_data) {
+            (call.prompt, Vec::new(), false, Some(call.language), Vec::new())
+        } else if let Ok(call) = trigger::solidity::askAboutImagesCall::abi_decode(&hex_data) {
+            (call.prompt, Vec::new(), false, None, call.imageUrls)
+        } else if let Ok(call) = trigger::solidity::sendPromptCall::abi_decode(&hex_data) {
+            (call.prompt, Vec::new(), false, None, Vec::new())
+        } else {
             // Now ABI decode the binary data as a string parameter
-            <String as SolValue>::abi_decode(&hex_data)
-                .map_err(|e| format!("Failed to decode input as ABI string: {}", e))?
-        };
-        println!("Decoded prompt: {}", prompt);
+            let prompt = decode::abi_string(&hex_data)?;
+            (prompt, Vec::new(), false, None, Vec::new())
+        }
+    };
+    let language = language_code.as_deref().map(language::lookup).transpose()?;
+    debug!("Decoded prompt: {}", prompt);
+    timing::end_phase("decode");
+    if options.dry_run {
+        let plan = plan(&prompt, &source_entries, require_citations, language, &image_urls)?;
+        let payload = plan.output(matches!(dest, Destination::Ethereum), options.json_format())?;
+        return payload.map(encode_cli_output).transpose();
+    }
 
-        // Send prompt to OpenAI and get response
-        let result = block_on(async move {
-            let chat_result = send_to_openai(&prompt).await?;
-            serde_json::to_vec(&chat_result).map_err(|e| e.to_string())
-        })?;
+    // Send prompt to OpenAI and get response
+    let mut chat_result = block_on(async move {
+        let sources = grounding::load_sources(&source_entries).await?;
+        let images = vision::validate_images(&image_urls).await?;
+        send_to_openai(&prompt, &sources, require_citations, language, images).await
+    })?;
+    timing::end_phase("upstream");
 
-        let output = match dest {
-            Destination::Ethereum => Some(encode_trigger_output(trigger_id, &result)),
-            Destination::CliOutput => Some(WasmResponse { payload: result.into(), ordering: None }),
-        };
-        Ok(output)
+    let answer = trigger::solidity::AiAnswer {
+        triggerId: trigger_id,
+        promptHash: prompt_hash(&chat_result.prompt, chat_result.image_urls.as_deref()),
+        answer: chat_result.response.clone(),
+        model: chat_result.model.clone(),
+        totalTokens: chat_result.tokens_used,
+    };
+    let digest = eip712::digest(&answer, "openai-chat")?;
+    chat_result.eip712_digest = Some(format!("0x{}", hex::encode(digest)));
+    chat_result.bls_signature = bls::sign(digest)?;
+
+    if matches!(dest, Destination::Ethereum) {
+        info!("EIP-712 digest: 0x{}", hex::encode(digest));
+        if let Some(signature) = &chat_result.bls_signature {
+            info!("BLS signature: {} (key {})", signature.signature, signature.public_key);
+        }
+    }
+    let data = EthereumOutput::abi(&answer).traced();
+    run::respond(Codec, trigger_id, data, dest, &options, || {
+        Ok(CliOutput::new(versioned!(&chat_result)))
+    })
+}
+
+// Sources are fetched and images checked one request each, then the prompt is sent once, or twice
+// when the reply comes back in the wrong language. The answer's size isn't known in advance, so
+// there is no gas estimate.
+fn plan(
+    prompt: &str,
+    source_entries: &[String],
+    require_citations: bool,
+    language: Option<&Language>,
+    image_urls: &[String],
+) -> Result<dry_run::Plan, String> {
+    let sources = grounding::planned_sources(source_entries)?;
+    let images = vision::check_urls(image_urls)?;
+    let input = serde_json::json!({
+        "prompt": prompt,
+        "sources": source_entries.iter().map(|s| s.trim()).collect::<Vec<_>>(),
+        "require_citations": require_citations,
+        "language": language.map(|l| l.code),
+        "image_urls": images,
+    });
+    let mut plan = dry_run::Plan::new(env!("CARGO_PKG_NAME"), &input)?;
+    for url in sources.iter().filter_map(|source| source.url.as_deref()) {
+        plan = plan.http(url, 1);
+    }
+    for url in &images {
+        plan = plan.http(url, 1);
+    }
+    if sources.iter().any(|source| source.url.is_some()) {
+        plan = plan.note("Fetched sources are counted at their 8000 character limit");
+    }
+    if let Some(language) = language {
+        plan = plan.note(format!(
+            "A reply that is not in {} is retried once, with the first reply added to the prompt",
+            language.name
+        ));
+    }
+    let prompt_tokens: usize = messages(prompt, &sources, language, &images)
+        .iter()
+        .map(|m| vision::estimate_tokens(&m.content))
+        .sum();
+    plan.llm(OPENAI_CHAT_URL, MODEL, 1, prompt_tokens as u64, None)
+}
+
+// The system and user message, injecting the numbered sources when grounding is requested
+fn messages(
+    prompt: &str,
+    sources: &[Source],
+    language: Option<&Language>,
+    images: &[String],
+) -> Vec<Message> {
+    let (mut system_content, user_content) = if sources.is_empty() {
+        ("You are a helpful assistant.".to_string(), prompt.to_string())
+    } else {
+        (grounding::system_prompt(), grounding::user_prompt(prompt, sources))
+    };
+    if let Some(language) = language {
+        system_content = format!("{} {}", system_content, language::instruction(language));
     }
+    vec![
+        Message { role: "system".to_string(), content: Content::Text(system_content) },
+        Message { role: "user".to_string(), content: vision::user_content(user_content, images) },
+    ]
 }
 
-async fn send_to_openai(prompt: &str) -> Result<ChatResult, String> {
+async fn send_to_openai(
+    prompt: &str,
+    sources: &[Source],
+    require_citations: bool,
+    language: Option<&Language>,
+    images: Vec<String>,
+) -> Result<ChatResult, String> {
+    let mut messages = messages(prompt, sources, language, &images);
+
+    let (mut response_content, mut model, mut tokens_used) = complete(&messages).await?;
+
+    // Retry once when the reply is in the wrong language, showing the model its own answer
+    if let Some(language) = language {
+        if !language::matches(&response_content, language) {
+            warn!("Reply is not in {}, retrying once", language.name);
+            messages.push(Message {
+                role: "assistant".to_string(),
+                content: Content::Text(response_content),
+            });
+            messages.push(Message {
+                role: "user".to_string(),
+                content: Content::Text(language::retry_instruction(language)),
+            });
+            let (retry_content, retry_model, retry_tokens) = complete(&messages).await?;
+            if !language::matches(&retry_content, language) {
+                return Err(format!("Model did not answer in {} after a retry", language.name));
+            }
+            response_content = retry_content;
+            model = retry_model;
+            tokens_used = tokens_used.saturating_add(retry_tokens);
+        }
+    }
+
+    let citations = if sources.is_empty() {
+        None
+    } else {
+        let citations = if response_content.trim() == NO_ANSWER {
+            Vec::new()
+        } else {
+            grounding::extract_citations(&response_content, sources)
+        };
+        if require_citations && citations.is_empty() {
+            return Err("Answer is not grounded in the provided sources".to_string());
+        }
+        Some(citations)
+    };
+
+    Ok(ChatResult {
+        prompt: prompt.to_string(),
+        response: response_content,
+        model,
+        tokens_used,
+        citations,
+        language: language.map(|l| l.code.to_string()),
+        image_urls: Some(images).filter(|images| !images.is_empty()),
+        eip712_digest: None,
+        bls_signature: None,
+        expires_at: freshness::expires_at(freshness::now_secs(), DEFAULT_RESULT_TTL_SECS),
+    })
+}
+
+// Sends one chat completion request and returns the reply content, model and total tokens.
+// Requests that would take the run over its spend ceiling are refused before they are sent.
+async fn complete(messages: &[Message]) -> Result<(String, String, u32), String> {
     // Get API key from environment
     let api_key = std::env::var("WAVS_ENV_OPENAI_KEY")
         .map_err(|_| "Failed to get OPENAI_KEY from environment variables".to_string())?;
 
-    // Create OpenAI request
+    let prompt_tokens: usize = messages.iter().map(|m| vision::estimate_tokens(&m.content)).sum();
+    spend::check(MODEL, prompt_tokens as u64)?;
     let openai_request = OpenAIRequest {
-        model: "gpt-4o".to_string(),
-        messages: vec![
-            Message {
-                role: "system".to_string(),
-                content: "You are a helpful assistant.".to_string(),
-            },
-            Message { role: "user".to_string(), content: prompt.to_string() },
-        ],
+        model: MODEL.to_string(),
+        messages: messages.to_vec(),
+        sampling: sampling::current(),
     };
 
     // Create HTTP request
-    let url = "https://api.openai.com/v1/chat/completions";
-    let mut req = http_request_post_json(url, &openai_request)
+    let mut req = http_request_post_json(OPENAI_CHAT_URL, &openai_request)
         .map_err(|e| format!("Failed to create request: {}", e))?;
 
     // Add authorization header
@@ -87,8 +269,9 @@ async fn send_to_openai(prompt: &str) -> Result<ChatResult, String> {
     req.headers_mut().insert("User-Agent", HeaderValue::from_static("WAVS-OpenAI-Component/1.0"));
 
     // Send request and parse response
-    let openai_response: OpenAIResponse =
-        fetch_json(req).await.map_err(|e| format!("Failed to send request to OpenAI: {}", e))?;
+    let openai_response: OpenAIResponse = replay::fetch_json(req)
+        .await
+        .map_err(|e| format!("Failed to send request to OpenAI: {}", e))?;
 
     // Extract response content
     let response_content = openai_response
@@ -96,31 +279,42 @@ async fn send_to_openai(prompt: &str) -> Result<ChatResult, String> {
         .as_ref()
         .and_then(|choices| choices.first())
         .and_then(|choice| choice.message.as_ref())
-        .and_then(|message| message.content.as_ref())
+        .and_then(|message| message.content.clone())
         .ok_or_else(|| "No response content found in OpenAI response".to_string())?;
 
-    let model = openai_response.model.unwrap_or_else(|| "gpt-4o".to_string());
-    let tokens_used =
-        openai_response.usage.as_ref().and_then(|usage| usage.total_tokens).unwrap_or(0);
+    let usage = openai_response.usage.clone().unwrap_or_default();
+    spend::record(
+        MODEL,
+        usage.prompt_tokens.map_or(prompt_tokens as u64, u64::from),
+        usage.completion_tokens.map_or(llm::estimate_tokens(&response_content) as u64, u64::from),
+    );
+    let model = openai_response.model.unwrap_or_else(|| MODEL.to_string());
+    let tokens_used = usage.total_tokens.unwrap_or(0);
 
-    Ok(ChatResult {
-        prompt: prompt.to_string(),
-        response: response_content.clone(),
-        model,
-        tokens_used,
-    })
+    Ok((response_content, model, tokens_used))
+}
+
+// Image prompts would otherwise hash the same for any image, so their hash commits to the URLs
+// too: keccak256(abi.encode(prompt, imageUrls))
+fn prompt_hash(prompt: &str, image_urls: Option<&[String]>) -> B256 {
+    match image_urls {
+        Some(urls) => keccak256((prompt.to_string(), urls.to_vec()).abi_encode_params()),
+        None => keccak256(prompt.as_bytes()),
+    }
 }
 
 #[derive(Debug, Serialize, Clone)]
 pub struct OpenAIRequest {
     model: String,
     messages: Vec<Message>,
+    #[serde(flatten)]
+    sampling: Sampling,
 }
 
 #[derive(Debug, Serialize, Clone)]
 pub struct Message {
     role: String,
-    content: String,
+    content: Content,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
@@ -163,4 +357,16 @@ pub struct ChatResult {
     response: String,
     model: String,
     tokens_used: u32,
+    // sources the answer cites; only present when sources were provided
+    citations: Option<Vec<Citation>>,
+    // requested reply language (ISO 639-1), verified before the answer is returned
+    language: Option<String>,
+    // images the question was asked about, as validated and sent to the model
+    image_urls: Option<Vec<String>>,
+    // EIP-712 digest of the AiAnswer struct (trigger id 0 for CLI runs)
+    eip712_digest: Option<String>,
+    // the digest signed with WAVS_ENV_BLS_SECRET_KEY for aggregation, when it is set
+    bls_signature: Option<bls::BlsSignature>,
+    // unix seconds after which the answer should not be used
+    expires_at: u64,
 }
@@ -0,0 +1,123 @@
+// Image prompts: the user message becomes a multi-part content array with one image_url part per
+// image. OpenAI downloads the images itself, so each URL is checked with a HEAD request first and
+// a run fails here, with a clear reason, instead of in the middle of the API call.
+use serde::Serialize;
+use wavs_component_common::{llm, replay};
+use wavs_wasi_utils::http::http_request_get;
+use wstd::http::HeaderValue;
+
+pub const MAX_IMAGES: usize = 4;
+// OpenAI rejects images above 20 MB
+const DEFAULT_MAX_IMAGE_BYTES: u64 = 20 * 1024 * 1024;
+// What one image costs at high detail for a 1024x1024 picture; larger images cost more tiles
+const IMAGE_TOKENS: usize = 765;
+// Formats the vision API accepts
+const IMAGE_TYPES: [&str; 4] = ["image/png", "image/jpeg", "image/webp", "image/gif"];
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(untagged)]
+pub enum Content {
+    Text(String),
+    Parts(Vec<ContentPart>),
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContentPart {
+    Text { text: String },
+    ImageUrl { image_url: ImageUrl },
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ImageUrl {
+    url: String,
+}
+
+pub fn user_content(text: String, images: &[String]) -> Content {
+    if images.is_empty() {
+        return Content::Text(text);
+    }
+    let mut parts = vec![ContentPart::Text { text }];
+    parts.extend(
+        images.iter().map(|url| ContentPart::ImageUrl { image_url: ImageUrl { url: url.clone() } }),
+    );
+    Content::Parts(parts)
+}
+
+// Pre-flight token estimate of a message's content
+pub fn estimate_tokens(content: &Content) -> usize {
+    match content {
+        Content::Text(text) => llm::estimate_tokens(text),
+        Content::Parts(parts) => parts
+            .iter()
+            .map(|part| match part {
+                ContentPart::Text { text } => llm::estimate_tokens(text),
+                ContentPart::ImageUrl { .. } => IMAGE_TOKENS,
+            })
+            .sum(),
+    }
+}
+
+// The checks that need no request: the image count, and https for every (trimmed) URL
+pub fn check_urls(urls: &[String]) -> Result<Vec<String>, String> {
+    if urls.len() > MAX_IMAGES {
+        return Err(format!("{} images provided, at most {} are allowed", urls.len(), MAX_IMAGES));
+    }
+    urls.iter()
+        .map(|url| {
+            let url = url.trim();
+            // Plain http would let anyone on the path swap the image OpenAI sees
+            if !url.starts_with("https://") {
+                return Err(format!("Image URL {} must use https", url));
+            }
+            Ok(url.to_string())
+        })
+        .collect()
+}
+
+// Returns the trimmed URLs once every one points at an image of an accepted type and size
+pub async fn validate_images(urls: &[String]) -> Result<Vec<String>, String> {
+    let urls = check_urls(urls)?;
+    let max_bytes = std::env::var("WAVS_ENV_OPENAI_MAX_IMAGE_BYTES")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_MAX_IMAGE_BYTES)
+        .min(DEFAULT_MAX_IMAGE_BYTES);
+
+    for url in &urls {
+        let mut req = http_request_get(url)
+            .map_err(|e| format!("Failed to create request for {}: {}", url, e))?;
+        req.headers_mut()
+            .insert("User-Agent", HeaderValue::from_static("WAVS-OpenAI-Component/1.0"));
+        let head = replay::fetch_head(req)
+            .await
+            .map_err(|e| format!("Failed to check image {}: {}", url, e))?;
+
+        if !(200..300).contains(&head.status) {
+            return Err(format!("Image {} returned HTTP {}", url, head.status));
+        }
+        let content_type = head
+            .content_type
+            .as_deref()
+            .and_then(|t| t.split(';').next())
+            .map(|t| t.trim().to_ascii_lowercase())
+            .unwrap_or_default();
+        if !IMAGE_TYPES.contains(&content_type.as_str()) {
+            return Err(format!(
+                "Image {} has content type '{}', expected one of {}",
+                url,
+                content_type,
+                IMAGE_TYPES.join(", ")
+            ));
+        }
+        // A missing length can't be checked against the limit, so it is treated as too large
+        match head.content_length {
+            Some(len) if len <= max_bytes => {}
+            Some(len) => {
+                return Err(format!("Image {} is {} bytes, the limit is {}", url, len, max_bytes))
+            }
+            None => return Err(format!("Image {} does not declare its size", url)),
+        }
+    }
+    Ok(urls)
+}
@@ -0,0 +1,67 @@
+// Splitting of prompts that don't fit the model's context window
+use crate::tokenizer;
+use serde::{Deserialize, Serialize};
+
+// Starting window size for a chunk; English text averages about 4 characters per token
+const CHARS_PER_TOKEN: usize = 4;
+const DEFAULT_CONTEXT_TOKENS: usize = 8_192;
+// Room left for the system prompt, instructions and the model's reply
+const RESERVED_TOKENS: usize = 2_048;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ChunkReport {
+    pub original_prompt_tokens: usize,
+    pub context_tokens: usize,
+    pub chunk_count: usize,
+    // number of summarize passes before the combined text fit
+    pub rounds: usize,
+    // total_tokens reported for each summarize call, in call order
+    pub intermediate_tokens: Vec<u64>,
+    pub final_tokens: u64,
+}
+
+pub fn context_tokens() -> usize {
+    std::env::var("WAVS_ENV_OPENAI_CONTEXT_TOKENS")
+        .ok()
+        .and_then(|v| v.parse::<usize>().ok())
+        .filter(|t| *t > RESERVED_TOKENS)
+        .unwrap_or(DEFAULT_CONTEXT_TOKENS)
+}
+
+// Largest prompt, in tokens, that is sent to the model in one request
+pub fn prompt_budget(context_tokens: usize) -> usize {
+    context_tokens - RESERVED_TOKENS
+}
+
+// Splits text into chunks of at most `max_tokens` tokens, preferring paragraph, line, sentence
+// and word boundaries in the second half of each window. Windows that tokenize denser than
+// expected are shrunk until they fit.
+pub fn split(text: &str, max_tokens: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        let mut max_chars = (max_tokens * CHARS_PER_TOKEN).max(1);
+        loop {
+            let cut = cut_point(rest, max_chars);
+            if max_chars == 1 || tokenizer::count(&rest[..cut]) <= max_tokens {
+                chunks.push(rest[..cut].to_string());
+                rest = &rest[cut..];
+                break;
+            }
+            max_chars = (max_chars * 3 / 4).max(1);
+        }
+    }
+    chunks
+}
+
+fn cut_point(text: &str, max_chars: usize) -> usize {
+    let Some((limit, _)) = text.char_indices().nth(max_chars) else {
+        return text.len();
+    };
+    let window = &text[..limit];
+    ["\n\n", "\n", ". ", " "]
+        .iter()
+        .filter_map(|sep| window.rfind(sep).map(|i| i + sep.len()))
+        .find(|i| *i > limit / 2)
+        .unwrap_or(limit)
+}
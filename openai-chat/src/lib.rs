@@ -1,11 +1,23 @@
+mod addresses;
+mod chunking;
+mod postprocess;
+mod tokenizer;
 mod trigger;
-use trigger::{decode_trigger_event, encode_trigger_output, Destination};
-use wavs_wasi_utils::http::{fetch_json, http_request_post_json};
+use chunking::ChunkReport;
+use trigger::{encode_cli_output, encode_trigger_output, Codec, Destination};
+use wavs_wasi_utils::http::http_request_post_json;
 pub mod bindings;
 use crate::bindings::{export, Guest, TriggerAction, WasmResponse};
-use alloy_sol_types::{SolCall, SolValue};
+use alloy_sol_types::SolCall;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use wavs_component_common::{
+    batch, commit_reveal, debug, decode, dry_run, envelope, error, freshness, info,
+    output::{CliOutput, EthereumOutput},
+    replay, run,
+    sampling::{self, Sampling},
+    selector, spend, timing, versioned,
+};
 use wstd::{http::HeaderValue, runtime::block_on};
 
 // API request and response structures
@@ -19,6 +31,8 @@ struct Message {
 struct ChatRequest {
     model: String,
     messages: Vec<Message>,
+    #[serde(flatten)]
+    sampling: Sampling,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
@@ -33,6 +47,16 @@ struct ChatResponse {
     choices: Vec<Choice>,
     #[serde(default)]
     usage: Option<Usage>,
+    #[serde(default)]
+    error: Option<ApiError>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+struct ApiError {
+    #[serde(default)]
+    message: Option<String>,
+    #[serde(default)]
+    code: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
@@ -58,48 +82,206 @@ struct Usage {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 struct ResultData {
     prompt: String,
+    // the answer after WAVS_ENV_OPENAI_POSTPROCESS, if configured
     response: String,
-    timestamp: String,
+    // the answer as the model wrote it, present when a post-processing chain is configured
+    raw_response: Option<String>,
+    // unix seconds after which the answer should not be used
+    expires_at: u64,
+    // prompt tokens of the request that produced the response, from the API usage or counted
+    // locally when the API omits it
+    prompt_tokens: u64,
+    // present when the prompt was too long and had to be condensed map-reduce style
+    chunking: Option<ChunkReport>,
 }
 
+const MODEL: &str = "gpt-4";
+const OPENAI_CHAT_URL: &str = "https://api.openai.com/v1/chat/completions";
+// Upper bound on summarize calls for a single prompt
+const MAX_CHUNKS: usize = 32;
+const DEFAULT_MAX_PROMPT_TOKENS: usize = 100_000;
+// LLM answers are meant for the submission they were produced for; five blocks leave it time
+// to land
+const DEFAULT_RESULT_TTL_SECS: u64 = 60;
+const SYSTEM_PROMPT: &str = "You are a helpful assistant.";
+// Room for the "Part i of n" header and message framing of each summarize call
+const PART_OVERHEAD_TOKENS: usize = 32;
+const MAP_INSTRUCTION: &str = "You condense one part of a longer request. Rewrite the part below as briefly as possible while keeping every fact, figure, instruction and question that could be needed to answer the full request.";
+
 struct Component;
 export!(Component with_types_in bindings);
 
 impl Guest for Component {
     fn run(action: TriggerAction) -> std::result::Result<Option<WasmResponse>, String> {
-        let (trigger_id, req, dest) =
-            decode_trigger_event(action.data).map_err(|e| e.to_string())?;
-
-        // Clone request data to avoid ownership issues
-        let req_clone = req.clone();
-
-        // Decode the prompt string using proper ABI decoding
-        let prompt = if let Ok(decoded) = trigger::solidity::sendPromptCall::abi_decode(&req_clone)
-        {
-            // If it has a function selector (from cast abi-encode "f(string)" format)
-            decoded.prompt
-        } else {
-            // Fallback: try decoding just as a string parameter (no function selector)
-            match <String as SolValue>::abi_decode(&req_clone) {
-                Ok(s) => s,
-                Err(e) => return Err(format!("Failed to decode input as ABI string: {}", e)),
-            }
-        };
+        run::run(Codec, action.data, process)
+    }
+}
 
-        println!("Decoded prompt: {}", prompt);
+fn process(
+    trigger_id: u64,
+    req: Vec<u8>,
+    dest: Destination,
+    options: envelope::Options,
+) -> Result<Option<WasmResponse>, String> {
+    options.check_supported(&["encrypt_to", "commit", "reveal", "dry_run"])?;
+    let on_chain = matches!(dest, Destination::Ethereum);
+    if let Some(reveal) = commit_reveal::reveal(&options, env!("CARGO_PKG_NAME"), on_chain)? {
+        return Ok(Some(encode_trigger_output(trigger_id, reveal)));
+    }
+    let sampling = sampling::select(matches!(dest, Destination::Ethereum))?;
+    debug!("Sampling: {:?}", sampling);
+
+    // Clone request data to avoid ownership issues
+    let req_clone = decode::input_bytes(&req)?;
+
+    selector::check(
+        &req_clone,
+        &[
+            selector::Expected::of::<trigger::solidity::sendPromptCall>(),
+            selector::Expected::of::<trigger::solidity::sendPromptsCall>(),
+            selector::Expected::of::<trigger::solidity::extractAddressesCall>(),
+        ],
+    )?;
 
-        // Process the prompt with OpenAI
-        let result = block_on(async move {
-            let response = send_to_openai(&prompt).await?;
-            serde_json::to_vec(&response).map_err(|e| e.to_string())
+    // A batch of prompts is answered prompt by prompt, each with its own ok/err status
+    if let Ok(decoded) = trigger::solidity::sendPromptsCall::abi_decode(&req_clone) {
+        debug!("Decoded batch of {} prompts", decoded.prompts.len());
+        timing::end_phase("decode");
+        if options.dry_run {
+            let input = serde_json::json!({ "prompts": decoded.prompts });
+            let mut plan = dry_run::Plan::new(env!("CARGO_PKG_NAME"), &input)?;
+            for (i, prompt) in decoded.prompts.iter().enumerate() {
+                plan = plan_prompt(plan, prompt).map_err(|e| format!("Prompt {}: {}", i, e))?;
+            }
+            let payload = plan.output(on_chain, options.json_format())?;
+            return payload.map(encode_cli_output).transpose();
+        }
+        let batch = block_on(async move {
+            batch::run(decoded.prompts, |prompt| async move {
+                let response = send_to_openai(&prompt).await?;
+                serde_json::to_value(&response).map_err(|e| e.to_string())
+            })
+            .await
         })?;
+        timing::end_phase("upstream");
+        let data = EthereumOutput::json(&versioned!(&batch))?;
+        return run::respond(Codec, trigger_id, data, dest, &options, || {
+            Ok(CliOutput::new(versioned!(&batch)))
+        });
+    }
 
-        let output = match dest {
-            Destination::Ethereum => Some(encode_trigger_output(trigger_id, &result)),
-            Destination::CliOutput => Some(WasmResponse { payload: result.into(), ordering: None }),
-        };
-        Ok(output)
+    if let Ok(decoded) = trigger::solidity::extractAddressesCall::abi_decode(&req_clone) {
+        debug!("Decoded extraction text of {} bytes", decoded.text.len());
+        timing::end_phase("decode");
+        if options.dry_run {
+            let input = serde_json::json!({ "text": decoded.text });
+            let widest = EthereumOutput::abi(&addresses::widest(&decoded.text));
+            let plan = addresses::planned(
+                dry_run::Plan::new(env!("CARGO_PKG_NAME"), &input)?,
+                &decoded.text,
+            )?
+            .submission(&encode_trigger_output(trigger_id, widest).payload);
+            let payload = plan.output(on_chain, options.json_format())?;
+            return payload.map(encode_cli_output).transpose();
+        }
+        let (extraction, found) = block_on(async move { addresses::extract(&decoded.text).await })?;
+        timing::end_phase("upstream");
+        let data = EthereumOutput::abi(&found).traced();
+        return run::respond(Codec, trigger_id, data, dest, &options, || {
+            Ok(CliOutput::new(versioned!(&extraction)))
+        });
+    }
+
+    // Decode the prompt string using proper ABI decoding
+    let prompt = if let Ok(decoded) = trigger::solidity::sendPromptCall::abi_decode(&req_clone) {
+        // If it has a function selector (from cast abi-encode "f(string)" format)
+        decoded.prompt
+    } else {
+        // Fallback: try decoding just as a string parameter (no function selector)
+        decode::abi_string(&req_clone)?
+    };
+
+    debug!("Decoded prompt: {}", prompt);
+    timing::end_phase("decode");
+    if options.dry_run {
+        let plan =
+            dry_run::Plan::new(env!("CARGO_PKG_NAME"), &serde_json::json!({ "prompt": prompt }))?;
+        let payload = plan_prompt(plan, &prompt)?.output(on_chain, options.json_format())?;
+        return payload.map(encode_cli_output).transpose();
+    }
+
+    // Process the prompt with OpenAI
+    let response = block_on(async move { send_to_openai(&prompt).await })?;
+    timing::end_phase("upstream");
+    let data = EthereumOutput::json(&versioned!(&response))?;
+    run::respond(Codec, trigger_id, data, dest, &options, || {
+        Ok(CliOutput::new(versioned!(&response)))
+    })
+}
+
+// Pre-flight: refuse prompts over the configured budget before spending any API calls
+fn check_prompt_tokens(prompt_tokens: usize) -> Result<(), String> {
+    let max_prompt_tokens = std::env::var("WAVS_ENV_OPENAI_MAX_PROMPT_TOKENS")
+        .ok()
+        .and_then(|v| v.parse::<usize>().ok())
+        .unwrap_or(DEFAULT_MAX_PROMPT_TOKENS);
+    if prompt_tokens > max_prompt_tokens {
+        return Err(format!(
+            "Prompt is {} tokens (cl100k_base), over the budget of {} tokens",
+            prompt_tokens, max_prompt_tokens
+        ));
+    }
+    Ok(())
+}
+
+// Tokens of prompt text per map call, once the instruction and part header are counted. A
+// context only just over the reserved tokens leaves no room for any.
+fn chunk_tokens(budget: usize, context_tokens: usize) -> Result<usize, String> {
+    budget
+        .checked_sub(tokenizer::count(MAP_INSTRUCTION) + PART_OVERHEAD_TOKENS)
+        .filter(|tokens| *tokens > 0)
+        .ok_or_else(|| {
+            format!(
+                "WAVS_ENV_OPENAI_CONTEXT_TOKENS of {} is too small to split prompts for",
+                context_tokens
+            )
+        })
+}
+
+// The requests send_to_openai would make for `prompt`. A prompt over the budget is split the
+// same way; only the first map round can be counted, since later ones depend on the summaries.
+fn plan_prompt(plan: dry_run::Plan, prompt: &str) -> Result<dry_run::Plan, String> {
+    postprocess::Chain::from_env()?;
+    let context_tokens = chunking::context_tokens();
+    let budget = chunking::prompt_budget(context_tokens);
+    let prompt_tokens = tokenizer::count_messages(&chat_messages(SYSTEM_PROMPT, prompt));
+    check_prompt_tokens(prompt_tokens)?;
+    if prompt_tokens <= budget {
+        return plan.llm(OPENAI_CHAT_URL, MODEL, 1, prompt_tokens as u64, None);
+    }
+
+    let chunks = chunking::split(prompt, chunk_tokens(budget, context_tokens)?);
+    if chunks.len() > MAX_CHUNKS {
+        return Err(format!(
+            "Prompt of {} tokens needs more than {} chunks to fit a {} token context",
+            prompt_tokens, MAX_CHUNKS, context_tokens
+        ));
     }
+    let map_tokens: usize = chunks
+        .iter()
+        .map(|chunk| tokenizer::count_messages(&chat_messages(MAP_INSTRUCTION, chunk)))
+        .sum::<usize>()
+        + chunks.len() * PART_OVERHEAD_TOKENS;
+    let note = format!(
+        "The prompt is {} tokens and is condensed in {} parts before it is answered; longer \
+         summaries need more rounds",
+        prompt_tokens,
+        chunks.len()
+    );
+    let calls = chunks.len() as u64;
+    plan.llm(OPENAI_CHAT_URL, MODEL, calls, map_tokens as u64 / calls, None)?
+        .llm(OPENAI_CHAT_URL, MODEL, 1, budget as u64, None)
+        .map(|plan| plan.note(note))
 }
 
 async fn send_to_openai(prompt: &str) -> Result<ResultData, String> {
@@ -107,21 +289,98 @@ async fn send_to_openai(prompt: &str) -> Result<ResultData, String> {
     let api_key = std::env::var("WAVS_ENV_OPENAI_KEY")
         .map_err(|_| "Failed to get OPENAI_KEY from environment variables".to_string())?;
 
-    // Create request
-    let request = ChatRequest {
-        model: "gpt-4".to_string(),
-        messages: vec![
-            Message {
-                role: "system".to_string(),
-                content: "You are a helpful assistant.".to_string(),
-            },
-            Message { role: "user".to_string(), content: prompt.to_string() },
-        ],
+    // Checked before any API call, so a bad configuration costs nothing
+    let postprocess = postprocess::Chain::from_env()?;
+
+    let context_tokens = chunking::context_tokens();
+    let budget = chunking::prompt_budget(context_tokens);
+    let messages = chat_messages(SYSTEM_PROMPT, prompt);
+    let prompt_tokens = tokenizer::count_messages(&messages);
+
+    check_prompt_tokens(prompt_tokens)?;
+
+    let (completion, chunking) = if prompt_tokens <= budget {
+        (complete(&api_key, messages).await?, None)
+    } else {
+        // Map: condense each chunk; reduce: repeat on the combined summaries until they fit
+        info!("Prompt is {} tokens, splitting into chunks", prompt_tokens);
+        let chunk_tokens = chunk_tokens(budget, context_tokens)?;
+        let mut text = prompt.to_string();
+        let mut chunk_count = 0;
+        let mut rounds = 0;
+        let mut intermediate_tokens = Vec::new();
+        while tokenizer::count_messages(&chat_messages(SYSTEM_PROMPT, &text)) > budget {
+            let chunks = chunking::split(&text, chunk_tokens);
+            chunk_count += chunks.len();
+            if chunk_count > MAX_CHUNKS {
+                return Err(format!(
+                    "Prompt of {} tokens needs more than {} chunks to fit a {} token context",
+                    prompt_tokens, MAX_CHUNKS, context_tokens
+                ));
+            }
+            let mut summaries = Vec::with_capacity(chunks.len());
+            for (i, chunk) in chunks.iter().enumerate() {
+                let part = format!("Part {} of {}:\n\n{}", i + 1, chunks.len(), chunk);
+                let summary = complete(&api_key, chat_messages(MAP_INSTRUCTION, &part)).await?;
+                intermediate_tokens.push(summary.total_tokens);
+                summaries.push(summary.text);
+            }
+            text = summaries.join("\n\n");
+            rounds += 1;
+        }
+
+        let condensed = format!(
+            "The original request was too long and has been condensed part by part. Answer it \
+             based on the condensed version below.\n\n{}",
+            text
+        );
+        let completion = complete(&api_key, chat_messages(SYSTEM_PROMPT, &condensed)).await?;
+        let report = ChunkReport {
+            original_prompt_tokens: prompt_tokens,
+            context_tokens,
+            chunk_count,
+            rounds,
+            intermediate_tokens,
+            final_tokens: completion.total_tokens,
+        };
+        (completion, Some(report))
     };
 
+    let response = postprocess.apply(&completion.text)?;
+    Ok(ResultData {
+        prompt: prompt.to_string(),
+        response,
+        raw_response: (!postprocess.is_empty()).then_some(completion.text),
+        expires_at: freshness::expires_at(freshness::now_secs(), DEFAULT_RESULT_TTL_SECS),
+        prompt_tokens: completion.prompt_tokens,
+        chunking,
+    })
+}
+
+fn chat_messages(system: &str, user: &str) -> Vec<Message> {
+    vec![
+        Message { role: "system".to_string(), content: system.to_string() },
+        Message { role: "user".to_string(), content: user.to_string() },
+    ]
+}
+
+struct Completion {
+    text: String,
+    prompt_tokens: u64,
+    total_tokens: u64,
+}
+
+// Sends a single chat completion, unless it would take the run over its spend ceiling. Token
+// usage falls back to local counts when the API response has no usage block.
+async fn complete(api_key: &str, messages: Vec<Message>) -> Result<Completion, String> {
+    let local_prompt_tokens = tokenizer::count_messages(&messages) as u64;
+    spend::check(MODEL, local_prompt_tokens)?;
+
+    // Create request
+    let request = ChatRequest { model: MODEL.to_string(), messages, sampling: sampling::current() };
+
     // Create HTTP request
-    let url = "https://api.openai.com/v1/chat/completions";
-    let mut req = http_request_post_json(url, &request)
+    let mut req = http_request_post_json(OPENAI_CHAT_URL, &request)
         .map_err(|e| format!("Failed to create request: {}", e))?;
 
     // Add headers
@@ -134,7 +393,14 @@ async fn send_to_openai(prompt: &str) -> Result<ResultData, String> {
 
     // Make API request
     let response: ChatResponse =
-        fetch_json(req).await.map_err(|e| format!("Failed to fetch data: {}", e))?;
+        replay::fetch_json(req).await.map_err(|e| format!("Failed to fetch data: {}", e))?;
+    if let Some(error) = response.error {
+        return Err(format!(
+            "OpenAI API error ({}): {}",
+            error.code.unwrap_or_else(|| "unknown".to_string()),
+            error.message.unwrap_or_default()
+        ));
+    }
 
     // Extract response text
     let response_text = response
@@ -143,11 +409,12 @@ async fn send_to_openai(prompt: &str) -> Result<ResultData, String> {
         .and_then(|choice| choice.message.as_ref())
         .map(|msg| msg.content.clone())
         .unwrap_or_else(|| "No response received".to_string());
+    let usage = response.usage.unwrap_or_default();
+    let prompt_tokens = usage.prompt_tokens.unwrap_or(local_prompt_tokens);
+    let completion_tokens =
+        usage.completion_tokens.unwrap_or_else(|| tokenizer::count(&response_text) as u64);
+    let total_tokens = usage.total_tokens.unwrap_or(prompt_tokens + completion_tokens);
+    spend::record(MODEL, prompt_tokens, completion_tokens);
 
-    // Return result
-    Ok(ResultData {
-        prompt: prompt.to_string(),
-        response: response_text,
-        timestamp: chrono::Utc::now().to_rfc3339(),
-    })
+    Ok(Completion { text: response_text, prompt_tokens, total_tokens })
 }
@@ -0,0 +1,91 @@
+// Post-processing of the model's answer before it is encoded, so consumers get the exact string
+// shape they parse. WAVS_ENV_OPENAI_POSTPROCESS is a JSON array of steps, applied in order:
+//
+//   ["extract:(?i)answer:\\s*(yes|no)", "trim", "lower", "max_chars:64"]
+//
+//   extract:<regex>   keep the first match, or its first capture group if the regex has one;
+//                     an answer with no match fails the run instead of passing through unshaped
+//   trim              strip leading and trailing whitespace
+//   lower, upper      Unicode case mapping
+//   max_chars:<n>     cut to at most n characters (Unicode scalar values, not bytes)
+//
+// Unset or empty, the answer is returned as the model wrote it.
+use regex::Regex;
+
+const ENV_VAR: &str = "WAVS_ENV_OPENAI_POSTPROCESS";
+
+enum Step {
+    Extract(Regex),
+    Trim,
+    Lower,
+    Upper,
+    MaxChars(usize),
+}
+
+pub struct Chain {
+    steps: Vec<Step>,
+}
+
+impl Chain {
+    pub fn from_env() -> Result<Chain, String> {
+        let configured = std::env::var(ENV_VAR).unwrap_or_default();
+        if configured.trim().is_empty() {
+            return Ok(Chain { steps: Vec::new() });
+        }
+        let specs: Vec<String> = serde_json::from_str(&configured)
+            .map_err(|e| format!("{} must be a JSON array of strings: {}", ENV_VAR, e))?;
+        let steps = specs.iter().map(|spec| parse_step(spec)).collect::<Result<_, _>>()?;
+        Ok(Chain { steps })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    pub fn apply(&self, answer: &str) -> Result<String, String> {
+        let mut text = answer.to_string();
+        for step in &self.steps {
+            text = match step {
+                Step::Extract(pattern) => extract(pattern, &text)?,
+                Step::Trim => text.trim().to_string(),
+                Step::Lower => text.to_lowercase(),
+                Step::Upper => text.to_uppercase(),
+                Step::MaxChars(max) => text.chars().take(*max).collect(),
+            };
+        }
+        Ok(text)
+    }
+}
+
+fn parse_step(spec: &str) -> Result<Step, String> {
+    let (name, arg) = match spec.split_once(':') {
+        Some((name, arg)) => (name, Some(arg)),
+        None => (spec, None),
+    };
+    let invalid = |reason: String| format!("Invalid {} step '{}': {}", ENV_VAR, spec, reason);
+    match (name, arg) {
+        ("extract", Some(pattern)) => {
+            Regex::new(pattern).map(Step::Extract).map_err(|e| invalid(e.to_string()))
+        }
+        ("trim", None) => Ok(Step::Trim),
+        ("lower", None) => Ok(Step::Lower),
+        ("upper", None) => Ok(Step::Upper),
+        ("max_chars", Some(max)) => {
+            max.trim().parse().map(Step::MaxChars).map_err(|e| invalid(e.to_string()))
+        }
+        ("extract" | "max_chars", None) => Err(invalid("missing argument".to_string())),
+        ("trim" | "lower" | "upper", Some(_)) => Err(invalid("takes no argument".to_string())),
+        _ => Err(invalid(
+            "expected extract:<regex>, trim, lower, upper or max_chars:<n>".to_string(),
+        )),
+    }
+}
+
+fn extract(pattern: &Regex, text: &str) -> Result<String, String> {
+    let captures = pattern
+        .captures(text)
+        .ok_or_else(|| format!("Answer does not match the extract pattern {}", pattern))?;
+    // Group 1 when the pattern has one; an optional group that didn't take part counts as empty
+    let matched = if pattern.captures_len() > 1 { captures.get(1) } else { captures.get(0) };
+    Ok(matched.map_or("", |m| m.as_str()).to_string())
+}
@@ -0,0 +1,12 @@
+// Token counting with cl100k_base, the encoding gpt-4 uses. Chat requests are counted the way
+// the API bills them: 3 framing tokens per message, plus 3 that prime the assistant reply.
+use crate::Message;
+use tiktoken_rs::cl100k_base_singleton;
+
+pub fn count(text: &str) -> usize {
+    cl100k_base_singleton().encode_with_special_tokens(text).len()
+}
+
+pub fn count_messages(messages: &[Message]) -> usize {
+    messages.iter().map(|m| 3 + count(&m.role) + count(&m.content)).sum::<usize>() + 3
+}
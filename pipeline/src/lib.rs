@@ -0,0 +1,214 @@
+mod trigger;
+use trigger::{encode_cli_output, encode_trigger_output, Codec, Destination};
+use wavs_wasi_utils::{
+    evm::alloy_primitives::{hex, keccak256},
+    http::http_request_get,
+};
+pub mod bindings;
+use crate::bindings::{export, Guest, TriggerAction, WasmResponse};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use wavs_component_common::{
+    commit_reveal, decode_abi_string_input, dry_run, envelope, info, llm,
+    output::{CliOutput, EthereumOutput},
+    replay, run, selector, timing, versioned,
+};
+use wstd::{http::HeaderValue, runtime::block_on};
+
+const MAX_STEPS: usize = 8;
+const BREWERY_API_URL: &str = "https://api.openbrewerydb.org/v1/breweries";
+const DEFAULT_INSTRUCTION: &str = "Summarize the following data in a few sentences.";
+const DEFAULT_SUMMARY_MODEL: &str = "gpt-4o";
+
+// Pipeline description carried in the trigger as a JSON string
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PipelineSpec {
+    steps: Vec<Step>,
+}
+
+// Each step consumes the previous step's output. The first step starts from `null`,
+// so steps that need input take it from their own fields when chaining has nothing yet.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Step {
+    BreweryLookup {
+        #[serde(default)]
+        zip_code: Option<String>,
+        #[serde(default)]
+        per_page: Option<u32>,
+    },
+    LlmSummary {
+        #[serde(default)]
+        instruction: Option<String>,
+        #[serde(default)]
+        model: Option<String>,
+    },
+    Keccak256,
+}
+
+impl Step {
+    fn name(&self) -> &'static str {
+        match self {
+            Step::BreweryLookup { .. } => "brewery_lookup",
+            Step::LlmSummary { .. } => "llm_summary",
+            Step::Keccak256 => "keccak256",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StepSummary {
+    index: usize,
+    step: String,
+    output_bytes: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PipelineResult {
+    steps: Vec<StepSummary>,
+    output: Value,
+}
+
+struct Component;
+export!(Component with_types_in bindings);
+
+impl Guest for Component {
+    fn run(action: TriggerAction) -> std::result::Result<Option<WasmResponse>, String> {
+        run::run(Codec, action.data, process)
+    }
+}
+
+fn process(
+    trigger_id: u64,
+    req: Vec<u8>,
+    dest: Destination,
+    options: envelope::Options,
+) -> Result<Option<WasmResponse>, String> {
+    options.check_supported(&["encrypt_to", "commit", "reveal", "dry_run"])?;
+    let on_chain = matches!(dest, Destination::Ethereum);
+    if let Some(reveal) = commit_reveal::reveal(&options, env!("CARGO_PKG_NAME"), on_chain)? {
+        return Ok(Some(encode_trigger_output(trigger_id, reveal)));
+    }
+
+    // Hex text, runPipeline calldata or a bare ABI string
+    let spec_json = decode_abi_string_input(
+        &req,
+        &[selector::Expected::of::<trigger::solidity::runPipelineCall>()],
+    )?;
+
+    let spec: PipelineSpec = serde_json::from_str(&spec_json)
+        .map_err(|e| format!("Failed to parse pipeline spec: {}", e))?;
+    if spec.steps.is_empty() {
+        return Err("Pipeline must contain at least one step".to_string());
+    }
+    if spec.steps.len() > MAX_STEPS {
+        return Err(format!(
+            "Pipeline has {} steps, at most {} are allowed",
+            spec.steps.len(),
+            MAX_STEPS
+        ));
+    }
+
+    timing::end_phase("decode");
+    if options.dry_run {
+        let payload = plan(&spec)?.output(on_chain, options.json_format())?;
+        return payload.map(encode_cli_output).transpose();
+    }
+    let result = block_on(async move { run_pipeline(&spec).await })?;
+    timing::end_phase("upstream");
+
+    run::respond(
+        Codec,
+        trigger_id,
+        EthereumOutput::json(&versioned!(&result))?,
+        dest,
+        &options,
+        || Ok(CliOutput::new(versioned!(&result))),
+    )
+}
+
+// What the steps would fetch and ask. Step outputs are only known once the run makes them, so
+// summary prompts are counted without the data they summarize.
+fn plan(spec: &PipelineSpec) -> Result<dry_run::Plan, String> {
+    let mut plan = dry_run::Plan::new(env!("CARGO_PKG_NAME"), spec)?;
+    for (index, step) in spec.steps.iter().enumerate() {
+        plan = match step {
+            Step::BreweryLookup { zip_code: None, .. } if index == 0 => {
+                return Err("Step 0 (brewery_lookup) needs a zip code".to_string());
+            }
+            Step::BreweryLookup { .. } => plan.http(BREWERY_API_URL, 1),
+            Step::LlmSummary { instruction, model } => {
+                let model = llm::resolve_model(model.clone(), DEFAULT_SUMMARY_MODEL)?;
+                let instruction = instruction.as_deref().unwrap_or(DEFAULT_INSTRUCTION);
+                plan.chat(&model, &[llm::Message::system(instruction)], 1)?.note(format!(
+                    "Step {} (llm_summary) prompt tokens leave out the previous step's output",
+                    index
+                ))
+            }
+            Step::Keccak256 => plan,
+        };
+    }
+    Ok(plan)
+}
+
+async fn run_pipeline(spec: &PipelineSpec) -> Result<PipelineResult, String> {
+    let mut current = Value::Null;
+    let mut steps = Vec::with_capacity(spec.steps.len());
+
+    for (index, step) in spec.steps.iter().enumerate() {
+        info!("Running pipeline step {}: {}", index, step.name());
+        current = run_step(step, current)
+            .await
+            .map_err(|e| format!("Step {} ({}) failed: {}", index, step.name(), e))?;
+        let output_bytes = serde_json::to_vec(&current).map_err(|e| e.to_string())?.len();
+        steps.push(StepSummary { index, step: step.name().to_string(), output_bytes });
+    }
+
+    Ok(PipelineResult { steps, output: current })
+}
+
+async fn run_step(step: &Step, input: Value) -> Result<Value, String> {
+    match step {
+        Step::BreweryLookup { zip_code, per_page } => {
+            let zip_code = match (zip_code, &input) {
+                (Some(zip), _) => zip.clone(),
+                (None, Value::String(zip)) => zip.clone(),
+                _ => {
+                    return Err("No zip code given and previous output is not a string".to_string())
+                }
+            };
+            let breweries = fetch_breweries(&zip_code, per_page.unwrap_or(5).clamp(1, 50)).await?;
+            Ok(breweries)
+        }
+        Step::LlmSummary { instruction, model } => {
+            let instruction = instruction.as_deref().unwrap_or(DEFAULT_INSTRUCTION);
+            let model = llm::resolve_model(model.clone(), DEFAULT_SUMMARY_MODEL)?;
+            let summary = summarize(instruction, &model, &input).await?;
+            Ok(Value::String(summary))
+        }
+        Step::Keccak256 => {
+            let bytes = match &input {
+                Value::String(s) => s.as_bytes().to_vec(),
+                other => serde_json::to_vec(other).map_err(|e| e.to_string())?,
+            };
+            Ok(Value::String(format!("0x{}", hex::encode(keccak256(&bytes)))))
+        }
+    }
+}
+
+async fn fetch_breweries(zip_code: &str, per_page: u32) -> Result<Value, String> {
+    let url = format!("{}?by_postal={}&per_page={}", BREWERY_API_URL, zip_code, per_page);
+    let mut req = http_request_get(&url).map_err(|e| format!("Failed to create request: {}", e))?;
+    req.headers_mut().insert("Accept", HeaderValue::from_static("application/json"));
+    req.headers_mut().insert("Content-Type", HeaderValue::from_static("application/json"));
+    req.headers_mut().insert("User-Agent", HeaderValue::from_static("Mozilla/5.0"));
+    replay::fetch_json(req).await.map_err(|e| format!("Failed to fetch breweries: {}", e))
+}
+
+async fn summarize(instruction: &str, model: &str, input: &Value) -> Result<String, String> {
+    let content = match input {
+        Value::String(s) => s.clone(),
+        other => serde_json::to_string(other).map_err(|e| e.to_string())?,
+    };
+    llm::chat(model, &[llm::Message::system(instruction), llm::Message::user(content)]).await
+}
@@ -1,10 +1,16 @@
+mod stats;
 mod trigger;
-use trigger::{decode_trigger_event, encode_trigger_output, Destination};
+use trigger::{encode_cli_output, encode_trigger_output, Codec, Destination};
 pub mod bindings;
 use crate::bindings::{export, Guest, TriggerAction, WasmResponse};
-use alloy_sol_types::{SolCall, SolValue};
+use alloy_sol_types::SolCall;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use wavs_component_common::{
+    commit_reveal, debug, decode, dry_run, envelope, info,
+    output::{CliOutput, EthereumOutput},
+    run, selector, timing, versioned,
+};
 
 struct Component;
 export!(Component with_types_in bindings);
@@ -17,38 +23,90 @@ pub struct SquareResult {
 
 impl Guest for Component {
     fn run(action: TriggerAction) -> std::result::Result<Option<WasmResponse>, String> {
-        let (trigger_id, req, dest) =
-            decode_trigger_event(action.data).map_err(|e| e.to_string())?;
-
-        let req_clone = req.clone();
-
-        // Decode the string using proper ABI decoding
-        let input_str =
-            if let Ok(decoded) = trigger::solidity::squareNumberCall::abi_decode(&req_clone) {
-                decoded.input
-            } else {
-                match <String as SolValue>::abi_decode(&req_clone) {
-                    Ok(s) => s,
-                    Err(e) => return Err(format!("Failed to decode input as ABI string: {}", e)),
-                }
-            };
-
-        println!("Decoded input string: {}", input_str);
-
-        // Parse as u64, square, and prepare result
-        let n: u64 =
-            input_str.parse().map_err(|e| format!("Failed to parse input as u64: {}", e))?;
-        let squared =
-            n.checked_mul(n).ok_or_else(|| "Overflow when squaring number".to_string())?;
-        let result = SquareResult { input: n.to_string(), squared: squared.to_string() };
-        let res = serde_json::to_vec(&result).map_err(|e| e.to_string())?;
-
-        println!("Squared result: {}", squared);
-
-        let output = match dest {
-            Destination::Ethereum => Some(encode_trigger_output(trigger_id, &res)),
-            Destination::CliOutput => Some(WasmResponse { payload: res.into(), ordering: None }),
+        run::run(Codec, action.data, process)
+    }
+}
+
+fn process(
+    trigger_id: u64,
+    req: Vec<u8>,
+    dest: Destination,
+    options: envelope::Options,
+) -> Result<Option<WasmResponse>, String> {
+    options.check_supported(&["encrypt_to", "commit", "reveal", "dry_run"])?;
+    let on_chain = matches!(dest, Destination::Ethereum);
+    if let Some(reveal) = commit_reveal::reveal(&options, env!("CARGO_PKG_NAME"), on_chain)? {
+        return Ok(Some(encode_trigger_output(trigger_id, reveal)));
+    }
+
+    let req_clone = decode::input_bytes(&req)?;
+
+    selector::check(
+        &req_clone,
+        &[
+            selector::Expected::of::<trigger::solidity::squareNumbersCall>(),
+            selector::Expected::of::<trigger::solidity::squareNumberCall>(),
+        ],
+    )?;
+
+    // A batch of uint256 inputs is squared item by item and summarized
+    let (input, result) =
+        if let Ok(decoded) = trigger::solidity::squareNumbersCall::abi_decode(&req_clone) {
+            debug!("Decoded batch of {} inputs", decoded.inputs.len());
+            timing::end_phase("decode");
+
+            let batch = stats::square_batch(&decoded.inputs)?;
+            info!("Squared batch of {} inputs", decoded.inputs.len());
+            let inputs: Vec<String> = decoded.inputs.iter().map(|i| i.to_string()).collect();
+            (
+                serde_json::json!({ "inputs": inputs }),
+                serde_json::to_value(&batch).map_err(|e| e.to_string())?,
+            )
+        } else {
+            // Decode the string using proper ABI decoding
+            let input_str =
+                if let Ok(decoded) = trigger::solidity::squareNumberCall::abi_decode(&req_clone) {
+                    decoded.input
+                } else {
+                    decode::abi_string(&req_clone)?
+                };
+
+            debug!("Decoded input string: {}", input_str);
+            timing::end_phase("decode");
+
+            // Parse as u64, square, and prepare result
+            let n: u64 =
+                input_str.parse().map_err(|e| format!("Failed to parse input as u64: {}", e))?;
+            let squared =
+                n.checked_mul(n).ok_or_else(|| "Overflow when squaring number".to_string())?;
+            info!("Squared result: {}", squared);
+            let result = serde_json::to_value(SquareResult {
+                input: n.to_string(),
+                squared: squared.to_string(),
+            })
+            .map_err(|e| e.to_string())?;
+            (serde_json::json!({ "input": n.to_string() }), result)
         };
-        Ok(output)
+    timing::end_phase("upstream");
+
+    // Squaring is local, so a dry run only skips what would persist: the commitment's stored
+    // salt and the submission
+    if options.dry_run {
+        let mut plan = dry_run::Plan::new(env!("CARGO_PKG_NAME"), &input)?;
+        if options.encrypt_to.is_none() && !options.commit {
+            let data = EthereumOutput::json(&versioned!(&result))?;
+            plan = plan.submission(&encode_trigger_output(trigger_id, data).payload);
+        }
+        let payload = plan.output(on_chain, options.json_format())?;
+        return payload.map(encode_cli_output).transpose();
     }
+
+    run::respond(
+        Codec,
+        trigger_id,
+        EthereumOutput::json(&versioned!(&result))?,
+        dest,
+        &options,
+        || Ok(CliOutput::new(versioned!(&result))),
+    )
 }
@@ -0,0 +1,88 @@
+// Batch mode: squares every input and summarizes the batch, as a small deterministic compute
+// oracle for exercising aggregation end to end. Everything is exact U256 arithmetic; an input
+// whose square, or a batch whose sums, don't fit in 256 bits is an error rather than a wrapped
+// value. mean and variance (population variance of the inputs) are exact fractions printed as
+// decimals truncated to FRACTION_DIGITS places, so every operator prints the same string.
+use alloy_primitives::U256;
+use serde::{Deserialize, Serialize};
+
+pub const MAX_BATCH: usize = 256;
+const FRACTION_DIGITS: usize = 18;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SquaredItem {
+    input: String,
+    squared: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BatchSquareResult {
+    count: u64,
+    items: Vec<SquaredItem>,
+    sum: String,
+    sum_of_squares: String,
+    mean: String,
+    variance: String,
+}
+
+pub fn square_batch(inputs: &[U256]) -> Result<BatchSquareResult, String> {
+    if inputs.is_empty() {
+        return Err("inputs must not be empty".to_string());
+    }
+    if inputs.len() > MAX_BATCH {
+        return Err(format!("At most {} inputs per batch", MAX_BATCH));
+    }
+    let overflow = |what: &str| format!("Overflow computing the {} of the batch", what);
+
+    let mut items = Vec::with_capacity(inputs.len());
+    let mut sum = U256::ZERO;
+    let mut sum_of_squares = U256::ZERO;
+    for (index, n) in inputs.iter().enumerate() {
+        let squared = n
+            .checked_mul(*n)
+            .ok_or_else(|| format!("Overflow when squaring input {} ({})", index, n))?;
+        sum = sum.checked_add(*n).ok_or_else(|| overflow("sum"))?;
+        sum_of_squares =
+            sum_of_squares.checked_add(squared).ok_or_else(|| overflow("sum of squares"))?;
+        items.push(SquaredItem { input: n.to_string(), squared: squared.to_string() });
+    }
+
+    // variance = (n·Σx² − (Σx)²) / n², which is never negative
+    let count = U256::from(inputs.len());
+    let variance_numerator = count
+        .checked_mul(sum_of_squares)
+        .zip(sum.checked_mul(sum))
+        .map(|(scaled, squared_sum)| scaled - squared_sum)
+        .ok_or_else(|| overflow("variance"))?;
+
+    Ok(BatchSquareResult {
+        count: inputs.len() as u64,
+        items,
+        sum: sum.to_string(),
+        sum_of_squares: sum_of_squares.to_string(),
+        mean: decimal(sum, count),
+        variance: decimal(variance_numerator, count * count),
+    })
+}
+
+// numerator / denominator in decimal, truncated, without trailing zeros
+fn decimal(numerator: U256, denominator: U256) -> String {
+    let whole = numerator / denominator;
+    let mut remainder = numerator % denominator;
+    let mut fraction = String::new();
+    for _ in 0..FRACTION_DIGITS {
+        if remainder.is_zero() {
+            break;
+        }
+        // remainder < denominator ≤ MAX_BATCH², so this can't overflow
+        remainder *= U256::from(10);
+        fraction.push_str(&(remainder / denominator).to_string());
+        remainder %= denominator;
+    }
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        whole.to_string()
+    } else {
+        format!("{}.{}", whole, fraction)
+    }
+}
@@ -0,0 +1,146 @@
+// Certificate details from Certificate Transparency logs. WASI's HTTP client validates a server's
+// chain but doesn't expose it, so the certificate is looked up in crt.sh's CT log index instead
+// (WAVS_ENV_CERT_CT_URL points at another instance): the most recently issued certificate that
+// covers the domain is taken to be the one it serves.
+use serde::Deserialize;
+use wavs_component_common::{dry_run::Plan, replay};
+use wavs_wasi_utils::http::http_request_get;
+use wstd::http::HeaderValue;
+
+const DEFAULT_CT_URL: &str = "https://crt.sh";
+
+#[derive(Debug, Deserialize)]
+struct CtEntry {
+    #[serde(default)]
+    id: u64,
+    #[serde(default)]
+    issuer_name: String,
+    // the certificate's names, one per line
+    #[serde(default)]
+    name_value: String,
+    #[serde(default)]
+    serial_number: String,
+    not_before: String,
+    not_after: String,
+}
+
+pub struct Certificate {
+    // issuer distinguished name, e.g. "C=US, O=Let's Encrypt, CN=R11"
+    pub issuer: String,
+    pub serial_number: String,
+    pub not_before: u64,
+    pub not_after: u64,
+    pub ct_log_id: u64,
+}
+
+impl Certificate {
+    pub fn issuer_organization(&self) -> Option<String> {
+        self.issuer
+            .split(", ")
+            .find_map(|part| part.strip_prefix("O="))
+            .map(|org| org.trim_matches('"').to_string())
+    }
+}
+
+pub fn ct_url() -> String {
+    std::env::var("WAVS_ENV_CERT_CT_URL")
+        .ok()
+        .map(|url| url.trim().trim_end_matches('/').to_string())
+        .filter(|url| !url.is_empty())
+        .unwrap_or_else(|| DEFAULT_CT_URL.to_string())
+}
+
+pub async fn latest_certificate(domain: &str, now: u64) -> Result<Certificate, String> {
+    // Unexpired certificates first; only a domain whose certificates have all expired needs its
+    // full history, which can be large
+    if let Some(certificate) = newest(lookup(domain, true).await?, domain, now) {
+        return Ok(certificate);
+    }
+    newest(lookup(domain, false).await?, domain, now)
+        .ok_or_else(|| format!("No certificate for {} found in the CT logs", domain))
+}
+
+// Both lookups of latest_certificate, each querying every identity
+pub fn planned(plan: Plan, domain: &str) -> Plan {
+    plan.http(&ct_url(), 2 * identities(domain).len() as u64)
+        .note("The full CT history is only queried when no unexpired certificate covers the domain")
+}
+
+// crt.sh matches identities literally, so wildcard certificates for the parent domain need a
+// query of their own
+fn identities(domain: &str) -> Vec<String> {
+    let mut identities = vec![domain.to_string()];
+    if let Some((_, parent)) = domain.split_once('.').filter(|(_, parent)| parent.contains('.')) {
+        identities.push(format!("%2A.{}", parent));
+    }
+    identities
+}
+
+async fn lookup(domain: &str, unexpired_only: bool) -> Result<Vec<CtEntry>, String> {
+    let mut entries = Vec::new();
+    for identity in identities(domain) {
+        let mut url = format!("{}/?q={}&output=json", ct_url(), identity);
+        if unexpired_only {
+            url.push_str("&exclude=expired");
+        }
+        entries.extend(get::<Vec<CtEntry>>(&url).await?);
+    }
+    Ok(entries)
+}
+
+fn newest(entries: Vec<CtEntry>, domain: &str, now: u64) -> Option<Certificate> {
+    entries
+        .into_iter()
+        .filter(|entry| entry.name_value.lines().any(|name| covers(name.trim(), domain)))
+        .filter_map(|entry| {
+            Some(Certificate {
+                not_before: parse_utc(&entry.not_before)?,
+                not_after: parse_utc(&entry.not_after)?,
+                issuer: entry.issuer_name,
+                serial_number: entry.serial_number,
+                ct_log_id: entry.id,
+            })
+        })
+        // certificates issued ahead of time aren't being served yet
+        .filter(|certificate| certificate.not_before <= now)
+        .max_by_key(|certificate| {
+            (certificate.not_before, certificate.not_after, certificate.ct_log_id)
+        })
+}
+
+// A wildcard covers exactly one label
+fn covers(name: &str, domain: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    match name.strip_prefix("*.") {
+        Some(parent) => domain.split_once('.').is_some_and(|(_, rest)| rest == parent),
+        None => name == domain,
+    }
+}
+
+// crt.sh timestamps are UTC without an offset, e.g. "2025-01-31T23:59:59"
+fn parse_utc(timestamp: &str) -> Option<u64> {
+    let (date, time) = timestamp.get(..19)?.split_once('T')?;
+    let mut date = date.split('-').map(|part| part.parse::<i64>().ok());
+    let (year, month, day) = (date.next()??, date.next()??, date.next()??);
+    let mut time = time.split(':').map(|part| part.parse::<i64>().ok());
+    let (hour, minute, second) = (time.next()??, time.next()??, time.next()??);
+    let secs = days_from_civil(year, month, day) * 86_400 + hour * 3_600 + minute * 60 + second;
+    u64::try_from(secs).ok()
+}
+
+// Hinnant's days_from_civil: days since 1970-01-01 in the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+async fn get<T: serde::de::DeserializeOwned>(url: &str) -> Result<T, String> {
+    let mut req = http_request_get(url).map_err(|e| format!("Failed to create request: {}", e))?;
+    req.headers_mut().insert("Accept", HeaderValue::from_static("application/json"));
+    req.headers_mut().insert("User-Agent", HeaderValue::from_static("Mozilla/5.0"));
+    replay::fetch_json(req).await.map_err(|e| format!("Failed to fetch {}: {}", url, e))
+}
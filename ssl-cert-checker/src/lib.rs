@@ -0,0 +1,203 @@
+mod ct;
+mod trigger;
+use trigger::{encode_cli_output, Codec, Destination};
+pub mod bindings;
+use crate::bindings::{export, Guest, TriggerAction, WasmResponse};
+use alloy_sol_types::SolCall;
+use serde::{Deserialize, Serialize};
+use wavs_component_common::{
+    decode, dry_run, envelope, info,
+    output::{CliOutput, EthereumOutput},
+    replay, run, selector, timestamp, timing, versioned, warn,
+};
+use wavs_wasi_utils::http::http_request_get;
+use wstd::runtime::block_on;
+
+const DEFAULT_ALERT_DAYS: u32 = 14;
+const SECS_PER_DAY: i64 = 86_400;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CertificateResult {
+    domain: String,
+    issuer: String,
+    issuer_organization: Option<String>,
+    serial_number: String,
+    not_before: u64,
+    not_after: u64,
+    // whole days until notAfter, negative once expired
+    days_remaining: i64,
+    alert_days: u32,
+    alert: bool,
+    // whether an HTTPS request to the domain completed; the runtime only completes it when the
+    // served chain validates, whatever the status
+    https_reachable: bool,
+    https_status: Option<u16>,
+    ct_source: String,
+    ct_log_id: u64,
+}
+
+struct Component;
+export!(Component with_types_in bindings);
+
+impl Guest for Component {
+    fn run(action: TriggerAction) -> std::result::Result<Option<WasmResponse>, String> {
+        run::run(Codec, action.data, process)
+    }
+}
+
+fn process(
+    trigger_id: u64,
+    req: Vec<u8>,
+    dest: Destination,
+    options: envelope::Options,
+) -> Result<Option<WasmResponse>, String> {
+    options.check_supported(&["dry_run"])?;
+
+    // Decode trigger data inline - handles hex string input
+    let (domain, alert_days) = {
+        let hex_data = decode::input_bytes(&req)?;
+        selector::check(
+            &hex_data,
+            &[
+                selector::Expected::of::<trigger::solidity::checkCertificateWithinCall>(),
+                selector::Expected::of::<trigger::solidity::checkCertificateCall>(),
+            ],
+        )?;
+        if let Ok(decoded) = trigger::solidity::checkCertificateWithinCall::abi_decode(&hex_data) {
+            (decoded.domain, decoded.alertDays)
+        } else if let Ok(decoded) = trigger::solidity::checkCertificateCall::abi_decode(&hex_data) {
+            (decoded.domain, default_alert_days())
+        } else {
+            let domain = decode::abi_string(&hex_data)?;
+            (domain, default_alert_days())
+        }
+    };
+
+    let domain = normalize_domain(&domain)?;
+    check_allowed(&domain)?;
+    timing::end_phase("decode");
+    // The issuer's name is only known from the CT logs, so there is no gas estimate
+    if options.dry_run {
+        let input = serde_json::json!({ "domain": domain, "alert_days": alert_days });
+        let plan = dry_run::Plan::new(env!("CARGO_PKG_NAME"), &input)?;
+        let plan = ct::planned(plan, &domain).http(&format!("https://{}/", domain), 1);
+        let payload = plan.output(matches!(dest, Destination::Ethereum), options.json_format())?;
+        return payload.map(encode_cli_output).transpose();
+    }
+    let (result, encoded) = block_on(async move { check_certificate(&domain, alert_days).await })?;
+    timing::end_phase("upstream");
+
+    run::respond(Codec, trigger_id, EthereumOutput::abi(&encoded).traced(), dest, &options, || {
+        Ok(CliOutput::new(versioned!(&result)))
+    })
+}
+
+fn default_alert_days() -> u32 {
+    std::env::var("WAVS_ENV_CERT_ALERT_DAYS")
+        .ok()
+        .and_then(|v| v.trim().parse::<u32>().ok())
+        .unwrap_or(DEFAULT_ALERT_DAYS)
+}
+
+// A bare DNS name: no scheme, port or path, at least two labels, and not an IP address, so a
+// trigger can't aim the HTTPS probe at localhost or an internal address
+fn normalize_domain(input: &str) -> Result<String, String> {
+    let domain = input.trim().trim_end_matches('.').to_ascii_lowercase();
+    let invalid = || format!("'{}' is not a domain name", input.trim());
+    let labels: Vec<&str> = domain.split('.').collect();
+    let valid_label = |label: &&str| {
+        (1..=63).contains(&label.len())
+            && label.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+    };
+    if domain.len() > 253 || labels.len() < 2 || !labels.iter().all(valid_label) {
+        return Err(invalid());
+    }
+    if labels.last().is_some_and(|tld| tld.bytes().all(|b| b.is_ascii_digit())) {
+        return Err(invalid());
+    }
+    Ok(domain)
+}
+
+// WAVS_ENV_CERT_ALLOWED_DOMAINS, when set, limits checks to the listed domains and their
+// subdomains (comma-separated, e.g. "example.com,example.org")
+fn check_allowed(domain: &str) -> Result<(), String> {
+    let Ok(allowed) = std::env::var("WAVS_ENV_CERT_ALLOWED_DOMAINS") else {
+        return Ok(());
+    };
+    let permitted = allowed
+        .split(',')
+        .map(|entry| entry.trim().trim_start_matches('.').to_ascii_lowercase())
+        .filter(|entry| !entry.is_empty())
+        .any(|entry| domain == entry || domain.ends_with(&format!(".{}", entry)));
+    if permitted {
+        Ok(())
+    } else {
+        Err(format!("Domain {} is not in WAVS_ENV_CERT_ALLOWED_DOMAINS", domain))
+    }
+}
+
+async fn check_certificate(
+    domain: &str,
+    alert_days: u32,
+) -> Result<(CertificateResult, trigger::solidity::CertificateStatus), String> {
+    info!("Checking the certificate of {}", domain);
+    let observed_at = timestamp::run_secs();
+    let certificate = ct::latest_certificate(domain, observed_at).await?;
+    let https_status = probe_https(domain).await;
+
+    let days_remaining =
+        (certificate.not_after as i64 - observed_at as i64).div_euclid(SECS_PER_DAY);
+    let alert = days_remaining < i64::from(alert_days);
+    if alert {
+        warn!("Certificate of {} expires in {} days", domain, days_remaining);
+    }
+
+    let encoded = trigger::solidity::CertificateStatus {
+        domain: domain.to_string(),
+        issuer: certificate.issuer.clone(),
+        notAfter: certificate.not_after,
+        daysRemaining: days_remaining,
+        alertDays: alert_days,
+        alert,
+        httpsReachable: https_status.is_some(),
+        observedAt: observed_at,
+    };
+    let result = CertificateResult {
+        domain: domain.to_string(),
+        issuer_organization: certificate.issuer_organization(),
+        issuer: certificate.issuer,
+        serial_number: certificate.serial_number,
+        not_before: certificate.not_before,
+        not_after: certificate.not_after,
+        days_remaining,
+        alert_days,
+        alert,
+        https_reachable: https_status.is_some(),
+        https_status,
+        ct_source: ct::ct_url(),
+        ct_log_id: certificate.ct_log_id,
+    };
+    Ok((result, encoded))
+}
+
+// The status of a HEAD request to the domain, or None when it didn't complete (DNS, TCP or TLS
+// failure). The error itself is only logged; it is worded differently between hosts.
+async fn probe_https(domain: &str) -> Option<u16> {
+    let url = format!("https://{}/", domain);
+    let request = match http_request_get(&url) {
+        Ok(request) => request,
+        Err(e) => {
+            warn!("Failed to create request for {}: {}", url, e);
+            return None;
+        }
+    };
+    match replay::fetch_head(request).await {
+        Ok(head) => Some(head.status),
+        Err(e) => {
+            warn!("HTTPS request to {} failed: {}", url, e);
+            None
+        }
+    }
+}
@@ -0,0 +1,156 @@
+// The two price sources: Chainlink's USD feeds read on-chain, and an HTTP quote from Coingecko
+// (or CoinMarketCap when Coingecko fails, see price.rs). Both are returned as integers with
+// PRICE_DECIMALS digits of fraction so no float reaches the output.
+use alloy_network::Ethereum;
+use alloy_primitives::{address, Address, TxKind, U256};
+use alloy_provider::{Provider, RootProvider};
+use alloy_rpc_types::TransactionInput;
+use alloy_sol_types::{sol, SolCall};
+use wavs_component_common::{dry_run::Plan, freshness, price, rpc};
+
+pub const PRICE_DECIMALS: u8 = 8;
+// Grace on top of a feed's heartbeat before its answer counts as stale
+const HEARTBEAT_GRACE_SECS: u64 = 3_600;
+
+sol! {
+    interface AggregatorV3Interface {
+        function decimals() external view returns (uint8);
+        function latestRoundData() external view returns (uint80 roundId, int256 answer, uint256 startedAt, uint256 updatedAt, uint80 answeredInRound);
+    }
+}
+
+pub struct Stablecoin {
+    pub symbol: &'static str,
+    // Chainlink <symbol> / USD aggregator on Ethereum mainnet
+    chainlink_feed: Address,
+    heartbeat_secs: u64,
+    quote: price::Asset,
+}
+
+pub const STABLECOINS: [Stablecoin; 3] = [
+    Stablecoin {
+        symbol: "USDT",
+        chainlink_feed: address!("3E7d1eAB13ad0104d2750B8863b489D65364e32D"),
+        heartbeat_secs: 86_400,
+        quote: price::Asset { coingecko_id: "tether", cmc_id: 825 },
+    },
+    Stablecoin {
+        symbol: "USDC",
+        chainlink_feed: address!("8fFfFfd4AfB6115b954Bd326cbe7B4BA576818f6"),
+        heartbeat_secs: 86_400,
+        quote: price::Asset { coingecko_id: "usd-coin", cmc_id: 3408 },
+    },
+    Stablecoin {
+        symbol: "DAI",
+        chainlink_feed: address!("Aed0c38402a5d19df6E4c03F4E2DceD6e29c1ee9"),
+        heartbeat_secs: 3_600,
+        quote: price::Asset { coingecko_id: "dai", cmc_id: 4943 },
+    },
+];
+
+pub fn stablecoin(symbol: &str) -> Result<&'static Stablecoin, String> {
+    let symbol = symbol.trim();
+    STABLECOINS.iter().find(|coin| coin.symbol.eq_ignore_ascii_case(symbol)).ok_or_else(|| {
+        let known: Vec<&str> = STABLECOINS.iter().map(|coin| coin.symbol).collect();
+        format!("Unsupported stablecoin '{}', expected one of {}", symbol, known.join(", "))
+    })
+}
+
+// The feed's decimals and latest round, and the HTTP quote
+pub fn planned(plan: Plan, endpoint: &str, coin: &Stablecoin) -> Plan {
+    price::planned(plan.rpc(endpoint, 2), &coin.quote)
+}
+
+pub struct ChainlinkPrice {
+    pub price: U256,
+    pub updated_at: u64,
+}
+
+pub async fn chainlink_price(
+    provider: &RootProvider<Ethereum>,
+    coin: &Stablecoin,
+) -> Result<ChainlinkPrice, String> {
+    let feed = coin.chainlink_feed;
+    let decimals = aggregator_call(provider, feed, AggregatorV3Interface::decimalsCall {}).await?;
+    let decimals = AggregatorV3Interface::decimalsCall::abi_decode_returns(&decimals)
+        .map_err(|e| format!("Failed to decode Chainlink decimals: {}", e))?;
+    let round =
+        aggregator_call(provider, feed, AggregatorV3Interface::latestRoundDataCall {}).await?;
+    let round = AggregatorV3Interface::latestRoundDataCall::abi_decode_returns(&round)
+        .map_err(|e| format!("Failed to decode Chainlink round data: {}", e))?;
+
+    let max_age = std::env::var("WAVS_ENV_PEG_MAX_PRICE_AGE_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(coin.heartbeat_secs + HEARTBEAT_GRACE_SECS);
+    let updated_at: u64 = round.updatedAt.try_into().unwrap_or(u64::MAX);
+    if freshness::now_secs().saturating_sub(updated_at) > max_age {
+        return Err(format!(
+            "Chainlink {} / USD price is stale (updated at {})",
+            coin.symbol, updated_at
+        ));
+    }
+    if !round.answer.is_positive() {
+        return Err(format!("Chainlink returned a non-positive price: {}", round.answer));
+    }
+
+    Ok(ChainlinkPrice { price: rescale(round.answer.into_raw(), decimals), updated_at })
+}
+
+async fn aggregator_call(
+    provider: &RootProvider<Ethereum>,
+    feed: Address,
+    call: impl SolCall,
+) -> Result<Vec<u8>, String> {
+    let tx = alloy_rpc_types::eth::TransactionRequest {
+        to: Some(TxKind::Call(feed)),
+        input: TransactionInput { input: Some(call.abi_encode().into()), data: None },
+        ..Default::default()
+    };
+    let result = provider.call(tx).await.map_err(|e| rpc::describe("Chainlink call failed", &e))?;
+    Ok(result.to_vec())
+}
+
+pub struct HttpPrice {
+    pub price: U256,
+    pub source: price::Source,
+}
+
+pub async fn http_price(coin: &Stablecoin) -> Result<HttpPrice, String> {
+    let quote = price::usd_quote(&coin.quote).await?;
+    Ok(HttpPrice { price: parse_scaled(&quote.price)?, source: quote.source })
+}
+
+// Parses a decimal string into an integer with PRICE_DECIMALS digits of fraction, truncating
+// any extra digits. The APIs quote small prices in exponent form, which a stablecoin near
+// $1.00 never needs, so exponents are rejected.
+fn parse_scaled(value: &str) -> Result<U256, String> {
+    let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if whole.is_empty() && fraction.is_empty()
+        || !whole.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit())
+    {
+        return Err(format!("Invalid price: {}", value));
+    }
+    let fraction: String =
+        fraction.chars().chain(std::iter::repeat('0')).take(PRICE_DECIMALS as usize).collect();
+    U256::from_str_radix(&format!("{}{}", whole, fraction), 10)
+        .map_err(|e| format!("Invalid price {}: {}", value, e))
+}
+
+fn rescale(value: U256, from: u8) -> U256 {
+    if from >= PRICE_DECIMALS {
+        value / U256::from(10).pow(U256::from(from - PRICE_DECIMALS))
+    } else {
+        value * U256::from(10).pow(U256::from(PRICE_DECIMALS - from))
+    }
+}
+
+pub fn format_price(price: U256) -> String {
+    let scale = U256::from(10).pow(U256::from(PRICE_DECIMALS));
+    let fraction =
+        format!("{:0>width$}", (price % scale).to_string(), width = PRICE_DECIMALS as usize);
+    match fraction.trim_end_matches('0') {
+        "" => (price / scale).to_string(),
+        fraction => format!("{}.{}", price / scale, fraction),
+    }
+}
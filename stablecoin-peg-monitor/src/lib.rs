@@ -0,0 +1,183 @@
+mod feeds;
+mod trigger;
+use feeds::{format_price, PRICE_DECIMALS};
+use trigger::{encode_cli_output, encode_trigger_output, Codec, Destination};
+pub mod bindings;
+use crate::bindings::host::get_evm_chain_config;
+use crate::bindings::{export, Guest, TriggerAction, WasmResponse};
+use alloy_primitives::{I256, U256};
+use serde::{Deserialize, Serialize};
+use wavs_component_common::{
+    decode_abi_string_input, dry_run, envelope, error, freshness, info,
+    output::{CliOutput, EthereumOutput},
+    price, replay, rpc, run, selector, timestamp, timing, versioned,
+};
+use wstd::runtime::block_on;
+
+const DEFAULT_MAX_SOURCE_SPREAD_BPS: u64 = 100;
+// A peg reading feeds circuit breakers, so it is only trusted for a few minutes
+const DEFAULT_RESULT_TTL_SECS: u64 = 300;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PegResult {
+    symbol: String,
+    // mean of the two sources, which the deviation is computed from
+    price: String,
+    chainlink_price: String,
+    chainlink_updated_at: u64,
+    coingecko_price: String,
+    // which API served `coingecko_price`: "coingecko", or "coinmarketcap" after a fallback
+    http_price_source: price::Source,
+    source_spread_bps: u64,
+    max_source_spread_bps: u64,
+    // signed distance of the price from $1.00, truncated toward zero
+    deviation_bps: i64,
+    expires_at: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct SourceDisagreement<'a> {
+    error: &'a str,
+    symbol: &'a str,
+    chainlink_price: String,
+    coingecko_price: String,
+    http_price_source: price::Source,
+    source_spread_bps: u64,
+    max_source_spread_bps: u64,
+}
+
+struct Component;
+export!(Component with_types_in bindings);
+
+impl Guest for Component {
+    fn run(action: TriggerAction) -> std::result::Result<Option<WasmResponse>, String> {
+        run::run(Codec, action.data, process)
+    }
+}
+
+fn process(
+    trigger_id: u64,
+    req: Vec<u8>,
+    dest: Destination,
+    options: envelope::Options,
+) -> Result<Option<WasmResponse>, String> {
+    options.check_supported(&["rpc_url", "dry_run"])?;
+
+    // Hex text, checkPeg calldata or a bare ABI string
+    let symbol = decode_abi_string_input(
+        &req,
+        &[selector::Expected::of::<trigger::solidity::checkPegCall>()],
+    )?;
+    let coin = feeds::stablecoin(&symbol)?;
+
+    let rpc_url = options.rpc_url.clone();
+    timing::end_phase("decode");
+    if options.dry_run {
+        let plan = plan(trigger_id, coin, rpc_url.as_deref())?;
+        let payload = plan.output(matches!(dest, Destination::Ethereum), options.json_format())?;
+        return payload.map(encode_cli_output).transpose();
+    }
+    let (result, encoded) = block_on(async move { check_peg(coin, rpc_url.as_deref()).await })?;
+    timing::end_phase("upstream");
+
+    run::respond(Codec, trigger_id, EthereumOutput::abi(&encoded).traced(), dest, &options, || {
+        Ok(CliOutput::new(versioned!(&result)))
+    })
+}
+
+// Gas is estimated for the widest deviation and timestamp words
+fn plan(
+    trigger_id: u64,
+    coin: &feeds::Stablecoin,
+    rpc_url: Option<&str>,
+) -> Result<dry_run::Plan, String> {
+    let endpoint = ethereum_endpoint(rpc_url)?;
+    let widest = trigger::solidity::PegDeviation {
+        symbol: coin.symbol.to_string(),
+        deviationBps: I256::MAX,
+        timestamp: U256::MAX,
+    };
+    let plan =
+        dry_run::Plan::new(env!("CARGO_PKG_NAME"), &serde_json::json!({ "symbol": coin.symbol }))?
+            .rpc(&endpoint, timestamp::trigger_block_requests("ethereum"));
+    Ok(feeds::planned(plan, &endpoint, coin)
+        .submission(&encode_trigger_output(trigger_id, EthereumOutput::abi(&widest)).payload))
+}
+
+fn ethereum_endpoint(rpc_url: Option<&str>) -> Result<String, String> {
+    let chain_config = get_evm_chain_config("ethereum")
+        .ok_or_else(|| "Failed to get Ethereum chain config".to_string())?;
+    rpc::resolve_endpoint(rpc_url, chain_config.http_endpoint)
+}
+
+// Both sources have to agree before a deviation is reported: a breaker tripped by one bad
+// feed is as costly as a missed de-peg, so disagreement fails the run with a structured error
+// and no submission
+async fn check_peg(
+    coin: &feeds::Stablecoin,
+    rpc_url: Option<&str>,
+) -> Result<(PegResult, trigger::solidity::PegDeviation), String> {
+    let provider = replay::evm_provider(ethereum_endpoint(rpc_url)?);
+    timestamp::record_trigger_block(&provider, "ethereum").await;
+
+    let chainlink = feeds::chainlink_price(&provider, coin).await?;
+    let http = feeds::http_price(coin).await?;
+    let coingecko = http.price;
+    let now = timestamp::run_secs();
+
+    let lower = chainlink.price.min(coingecko);
+    if lower.is_zero() {
+        return Err("Price feed returned zero".to_string());
+    }
+    let max_source_spread_bps = std::env::var("WAVS_ENV_PEG_MAX_SOURCE_SPREAD_BPS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_MAX_SOURCE_SPREAD_BPS);
+    let source_spread_bps: u64 = (chainlink.price.abs_diff(coingecko) * U256::from(10_000) / lower)
+        .try_into()
+        .unwrap_or(u64::MAX);
+    if source_spread_bps > max_source_spread_bps {
+        let error = SourceDisagreement {
+            error: "source_disagreement",
+            symbol: coin.symbol,
+            chainlink_price: format_price(chainlink.price),
+            coingecko_price: format_price(coingecko),
+            http_price_source: http.source,
+            source_spread_bps,
+            max_source_spread_bps,
+        };
+        return Err(serde_json::to_string(&error).map_err(|e| e.to_string())?);
+    }
+
+    let price = (chainlink.price + coingecko) / U256::from(2);
+    let deviation_bps = deviation_bps(price)?;
+    info!("{} at {} ({} bps from $1.00)", coin.symbol, format_price(price), deviation_bps);
+
+    let encoded = trigger::solidity::PegDeviation {
+        symbol: coin.symbol.to_string(),
+        deviationBps: I256::try_from(deviation_bps).map_err(|e| e.to_string())?,
+        timestamp: U256::from(now),
+    };
+    let result = PegResult {
+        symbol: coin.symbol.to_string(),
+        price: format_price(price),
+        chainlink_price: format_price(chainlink.price),
+        chainlink_updated_at: chainlink.updated_at,
+        coingecko_price: format_price(coingecko),
+        http_price_source: http.source,
+        source_spread_bps,
+        max_source_spread_bps,
+        deviation_bps,
+        expires_at: freshness::expires_at(now, DEFAULT_RESULT_TTL_SECS),
+    };
+    Ok((result, encoded))
+}
+
+fn deviation_bps(price: U256) -> Result<i64, String> {
+    let one_dollar = 10i128.pow(PRICE_DECIMALS as u32);
+    let price = i128::try_from(price).map_err(|_| format!("Price {} is out of range", price))?;
+    (price - one_dollar)
+        .checked_mul(10_000)
+        .and_then(|scaled| i64::try_from(scaled / one_dollar).ok())
+        .ok_or_else(|| "Deviation is out of range".to_string())
+}
@@ -0,0 +1,151 @@
+mod providers;
+mod trigger;
+use providers::Provider;
+use trigger::{encode_cli_output, Codec, Destination};
+pub mod bindings;
+use crate::bindings::{export, Guest, TriggerAction, WasmResponse};
+use serde::{Deserialize, Serialize};
+use wavs_component_common::{
+    decode_abi_string_input, dry_run, envelope, info,
+    output::{CliOutput, EthereumOutput},
+    run, selector, timestamp, timing, versioned,
+};
+use wstd::runtime::block_on;
+
+// Bounds the payload for pages that list hundreds of components
+const MAX_COMPONENTS: usize = 64;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ComponentState {
+    name: String,
+    status: String,
+    status_code: u8,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StatusResult {
+    service: String,
+    provider: String,
+    page_url: String,
+    overall: String,
+    overall_code: u8,
+    description: String,
+    active_incidents: u32,
+    components: Vec<ComponentState>,
+    // components left out of the on-chain struct beyond MAX_COMPONENTS
+    omitted_components: usize,
+}
+
+struct Component;
+export!(Component with_types_in bindings);
+
+impl Guest for Component {
+    fn run(action: TriggerAction) -> std::result::Result<Option<WasmResponse>, String> {
+        run::run(Codec, action.data, process)
+    }
+}
+
+fn process(
+    trigger_id: u64,
+    req: Vec<u8>,
+    dest: Destination,
+    options: envelope::Options,
+) -> Result<Option<WasmResponse>, String> {
+    options.check_supported(&["dry_run"])?;
+
+    // Hex text, checkStatus calldata or a bare ABI string
+    let service = decode_abi_string_input(
+        &req,
+        &[selector::Expected::of::<trigger::solidity::checkStatusCall>()],
+    )?;
+
+    let (provider, page_url) = configured_page(&service)?;
+    timing::end_phase("decode");
+    // The status lists however many components the page has, so there is no gas estimate
+    if options.dry_run {
+        let input = serde_json::json!({
+            "service": service.trim(),
+            "provider": provider.name(),
+            "page_url": page_url,
+        });
+        let plan = dry_run::Plan::new(env!("CARGO_PKG_NAME"), &input)?
+            .http(&page_url, provider.requests());
+        let payload = plan.output(matches!(dest, Destination::Ethereum), options.json_format())?;
+        return payload.map(encode_cli_output).transpose();
+    }
+    let (result, encoded) =
+        block_on(async move { check_status(&service, provider, &page_url).await })?;
+    timing::end_phase("upstream");
+
+    run::respond(Codec, trigger_id, EthereumOutput::abi(&encoded).traced(), dest, &options, || {
+        Ok(CliOutput::new(versioned!(&result)))
+    })
+}
+
+// Services must be configured in WAVS_ENV_STATUS_PAGES as comma-separated
+// `name=url` or `name=provider:url` entries, so a trigger can't point the component at an
+// arbitrary host. The provider defaults to statuspage.
+fn configured_page(service: &str) -> Result<(Provider, String), String> {
+    let pages = std::env::var("WAVS_ENV_STATUS_PAGES")
+        .map_err(|_| "WAVS_ENV_STATUS_PAGES is not set, no services are configured".to_string())?;
+    let service = service.trim();
+    let entry = pages
+        .split(',')
+        .filter_map(|entry| entry.split_once('='))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case(service))
+        .map(|(_, page)| page.trim())
+        .ok_or_else(|| format!("Service '{}' is not configured", service))?;
+
+    match entry.split_once(':') {
+        Some((provider, url)) if !provider.starts_with("http") => {
+            Ok((Provider::parse(provider)?, url.trim().to_string()))
+        }
+        _ => Ok((Provider::Statuspage, entry.to_string())),
+    }
+}
+
+async fn check_status(
+    service: &str,
+    provider: Provider,
+    page_url: &str,
+) -> Result<(StatusResult, trigger::solidity::ServiceStatus), String> {
+    info!("Checking {} status page {}", provider.name(), page_url);
+    let summary = providers::fetch_summary(provider, page_url).await?;
+
+    let omitted_components = summary.components.len().saturating_sub(MAX_COMPONENTS);
+    let components: Vec<(String, u8)> =
+        summary.components.into_iter().take(MAX_COMPONENTS).collect();
+
+    let encoded = trigger::solidity::ServiceStatus {
+        service: service.trim().to_string(),
+        overall: summary.overall,
+        activeIncidents: summary.active_incidents,
+        observedAt: timestamp::run_secs(),
+        components: components
+            .iter()
+            .map(|(name, status)| trigger::solidity::ComponentStatus {
+                name: name.clone(),
+                status: *status,
+            })
+            .collect(),
+    };
+    let result = StatusResult {
+        service: service.trim().to_string(),
+        provider: provider.name().to_string(),
+        page_url: page_url.to_string(),
+        overall: providers::status_name(summary.overall).to_string(),
+        overall_code: summary.overall,
+        description: summary.description,
+        active_incidents: summary.active_incidents,
+        components: components
+            .into_iter()
+            .map(|(name, status)| ComponentState {
+                name,
+                status: providers::status_name(status).to_string(),
+                status_code: status,
+            })
+            .collect(),
+        omitted_components,
+    };
+    Ok((result, encoded))
+}
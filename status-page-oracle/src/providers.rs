@@ -0,0 +1,201 @@
+// Summary formats of the supported status page products, mapped onto one status scale
+use serde::Deserialize;
+use serde_json::Value;
+use wavs_component_common::replay;
+use wavs_wasi_utils::http::http_request_get;
+use wstd::http::HeaderValue;
+
+pub const OPERATIONAL: u8 = 0;
+pub const DEGRADED: u8 = 1;
+pub const PARTIAL_OUTAGE: u8 = 2;
+pub const MAJOR_OUTAGE: u8 = 3;
+pub const MAINTENANCE: u8 = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Provider {
+    Statuspage,
+    Instatus,
+}
+
+impl Provider {
+    pub fn parse(name: &str) -> Result<Provider, String> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "statuspage" => Ok(Provider::Statuspage),
+            "instatus" => Ok(Provider::Instatus),
+            other => Err(format!("Unsupported status page provider '{}'", other)),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Provider::Statuspage => "statuspage",
+            Provider::Instatus => "instatus",
+        }
+    }
+
+    // requests fetch_summary makes; Instatus lists its components separately
+    pub fn requests(&self) -> u64 {
+        match self {
+            Provider::Statuspage => 1,
+            Provider::Instatus => 2,
+        }
+    }
+}
+
+pub struct Summary {
+    pub overall: u8,
+    // what the page itself reports, e.g. "All Systems Operational"
+    pub description: String,
+    pub active_incidents: u32,
+    pub components: Vec<(String, u8)>,
+}
+
+pub fn status_name(status: u8) -> &'static str {
+    match status {
+        OPERATIONAL => "operational",
+        DEGRADED => "degraded",
+        PARTIAL_OUTAGE => "partial_outage",
+        MAJOR_OUTAGE => "major_outage",
+        _ => "under_maintenance",
+    }
+}
+
+pub async fn fetch_summary(provider: Provider, base_url: &str) -> Result<Summary, String> {
+    let base_url = base_url.trim_end_matches('/');
+    match provider {
+        Provider::Statuspage => {
+            let page: StatuspageSummary = get(&format!("{}/api/v2/summary.json", base_url)).await?;
+            Ok(page.into_summary())
+        }
+        Provider::Instatus => {
+            let page: InstatusSummary = get(&format!("{}/summary.json", base_url)).await?;
+            let components: Vec<InstatusComponent> =
+                get(&format!("{}/v2/components.json", base_url)).await?;
+            Ok(page.into_summary(components))
+        }
+    }
+}
+
+async fn get<T: serde::de::DeserializeOwned>(url: &str) -> Result<T, String> {
+    let mut req = http_request_get(url).map_err(|e| format!("Failed to create request: {}", e))?;
+    req.headers_mut().insert("Accept", HeaderValue::from_static("application/json"));
+    req.headers_mut().insert("User-Agent", HeaderValue::from_static("Mozilla/5.0"));
+    replay::fetch_json(req).await.map_err(|e| format!("Failed to fetch {}: {}", url, e))
+}
+
+// https://<page>/api/v2/summary.json
+#[derive(Debug, Deserialize, Default)]
+#[serde(default)]
+struct StatuspageSummary {
+    status: StatuspageIndicator,
+    components: Vec<StatuspageComponent>,
+    incidents: Vec<Value>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(default)]
+struct StatuspageIndicator {
+    indicator: String,
+    description: String,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(default)]
+struct StatuspageComponent {
+    name: String,
+    status: String,
+    // group containers repeat the state of their children
+    group: bool,
+}
+
+impl StatuspageSummary {
+    fn into_summary(self) -> Summary {
+        let components: Vec<(String, u8)> = self
+            .components
+            .into_iter()
+            .filter(|c| !c.group)
+            .map(|c| (c.name, component_status(&c.status)))
+            .collect();
+        let overall = match self.status.indicator.as_str() {
+            "none" => OPERATIONAL,
+            "minor" => DEGRADED,
+            "major" => PARTIAL_OUTAGE,
+            "critical" => MAJOR_OUTAGE,
+            "maintenance" => MAINTENANCE,
+            // unknown indicators fall back to the worst component
+            _ => worst(&components),
+        };
+        Summary {
+            overall,
+            description: self.status.description,
+            active_incidents: self.incidents.len() as u32,
+            components,
+        }
+    }
+}
+
+// https://<page>/summary.json and https://<page>/v2/components.json
+#[derive(Debug, Deserialize, Default)]
+#[serde(default, rename_all = "camelCase")]
+struct InstatusSummary {
+    page: InstatusPage,
+    active_incidents: Vec<Value>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(default)]
+struct InstatusPage {
+    status: String,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(default)]
+struct InstatusComponent {
+    name: String,
+    status: String,
+}
+
+impl InstatusSummary {
+    fn into_summary(self, components: Vec<InstatusComponent>) -> Summary {
+        let components: Vec<(String, u8)> =
+            components.into_iter().map(|c| (c.name, component_status(&c.status))).collect();
+        // Instatus only says whether there are issues, so their severity comes from the components
+        let overall = match self.page.status.as_str() {
+            "UP" => OPERATIONAL,
+            "UNDERMAINTENANCE" => MAINTENANCE,
+            _ => match worst(&components) {
+                OPERATIONAL | MAINTENANCE => DEGRADED,
+                other => other,
+            },
+        };
+        Summary {
+            overall,
+            description: self.page.status,
+            active_incidents: self.active_incidents.len() as u32,
+            components,
+        }
+    }
+}
+
+// Both products use the same component states, in snake_case or upper case without separators
+fn component_status(status: &str) -> u8 {
+    match status.to_ascii_lowercase().replace('_', "").as_str() {
+        "operational" => OPERATIONAL,
+        "degradedperformance" => DEGRADED,
+        "partialoutage" => PARTIAL_OUTAGE,
+        "majoroutage" => MAJOR_OUTAGE,
+        "undermaintenance" => MAINTENANCE,
+        // an unrecognized state is not evidence that the component is up
+        _ => DEGRADED,
+    }
+}
+
+// Maintenance ranks below real outages but above a fully operational service
+fn worst(components: &[(String, u8)]) -> u8 {
+    let severity = |status: u8| match status {
+        MAINTENANCE => 1,
+        OPERATIONAL => 0,
+        other => other + 1,
+    };
+    components.iter().map(|(_, s)| *s).max_by_key(|s| severity(*s)).unwrap_or(OPERATIONAL)
+}
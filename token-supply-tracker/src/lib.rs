@@ -0,0 +1,239 @@
+mod trigger;
+use trigger::{encode_cli_output, encode_trigger_output, Codec, Destination};
+
+pub mod bindings;
+use crate::bindings::host::get_evm_chain_config;
+use crate::bindings::{export, Guest, TriggerAction, WasmResponse};
+
+use alloy_network::Ethereum;
+use alloy_primitives::{Address, TxKind, I256, U256};
+use alloy_provider::{Provider, RootProvider};
+use alloy_rpc_types::{BlockId, TransactionInput};
+use alloy_sol_types::{sol, SolCall};
+use serde::{Deserialize, Serialize};
+use wavs_component_common::{
+    bls, decode, dry_run, eip712, envelope, info,
+    output::{CliOutput, EthereumOutput},
+    replay, rpc, run, selector, timestamp, timing, versioned,
+};
+use wavs_wasi_utils::evm::alloy_primitives::hex;
+use wstd::runtime::block_on;
+
+sol! {
+    interface IERC20 {
+        function totalSupply() external view returns (uint256);
+        function decimals() external view returns (uint8);
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SupplyChangeData {
+    token: String,
+    from_block: u64,
+    to_block: u64,
+    supply_from: String,
+    supply_to: String,
+    net_change: String,
+    direction: String,
+    // null when the starting supply is zero, since the change is then undefined
+    change_bps: Option<String>,
+    change_percent: Option<String>,
+    decimals: u8,
+    // EIP-712 digest of the SupplyChange struct submitted on-chain
+    eip712_digest: String,
+    // the digest signed with WAVS_ENV_BLS_SECRET_KEY for aggregation, when it is set
+    bls_signature: Option<bls::BlsSignature>,
+}
+
+struct Component;
+export!(Component with_types_in bindings);
+
+impl Guest for Component {
+    fn run(action: TriggerAction) -> std::result::Result<Option<WasmResponse>, String> {
+        run::run(Codec, action.data, process)
+    }
+}
+
+fn process(
+    trigger_id: u64,
+    req: Vec<u8>,
+    dest: Destination,
+    options: envelope::Options,
+) -> Result<Option<WasmResponse>, String> {
+    options.check_supported(&["dry_run"])?;
+
+    // Decode trigger data inline - handles hex string input, with or without selector
+    let call = {
+        let hex_data = decode::input_bytes(&req)?;
+        selector::check(
+            &hex_data,
+            &[selector::Expected::of::<trigger::solidity::trackSupplyCall>()],
+        )?;
+        trigger::solidity::trackSupplyCall::abi_decode(&hex_data)
+            .or_else(|_| trigger::solidity::trackSupplyCall::abi_decode_raw(&hex_data))
+            .map_err(|e| format!("Failed to decode input as trackSupply call: {}", e))?
+    };
+
+    if call.fromBlock >= call.toBlock {
+        return Err(format!(
+            "fromBlock ({}) must be lower than toBlock ({})",
+            call.fromBlock, call.toBlock
+        ));
+    }
+
+    timing::end_phase("decode");
+    if options.dry_run {
+        let plan = plan(trigger_id, &call)?;
+        let payload = plan.output(matches!(dest, Destination::Ethereum), options.json_format())?;
+        return payload.map(encode_cli_output).transpose();
+    }
+    let (data, encoded) =
+        block_on(async move { get_supply_change(call.token, call.fromBlock, call.toBlock).await })?;
+    timing::end_phase("upstream");
+
+    if matches!(dest, Destination::Ethereum) {
+        info!("EIP-712 digest: {}", data.eip712_digest);
+        if let Some(signature) = &data.bls_signature {
+            info!("BLS signature: {} (key {})", signature.signature, signature.public_key);
+        }
+    }
+    let encoded = EthereumOutput::abi(&encoded).traced();
+    run::respond(Codec, trigger_id, encoded, dest, &options, || {
+        Ok(CliOutput::new(versioned!(&data)))
+    })
+}
+
+// Two totalSupply reads and the decimals read. The gas estimate is for the largest supplies, whose
+// words have no zero bytes.
+fn plan(
+    trigger_id: u64,
+    call: &trigger::solidity::trackSupplyCall,
+) -> Result<dry_run::Plan, String> {
+    let input = serde_json::json!({
+        "token": call.token.to_checksum(None),
+        "from_block": call.fromBlock,
+        "to_block": call.toBlock,
+    });
+    let largest = trigger::solidity::SupplyChange {
+        token: call.token,
+        fromBlock: call.fromBlock,
+        toBlock: call.toBlock,
+        supplyFrom: U256::MAX,
+        supplyTo: U256::MAX,
+        netChange: I256::MAX,
+        changeBps: I256::MAX,
+    };
+    let calls = 3 + timestamp::trigger_block_requests("ethereum");
+    Ok(dry_run::Plan::new(env!("CARGO_PKG_NAME"), &input)?
+        .rpc(&ethereum_endpoint()?, calls)
+        .submission(&encode_trigger_output(trigger_id, EthereumOutput::abi(&largest)).payload))
+}
+
+fn ethereum_endpoint() -> Result<String, String> {
+    let chain_config = get_evm_chain_config("ethereum")
+        .ok_or_else(|| "Failed to get Ethereum chain config".to_string())?;
+    chain_config
+        .http_endpoint
+        .ok_or_else(|| "Ethereum chain config has no HTTP endpoint".to_string())
+}
+
+async fn get_supply_change(
+    token: Address,
+    from_block: u64,
+    to_block: u64,
+) -> Result<(SupplyChangeData, trigger::solidity::SupplyChange), String> {
+    let provider: RootProvider<Ethereum> = replay::evm_provider(ethereum_endpoint()?);
+    timestamp::record_trigger_block(&provider, "ethereum").await;
+
+    let supply_from = total_supply_at(&provider, token, from_block).await?;
+    let supply_to = total_supply_at(&provider, token, to_block).await?;
+
+    let decimals_call = IERC20::decimalsCall {};
+    let tx_decimals = alloy_rpc_types::eth::TransactionRequest {
+        to: Some(TxKind::Call(token)),
+        input: TransactionInput { input: Some(decimals_call.abi_encode().into()), data: None },
+        ..Default::default()
+    };
+    let result_decimals = provider
+        .call(tx_decimals)
+        .await
+        .map_err(|e| rpc::describe("Failed to read decimals", &e))?;
+    let decimals = IERC20::decimalsCall::abi_decode_returns(&result_decimals)
+        .map_err(|e| format!("Failed to decode decimals: {}", e))?;
+
+    let net_change = signed(supply_to)? - signed(supply_from)?;
+    // Percentage change in basis points; undefined when the starting supply is zero
+    let change_bps = if supply_from.is_zero() {
+        None
+    } else {
+        let scaled = net_change.checked_mul(I256::try_from(10_000).unwrap()).ok_or_else(|| {
+            format!("Net change of {} overflows when scaled to basis points", net_change)
+        })?;
+        Some(scaled / signed(supply_from)?)
+    };
+
+    let direction = if net_change.is_positive() {
+        "mint"
+    } else if net_change.is_negative() {
+        "burn"
+    } else {
+        "unchanged"
+    };
+
+    let encoded = trigger::solidity::SupplyChange {
+        token,
+        fromBlock: from_block,
+        toBlock: to_block,
+        supplyFrom: supply_from,
+        supplyTo: supply_to,
+        netChange: net_change,
+        // int256's minimum stands for an undefined change, which no real change can reach
+        changeBps: change_bps.unwrap_or(I256::MIN),
+    };
+    let digest = eip712::digest(&encoded, "token-supply-tracker")?;
+    let data = SupplyChangeData {
+        token: token.to_checksum(None),
+        from_block,
+        to_block,
+        supply_from: supply_from.to_string(),
+        supply_to: supply_to.to_string(),
+        net_change: net_change.to_string(),
+        direction: direction.to_string(),
+        change_bps: change_bps.map(|bps| bps.to_string()),
+        change_percent: change_bps.map(format_bps_as_percent),
+        decimals,
+        eip712_digest: format!("0x{}", hex::encode(digest)),
+        bls_signature: bls::sign(digest)?,
+    };
+    Ok((data, encoded))
+}
+
+async fn total_supply_at(
+    provider: &RootProvider<Ethereum>,
+    token: Address,
+    block: u64,
+) -> Result<U256, String> {
+    let supply_call = IERC20::totalSupplyCall {};
+    let tx = alloy_rpc_types::eth::TransactionRequest {
+        to: Some(TxKind::Call(token)),
+        input: TransactionInput { input: Some(supply_call.abi_encode().into()), data: None },
+        ..Default::default()
+    };
+    let result = provider.call(tx).block(BlockId::number(block)).await.map_err(|e| {
+        rpc::describe(&format!("Failed to read totalSupply at block {}", block), &e)
+    })?;
+    IERC20::totalSupplyCall::abi_decode_returns(&result)
+        .map_err(|e| format!("Failed to decode totalSupply at block {}: {}", block, e))
+}
+
+fn signed(value: U256) -> Result<I256, String> {
+    I256::try_from(value).map_err(|_| format!("Supply {} does not fit in int256", value))
+}
+
+fn format_bps_as_percent(bps: I256) -> String {
+    let sign = if bps.is_negative() { "-" } else { "" };
+    let abs = bps.unsigned_abs();
+    let whole = abs / U256::from(100);
+    let frac = abs % U256::from(100);
+    format!("{}{}.{:0>2}", sign, whole, frac.to_string())
+}
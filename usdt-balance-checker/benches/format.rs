@@ -0,0 +1,23 @@
+// U256 formatting for balance outputs. Run with `cargo bench -p usdt-balance-checker`.
+use alloy_primitives::U256;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use usdt_balance_checker::format_token_amount;
+
+fn format(c: &mut Criterion) {
+    let amounts = [
+        ("whole", U256::from(1_000_000_000u64), 6),
+        ("fractional", U256::from(1_234_567_891u64), 6),
+        ("dust", U256::from(1u64), 18),
+        ("max", U256::MAX, 18),
+    ];
+    let mut group = c.benchmark_group("format_token_amount");
+    for (name, amount, decimals) in amounts {
+        group.bench_with_input(BenchmarkId::from_parameter(name), &amount, |b, amount| {
+            b.iter(|| format_token_amount(black_box(*amount), decimals))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, format);
+criterion_main!(benches);
@@ -0,0 +1,254 @@
+use crate::{history, USDT_CONTRACT_ADDRESS};
+use alloy_network::Ethereum;
+use alloy_primitives::{b256, Address, B256};
+use alloy_provider::{Provider, RootProvider};
+use alloy_rpc_types::{BlockNumberOrTag, Filter, Log};
+use futures::stream::{self, StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use wavs_component_common::{
+    info, rpc,
+    sanctions::{self, Screening},
+};
+
+pub const MAX_ACTIVITY_BLOCKS: u64 = 1_000_000;
+pub const MAX_ACTIVITY_DAYS: u64 = 366;
+const MAX_TRANSFERS: usize = 10_000;
+const DEFAULT_CHUNK_BLOCKS: u64 = 10_000;
+const SECS_PER_DAY: u64 = 86_400;
+
+// keccak256("Transfer(address,address,uint256)")
+const TRANSFER_TOPIC: B256 =
+    b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DayActivity {
+    // UTC day, YYYY-MM-DD
+    date: String,
+    sent: u64,
+    received: u64,
+    // a transfer from the wallet to itself is counted as sent and received, but once here
+    total: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ActivityHeatmapData {
+    wallet: String,
+    usdt_contract: String,
+    from_block: u64,
+    to_block: u64,
+    // every UTC day the range touches, ascending, including days without transfers
+    days: Vec<DayActivity>,
+    active_days: u64,
+    total_sent: u64,
+    total_received: u64,
+    total_transfers: u64,
+    // `sanctioned` and `sanctions_list_version` from the embedded OFAC SDN list, left out while
+    // none is embedded
+    #[serde(flatten)]
+    screening: Option<Screening>,
+}
+
+// How the range was scanned. Only logged: it depends on the operator's provider, and
+// submissions must match across operators.
+#[derive(Debug, Default)]
+struct ScanStats {
+    queries: u64,
+    splits: u64,
+}
+
+// Buckets the wallet's USDT Transfer events in [from_block, to_block] by the UTC day of their
+// block. The range is scanned in chunks of WAVS_ENV_USDT_LOGS_CHUNK_BLOCKS (default 10000)
+// blocks; when a provider rejects a chunk for returning too many logs (or spanning too many
+// blocks), the chunk is halved and the smaller size is kept for the rest of the range.
+pub async fn get_activity_heatmap(
+    provider: &RootProvider<Ethereum>,
+    wallet: Address,
+    from_block: u64,
+    to_block: u64,
+) -> Result<ActivityHeatmapData, String> {
+    let usdt_address = USDT_CONTRACT_ADDRESS
+        .parse::<Address>()
+        .map_err(|e| format!("Invalid USDT contract address: {}", e))?;
+
+    let mut stats = ScanStats::default();
+    let logs =
+        transfer_logs(provider, usdt_address, wallet, from_block, to_block, &mut stats).await?;
+    info!(
+        "Scanned blocks {}..={} in {} getLogs queries ({} splits), {} transfers",
+        from_block,
+        to_block,
+        stats.queries,
+        stats.splits,
+        logs.len()
+    );
+
+    // Timestamps come with the logs on some providers; the rest, and the range's ends, are
+    // fetched once per block
+    let mut timestamps: BTreeMap<u64, u64> = BTreeMap::new();
+    for log in logs.values() {
+        if let (Some(block), Some(timestamp)) = (log.block_number, log.block_timestamp) {
+            timestamps.insert(block, timestamp);
+        }
+    }
+    let missing: BTreeSet<u64> = logs
+        .values()
+        .filter_map(|log| log.block_number)
+        .chain([from_block, to_block])
+        .filter(|block| !timestamps.contains_key(block))
+        .collect();
+    let fetched: Vec<(u64, u64)> = stream::iter(missing)
+        .map(|block| async move { block_timestamp(provider, block).await.map(|t| (block, t)) })
+        .buffered(history::concurrency())
+        .try_collect()
+        .await?;
+    timestamps.extend(fetched);
+
+    let first_day = timestamps[&from_block] / SECS_PER_DAY;
+    let last_day = timestamps[&to_block] / SECS_PER_DAY;
+    if last_day - first_day >= MAX_ACTIVITY_DAYS {
+        return Err(format!(
+            "Blocks {}..={} span {} days; at most {} per request",
+            from_block,
+            to_block,
+            last_day - first_day + 1,
+            MAX_ACTIVITY_DAYS
+        ));
+    }
+
+    let mut buckets: BTreeMap<u64, (u64, u64, u64)> =
+        (first_day..=last_day).map(|day| (day, (0, 0, 0))).collect();
+    let wallet_topic = wallet.into_word();
+    for log in logs.values() {
+        let block = log.block_number.ok_or("Transfer log without a block number")?;
+        let day = timestamps[&block] / SECS_PER_DAY;
+        let bucket = buckets.entry(day).or_default();
+        if log.topics().get(1) == Some(&wallet_topic) {
+            bucket.0 += 1;
+        }
+        if log.topics().get(2) == Some(&wallet_topic) {
+            bucket.1 += 1;
+        }
+        bucket.2 += 1;
+    }
+
+    let days: Vec<DayActivity> = buckets
+        .into_iter()
+        .map(|(day, (sent, received, total))| DayActivity {
+            date: utc_date(day),
+            sent,
+            received,
+            total,
+        })
+        .collect();
+
+    Ok(ActivityHeatmapData {
+        wallet: wallet.to_checksum(None),
+        usdt_contract: USDT_CONTRACT_ADDRESS.to_string(),
+        from_block,
+        to_block,
+        active_days: days.iter().filter(|d| d.total > 0).count() as u64,
+        total_sent: days.iter().map(|d| d.sent).sum(),
+        total_received: days.iter().map(|d| d.received).sum(),
+        total_transfers: days.iter().map(|d| d.total).sum(),
+        days,
+        screening: sanctions::screen(wallet),
+    })
+}
+
+// The wallet's Transfer logs in the range, keyed by (transaction hash, log index) so a
+// self-transfer, which matches both the sent and the received query, is kept once
+async fn transfer_logs(
+    provider: &RootProvider<Ethereum>,
+    token: Address,
+    wallet: Address,
+    from_block: u64,
+    to_block: u64,
+    stats: &mut ScanStats,
+) -> Result<BTreeMap<(B256, u64), Log>, String> {
+    let mut chunk = chunk_blocks();
+    let mut logs = BTreeMap::new();
+    let mut start = from_block;
+    while start <= to_block {
+        let end = start.saturating_add(chunk - 1).min(to_block);
+        match chunk_logs(provider, token, wallet, start, end, stats).await {
+            Ok(found) => {
+                for log in found.into_iter().filter(|log| !log.removed) {
+                    let key = (
+                        log.transaction_hash.ok_or("Transfer log without a transaction hash")?,
+                        log.log_index.ok_or("Transfer log without a log index")?,
+                    );
+                    logs.insert(key, log);
+                }
+                if logs.len() > MAX_TRANSFERS {
+                    return Err(format!(
+                        "More than {} transfers in blocks {}..={}; request a shorter range",
+                        MAX_TRANSFERS, from_block, to_block
+                    ));
+                }
+                start = end + 1;
+            }
+            Err(e) if end > start && rpc::is_log_limit_error(&e) => {
+                chunk = (end - start).div_ceil(2);
+                stats.splits += 1;
+            }
+            Err(e) => return Err(format!("getLogs for blocks {}..={}: {}", start, end, e)),
+        }
+    }
+    Ok(logs)
+}
+
+// eth_getLogs can't OR topics across positions, so sent and received transfers are two queries
+async fn chunk_logs(
+    provider: &RootProvider<Ethereum>,
+    token: Address,
+    wallet: Address,
+    start: u64,
+    end: u64,
+    stats: &mut ScanStats,
+) -> Result<Vec<Log>, String> {
+    let filter = Filter::new()
+        .address(token)
+        .event_signature(TRANSFER_TOPIC)
+        .from_block(start)
+        .to_block(end);
+    let sent = filter.clone().topic1(wallet.into_word());
+    let received = filter.topic2(wallet.into_word());
+    stats.queries += 2;
+    let (mut sent, received) =
+        futures::try_join!(provider.get_logs(&sent), provider.get_logs(&received))
+            .map_err(|e| rpc::describe("Failed to fetch Transfer logs", &e))?;
+    sent.extend(received);
+    Ok(sent)
+}
+
+pub(crate) fn chunk_blocks() -> u64 {
+    std::env::var("WAVS_ENV_USDT_LOGS_CHUNK_BLOCKS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_CHUNK_BLOCKS)
+        .clamp(1, MAX_ACTIVITY_BLOCKS)
+}
+
+async fn block_timestamp(provider: &RootProvider<Ethereum>, block: u64) -> Result<u64, String> {
+    let header = provider
+        .get_block_by_number(BlockNumberOrTag::Number(block))
+        .await
+        .map_err(|e| rpc::describe(&format!("Failed to fetch block {}", block), &e))?
+        .ok_or_else(|| format!("Block {} not found", block))?;
+    Ok(header.header.timestamp)
+}
+
+// Days since the Unix epoch as a proleptic Gregorian date (Hinnant's civil_from_days)
+fn utc_date(days: u64) -> String {
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
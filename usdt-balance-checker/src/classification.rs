@@ -0,0 +1,107 @@
+use alloy_network::Network;
+use alloy_primitives::{Address, U256};
+use alloy_provider::Provider;
+use serde::{Deserialize, Serialize};
+use wavs_component_common::rpc;
+
+// Roughly 30 days of 12 second blocks; a wallet whose first transaction is younger is fresh
+const FRESH_BLOCKS: u64 = 216_000;
+// Roughly 90 days of 12 second blocks without an outgoing transaction counts as dormant
+const DORMANT_BLOCKS: u64 = 648_000;
+// Nonces and balances at this scale are typical of exchange hot wallets
+const EXCHANGE_TX_COUNT: u64 = 10_000;
+const EXCHANGE_BALANCE_TOKENS: u64 = 10_000_000;
+
+// How long ago the wallet sent its first transaction, from its nonce at the window starts
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Age {
+    // never sent a transaction
+    None,
+    Under30Days,
+    Under90Days,
+    Over90Days,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WalletClassification {
+    pub label: String,
+    pub tx_count: u64,
+    pub is_contract: bool,
+    pub txs_in_dormancy_window: u64,
+    pub age: Age,
+}
+
+// Labels a wallet as fresh, active_eoa, contract, exchange_like or dormant from its nonce,
+// code, token balance and nonce history. Every read is at `block`, the run's pinned block, or a
+// fixed distance before it, so all operators classify from the same state; the historical
+// nonces need an archive endpoint, and a failed read fails the run rather than the label.
+pub async fn classify_wallet<N: Network>(
+    provider: &impl Provider<N>,
+    wallet: Address,
+    block: u64,
+    token_balance: U256,
+    decimals: u8,
+) -> Result<WalletClassification, String> {
+    let code = provider
+        .get_code_at(wallet)
+        .number(block)
+        .await
+        .map_err(|e| rpc::describe(&format!("Failed to fetch code for {}", wallet), &e))?;
+    let tx_count = nonce_at(provider, wallet, block).await?;
+
+    // A nonce never decreases, so a wallet that has never sent needs no history
+    let (fresh_window_start, dormancy_window_start) = if tx_count == 0 {
+        (0, 0)
+    } else {
+        let fresh = nonce_at(provider, wallet, block.saturating_sub(FRESH_BLOCKS)).await?;
+        let dormant = nonce_at(provider, wallet, block.saturating_sub(DORMANT_BLOCKS)).await?;
+        (fresh, dormant)
+    };
+    let age = if tx_count == 0 {
+        Age::None
+    } else if fresh_window_start == 0 {
+        Age::Under30Days
+    } else if dormancy_window_start == 0 {
+        Age::Under90Days
+    } else {
+        Age::Over90Days
+    };
+    let txs_in_dormancy_window = tx_count.saturating_sub(dormancy_window_start);
+
+    let exchange_balance =
+        U256::from(EXCHANGE_BALANCE_TOKENS) * U256::from(10).pow(U256::from(decimals));
+    let is_contract = !code.is_empty();
+    let label = if is_contract {
+        "contract"
+    } else if tx_count >= EXCHANGE_TX_COUNT || token_balance >= exchange_balance {
+        "exchange_like"
+    } else if matches!(age, Age::None | Age::Under30Days) {
+        "fresh"
+    } else if txs_in_dormancy_window == 0 {
+        "dormant"
+    } else {
+        "active_eoa"
+    };
+
+    Ok(WalletClassification {
+        label: label.to_string(),
+        tx_count,
+        is_contract,
+        txs_in_dormancy_window,
+        age,
+    })
+}
+
+async fn nonce_at<N: Network>(
+    provider: &impl Provider<N>,
+    wallet: Address,
+    block: u64,
+) -> Result<u64, String> {
+    provider.get_transaction_count(wallet).number(block).await.map_err(|e| {
+        rpc::describe(
+            &format!("Failed to fetch transaction count for {} at block {}", wallet, block),
+            &e,
+        )
+    })
+}
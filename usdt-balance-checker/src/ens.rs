@@ -0,0 +1,143 @@
+// ENS resolution: forward (registry → resolver → addr(node)) and reverse, for the primary name
+// of a wallet. Names are lowercased as ASCII; full ENSIP-15 normalization (emoji, confusables) is
+// out of scope, so non-ASCII names are rejected rather than resolved to a possibly different node.
+use alloy_network::{Network, TransactionBuilder};
+use alloy_primitives::{address, hex, keccak256, Address, B256};
+use alloy_provider::Provider;
+use alloy_rpc_types::BlockId;
+use alloy_sol_types::{sol, SolCall};
+use serde::{Deserialize, Serialize};
+use wavs_component_common::{info, rpc};
+
+sol! {
+    interface IENSRegistry {
+        function resolver(bytes32 node) external view returns (address);
+    }
+    interface IAddrResolver {
+        function addr(bytes32 node) external view returns (address);
+    }
+    interface INameResolver {
+        function name(bytes32 node) external view returns (string);
+    }
+}
+
+const ENS_REGISTRY: Address = address!("00000000000C2E074eC69A0dFb2997BA6C7d2e1e");
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EnsResolution {
+    pub name: String,
+    pub node: String,
+    pub resolver: String,
+    pub address: String,
+}
+
+pub async fn resolve<N: Network>(
+    provider: &impl Provider<N>,
+    name: &str,
+) -> Result<EnsResolution, String> {
+    let name = normalize(name)?;
+    let node = namehash(&name);
+    let at = BlockId::latest();
+
+    let resolver = call(provider, ENS_REGISTRY, IENSRegistry::resolverCall { node }, at).await?;
+    let resolver = IENSRegistry::resolverCall::abi_decode_returns(&resolver)
+        .map_err(|e| format!("Failed to decode ENS resolver: {}", e))?;
+    if resolver.is_zero() {
+        return Err(format!("ENS name {} has no resolver", name));
+    }
+
+    let address = call(provider, resolver, IAddrResolver::addrCall { node }, at).await?;
+    let address = IAddrResolver::addrCall::abi_decode_returns(&address)
+        .map_err(|e| format!("Failed to decode ENS address: {}", e))?;
+    if address.is_zero() {
+        return Err(format!("ENS name {} does not resolve to an address", name));
+    }
+
+    Ok(EnsResolution {
+        name,
+        node: node.to_string(),
+        resolver: resolver.to_checksum(None),
+        address: address.to_checksum(None),
+    })
+}
+
+// The primary name of `address`: the name its reverse record (<hex address>.addr.reverse) points
+// to, kept only if that name resolves back to the address, since anyone can set a reverse record
+// to any name. None when there is no reverse record or it doesn't verify.
+pub async fn reverse<N: Network>(
+    provider: &impl Provider<N>,
+    address: Address,
+    at: BlockId,
+) -> Result<Option<String>, String> {
+    let node = namehash(&format!("{}.addr.reverse", hex::encode(address)));
+    let Some(name) = lookup(provider, node, INameResolver::nameCall { node }, at).await? else {
+        return Ok(None);
+    };
+    let Ok(name) = normalize(&name) else {
+        info!("Primary name {:?} of {} is not a supported ENS name", name, address);
+        return Ok(None);
+    };
+
+    let node = namehash(&name);
+    let forward = lookup(provider, node, IAddrResolver::addrCall { node }, at).await?;
+    if forward != Some(address) {
+        info!("Primary name {} of {} does not resolve back to it", name, address);
+        return Ok(None);
+    }
+    Ok(Some(name))
+}
+
+// Reads `record` from the resolver of `node`. None when the node has no resolver, or the
+// resolver reverts or returns something undecodable, as resolvers without the method do.
+async fn lookup<N: Network, C: SolCall>(
+    provider: &impl Provider<N>,
+    node: B256,
+    record: C,
+    at: BlockId,
+) -> Result<Option<C::Return>, String> {
+    let resolver = call(provider, ENS_REGISTRY, IENSRegistry::resolverCall { node }, at).await?;
+    let resolver = IENSRegistry::resolverCall::abi_decode_returns(&resolver)
+        .map_err(|e| format!("Failed to decode ENS resolver: {}", e))?;
+    if resolver.is_zero() {
+        return Ok(None);
+    }
+    let tx = N::TransactionRequest::default().with_to(resolver).with_input(record.abi_encode());
+    match provider.call(tx).block(at).await {
+        Ok(output) => Ok(C::abi_decode_returns(&output).ok()),
+        Err(e) if e.as_error_resp().is_some() => Ok(None),
+        Err(e) => Err(rpc::describe("ENS call failed", &e)),
+    }
+}
+
+pub(crate) fn normalize(name: &str) -> Result<String, String> {
+    let name = name.trim().trim_end_matches('.').to_ascii_lowercase();
+    if !name.is_ascii() {
+        return Err(format!("ENS name {} is not ASCII", name));
+    }
+    if !name.contains('.') || name.split('.').any(|label| label.is_empty()) {
+        return Err(format!("Invalid ENS name: {}", name));
+    }
+    Ok(name)
+}
+
+// EIP-137 namehash
+fn namehash(name: &str) -> B256 {
+    name.rsplit('.').fold(B256::ZERO, |node, label| {
+        let mut buf = [0u8; 64];
+        buf[..32].copy_from_slice(node.as_slice());
+        buf[32..].copy_from_slice(keccak256(label.as_bytes()).as_slice());
+        keccak256(buf)
+    })
+}
+
+async fn call<N: Network>(
+    provider: &impl Provider<N>,
+    to: Address,
+    call: impl SolCall,
+    at: BlockId,
+) -> Result<Vec<u8>, String> {
+    let tx = N::TransactionRequest::default().with_to(to).with_input(call.abi_encode());
+    let result =
+        provider.call(tx).block(at).await.map_err(|e| rpc::describe("ENS call failed", &e))?;
+    Ok(result.to_vec())
+}
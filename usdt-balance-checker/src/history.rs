@@ -0,0 +1,81 @@
+use crate::{format_token_amount, token, USDT_CONTRACT_ADDRESS};
+use alloy_network::Network;
+use alloy_primitives::Address;
+use alloy_provider::Provider;
+use alloy_rpc_types::BlockId;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+use wavs_component_common::sanctions::{self, Screening};
+
+pub const MAX_HISTORY_BLOCKS: usize = 256;
+const DEFAULT_CONCURRENCY: usize = 8;
+const MAX_CONCURRENCY: usize = 32;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HistoryPoint {
+    block: u64,
+    balance_raw: String,
+    balance_formatted: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BalanceHistoryData {
+    wallet: String,
+    usdt_contract: String,
+    decimals: u8,
+    // one point per requested block, ascending and without duplicates
+    points: Vec<HistoryPoint>,
+    // `sanctioned` and `sanctions_list_version` from the embedded OFAC SDN list, left out while
+    // none is embedded
+    #[serde(flatten)]
+    screening: Option<Screening>,
+}
+
+// Multicall3 runs every call in a batch against the same block, so a series across blocks
+// still needs one eth_call each. They run concurrently, at most
+// WAVS_ENV_USDT_HISTORY_CONCURRENCY (default 8) in flight so a long series doesn't trip the
+// node's rate limits.
+pub async fn get_balance_history<N: Network>(
+    provider: &impl Provider<N>,
+    wallet: Address,
+    decimals: u8,
+    mut blocks: Vec<u64>,
+) -> Result<BalanceHistoryData, String> {
+    let usdt_address = USDT_CONTRACT_ADDRESS
+        .parse::<Address>()
+        .map_err(|e| format!("Invalid USDT contract address: {}", e))?;
+    blocks.sort_unstable();
+    blocks.dedup();
+
+    let points: Vec<HistoryPoint> = stream::iter(blocks)
+        .map(|block| async move {
+            let balance = token::balance_of(provider, usdt_address, wallet, BlockId::number(block))
+                .await
+                .map_err(|e| format!("Block {}: {}", block, e))?;
+            Ok::<_, String>(HistoryPoint {
+                block,
+                balance_raw: balance.to_string(),
+                balance_formatted: format_token_amount(balance, decimals),
+            })
+        })
+        .buffered(concurrency())
+        .try_collect()
+        .await?;
+
+    Ok(BalanceHistoryData {
+        wallet: wallet.to_checksum(None),
+        usdt_contract: USDT_CONTRACT_ADDRESS.to_string(),
+        decimals,
+        points,
+        screening: sanctions::screen(wallet),
+    })
+}
+
+// Also bounds the block timestamp lookups of the activity heatmap
+pub(crate) fn concurrency() -> usize {
+    std::env::var("WAVS_ENV_USDT_HISTORY_CONCURRENCY")
+        .ok()
+        .and_then(|v| v.parse::<usize>().ok())
+        .unwrap_or(DEFAULT_CONCURRENCY)
+        .clamp(1, MAX_CONCURRENCY)
+}
@@ -1,19 +1,47 @@
+mod activity;
+mod classification;
+mod ens;
+mod history;
+mod price;
+mod proxy;
+mod simulate;
+mod token;
 mod trigger;
-use trigger::{decode_trigger_event, encode_trigger_output, Destination};
+mod twab;
+mod vault;
+use activity::{get_activity_heatmap, MAX_ACTIVITY_BLOCKS};
+use classification::{classify_wallet, WalletClassification};
+use ens::EnsResolution;
+use history::{get_balance_history, MAX_HISTORY_BLOCKS};
+use price::PriceInfo;
+use proxy::{inspect_proxy, ProxyInfo};
+use trigger::solidity::{address_wallet, bytes32_wallet};
+use trigger::{encode_cli_output, encode_trigger_output, Codec, Destination};
+use twab::{get_time_weighted_balance, MAX_SAMPLES, MIN_SAMPLES};
 
 pub mod bindings;
 use crate::bindings::host::get_evm_chain_config;
 use crate::bindings::{export, Guest, TriggerAction, WasmResponse};
 
-use alloy_network::Ethereum;
-use alloy_primitives::{Address, TxKind, U256};
+use alloy_network::{Ethereum, Network};
+use alloy_primitives::{Address, U256};
 use alloy_provider::{Provider, RootProvider};
-use alloy_rpc_types::TransactionInput;
-use alloy_sol_types::{sol, SolCall, SolValue};
+use alloy_rpc_types::BlockId;
+use alloy_sol_types::{sol, SolCall};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
-use wavs_wasi_utils::evm::{alloy_primitives::hex, new_evm_provider};
+use std::time::Instant;
+use wavs_component_common::{
+    batch, commit_reveal,
+    decode::{self, WalletEncoding},
+    dry_run, envelope, freshness, info, json_patch,
+    output::{CliOutput, EthereumOutput},
+    reorg::{self, BlockInfo},
+    replay, rpc, run,
+    sanctions::{self, Screening},
+    selector, timestamp, timing, truncation, versioned,
+};
 use wstd::runtime::block_on;
 
 sol! {
@@ -24,15 +52,71 @@ sol! {
 }
 
 const USDT_CONTRACT_ADDRESS: &str = "0xdAC17F958D2ee523a2206206994597C13D831ec7";
+// RPC calls of one balance read: the pinned block, balanceOf, decimals, up to four for the ENS
+// primary name and four for the classification, the proxy slot, and the reorg check's two
+const BALANCE_RPC_CALLS: u64 = 14;
+// Balances move with every transfer, so they are only trusted for a few minutes
+const DEFAULT_RESULT_TTL_SECS: u64 = 300;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct UsdtBalanceData {
     wallet: String,
     balance_raw: String,
     balance_formatted: String,
+    #[cfg(not(feature = "token-schema"))]
     usdt_contract: String,
+    // usdt-balance-checker-2's names for the contract, plus the symbol
+    #[cfg(feature = "token-schema")]
+    token_contract: String,
+    #[cfg(feature = "token-schema")]
+    token_symbol: String,
     decimals: u8,
-    timestamp: String,
+    // unix seconds after which consumers should treat the balance as stale
+    expires_at: u64,
+    // the wallet's verified ENS primary name, if it has one
+    ens_name: Option<String>,
+    classification: WalletClassification,
+    proxy: ProxyInfo,
+    // present when WAVS_ENV_USDT_PRICE_ENRICHMENT is enabled
+    #[cfg(not(feature = "token-schema"))]
+    price: Option<PriceInfo>,
+    // `sanctioned` and `sanctions_list_version` from the embedded OFAC SDN list, left out while
+    // none is embedded
+    #[serde(flatten)]
+    screening: Option<Screening>,
+    // block the balance was read at, its confirmations and `reorg_risk`
+    #[serde(flatten)]
+    block: BlockInfo,
+}
+
+// ENS name → balance → USD in one round
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EnsBalanceData {
+    ens: EnsResolution,
+    balance: UsdtBalanceData,
+    price: PriceInfo,
+    // CLI output only
+    #[serde(skip)]
+    latencies_ms: StageLatencies,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct StageLatencies {
+    ens: u64,
+    balance: u64,
+    price: u64,
+    total: u64,
+}
+
+enum BalanceRequest {
+    Balance { wallet: String, since_block: Option<u64>, encoding: WalletEncoding },
+    Batch { wallets: Vec<String> },
+    TimeWeighted { wallet: String, from_block: u64, to_block: u64, samples: u16 },
+    History { wallet: String, blocks: Vec<u64> },
+    Activity { wallet: String, from_block: u64, to_block: u64 },
+    Vault { wallet: String, vault: Address },
+    Transfer { wallet: String, to: Address, amount: U256 },
+    Ens { name: String },
 }
 
 struct Component;
@@ -40,83 +124,549 @@ export!(Component with_types_in bindings);
 
 impl Guest for Component {
     fn run(action: TriggerAction) -> std::result::Result<Option<WasmResponse>, String> {
-        let (trigger_id, req, dest) =
-            decode_trigger_event(action.data).map_err(|e| e.to_string())?;
-
-        let wallet_address_str = {
-            let input_str = String::from_utf8(req.clone())
-                .map_err(|e| format!("Input is not valid UTF-8: {}", e))?;
-
-            let hex_data = if input_str.starts_with("0x") {
-                hex::decode(&input_str[2..])
-                    .map_err(|e| format!("Failed to decode hex string: {}", e))?
-            } else {
-                req.clone()
-            };
-
-            <String as SolValue>::abi_decode(&hex_data)
-                .map_err(|e| format!("Failed to decode input as ABI string: {}", e))?
-        };
+        run::run(Codec, action.data, process)
+    }
+}
+
+fn process(
+    trigger_id: u64,
+    req: Vec<u8>,
+    dest: Destination,
+    options: envelope::Options,
+) -> Result<Option<WasmResponse>, String> {
+    options.check_supported(&["rpc_url", "encrypt_to", "commit", "reveal", "diff", "dry_run"])?;
+    let on_chain = matches!(dest, Destination::Ethereum);
+    if let Some(reveal) = commit_reveal::reveal(&options, env!("CARGO_PKG_NAME"), on_chain)? {
+        return Ok(Some(encode_trigger_output(trigger_id, reveal)));
+    }
+
+    let request = {
+        let hex_data = decode::input_bytes(&req)?;
+        selector::check(
+            &hex_data,
+            &[
+                selector::Expected::of::<trigger::solidity::checkUsdtBalanceCall>(),
+                selector::Expected::of::<trigger::solidity::checkBalanceCall>(),
+                selector::Expected::of::<trigger::solidity::checkUsdtBalanceSinceCall>(),
+                selector::Expected::of::<trigger::solidity::checkBalanceSinceCall>(),
+                selector::Expected::of::<trigger::solidity::checkUsdtBalancesCall>(),
+                selector::Expected::of::<trigger::solidity::sampleUsdtBalanceCall>(),
+                selector::Expected::of::<trigger::solidity::checkUsdtBalanceHistoryCall>(),
+                selector::Expected::of::<trigger::solidity::checkUsdtActivityCall>(),
+                selector::Expected::of::<trigger::solidity::checkVaultSharesCall>(),
+                selector::Expected::of::<trigger::solidity::simulateUsdtTransferCall>(),
+                selector::Expected::of::<trigger::solidity::checkEnsBalanceCall>(),
+                selector::Expected::of::<address_wallet::checkUsdtBalanceCall>(),
+                selector::Expected::of::<address_wallet::checkBalanceCall>(),
+                selector::Expected::of::<bytes32_wallet::checkUsdtBalanceCall>(),
+                selector::Expected::of::<bytes32_wallet::checkBalanceCall>(),
+            ],
+        )?;
+
+        let string = WalletEncoding::String;
+        if let Ok(call) = trigger::solidity::checkUsdtBalanceCall::abi_decode(&hex_data) {
+            BalanceRequest::Balance { wallet: call.wallet, since_block: None, encoding: string }
+        } else if let Ok(call) = trigger::solidity::checkBalanceCall::abi_decode(&hex_data) {
+            BalanceRequest::Balance {
+                wallet: call.walletAddress,
+                since_block: None,
+                encoding: string,
+            }
+        } else if let Ok(call) = address_wallet::checkUsdtBalanceCall::abi_decode(&hex_data) {
+            address_balance(call.wallet)
+        } else if let Ok(call) = address_wallet::checkBalanceCall::abi_decode(&hex_data) {
+            address_balance(call.walletAddress)
+        } else if let Ok(call) = bytes32_wallet::checkUsdtBalanceCall::abi_decode(&hex_data) {
+            bytes32_balance(&call.wallet.0)?
+        } else if let Ok(call) = bytes32_wallet::checkBalanceCall::abi_decode(&hex_data) {
+            bytes32_balance(&call.walletAddress.0)?
+        } else if let Ok(call) = trigger::solidity::checkUsdtBalanceSinceCall::abi_decode(&hex_data)
+        {
+            BalanceRequest::Balance {
+                wallet: call.wallet,
+                since_block: Some(call.sinceBlock),
+                encoding: string,
+            }
+        } else if let Ok(call) = trigger::solidity::checkBalanceSinceCall::abi_decode(&hex_data) {
+            BalanceRequest::Balance {
+                wallet: call.walletAddress,
+                since_block: Some(call.sinceBlock),
+                encoding: string,
+            }
+        } else if let Ok(call) = trigger::solidity::checkUsdtBalancesCall::abi_decode(&hex_data) {
+            BalanceRequest::Batch { wallets: call.wallets }
+        } else if let Ok(call) = trigger::solidity::sampleUsdtBalanceCall::abi_decode(&hex_data) {
+            BalanceRequest::TimeWeighted {
+                wallet: call.wallet,
+                from_block: call.fromBlock,
+                to_block: call.toBlock,
+                samples: call.samples,
+            }
+        } else if let Ok(call) =
+            trigger::solidity::checkUsdtBalanceHistoryCall::abi_decode(&hex_data)
+        {
+            BalanceRequest::History { wallet: call.wallet, blocks: call.blockNumbers }
+        } else if let Ok(call) = trigger::solidity::checkUsdtActivityCall::abi_decode(&hex_data) {
+            BalanceRequest::Activity {
+                wallet: call.wallet,
+                from_block: call.fromBlock,
+                to_block: call.toBlock,
+            }
+        } else if let Ok(call) = trigger::solidity::checkVaultSharesCall::abi_decode(&hex_data) {
+            BalanceRequest::Vault { wallet: call.wallet, vault: call.vault }
+        } else if let Ok(call) = trigger::solidity::simulateUsdtTransferCall::abi_decode(&hex_data)
+        {
+            BalanceRequest::Transfer { wallet: call.wallet, to: call.to, amount: call.amount }
+        } else if let Ok(call) = trigger::solidity::checkEnsBalanceCall::abi_decode(&hex_data) {
+            BalanceRequest::Ens { name: call.name }
+        } else {
+            let (wallet, encoding) = decode::wallet(&hex_data)?;
+            BalanceRequest::Balance { wallet, since_block: None, encoding }
+        }
+    };
 
-        let res = block_on(async move {
-            let balance_data = get_usdt_balance(&wallet_address_str).await?;
-            serde_json::to_vec(&balance_data).map_err(|e| e.to_string())
-        })?;
+    let rpc_url = options.rpc_url.clone();
+    let list_key = match request {
+        BalanceRequest::Batch { .. } => "items",
+        _ => "",
+    };
 
-        let output = match dest {
-            Destination::Ethereum => Some(encode_trigger_output(trigger_id, &res)),
-            Destination::CliOutput => Some(WasmResponse { payload: res.into(), ordering: None }),
+    timing::end_phase("decode");
+    if options.dry_run {
+        let payload =
+            plan(&request, rpc_url.as_deref())?.output(on_chain, options.json_format())?;
+        return payload.map(encode_cli_output).transpose();
+    }
+    let (value, latencies) = block_on(async move {
+        let rpc_url = rpc_url.as_deref();
+        let value = match request {
+            BalanceRequest::Balance { wallet, since_block, encoding } => {
+                info!("Decoded wallet {} ({} encoding)", wallet, encoding.as_str());
+                let balance_data = get_usdt_balance(&wallet, since_block, rpc_url).await?;
+                let mut value = serde_json::to_value(&balance_data).map_err(|e| e.to_string())?;
+                value["wallet_encoding"] = serde_json::json!(encoding);
+                Ok(value)
+            }
+            BalanceRequest::Batch { wallets } => {
+                let batch = batch::run(wallets, |wallet| async move {
+                    let balance_data = get_usdt_balance(&wallet, None, rpc_url).await?;
+                    serde_json::to_value(&balance_data).map_err(|e| e.to_string())
+                })
+                .await?;
+                info!("Checked {} wallets, {} failed", batch.items.len(), batch.err_count);
+                serde_json::to_value(&batch).map_err(|e| e.to_string())
+            }
+            BalanceRequest::TimeWeighted { wallet, from_block, to_block, samples } => {
+                let twab_data =
+                    get_usdt_twab(&wallet, from_block, to_block, samples, rpc_url).await?;
+                serde_json::to_value(&twab_data).map_err(|e| e.to_string())
+            }
+            BalanceRequest::History { wallet, blocks } => {
+                let history = get_usdt_history(&wallet, blocks, rpc_url).await?;
+                serde_json::to_value(&history).map_err(|e| e.to_string())
+            }
+            BalanceRequest::Activity { wallet, from_block, to_block } => {
+                let activity = get_usdt_activity(&wallet, from_block, to_block, rpc_url).await?;
+                serde_json::to_value(&activity).map_err(|e| e.to_string())
+            }
+            BalanceRequest::Vault { wallet, vault } => {
+                let vault_data = get_vault_shares(&wallet, vault, rpc_url).await?;
+                serde_json::to_value(&vault_data).map_err(|e| e.to_string())
+            }
+            BalanceRequest::Transfer { wallet, to, amount } => {
+                let simulation = get_transfer_simulation(&wallet, to, amount, rpc_url).await?;
+                serde_json::to_value(&simulation).map_err(|e| e.to_string())
+            }
+            BalanceRequest::Ens { name } => {
+                info!("Decoded ENS name: {}", name);
+                let ens_data = get_ens_balance(&name, rpc_url).await?;
+                let value = serde_json::to_value(&ens_data).map_err(|e| e.to_string())?;
+                return Ok((value, Some(ens_data.latencies_ms)));
+            }
         };
-        Ok(output)
+        value.map(|value| (value, None))
+    })?;
+    let mut value = json_patch::apply(&options, env!("CARGO_PKG_NAME"), &req, value)?;
+    truncation::apply(&mut value, list_key)?;
+    timing::end_phase("upstream");
+
+    let data = EthereumOutput::json(&versioned!(&value))?;
+    // Stage latencies differ between operators, so like phase_timings_ms they are only printed
+    if let (Some(latencies), Some(fields)) = (latencies, value.as_object_mut()) {
+        let latencies = serde_json::to_value(latencies).map_err(|e| e.to_string())?;
+        fields.insert("latencies_ms".to_string(), latencies);
+    }
+    run::respond(Codec, trigger_id, data, dest, &options, || Ok(CliOutput::new(versioned!(&value))))
+}
+
+// The most RPC calls each request makes, following the reads of usdt_balance and the helpers
+// below. Results carry data-dependent fields, so there is no gas estimate.
+fn plan(request: &BalanceRequest, rpc_url: Option<&str>) -> Result<dry_run::Plan, String> {
+    use serde_json::json;
+    // (input, RPC calls, USD price lookups)
+    let (input, calls, prices) = match request {
+        BalanceRequest::Balance { wallet, since_block, encoding } => {
+            parse_wallet(wallet)?;
+            let calls = BALANCE_RPC_CALLS + since_block.is_some() as u64;
+            let input = json!({
+                "wallet": wallet,
+                "since_block": since_block,
+                "wallet_encoding": encoding,
+            });
+            (input, calls, balance_prices())
+        }
+        // a wallet that doesn't parse only fails its own item
+        BalanceRequest::Batch { wallets } => {
+            batch::check_len(wallets.len())?;
+            let count = wallets.len() as u64;
+            (json!({ "wallets": wallets }), BALANCE_RPC_CALLS * count, balance_prices() * count)
+        }
+        BalanceRequest::TimeWeighted { wallet, from_block, to_block, samples } => {
+            check_twab_range(*from_block, *to_block, *samples)?;
+            parse_wallet(wallet)?;
+            // decimals, then a timestamp and a balance per sample
+            let sampled = twab::sample_blocks(*from_block, *to_block, *samples).len() as u64;
+            let input = json!({
+                "wallet": wallet,
+                "from_block": from_block,
+                "to_block": to_block,
+                "samples": samples,
+            });
+            (input, 1 + 2 * sampled, 0)
+        }
+        BalanceRequest::History { wallet, blocks } => {
+            check_history_blocks(blocks)?;
+            parse_wallet(wallet)?;
+            let mut unique = blocks.clone();
+            unique.sort_unstable();
+            unique.dedup();
+            (json!({ "wallet": wallet, "blocks": unique }), 1 + unique.len() as u64, 0)
+        }
+        BalanceRequest::Activity { wallet, from_block, to_block } => {
+            check_activity_range(*from_block, *to_block)?;
+            parse_wallet(wallet)?;
+            // the head, a sent and a received query per chunk, and the range's end timestamps
+            let chunks = (to_block - from_block + 1).div_ceil(activity::chunk_blocks());
+            let input = json!({ "wallet": wallet, "from_block": from_block, "to_block": to_block });
+            (input, 1 + 2 * chunks + 2, 0)
+        }
+        BalanceRequest::Vault { wallet, vault } => {
+            parse_wallet(wallet)?;
+            (json!({ "wallet": wallet, "vault": vault.to_checksum(None) }), 5, 0)
+        }
+        BalanceRequest::Transfer { wallet, to, amount } => {
+            parse_wallet(wallet)?;
+            let input = json!({
+                "wallet": wallet,
+                "to": to.to_checksum(None),
+                "amount": amount.to_string(),
+            });
+            // decimals, the pinned block, three views, the simulated call and the reorg check
+            (input, 8, 0)
+        }
+        BalanceRequest::Ens { name } => {
+            let name = ens::normalize(name)?;
+            // the resolver and address reads, then the balance and its USD value
+            (json!({ "name": name }), 2 + BALANCE_RPC_CALLS, 1 + balance_prices())
+        }
+    };
+
+    let endpoint = ethereum_endpoint(rpc_url)?;
+    let calls = calls + timestamp::trigger_block_requests("ethereum");
+    let mut plan = dry_run::Plan::new(env!("CARGO_PKG_NAME"), &input)?.rpc(&endpoint, calls);
+    for _ in 0..prices {
+        plan = price::planned(plan, &endpoint);
+    }
+    if let BalanceRequest::Activity { .. } = request {
+        plan = plan.note(
+            "Chunks rejected for returning too many logs are split and retried, and the blocks \
+             of the transfers found need their timestamps unless the provider includes them",
+        );
+    }
+    Ok(plan)
+}
+
+// Price lookups of one balance read: the USD enrichment, when it is on
+fn balance_prices() -> u64 {
+    #[cfg(not(feature = "token-schema"))]
+    if price::enabled() {
+        return 1;
+    }
+    0
+}
+
+// Requests from the address and bytes32 overloads of checkUsdtBalance and checkBalance
+fn address_balance(wallet: Address) -> BalanceRequest {
+    BalanceRequest::Balance {
+        wallet: wallet.to_checksum(None),
+        since_block: None,
+        encoding: WalletEncoding::Address,
     }
 }
 
-async fn get_usdt_balance(wallet_address_str: &str) -> Result<UsdtBalanceData, String> {
+// A bytes32 function's argument is reported as bytes32 whichever way the address is aligned
+fn bytes32_balance(word: &[u8; 32]) -> Result<BalanceRequest, String> {
+    let (wallet, _) = decode::wallet_word(word)?;
+    Ok(BalanceRequest::Balance {
+        wallet: wallet.to_checksum(None),
+        since_block: None,
+        encoding: WalletEncoding::Bytes32,
+    })
+}
+
+fn parse_wallet(wallet: &str) -> Result<Address, String> {
+    Address::from_str(wallet).map_err(|e| format!("Invalid wallet address: {}", e))
+}
+
+async fn get_usdt_balance(
+    wallet_address_str: &str,
+    since_block: Option<u64>,
+    rpc_url: Option<&str>,
+) -> Result<UsdtBalanceData, String> {
+    let provider = ethereum_provider(rpc_url)?;
+    timestamp::record_trigger_block(&provider, "ethereum").await;
+    usdt_balance(&provider, wallet_address_str, since_block).await
+}
+
+// The balance read itself, over any alloy provider and network type. get_usdt_balance passes
+// the Ethereum chain config's provider; a DynProvider or a provider over a mocked transport
+// works the same way.
+pub async fn usdt_balance<N: Network>(
+    provider: &impl Provider<N>,
+    wallet_address_str: &str,
+    since_block: Option<u64>,
+) -> Result<UsdtBalanceData, String> {
     let wallet_address = Address::from_str(wallet_address_str)
         .map_err(|e| format!("Invalid wallet address: {}", e))?;
 
     let usdt_address = Address::from_str(USDT_CONTRACT_ADDRESS)
         .map_err(|e| format!("Invalid USDT contract address: {}", e))?;
 
-    let chain_config = get_evm_chain_config("ethereum")
-        .ok_or_else(|| "Failed to get Ethereum chain config".to_string())?;
-
-    let provider: RootProvider<Ethereum> =
-        new_evm_provider::<Ethereum>(chain_config.http_endpoint.unwrap());
-
-    let balance_call = IERC20::balanceOfCall { owner: wallet_address };
-    let tx = alloy_rpc_types::eth::TransactionRequest {
-        to: Some(TxKind::Call(usdt_address)),
-        input: TransactionInput { input: Some(balance_call.abi_encode().into()), data: None },
-        ..Default::default()
-    };
+    // Reads are pinned to one block so its hash can be checked again afterwards
+    let pinned = reorg::pin_latest(provider).await?;
 
-    let result = provider.call(tx).await.map_err(|e| e.to_string())?;
-    let balance_raw: U256 = U256::from_be_slice(&result);
+    let balance_raw =
+        token::balance_of(provider, usdt_address, wallet_address, BlockId::number(pinned.number))
+            .await?;
+    let decimals = token::decimals(provider, usdt_address).await?;
 
-    let decimals_call = IERC20::decimalsCall {};
-    let tx_decimals = alloy_rpc_types::eth::TransactionRequest {
-        to: Some(TxKind::Call(usdt_address)),
-        input: TransactionInput { input: Some(decimals_call.abi_encode().into()), data: None },
-        ..Default::default()
+    let formatted_balance = format_token_amount(balance_raw, decimals);
+    let ens_name = ens::reverse(provider, wallet_address, BlockId::number(pinned.number)).await?;
+    let classification =
+        classify_wallet(provider, wallet_address, pinned.number, balance_raw, decimals).await?;
+    let proxy = inspect_proxy(provider, usdt_address, since_block).await?;
+    #[cfg(not(feature = "token-schema"))]
+    let price = if price::enabled() {
+        Some(price::get_usd_price(provider, balance_raw, decimals).await?)
+    } else {
+        None
     };
 
-    let result_decimals = provider.call(tx_decimals).await.map_err(|e| e.to_string())?;
-    let decimals: u8 = result_decimals[31];
-
-    let formatted_balance = format_token_amount(balance_raw, decimals);
+    let block = reorg::assess(provider, pinned).await?;
 
     Ok(UsdtBalanceData {
         wallet: wallet_address_str.to_string(),
         balance_raw: balance_raw.to_string(),
         balance_formatted: formatted_balance,
+        #[cfg(not(feature = "token-schema"))]
         usdt_contract: USDT_CONTRACT_ADDRESS.to_string(),
+        #[cfg(feature = "token-schema")]
+        token_contract: USDT_CONTRACT_ADDRESS.to_string(),
+        #[cfg(feature = "token-schema")]
+        token_symbol: "USDT".to_string(),
         decimals,
-        timestamp: get_current_timestamp(),
+        expires_at: freshness::expires_at(freshness::now_secs(), DEFAULT_RESULT_TTL_SECS),
+        ens_name,
+        classification,
+        proxy,
+        #[cfg(not(feature = "token-schema"))]
+        price,
+        screening: sanctions::screen(wallet_address),
+        block,
     })
 }
 
-fn format_token_amount(amount: U256, decimals: u8) -> String {
+async fn get_usdt_twab(
+    wallet_address_str: &str,
+    from_block: u64,
+    to_block: u64,
+    samples: u16,
+    rpc_url: Option<&str>,
+) -> Result<twab::TimeWeightedBalanceData, String> {
+    check_twab_range(from_block, to_block, samples)?;
+
+    let wallet_address = Address::from_str(wallet_address_str)
+        .map_err(|e| format!("Invalid wallet address: {}", e))?;
+    let usdt_address = Address::from_str(USDT_CONTRACT_ADDRESS)
+        .map_err(|e| format!("Invalid USDT contract address: {}", e))?;
+
+    let provider = ethereum_provider(rpc_url)?;
+    timestamp::record_trigger_block(&provider, "ethereum").await;
+
+    let decimals = token::decimals(&provider, usdt_address).await?;
+
+    get_time_weighted_balance(&provider, wallet_address, decimals, from_block, to_block, samples)
+        .await
+}
+
+fn check_twab_range(from_block: u64, to_block: u64, samples: u16) -> Result<(), String> {
+    if from_block >= to_block {
+        return Err(format!(
+            "fromBlock ({}) must be lower than toBlock ({})",
+            from_block, to_block
+        ));
+    }
+    if !(MIN_SAMPLES..=MAX_SAMPLES).contains(&samples) {
+        return Err(format!("samples must be between {} and {}", MIN_SAMPLES, MAX_SAMPLES));
+    }
+    Ok(())
+}
+
+async fn get_usdt_history(
+    wallet_address_str: &str,
+    blocks: Vec<u64>,
+    rpc_url: Option<&str>,
+) -> Result<history::BalanceHistoryData, String> {
+    check_history_blocks(&blocks)?;
+
+    let wallet_address = Address::from_str(wallet_address_str)
+        .map_err(|e| format!("Invalid wallet address: {}", e))?;
+    let usdt_address = Address::from_str(USDT_CONTRACT_ADDRESS)
+        .map_err(|e| format!("Invalid USDT contract address: {}", e))?;
+
+    let provider = ethereum_provider(rpc_url)?;
+    timestamp::record_trigger_block(&provider, "ethereum").await;
+    let decimals = token::decimals(&provider, usdt_address).await?;
+
+    get_balance_history(&provider, wallet_address, decimals, blocks).await
+}
+
+fn check_history_blocks(blocks: &[u64]) -> Result<(), String> {
+    if blocks.is_empty() {
+        return Err("blockNumbers must not be empty".to_string());
+    }
+    if blocks.len() > MAX_HISTORY_BLOCKS {
+        return Err(format!("At most {} block numbers per request", MAX_HISTORY_BLOCKS));
+    }
+    Ok(())
+}
+
+async fn get_usdt_activity(
+    wallet_address_str: &str,
+    from_block: u64,
+    to_block: u64,
+    rpc_url: Option<&str>,
+) -> Result<activity::ActivityHeatmapData, String> {
+    check_activity_range(from_block, to_block)?;
+
+    let wallet_address = Address::from_str(wallet_address_str)
+        .map_err(|e| format!("Invalid wallet address: {}", e))?;
+
+    let provider = ethereum_provider(rpc_url)?;
+    timestamp::record_trigger_block(&provider, "ethereum").await;
+    // Blocks past the head would be read differently by operators at different heights
+    let latest = reorg::pin_latest(&provider).await?;
+    if to_block > latest.number {
+        return Err(format!("toBlock ({}) is past the latest block ({})", to_block, latest.number));
+    }
+
+    get_activity_heatmap(&provider, wallet_address, from_block, to_block).await
+}
+
+fn check_activity_range(from_block: u64, to_block: u64) -> Result<(), String> {
+    if from_block > to_block {
+        return Err(format!(
+            "fromBlock ({}) must not be higher than toBlock ({})",
+            from_block, to_block
+        ));
+    }
+    if to_block - from_block >= MAX_ACTIVITY_BLOCKS {
+        return Err(format!("At most {} blocks per request", MAX_ACTIVITY_BLOCKS));
+    }
+    Ok(())
+}
+
+async fn get_vault_shares(
+    wallet_address_str: &str,
+    vault: Address,
+    rpc_url: Option<&str>,
+) -> Result<vault::VaultPositionData, String> {
+    let wallet_address = Address::from_str(wallet_address_str)
+        .map_err(|e| format!("Invalid wallet address: {}", e))?;
+
+    let provider = ethereum_provider(rpc_url)?;
+    timestamp::record_trigger_block(&provider, "ethereum").await;
+
+    info!("Valuing shares of vault {} for {}", vault, wallet_address);
+    vault::get_vault_position(&provider, wallet_address, vault).await
+}
+
+async fn get_transfer_simulation(
+    wallet_address_str: &str,
+    to: Address,
+    amount: U256,
+    rpc_url: Option<&str>,
+) -> Result<simulate::TransferSimulation, String> {
+    let wallet_address = Address::from_str(wallet_address_str)
+        .map_err(|e| format!("Invalid wallet address: {}", e))?;
+    let usdt_address = Address::from_str(USDT_CONTRACT_ADDRESS)
+        .map_err(|e| format!("Invalid USDT contract address: {}", e))?;
+
+    let provider = ethereum_provider(rpc_url)?;
+    timestamp::record_trigger_block(&provider, "ethereum").await;
+
+    let decimals = token::decimals(&provider, usdt_address).await?;
+
+    info!("Simulating a transfer of {} USDT units from {} to {}", amount, wallet_address, to);
+    simulate::simulate_transfer(&provider, usdt_address, wallet_address, to, amount, decimals).await
+}
+
+// Resolves an ENS name, reads its USDT balance and values it in USD, timing each stage
+async fn get_ens_balance(name: &str, rpc_url: Option<&str>) -> Result<EnsBalanceData, String> {
+    let provider = ethereum_provider(rpc_url)?;
+    timestamp::record_trigger_block(&provider, "ethereum").await;
+    let started = Instant::now();
+
+    let ens = ens::resolve(&provider, name).await?;
+    let ens_ms = elapsed_ms(started);
+
+    let balance = usdt_balance(&provider, &ens.address, None).await?;
+    let balance_ms = elapsed_ms(started) - ens_ms;
+
+    let balance_raw = U256::from_str_radix(&balance.balance_raw, 10)
+        .map_err(|e| format!("Invalid balance {}: {}", balance.balance_raw, e))?;
+    let price = price::get_usd_price(&provider, balance_raw, balance.decimals).await?;
+    let total_ms = elapsed_ms(started);
+
+    Ok(EnsBalanceData {
+        ens,
+        balance,
+        price,
+        latencies_ms: StageLatencies {
+            ens: ens_ms,
+            balance: balance_ms,
+            price: total_ms - ens_ms - balance_ms,
+            total: total_ms,
+        },
+    })
+}
+
+fn elapsed_ms(started: Instant) -> u64 {
+    started.elapsed().as_millis() as u64
+}
+
+fn ethereum_provider(rpc_url: Option<&str>) -> Result<RootProvider<Ethereum>, String> {
+    Ok(replay::evm_provider(ethereum_endpoint(rpc_url)?))
+}
+
+// The envelope's rpc_url, when set, replaces the chain config endpoint for this request
+fn ethereum_endpoint(rpc_url: Option<&str>) -> Result<String, String> {
+    let chain_config = get_evm_chain_config("ethereum")
+        .ok_or_else(|| "Failed to get Ethereum chain config".to_string())?;
+    rpc::resolve_endpoint(rpc_url, chain_config.http_endpoint)
+}
+
+pub fn format_token_amount(amount: U256, decimals: u8) -> String {
     let mut divisor = U256::from(1);
     for _ in 0..decimals {
         divisor = divisor * U256::from(10);
@@ -137,11 +687,3 @@ fn format_token_amount(amount: U256, decimals: u8) -> String {
         }
     }
 }
-
-fn get_current_timestamp() -> String {
-    std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap()
-        .as_secs()
-        .to_string()
-}
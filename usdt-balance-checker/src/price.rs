@@ -0,0 +1,159 @@
+// USD price enrichment guarded by two independent feeds. Chainlink is read on-chain and
+// Coingecko over HTTP, or CoinMarketCap when Coingecko fails (see wavs-component-common's
+// price.rs); if they disagree by more than the configured deviation the run fails with a
+// structured (JSON) error instead of emitting a price a single bad feed could corrupt.
+use crate::format_token_amount;
+use alloy_network::{Network, TransactionBuilder};
+use alloy_primitives::{address, Address, U256};
+use alloy_provider::Provider;
+use alloy_sol_types::{sol, SolCall};
+use serde::{Deserialize, Serialize};
+use wavs_component_common::{dry_run::Plan, freshness, price, rpc};
+
+sol! {
+    interface AggregatorV3Interface {
+        function decimals() external view returns (uint8);
+        function latestRoundData() external view returns (uint80 roundId, int256 answer, uint256 startedAt, uint256 updatedAt, uint80 answeredInRound);
+    }
+}
+
+// Chainlink USDT / USD feed on Ethereum mainnet
+const CHAINLINK_USDT_USD: Address = address!("3E7d1eAB13ad0104d2750B8863b489D65364e32D");
+const TETHER: price::Asset = price::Asset { coingecko_id: "tether", cmc_id: 825 };
+// Prices are carried as integers with this many decimals so no float reaches the output
+const PRICE_DECIMALS: u8 = 8;
+const DEFAULT_MAX_DEVIATION_BPS: u64 = 100;
+// The USDT / USD feed has a 24h heartbeat
+const DEFAULT_MAX_PRICE_AGE_SECS: u64 = 90_000;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PriceInfo {
+    pub usd_price: String,
+    pub chainlink_price: String,
+    pub coingecko_price: String,
+    // which API served `coingecko_price`: "coingecko", or "coinmarketcap" after a fallback
+    pub http_price_source: price::Source,
+    pub deviation_bps: u64,
+    pub max_deviation_bps: u64,
+    pub balance_usd: String,
+}
+
+#[derive(Debug, Serialize)]
+struct PriceDeviationError<'a> {
+    error: &'a str,
+    chainlink_price: String,
+    coingecko_price: String,
+    http_price_source: price::Source,
+    deviation_bps: u64,
+    max_deviation_bps: u64,
+}
+
+// The token-schema balance output has no price field
+#[cfg(not(feature = "token-schema"))]
+pub fn enabled() -> bool {
+    matches!(std::env::var("WAVS_ENV_USDT_PRICE_ENRICHMENT").as_deref(), Ok("1") | Ok("true"))
+}
+
+// The two Chainlink reads and the HTTP quote of one price lookup
+pub fn planned(plan: Plan, endpoint: &str) -> Plan {
+    price::planned(plan.rpc(endpoint, 2), &TETHER)
+}
+
+pub async fn get_usd_price<N: Network>(
+    provider: &impl Provider<N>,
+    balance_raw: U256,
+    decimals: u8,
+) -> Result<PriceInfo, String> {
+    let max_deviation_bps = env_u64("WAVS_ENV_PRICE_MAX_DEVIATION_BPS", DEFAULT_MAX_DEVIATION_BPS);
+    let chainlink = chainlink_price(provider).await?;
+    let quote = price::usd_quote(&TETHER).await?;
+    let coingecko = parse_scaled(&quote.price, PRICE_DECIMALS)?;
+
+    let lower = chainlink.min(coingecko);
+    if lower.is_zero() {
+        return Err("Price feed returned zero".to_string());
+    }
+    let deviation_bps: u64 =
+        (chainlink.abs_diff(coingecko) * U256::from(10_000) / lower).try_into().unwrap_or(u64::MAX);
+    if deviation_bps > max_deviation_bps {
+        let error = PriceDeviationError {
+            error: "price_deviation",
+            chainlink_price: format_token_amount(chainlink, PRICE_DECIMALS),
+            coingecko_price: format_token_amount(coingecko, PRICE_DECIMALS),
+            http_price_source: quote.source,
+            deviation_bps,
+            max_deviation_bps,
+        };
+        return Err(serde_json::to_string(&error).map_err(|e| e.to_string())?);
+    }
+
+    // Chainlink is the reference price once the feeds agree
+    let balance_usd = balance_raw * chainlink / U256::from(10).pow(U256::from(decimals));
+    Ok(PriceInfo {
+        usd_price: format_token_amount(chainlink, PRICE_DECIMALS),
+        chainlink_price: format_token_amount(chainlink, PRICE_DECIMALS),
+        coingecko_price: format_token_amount(coingecko, PRICE_DECIMALS),
+        http_price_source: quote.source,
+        deviation_bps,
+        max_deviation_bps,
+        balance_usd: format_token_amount(balance_usd, PRICE_DECIMALS),
+    })
+}
+
+async fn chainlink_price<N: Network>(provider: &impl Provider<N>) -> Result<U256, String> {
+    let decimals = aggregator_call(provider, AggregatorV3Interface::decimalsCall {}).await?;
+    let decimals = AggregatorV3Interface::decimalsCall::abi_decode_returns(&decimals)
+        .map_err(|e| format!("Failed to decode Chainlink decimals: {}", e))?;
+    let round = aggregator_call(provider, AggregatorV3Interface::latestRoundDataCall {}).await?;
+    let round = AggregatorV3Interface::latestRoundDataCall::abi_decode_returns(&round)
+        .map_err(|e| format!("Failed to decode Chainlink round data: {}", e))?;
+
+    let max_age = env_u64("WAVS_ENV_PRICE_MAX_AGE_SECS", DEFAULT_MAX_PRICE_AGE_SECS);
+    let now = freshness::now_secs();
+    let updated_at: u64 = round.updatedAt.try_into().unwrap_or(u64::MAX);
+    if now.saturating_sub(updated_at) > max_age {
+        return Err(format!("Chainlink price is stale (updated at {})", updated_at));
+    }
+    if !round.answer.is_positive() {
+        return Err(format!("Chainlink returned a non-positive price: {}", round.answer));
+    }
+
+    Ok(rescale(round.answer.into_raw(), decimals, PRICE_DECIMALS))
+}
+
+async fn aggregator_call<N: Network>(
+    provider: &impl Provider<N>,
+    call: impl SolCall,
+) -> Result<Vec<u8>, String> {
+    let tx =
+        N::TransactionRequest::default().with_to(CHAINLINK_USDT_USD).with_input(call.abi_encode());
+    let result = provider.call(tx).await.map_err(|e| rpc::describe("Chainlink call failed", &e))?;
+    Ok(result.to_vec())
+}
+
+// Parses an exponent-free decimal string into an integer with `decimals` digits of fraction,
+// truncating any extra digits
+fn parse_scaled(value: &str, decimals: u8) -> Result<U256, String> {
+    let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if whole.is_empty() && fraction.is_empty()
+        || !whole.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit())
+    {
+        return Err(format!("Invalid price: {}", value));
+    }
+    let fraction: String =
+        fraction.chars().chain(std::iter::repeat('0')).take(decimals as usize).collect();
+    U256::from_str_radix(&format!("{}{}", whole, fraction), 10)
+        .map_err(|e| format!("Invalid price {}: {}", value, e))
+}
+
+fn rescale(value: U256, from: u8, to: u8) -> U256 {
+    if from >= to {
+        value / U256::from(10).pow(U256::from(from - to))
+    } else {
+        value * U256::from(10).pow(U256::from(to - from))
+    }
+}
+
+fn env_u64(name: &str, default: u64) -> u64 {
+    std::env::var(name).ok().and_then(|v| v.parse::<u64>().ok()).unwrap_or(default)
+}
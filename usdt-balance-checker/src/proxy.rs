@@ -0,0 +1,83 @@
+use alloy_network::Network;
+use alloy_primitives::{b256, Address, B256, U256};
+use alloy_provider::Provider;
+use serde::{Deserialize, Serialize};
+use wavs_component_common::{rpc, warn};
+
+// bytes32(uint256(keccak256("eip1967.proxy.implementation")) - 1)
+const EIP1967_IMPLEMENTATION_SLOT: B256 =
+    b256!("360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc");
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProxyInfo {
+    pub is_proxy: bool,
+    pub implementation: Option<String>,
+    pub since_block: Option<u64>,
+    pub implementation_at_since_block: Option<String>,
+    pub implementation_changed: bool,
+    pub warning: Option<String>,
+}
+
+// Reads the EIP-1967 implementation slot of the token, and when a reference block is given,
+// compares it against the implementation recorded at that block
+pub async fn inspect_proxy<N: Network>(
+    provider: &impl Provider<N>,
+    token: Address,
+    since_block: Option<u64>,
+) -> Result<ProxyInfo, String> {
+    let implementation = read_implementation(provider, token, None).await?;
+
+    let implementation_at_since_block = match since_block {
+        Some(block) => read_implementation(provider, token, Some(block)).await?,
+        None => None,
+    };
+
+    let implementation_changed =
+        since_block.is_some() && implementation != implementation_at_since_block;
+    let warning = implementation_changed.then(|| {
+        format!(
+            "Token implementation changed since block {}: {} -> {}",
+            since_block.unwrap_or_default(),
+            display(implementation_at_since_block),
+            display(implementation)
+        )
+    });
+    if let Some(warning) = &warning {
+        warn!("{}", warning);
+    }
+
+    Ok(ProxyInfo {
+        is_proxy: implementation.is_some(),
+        implementation: implementation.map(|a| a.to_checksum(None)),
+        since_block,
+        implementation_at_since_block: implementation_at_since_block.map(|a| a.to_checksum(None)),
+        implementation_changed,
+        warning,
+    })
+}
+
+async fn read_implementation<N: Network>(
+    provider: &impl Provider<N>,
+    token: Address,
+    block: Option<u64>,
+) -> Result<Option<Address>, String> {
+    let request = provider.get_storage_at(token, EIP1967_IMPLEMENTATION_SLOT.into());
+    let request = match block {
+        Some(block) => request.number(block),
+        None => request,
+    };
+    let word: U256 = request
+        .await
+        .map_err(|e| rpc::describe("Failed to read EIP-1967 implementation slot", &e))?;
+
+    // An empty slot means the token is not an EIP-1967 proxy
+    if word.is_zero() {
+        return Ok(None);
+    }
+    let bytes = word.to_be_bytes::<32>();
+    Ok(Some(Address::from_slice(&bytes[12..])))
+}
+
+fn display(address: Option<Address>) -> String {
+    address.map(|a| a.to_checksum(None)).unwrap_or_else(|| "none".to_string())
+}
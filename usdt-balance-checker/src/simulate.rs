@@ -0,0 +1,145 @@
+use crate::{format_token_amount, DEFAULT_RESULT_TTL_SECS, IERC20};
+use alloy_network::Ethereum;
+use alloy_primitives::{Address, TxKind, U256};
+use alloy_provider::{Provider, RootProvider};
+use alloy_rpc_types::{BlockId, TransactionInput};
+use alloy_sol_types::{sol, SolCall};
+use serde::{Deserialize, Serialize};
+use wavs_component_common::{
+    freshness,
+    reorg::{self, BlockInfo},
+    rpc,
+    sanctions::{self, Screening},
+    warn,
+};
+
+sol! {
+    // TetherToken's own admin views; transfer is declared without a return value because USDT
+    // predates the ERC-20 bool return
+    interface ITether {
+        function paused() external view returns (bool);
+        function getBlackListStatus(address maker) external view returns (bool);
+        function transfer(address to, uint256 value) external;
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TransferChecks {
+    paused: bool,
+    sender_blacklisted: bool,
+    balance_raw: String,
+    sufficient_balance: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TransferSimulation {
+    wallet: String,
+    to: String,
+    amount_raw: String,
+    amount_formatted: String,
+    would_succeed: bool,
+    // paused, sender_blacklisted, insufficient_balance or reverted; absent on success
+    failure_reason: Option<String>,
+    checks: TransferChecks,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recipient_sanctioned: Option<bool>,
+    expires_at: u64,
+    #[serde(flatten)]
+    screening: Option<Screening>,
+    #[serde(flatten)]
+    block: BlockInfo,
+}
+
+// Simulates transfer(to, amount) sent by `wallet` with eth_call. Setting `from` is enough to
+// make the wallet msg.sender, so no state override is needed. USDT reverts without a reason,
+// so the pause flag, blacklist and balance are read at the same block to explain a failure.
+pub async fn simulate_transfer(
+    provider: &RootProvider<Ethereum>,
+    usdt: Address,
+    wallet: Address,
+    to: Address,
+    amount: U256,
+    decimals: u8,
+) -> Result<TransferSimulation, String> {
+    let pinned = reorg::pin_latest(provider).await?;
+    let at = BlockId::number(pinned.number);
+
+    let paused = view(provider, usdt, ITether::pausedCall {}, at).await?;
+    let sender_blacklisted =
+        view(provider, usdt, ITether::getBlackListStatusCall { maker: wallet }, at).await?;
+    let balance = view(provider, usdt, IERC20::balanceOfCall { owner: wallet }, at).await?;
+
+    let transfer = ITether::transferCall { to, value: amount };
+    let tx = alloy_rpc_types::eth::TransactionRequest {
+        from: Some(wallet),
+        to: Some(TxKind::Call(usdt)),
+        input: TransactionInput { input: Some(transfer.abi_encode().into()), data: None },
+        ..Default::default()
+    };
+    let would_succeed = match provider.call(tx).block(at).await {
+        // Tokens that do return a bool signal failure with false instead of reverting
+        Ok(output) => output.len() < 32 || output[..32] != [0u8; 32],
+        // The revert's wording differs between node implementations and providers, so it is
+        // only logged; the result explains the failure from the checks
+        Err(e) => match e.as_error_resp() {
+            Some(resp) => {
+                let data = resp.data.as_ref().map(|d| d.get().trim_matches('"').to_string());
+                warn!("Simulated transfer reverted: {} (data: {:?})", resp.message, data);
+                false
+            }
+            None => return Err(rpc::describe("Failed to simulate transfer", &e)),
+        },
+    };
+
+    let sufficient_balance = balance >= amount;
+    let failure_reason = if would_succeed {
+        None
+    } else if sender_blacklisted {
+        Some("sender_blacklisted")
+    } else if paused {
+        Some("paused")
+    } else if !sufficient_balance {
+        Some("insufficient_balance")
+    } else {
+        Some("reverted")
+    };
+
+    let block = reorg::assess(provider, pinned).await?;
+    Ok(TransferSimulation {
+        wallet: wallet.to_checksum(None),
+        to: to.to_checksum(None),
+        amount_raw: amount.to_string(),
+        amount_formatted: format_token_amount(amount, decimals),
+        would_succeed,
+        failure_reason: failure_reason.map(str::to_string),
+        checks: TransferChecks {
+            paused,
+            sender_blacklisted,
+            balance_raw: balance.to_string(),
+            sufficient_balance,
+        },
+        recipient_sanctioned: sanctions::is_sanctioned(to),
+        expires_at: freshness::expires_at(freshness::now_secs(), DEFAULT_RESULT_TTL_SECS),
+        screening: sanctions::screen(wallet),
+        block,
+    })
+}
+
+async fn view<C: SolCall>(
+    provider: &RootProvider<Ethereum>,
+    to: Address,
+    call: C,
+    at: BlockId,
+) -> Result<C::Return, String> {
+    let tx = alloy_rpc_types::eth::TransactionRequest {
+        to: Some(TxKind::Call(to)),
+        input: TransactionInput { input: Some(call.abi_encode().into()), data: None },
+        ..Default::default()
+    };
+    let result = provider
+        .call(tx)
+        .block(at)
+        .await
+        .map_err(|e| rpc::describe("Simulation call failed", &e))?;
+    C::abi_decode_returns(&result).map_err(|e| format!("Failed to decode call result: {}", e))
+}
@@ -0,0 +1,186 @@
+// balanceOf and decimals reads for the checked token. With the `alloy-contract` feature they go
+// through a typed contract instance; without it the calls are encoded by hand and sent with
+// eth_call, which keeps alloy-contract out of the build. `fixed-decimals` skips the decimals
+// read and uses USDT's 6. Both take any alloy provider for any network type, so they can be
+// pointed at other chains or a mocked transport.
+use alloy_network::Network;
+use alloy_primitives::{Address, U256};
+use alloy_provider::Provider;
+use alloy_rpc_types::BlockId;
+use wavs_component_common::rpc;
+
+#[cfg(feature = "fixed-decimals")]
+const USDT_DECIMALS: u8 = 6;
+
+#[cfg(feature = "alloy-contract")]
+mod contract {
+    alloy_sol_types::sol! {
+        #[sol(rpc)]
+        interface IERC20 {
+            function balanceOf(address owner) external view returns (uint256);
+            function decimals() external view returns (uint8);
+        }
+    }
+}
+
+// Provider failures keep their rpc category; ABI errors from the typed call have none
+#[cfg(feature = "alloy-contract")]
+fn contract_error(context: &str, error: alloy_contract::Error) -> String {
+    match error {
+        alloy_contract::Error::TransportError(e) => rpc::describe(context, &e),
+        e => format!("{}: {}", context, e),
+    }
+}
+
+#[cfg(feature = "alloy-contract")]
+pub async fn balance_of<N: Network>(
+    provider: &impl Provider<N>,
+    token: Address,
+    owner: Address,
+    at: BlockId,
+) -> Result<U256, String> {
+    contract::IERC20::new(token, provider)
+        .balanceOf(owner)
+        .block(at)
+        .call()
+        .await
+        .map_err(|e| contract_error("Failed to read balance", e))
+}
+
+#[cfg(not(feature = "alloy-contract"))]
+pub async fn balance_of<N: Network>(
+    provider: &impl Provider<N>,
+    token: Address,
+    owner: Address,
+    at: BlockId,
+) -> Result<U256, String> {
+    use alloy_network::TransactionBuilder;
+    use alloy_sol_types::SolCall;
+
+    let balance_call = crate::IERC20::balanceOfCall { owner };
+    let tx = N::TransactionRequest::default().with_to(token).with_input(balance_call.abi_encode());
+    let result = provider
+        .call(tx)
+        .block(at)
+        .await
+        .map_err(|e| rpc::describe("Failed to read balance", &e))?;
+    Ok(U256::from_be_slice(&result))
+}
+
+#[cfg(feature = "fixed-decimals")]
+pub async fn decimals<N: Network>(
+    _provider: &impl Provider<N>,
+    _token: Address,
+) -> Result<u8, String> {
+    Ok(USDT_DECIMALS)
+}
+
+#[cfg(all(feature = "alloy-contract", not(feature = "fixed-decimals")))]
+pub async fn decimals<N: Network>(
+    provider: &impl Provider<N>,
+    token: Address,
+) -> Result<u8, String> {
+    contract::IERC20::new(token, provider)
+        .decimals()
+        .call()
+        .await
+        .map_err(|e| contract_error("Failed to read decimals", e))
+}
+
+#[cfg(not(any(feature = "alloy-contract", feature = "fixed-decimals")))]
+pub async fn decimals<N: Network>(
+    provider: &impl Provider<N>,
+    token: Address,
+) -> Result<u8, String> {
+    use alloy_network::TransactionBuilder;
+    use alloy_sol_types::SolCall;
+
+    let decimals_call = crate::IERC20::decimalsCall {};
+    let tx = N::TransactionRequest::default().with_to(token).with_input(decimals_call.abi_encode());
+    let result =
+        provider.call(tx).await.map_err(|e| rpc::describe("Failed to read decimals", &e))?;
+    crate::IERC20::decimalsCall::abi_decode_returns(&result)
+        .map_err(|e| format!("Failed to decode decimals: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_json_rpc::ErrorPayload;
+    use alloy_primitives::{address, Bytes};
+    use alloy_provider::RootProvider;
+    use alloy_rpc_client::RpcClient;
+    use alloy_transport::mock::Asserter;
+    use futures::executor::block_on;
+
+    const TOKEN: Address = address!("dAC17F958D2ee523a2206206994597C13D831ec7");
+    const OWNER: Address = address!("F977814e90dA44bFA03b6295A0616a897441aceC");
+
+    fn mocked() -> (RootProvider, Asserter) {
+        let asserter = Asserter::new();
+        (RootProvider::new(RpcClient::mocked(asserter.clone())), asserter)
+    }
+
+    fn word(value: u64) -> Bytes {
+        Bytes::from(U256::from(value).to_be_bytes::<32>())
+    }
+
+    #[test]
+    fn balance_of_decodes_the_returned_word() {
+        let (provider, asserter) = mocked();
+        asserter.push_success(&word(1_234_500_000));
+
+        let balance = block_on(balance_of(&provider, TOKEN, OWNER, BlockId::number(100)));
+        assert_eq!(balance.unwrap(), U256::from(1_234_500_000u64));
+    }
+
+    #[test]
+    fn balance_of_reports_rpc_error_category() {
+        let (provider, asserter) = mocked();
+        asserter.push_failure(ErrorPayload {
+            code: 429,
+            message: "rate limit exceeded".into(),
+            data: None,
+        });
+
+        let error =
+            block_on(balance_of(&provider, TOKEN, OWNER, BlockId::number(100))).unwrap_err();
+        assert!(error.starts_with("Failed to read balance [rate_limited, retriable]"), "{}", error);
+    }
+
+    #[cfg(not(feature = "fixed-decimals"))]
+    #[test]
+    fn decimals_reads_the_token() {
+        let (provider, asserter) = mocked();
+        asserter.push_success(&word(6));
+
+        assert_eq!(block_on(decimals(&provider, TOKEN)).unwrap(), 6);
+    }
+
+    #[cfg(not(feature = "fixed-decimals"))]
+    #[test]
+    fn decimals_reports_rpc_error_category() {
+        let (provider, asserter) = mocked();
+        asserter.push_failure(ErrorPayload {
+            code: 3,
+            message: "execution reverted".into(),
+            data: None,
+        });
+
+        let error = block_on(decimals(&provider, TOKEN)).unwrap_err();
+        assert!(
+            error.starts_with("Failed to read decimals [execution_reverted, not retriable]"),
+            "{}",
+            error
+        );
+    }
+
+    // No response is queued, so any request would fail
+    #[cfg(feature = "fixed-decimals")]
+    #[test]
+    fn fixed_decimals_makes_no_request() {
+        let (provider, _asserter) = mocked();
+
+        assert_eq!(block_on(decimals(&provider, TOKEN)).unwrap(), USDT_DECIMALS);
+    }
+}
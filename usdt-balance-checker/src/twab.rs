@@ -0,0 +1,132 @@
+use crate::{format_token_amount, token, USDT_CONTRACT_ADDRESS};
+use alloy_consensus::BlockHeader;
+use alloy_network::{BlockResponse, Network};
+use alloy_primitives::{Address, U256};
+use alloy_provider::Provider;
+use alloy_rpc_types::{BlockId, BlockNumberOrTag};
+use serde::{Deserialize, Serialize};
+use wavs_component_common::{
+    rpc,
+    sanctions::{self, Screening},
+};
+
+pub const MIN_SAMPLES: u16 = 2;
+pub const MAX_SAMPLES: u16 = 64;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BalanceSample {
+    block: u64,
+    timestamp: u64,
+    balance_raw: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TimeWeightedBalanceData {
+    wallet: String,
+    usdt_contract: String,
+    from_block: u64,
+    to_block: u64,
+    decimals: u8,
+    twab_raw: String,
+    twab_formatted: String,
+    min_balance_raw: String,
+    min_balance_formatted: String,
+    samples: Vec<BalanceSample>,
+    // `sanctioned` and `sanctions_list_version` from the embedded OFAC SDN list, left out while
+    // none is embedded
+    #[serde(flatten)]
+    screening: Option<Screening>,
+}
+
+// Evenly spaced block heights from `from_block` to `to_block` inclusive
+pub fn sample_blocks(from_block: u64, to_block: u64, samples: u16) -> Vec<u64> {
+    let span = to_block - from_block;
+    let steps = (samples - 1) as u64;
+    // In u128, since span * i can exceed u64 for wide ranges; the quotient is at most span
+    let mut blocks: Vec<u64> = (0..=steps)
+        .map(|i| from_block + (span as u128 * i as u128 / steps as u128) as u64)
+        .collect();
+    blocks.dedup();
+    blocks
+}
+
+// Samples balanceOf at K evenly spaced blocks and averages them weighted by how long each
+// balance was held (block timestamp until the next sample). Flash deposits held only around
+// a single sample barely move the average, and the minimum is reported alongside it.
+pub async fn get_time_weighted_balance<N: Network>(
+    provider: &impl Provider<N>,
+    wallet: Address,
+    decimals: u8,
+    from_block: u64,
+    to_block: u64,
+    samples: u16,
+) -> Result<TimeWeightedBalanceData, String> {
+    let usdt_address = USDT_CONTRACT_ADDRESS
+        .parse::<Address>()
+        .map_err(|e| format!("Invalid USDT contract address: {}", e))?;
+
+    let mut points: Vec<(u64, u64, U256)> = Vec::new();
+    for block in sample_blocks(from_block, to_block, samples) {
+        let timestamp = block_timestamp(provider, block).await?;
+        let balance = balance_at(provider, usdt_address, wallet, block).await?;
+        points.push((block, timestamp, balance));
+    }
+
+    let (_, first_ts, _) = points[0];
+    let (_, last_ts, last_balance) = points[points.len() - 1];
+    let twab = if last_ts > first_ts {
+        let weighted = points.windows(2).fold(U256::ZERO, |acc, pair| {
+            let (_, ts, balance) = pair[0];
+            let (_, next_ts, _) = pair[1];
+            acc + balance * U256::from(next_ts - ts)
+        });
+        weighted / U256::from(last_ts - first_ts)
+    } else {
+        last_balance
+    };
+    let min_balance = points.iter().map(|(_, _, b)| *b).min().unwrap_or(U256::ZERO);
+
+    Ok(TimeWeightedBalanceData {
+        wallet: wallet.to_checksum(None),
+        usdt_contract: USDT_CONTRACT_ADDRESS.to_string(),
+        from_block,
+        to_block,
+        decimals,
+        twab_raw: twab.to_string(),
+        twab_formatted: format_token_amount(twab, decimals),
+        min_balance_raw: min_balance.to_string(),
+        min_balance_formatted: format_token_amount(min_balance, decimals),
+        samples: points
+            .into_iter()
+            .map(|(block, timestamp, balance)| BalanceSample {
+                block,
+                timestamp,
+                balance_raw: balance.to_string(),
+            })
+            .collect(),
+        screening: sanctions::screen(wallet),
+    })
+}
+
+async fn block_timestamp<N: Network>(
+    provider: &impl Provider<N>,
+    block: u64,
+) -> Result<u64, String> {
+    let header = provider
+        .get_block_by_number(BlockNumberOrTag::Number(block))
+        .await
+        .map_err(|e| rpc::describe(&format!("Failed to fetch block {}", block), &e))?
+        .ok_or_else(|| format!("Block {} not found", block))?;
+    Ok(header.header().timestamp())
+}
+
+async fn balance_at<N: Network>(
+    provider: &impl Provider<N>,
+    token: Address,
+    wallet: Address,
+    block: u64,
+) -> Result<U256, String> {
+    token::balance_of(provider, token, wallet, BlockId::number(block))
+        .await
+        .map_err(|e| format!("Block {}: {}", block, e))
+}
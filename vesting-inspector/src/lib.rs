@@ -0,0 +1,305 @@
+mod sablier;
+pub mod trigger;
+mod vesting_wallet;
+use trigger::{encode_cli_output, encode_trigger_output, Codec, Destination};
+pub mod bindings;
+use crate::bindings::host::get_evm_chain_config;
+use crate::bindings::{export, Guest, TriggerAction, WasmResponse};
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::SolCall;
+use serde::{Deserialize, Serialize};
+use wavs_component_common::{
+    decode, dry_run, envelope, info, multicall,
+    output::{CliOutput, EthereumOutput},
+    reorg, replay, rpc, run, selector, timestamp, timing, versioned,
+};
+use wstd::runtime::block_on;
+
+enum Request {
+    VestingWallet { wallet: Address, beneficiary: Address, token: Address },
+    Sablier { lockup: Address, beneficiary: Address, stream_ids: Vec<U256> },
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct VestingResult {
+    // "vesting_wallet" or "sablier"
+    kind: String,
+    schedule: String,
+    beneficiary: String,
+    // the zero address for ETH
+    token: String,
+    // amounts in the token's smallest unit, as decimal strings
+    total: String,
+    vested: String,
+    releasable: String,
+    released: String,
+    locked: String,
+    // VestingWallet only: the linear schedule, unix seconds
+    start: Option<u64>,
+    end: Option<u64>,
+    // Sablier only, by input position
+    streams: Option<Vec<sablier::Stream>>,
+    block_number: u64,
+    block_timestamp: u64,
+}
+
+// What either schedule reads to at the pinned block
+pub(crate) struct Inspection {
+    token: Address,
+    total: U256,
+    vested: U256,
+    releasable: U256,
+    released: U256,
+    start: Option<u64>,
+    end: Option<u64>,
+    streams: Option<Vec<sablier::Stream>>,
+    block_timestamp: u64,
+}
+
+pub struct Component;
+export!(Component with_types_in bindings);
+
+impl Guest for Component {
+    fn run(action: TriggerAction) -> std::result::Result<Option<WasmResponse>, String> {
+        run::run(Codec, action.data, process)
+    }
+}
+
+fn process(
+    trigger_id: u64,
+    req: Vec<u8>,
+    dest: Destination,
+    options: envelope::Options,
+) -> Result<Option<WasmResponse>, String> {
+    options.check_supported(&["rpc_url", "dry_run"])?;
+
+    let request = {
+        let hex_data = decode::input_bytes(&req)?;
+        selector::check(
+            &hex_data,
+            &[
+                selector::Expected::of::<trigger::solidity::inspectVestingWalletCall>(),
+                selector::Expected::of::<trigger::solidity::inspectSablierStreamsCall>(),
+            ],
+        )?;
+        if let Ok(d) = trigger::solidity::inspectVestingWalletCall::abi_decode(&hex_data) {
+            Request::VestingWallet { wallet: d.wallet, beneficiary: d.beneficiary, token: d.token }
+        } else if let Ok(d) = trigger::solidity::inspectSablierStreamsCall::abi_decode(&hex_data) {
+            Request::Sablier {
+                lockup: d.lockup,
+                beneficiary: d.beneficiary,
+                stream_ids: d.streamIds,
+            }
+        } else {
+            parse_request(&decode::abi_string(&hex_data)?)?
+        }
+    };
+    let request = check_request(request)?;
+
+    let rpc_url = options.rpc_url.clone();
+    timing::end_phase("decode");
+    if options.dry_run {
+        let plan = plan(trigger_id, &request, rpc_url.as_deref())?;
+        let payload = plan.output(matches!(dest, Destination::Ethereum), options.json_format())?;
+        return payload.map(encode_cli_output).transpose();
+    }
+    let (result, encoded) = block_on(async move { inspect(request, rpc_url.as_deref()).await })?;
+    timing::end_phase("upstream");
+
+    run::respond(Codec, trigger_id, EthereumOutput::abi(&encoded).traced(), dest, &options, || {
+        Ok(CliOutput::new(versioned!(&result)))
+    })
+}
+
+// "<wallet>:<beneficiary>[:<token>]" for a VestingWallet, without a token for ETH, or
+// "sablier:<lockup>:<beneficiary>:<streamId>[,<streamId>...]"
+fn parse_request(input: &str) -> Result<Request, String> {
+    let parts: Vec<&str> = input.trim().split(':').map(str::trim).collect();
+    let parse_address = |what: &str, value: &str| {
+        value.parse::<Address>().map_err(|e| format!("Invalid {} '{}': {}", what, value, e))
+    };
+    match parts[..] {
+        ["sablier", lockup, beneficiary, stream_ids] => {
+            let stream_ids = stream_ids
+                .split(',')
+                .map(str::trim)
+                .map(|id| {
+                    id.parse::<U256>().map_err(|e| format!("Invalid stream id '{}': {}", id, e))
+                })
+                .collect::<Result<Vec<U256>, String>>()?;
+            Ok(Request::Sablier {
+                lockup: parse_address("lockup address", lockup)?,
+                beneficiary: parse_address("beneficiary", beneficiary)?,
+                stream_ids,
+            })
+        }
+        [wallet, beneficiary] | [wallet, beneficiary, _] if wallet != "sablier" => {
+            let token = match parts.get(2) {
+                Some(token) => parse_address("token address", token)?,
+                None => Address::ZERO,
+            };
+            Ok(Request::VestingWallet {
+                wallet: parse_address("wallet address", wallet)?,
+                beneficiary: parse_address("beneficiary", beneficiary)?,
+                token,
+            })
+        }
+        _ => Err(format!(
+            "Expected <wallet>:<beneficiary>[:<token>] or \
+             sablier:<lockup>:<beneficiary>:<streamIds>, got '{}'",
+            input
+        )),
+    }
+}
+
+fn check_request(request: Request) -> Result<Request, String> {
+    let beneficiary = match &request {
+        Request::VestingWallet { beneficiary, .. } | Request::Sablier { beneficiary, .. } => {
+            *beneficiary
+        }
+    };
+    if beneficiary == Address::ZERO {
+        return Err("The beneficiary can't be the zero address".to_string());
+    }
+    let Request::Sablier { lockup, beneficiary, mut stream_ids } = request else {
+        return Ok(request);
+    };
+    let mut seen = Vec::with_capacity(stream_ids.len());
+    stream_ids.retain(|id| {
+        let new = !seen.contains(id);
+        seen.push(*id);
+        new
+    });
+    if stream_ids.is_empty() {
+        return Err("Name at least one stream id".to_string());
+    }
+    if stream_ids.len() > sablier::MAX_STREAMS {
+        return Err(format!(
+            "At most {} streams can be inspected at once, got {}",
+            sablier::MAX_STREAMS,
+            stream_ids.len()
+        ));
+    }
+    Ok(Request::Sablier { lockup, beneficiary, stream_ids })
+}
+
+// The pinned block and one Multicall3 batch, whichever the schedule
+fn plan(
+    trigger_id: u64,
+    request: &Request,
+    rpc_url: Option<&str>,
+) -> Result<dry_run::Plan, String> {
+    let (input, note, schedule, beneficiary) = match request {
+        Request::VestingWallet { wallet, beneficiary, token } => {
+            let input = serde_json::json!({
+                "wallet": wallet.to_checksum(None),
+                "beneficiary": beneficiary.to_checksum(None),
+                "token": token.to_checksum(None),
+            });
+            let note = "One Multicall3 batch reads owner, beneficiary, start, duration, released, \
+                        releasable and the balance";
+            (input, note.to_string(), *wallet, *beneficiary)
+        }
+        Request::Sablier { lockup, beneficiary, stream_ids } => {
+            let input = serde_json::json!({
+                "lockup": lockup.to_checksum(None),
+                "beneficiary": beneficiary.to_checksum(None),
+                "stream_ids": stream_ids.iter().map(U256::to_string).collect::<Vec<_>>(),
+            });
+            let note = format!(
+                "One Multicall3 batch of {} calls reads the {} streams",
+                1 + sablier::CALLS_PER_STREAM * stream_ids.len(),
+                stream_ids.len()
+            );
+            (input, note, *lockup, *beneficiary)
+        }
+    };
+    // the batch goes to this address, so a bad override fails the plan too
+    multicall::address()?;
+    let calls = 2 + timestamp::trigger_block_requests("ethereum");
+    let widest = trigger::solidity::VestingReport {
+        schedule,
+        beneficiary,
+        token: Address::repeat_byte(0xff),
+        total: U256::MAX,
+        vested: U256::MAX,
+        releasable: U256::MAX,
+        released: U256::MAX,
+        locked: U256::MAX,
+        blockNumber: u64::MAX,
+    };
+    Ok(dry_run::Plan::new(env!("CARGO_PKG_NAME"), &input)?
+        .rpc(&ethereum_endpoint(rpc_url)?, calls)
+        .note(note)
+        .submission(&encode_trigger_output(trigger_id, EthereumOutput::abi(&widest)).payload))
+}
+
+fn ethereum_endpoint(rpc_url: Option<&str>) -> Result<String, String> {
+    let chain_config = get_evm_chain_config("ethereum")
+        .ok_or_else(|| "Failed to get Ethereum chain config".to_string())?;
+    rpc::resolve_endpoint(rpc_url, chain_config.http_endpoint)
+}
+
+async fn inspect(
+    request: Request,
+    rpc_url: Option<&str>,
+) -> Result<(VestingResult, trigger::solidity::VestingReport), String> {
+    let provider = replay::evm_provider(ethereum_endpoint(rpc_url)?);
+    timestamp::record_trigger_block(&provider, "ethereum").await;
+    let block_number = reorg::pin_latest(&provider).await?.number;
+
+    let (kind, schedule, beneficiary, inspection) = match request {
+        Request::VestingWallet { wallet, beneficiary, token } => {
+            let inspection =
+                vesting_wallet::inspect(&provider, wallet, beneficiary, token, block_number)
+                    .await?;
+            ("vesting_wallet", wallet, beneficiary, inspection)
+        }
+        Request::Sablier { lockup, beneficiary, stream_ids } => {
+            let inspection =
+                sablier::inspect(&provider, lockup, beneficiary, &stream_ids, block_number).await?;
+            ("sablier", lockup, beneficiary, inspection)
+        }
+    };
+    let locked = inspection.total.saturating_sub(inspection.vested);
+    info!(
+        "{} vests {} of {} to {} at block {}: {} vested, {} releasable, {} locked",
+        schedule,
+        inspection.total,
+        inspection.token,
+        beneficiary,
+        block_number,
+        inspection.vested,
+        inspection.releasable,
+        locked
+    );
+
+    let result = VestingResult {
+        kind: kind.to_string(),
+        schedule: schedule.to_checksum(None),
+        beneficiary: beneficiary.to_checksum(None),
+        token: inspection.token.to_checksum(None),
+        total: inspection.total.to_string(),
+        vested: inspection.vested.to_string(),
+        releasable: inspection.releasable.to_string(),
+        released: inspection.released.to_string(),
+        locked: locked.to_string(),
+        start: inspection.start,
+        end: inspection.end,
+        streams: inspection.streams,
+        block_number,
+        block_timestamp: inspection.block_timestamp,
+    };
+    let encoded = trigger::solidity::VestingReport {
+        schedule,
+        beneficiary,
+        token: inspection.token,
+        total: inspection.total,
+        vested: inspection.vested,
+        releasable: inspection.releasable,
+        released: inspection.released,
+        locked,
+        blockNumber: block_number,
+    };
+    Ok((result, encoded))
+}
@@ -0,0 +1,160 @@
+// Sablier Lockup streams: the v2 LockupLinear, LockupDynamic and LockupTranched contracts and the
+// merged SablierLockup of v3. A stream is an NFT owned by its recipient. Its token is getAsset()
+// before v3 and getUnderlyingToken() since, so both are asked. Per stream:
+//
+//   total       deposited - refunded (a canceled stream refunds the unstreamed rest)
+//   vested      streamedAmountOf
+//   releasable  withdrawableAmountOf
+//   released    withdrawn
+//
+// Every read of every stream is one Multicall3 batch at the pinned block.
+use crate::Inspection;
+use alloy_network::Network;
+use alloy_primitives::{Address, U256};
+use alloy_provider::Provider;
+use alloy_sol_types::sol;
+use serde::{Deserialize, Serialize};
+use wavs_component_common::multicall::{Batch, IMulticall3, Slot};
+
+pub const CALLS_PER_STREAM: usize = 8;
+// Keeps the batch at 129 calls, well within the eth_call gas nodes allow
+pub const MAX_STREAMS: usize = 16;
+
+sol! {
+    interface ISablierLockup {
+        function ownerOf(uint256 tokenId) external view returns (address);
+        function getAsset(uint256 streamId) external view returns (address);
+        function getUnderlyingToken(uint256 streamId) external view returns (address);
+        function getDepositedAmount(uint256 streamId) external view returns (uint128);
+        function getWithdrawnAmount(uint256 streamId) external view returns (uint128);
+        function getRefundedAmount(uint256 streamId) external view returns (uint128);
+        function streamedAmountOf(uint256 streamId) external view returns (uint128);
+        function withdrawableAmountOf(uint256 streamId) external view returns (uint128);
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Stream {
+    stream_id: String,
+    deposited: String,
+    withdrawn: String,
+    refunded: String,
+    streamed: String,
+    withdrawable: String,
+}
+
+struct StreamReads {
+    owner: Slot<ISablierLockup::ownerOfCall>,
+    asset: Slot<ISablierLockup::getAssetCall>,
+    underlying_token: Slot<ISablierLockup::getUnderlyingTokenCall>,
+    deposited: Slot<ISablierLockup::getDepositedAmountCall>,
+    withdrawn: Slot<ISablierLockup::getWithdrawnAmountCall>,
+    refunded: Slot<ISablierLockup::getRefundedAmountCall>,
+    streamed: Slot<ISablierLockup::streamedAmountOfCall>,
+    withdrawable: Slot<ISablierLockup::withdrawableAmountOfCall>,
+}
+
+impl StreamReads {
+    fn add(batch: &mut Batch, lockup: Address, stream_id: U256) -> StreamReads {
+        StreamReads {
+            owner: batch.add(lockup, &ISablierLockup::ownerOfCall { tokenId: stream_id }),
+            asset: batch.add(lockup, &ISablierLockup::getAssetCall { streamId: stream_id }),
+            underlying_token: batch
+                .add(lockup, &ISablierLockup::getUnderlyingTokenCall { streamId: stream_id }),
+            deposited: batch
+                .add(lockup, &ISablierLockup::getDepositedAmountCall { streamId: stream_id }),
+            withdrawn: batch
+                .add(lockup, &ISablierLockup::getWithdrawnAmountCall { streamId: stream_id }),
+            refunded: batch
+                .add(lockup, &ISablierLockup::getRefundedAmountCall { streamId: stream_id }),
+            streamed: batch
+                .add(lockup, &ISablierLockup::streamedAmountOfCall { streamId: stream_id }),
+            withdrawable: batch
+                .add(lockup, &ISablierLockup::withdrawableAmountOfCall { streamId: stream_id }),
+        }
+    }
+}
+
+pub async fn inspect<N: Network>(
+    provider: &impl Provider<N>,
+    lockup: Address,
+    beneficiary: Address,
+    stream_ids: &[U256],
+    block_number: u64,
+) -> Result<Inspection, String> {
+    let mut batch = Batch::new();
+    let timestamp = batch.add_own(&IMulticall3::getCurrentBlockTimestampCall {})?;
+    let reads: Vec<StreamReads> =
+        stream_ids.iter().map(|id| StreamReads::add(&mut batch, lockup, *id)).collect();
+    let answers = batch.run(provider, block_number).await?;
+
+    let mut token = None;
+    let mut streams = Vec::with_capacity(stream_ids.len());
+    let (mut total, mut vested, mut releasable, mut released) =
+        (U256::ZERO, U256::ZERO, U256::ZERO, U256::ZERO);
+    for (stream_id, reads) in stream_ids.iter().zip(&reads) {
+        let owner = answers.get(reads.owner).ok_or_else(|| {
+            format!("Stream {} of {} doesn't exist or was burned", stream_id, lockup)
+        })?;
+        if owner != beneficiary {
+            return Err(format!("Stream {} belongs to {}, not {}", stream_id, owner, beneficiary));
+        }
+        let asset = answers
+            .get(reads.asset)
+            .or_else(|| answers.get(reads.underlying_token))
+            .ok_or_else(|| not_a_stream(lockup, stream_id))?;
+        match token {
+            None => token = Some(asset),
+            Some(token) if token != asset => {
+                return Err(format!(
+                    "Stream {} streams {}, the others {}; inspect streams of one token together",
+                    stream_id, asset, token
+                ))
+            }
+            Some(_) => {}
+        }
+        let amount = |value: Option<u128>| {
+            value.map(U256::from).ok_or_else(|| not_a_stream(lockup, stream_id))
+        };
+        let deposited = amount(answers.get(reads.deposited))?;
+        let withdrawn = amount(answers.get(reads.withdrawn))?;
+        let refunded = amount(answers.get(reads.refunded))?;
+        let streamed = amount(answers.get(reads.streamed))?;
+        let withdrawable = amount(answers.get(reads.withdrawable))?;
+
+        total = total.saturating_add(deposited.saturating_sub(refunded));
+        vested = vested.saturating_add(streamed);
+        releasable = releasable.saturating_add(withdrawable);
+        released = released.saturating_add(withdrawn);
+        streams.push(Stream {
+            stream_id: stream_id.to_string(),
+            deposited: deposited.to_string(),
+            withdrawn: withdrawn.to_string(),
+            refunded: refunded.to_string(),
+            streamed: streamed.to_string(),
+            withdrawable: withdrawable.to_string(),
+        });
+    }
+
+    Ok(Inspection {
+        token: token.unwrap_or_default(),
+        total,
+        vested,
+        releasable,
+        released,
+        start: None,
+        end: None,
+        streams: Some(streams),
+        block_timestamp: answers
+            .get(timestamp)
+            .map(|t| u64::try_from(t).unwrap_or(u64::MAX))
+            .unwrap_or_default(),
+    })
+}
+
+fn not_a_stream(lockup: Address, stream_id: &U256) -> String {
+    format!(
+        "{} doesn't answer the Lockup reads for stream {}; is it a Sablier Lockup contract?",
+        lockup, stream_id
+    )
+}
@@ -0,0 +1,128 @@
+// OpenZeppelin VestingWallet, v4 and v5, vesting ETH or one ERC-20. The beneficiary is owner() in
+// v5, which is Ownable, and beneficiary() in v4. releasable() is what vestedAmount at the block's
+// timestamp leaves after released(), so vested is their sum without passing a timestamp, and the
+// allocation is what the wallet holds plus what it already released. Everything is one Multicall3
+// batch at the pinned block.
+use crate::Inspection;
+use alloy_network::Network;
+use alloy_primitives::{Address, U256};
+use alloy_provider::Provider;
+use alloy_sol_types::sol;
+use wavs_component_common::multicall::{Answers, Batch, IMulticall3, Slot};
+
+sol! {
+    interface IVestingWallet {
+        function owner() external view returns (address);
+        function beneficiary() external view returns (address);
+        function start() external view returns (uint256);
+        function duration() external view returns (uint256);
+    }
+    interface IVestingWalletEth {
+        function released() external view returns (uint256);
+        function releasable() external view returns (uint256);
+    }
+    interface IVestingWalletErc20 {
+        function released(address token) external view returns (uint256);
+        function releasable(address token) external view returns (uint256);
+    }
+    interface IERC20 {
+        function balanceOf(address account) external view returns (uint256);
+    }
+}
+
+// The reads that depend on what is vested, typed per asset
+enum AssetReads {
+    Eth {
+        released: Slot<IVestingWalletEth::releasedCall>,
+        releasable: Slot<IVestingWalletEth::releasableCall>,
+        balance: Slot<IMulticall3::getEthBalanceCall>,
+    },
+    Erc20 {
+        released: Slot<IVestingWalletErc20::releasedCall>,
+        releasable: Slot<IVestingWalletErc20::releasableCall>,
+        balance: Slot<IERC20::balanceOfCall>,
+    },
+}
+
+impl AssetReads {
+    fn add(batch: &mut Batch, wallet: Address, token: Address) -> Result<AssetReads, String> {
+        if token.is_zero() {
+            return Ok(AssetReads::Eth {
+                released: batch.add(wallet, &IVestingWalletEth::releasedCall {}),
+                releasable: batch.add(wallet, &IVestingWalletEth::releasableCall {}),
+                balance: batch.add_own(&IMulticall3::getEthBalanceCall { addr: wallet })?,
+            });
+        }
+        Ok(AssetReads::Erc20 {
+            released: batch.add(wallet, &IVestingWalletErc20::releasedCall { token }),
+            releasable: batch.add(wallet, &IVestingWalletErc20::releasableCall { token }),
+            balance: batch.add(token, &IERC20::balanceOfCall { account: wallet }),
+        })
+    }
+
+    // released, releasable and balance
+    fn get(&self, answers: &Answers) -> (Option<U256>, Option<U256>, Option<U256>) {
+        match *self {
+            AssetReads::Eth { released, releasable, balance } => {
+                (answers.get(released), answers.get(releasable), answers.get(balance))
+            }
+            AssetReads::Erc20 { released, releasable, balance } => {
+                (answers.get(released), answers.get(releasable), answers.get(balance))
+            }
+        }
+    }
+}
+
+pub async fn inspect<N: Network>(
+    provider: &impl Provider<N>,
+    wallet: Address,
+    beneficiary: Address,
+    token: Address,
+    block_number: u64,
+) -> Result<Inspection, String> {
+    let mut batch = Batch::new();
+    let owner = batch.add(wallet, &IVestingWallet::ownerCall {});
+    let v4_beneficiary = batch.add(wallet, &IVestingWallet::beneficiaryCall {});
+    let start = batch.add(wallet, &IVestingWallet::startCall {});
+    let duration = batch.add(wallet, &IVestingWallet::durationCall {});
+    let timestamp = batch.add_own(&IMulticall3::getCurrentBlockTimestampCall {})?;
+    let reads = AssetReads::add(&mut batch, wallet, token)?;
+    let answers = batch.run(provider, block_number).await?;
+
+    let owner = answers.get(owner).or_else(|| answers.get(v4_beneficiary)).ok_or_else(|| {
+        format!("{} answers neither owner() nor beneficiary(); is it a VestingWallet?", wallet)
+    })?;
+    if owner != beneficiary {
+        return Err(format!("{} vests for {}, not {}", wallet, owner, beneficiary));
+    }
+    let (released, releasable, balance) = match reads.get(&answers) {
+        (Some(released), Some(releasable), Some(balance)) => (released, releasable, balance),
+        (None, _, _) | (_, None, _) => {
+            return Err(format!(
+                "{} doesn't answer released() and releasable(){}; is it a VestingWallet?",
+                wallet,
+                if token.is_zero() { "" } else { " for the token" }
+            ))
+        }
+        (_, _, None) => return Err(format!("Failed to read the balance of {}", wallet)),
+    };
+    let start = answers.get(start).map(to_secs);
+    let end = start.zip(answers.get(duration).map(to_secs)).map(|(s, d)| s.saturating_add(d));
+
+    let vested = released.saturating_add(releasable);
+    Ok(Inspection {
+        token,
+        total: balance.saturating_add(released),
+        vested,
+        releasable,
+        released,
+        start,
+        end,
+        streams: None,
+        block_timestamp: answers.get(timestamp).map(to_secs).unwrap_or_default(),
+    })
+}
+
+fn to_secs(value: U256) -> u64 {
+    u64::try_from(value).unwrap_or(u64::MAX)
+}
@@ -0,0 +1,351 @@
+mod transfers;
+mod trigger;
+use trigger::{encode_cli_output, encode_trigger_output, Codec, Destination};
+
+pub mod bindings;
+use crate::bindings::host::get_evm_chain_config;
+use crate::bindings::{export, Guest, TriggerAction, WasmResponse};
+
+use alloy_network::Ethereum;
+use alloy_primitives::{Address, TxKind, I256, U256};
+use alloy_provider::{Provider, RootProvider};
+use alloy_rpc_types::TransactionInput;
+use alloy_sol_types::{sol, SolCall};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use wavs_component_common::{
+    cache, commit_reveal, decode, dry_run, envelope, info,
+    output::{CliOutput, EthereumOutput},
+    partial::{self, StepError},
+    replay, run, sanctions, selector, timestamp, timing, truncation, versioned, warn,
+};
+use wavs_wasi_utils::evm::alloy_primitives::{hex, keccak256};
+use wstd::runtime::block_on;
+
+sol! {
+    interface IERC20 {
+        function balanceOf(address owner) external view returns (uint256);
+    }
+}
+
+// Bounds the number of balance reads per run
+const MAX_WALLETS: usize = 100;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BalanceChange {
+    wallet: String,
+    // null on the first run for a watch list, when there is no previous balance
+    old: Option<String>,
+    new: String,
+    change: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WatchlistDelta {
+    // checksummed token address, or "ETH" for native balances
+    token: String,
+    first_run: bool,
+    // when the old balances were read
+    previous_run_at: Option<timestamp::Timestamp>,
+    changes: Vec<BalanceChange>,
+    unchanged: usize,
+    // true when some balances couldn't be read after their retries; those wallets are listed
+    // in failures and left out of changes and unchanged
+    partial: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    failures: Vec<partial::Failure>,
+    // `sanctioned_wallets`, the watched wallets on the embedded OFAC SDN list whether or not
+    // their balance changed, and `sanctions_list_version`; left out while no list is embedded
+    #[serde(flatten)]
+    screening: Option<sanctions::WalletsScreening>,
+}
+
+struct Component;
+export!(Component with_types_in bindings);
+
+impl Guest for Component {
+    fn run(action: TriggerAction) -> std::result::Result<Option<WasmResponse>, String> {
+        run::run(Codec, action.data, process)
+    }
+}
+
+fn process(
+    trigger_id: u64,
+    req: Vec<u8>,
+    dest: Destination,
+    options: envelope::Options,
+) -> Result<Option<WasmResponse>, String> {
+    options.check_supported(&["encrypt_to", "commit", "reveal", "dry_run"])?;
+    let on_chain = matches!(dest, Destination::Ethereum);
+    if let Some(reveal) = commit_reveal::reveal(&options, env!("CARGO_PKG_NAME"), on_chain)? {
+        return Ok(Some(encode_trigger_output(trigger_id, reveal)));
+    }
+
+    let mode = Mode::from_env()?;
+    let (token, mut wallets) = if req.is_empty() {
+        // The transfer digest exists for USDT watchers, so that is its default token
+        let default_token = match mode {
+            Mode::Balances => Address::ZERO,
+            Mode::Transfers => transfers::USDT,
+        };
+        watchlist_from_config(default_token)?
+    } else {
+        // Decode trigger data inline - handles hex string input, with or without selector
+        let hex_data = decode::input_bytes(&req)?;
+        selector::check(
+            &hex_data,
+            &[selector::Expected::of::<trigger::solidity::watchWalletsCall>()],
+        )?;
+        let call = trigger::solidity::watchWalletsCall::abi_decode(&hex_data)
+            .or_else(|_| trigger::solidity::watchWalletsCall::abi_decode_raw(&hex_data))
+            .map_err(|e| format!("Failed to decode input as watchWallets call: {}", e))?;
+        (call.token, call.wallets)
+    };
+
+    wallets.sort();
+    wallets.dedup();
+    if wallets.is_empty() {
+        return Err("Watch list has no wallets".to_string());
+    }
+    if wallets.len() > MAX_WALLETS {
+        return Err(format!(
+            "Watch list has {} wallets, at most {} are supported",
+            wallets.len(),
+            MAX_WALLETS
+        ));
+    }
+
+    timing::end_phase("decode");
+    if options.dry_run {
+        let payload = plan(mode, token, &wallets)?.output(on_chain, options.json_format())?;
+        return payload.map(encode_cli_output).transpose();
+    }
+    let mut result = block_on(async move {
+        let provider = ethereum_provider()?;
+        timestamp::record_trigger_block(&provider, "ethereum").await;
+        match mode {
+            Mode::Balances => {
+                let delta = diff_balances(&provider, token, &wallets).await?;
+                info!(
+                    "{} of {} wallets changed since the previous run",
+                    delta.changes.len(),
+                    delta.changes.len() + delta.unchanged
+                );
+                serde_json::to_value(&delta).map_err(|e| e.to_string())
+            }
+            Mode::Transfers => {
+                let digest = transfers::digest(&provider, token, &wallets).await?;
+                serde_json::to_value(&digest).map_err(|e| e.to_string())
+            }
+        }
+    })?;
+    if matches!(mode, Mode::Transfers) {
+        truncation::apply(&mut result, "transfers")?;
+    }
+    timing::end_phase("upstream");
+
+    run::respond(
+        Codec,
+        trigger_id,
+        EthereumOutput::json(&versioned!(&result))?,
+        dest,
+        &options,
+        || Ok(CliOutput::new(versioned!(&result))),
+    )
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Mode {
+    // balance changes since the previous run
+    Balances,
+    // Transfer events since the previous run's high-water mark (see transfers.rs)
+    Transfers,
+}
+
+impl Mode {
+    fn from_env() -> Result<Mode, String> {
+        match std::env::var("WAVS_ENV_WATCHLIST_MODE").as_deref().map(str::trim) {
+            Err(_) | Ok("") | Ok("balances") => Ok(Mode::Balances),
+            Ok("transfers") => Ok(Mode::Transfers),
+            Ok(other) => Err(format!(
+                "WAVS_ENV_WATCHLIST_MODE must be balances or transfers, not '{}'",
+                other
+            )),
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Mode::Balances => "balances",
+            Mode::Transfers => "transfers",
+        }
+    }
+}
+
+// Cron and block-interval triggers have no input, so the watch list is configured instead
+fn watchlist_from_config(default_token: Address) -> Result<(Address, Vec<Address>), String> {
+    let token = match std::env::var("WAVS_ENV_WATCHLIST_TOKEN") {
+        Ok(token) if !token.trim().is_empty() => parse_address(&token)?,
+        _ => default_token,
+    };
+    let wallets = std::env::var("WAVS_ENV_WATCHLIST_WALLETS")
+        .map_err(|_| "WAVS_ENV_WATCHLIST_WALLETS must be set for scheduled runs".to_string())?;
+    let wallets = wallets
+        .split(',')
+        .filter(|w| !w.trim().is_empty())
+        .map(parse_address)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((token, wallets))
+}
+
+fn parse_address(value: &str) -> Result<Address, String> {
+    value.trim().parse().map_err(|e| format!("Invalid address {}: {}", value.trim(), e))
+}
+
+// One balance read per wallet with its retries, or the transfer scan. The result lists however
+// many changes or transfers there are, so there is no gas estimate.
+fn plan(mode: Mode, token: Address, wallets: &[Address]) -> Result<dry_run::Plan, String> {
+    let input = serde_json::json!({
+        "mode": mode.as_str(),
+        "token": token.to_checksum(None),
+        "wallets": wallets.iter().map(|w| w.to_checksum(None)).collect::<Vec<_>>(),
+    });
+    let endpoint = ethereum_endpoint()?;
+    let plan = dry_run::Plan::new(env!("CARGO_PKG_NAME"), &input)?
+        .rpc(&endpoint, timestamp::trigger_block_requests("ethereum"));
+    match mode {
+        Mode::Balances => {
+            check_balance_state()?;
+            Ok(plan.rpc(&endpoint, wallets.len() as u64 * u64::from(partial::attempts())))
+        }
+        Mode::Transfers => transfers::planned(plan, &endpoint, token),
+    }
+}
+
+fn ethereum_provider() -> Result<RootProvider<Ethereum>, String> {
+    Ok(replay::evm_provider(ethereum_endpoint()?))
+}
+
+fn ethereum_endpoint() -> Result<String, String> {
+    let chain_config = get_evm_chain_config("ethereum")
+        .ok_or_else(|| "Failed to get Ethereum chain config".to_string())?;
+    chain_config
+        .http_endpoint
+        .ok_or_else(|| "Ethereum chain config has no HTTP endpoint".to_string())
+}
+
+async fn diff_balances(
+    provider: &RootProvider<Ethereum>,
+    token: Address,
+    wallets: &[Address],
+) -> Result<WatchlistDelta, String> {
+    check_balance_state()?;
+    let state_key = state_key(token, wallets);
+    let previous = cache::get::<BTreeMap<String, String>>(&state_key);
+
+    let mut balances = BTreeMap::new();
+    let mut changes = Vec::new();
+    let mut unchanged = 0;
+    let mut failures = Vec::new();
+    for wallet in wallets {
+        let key = wallet.to_checksum(None);
+        let stored = previous.as_ref().and_then(|p| p.value.get(&key));
+        let new = match partial::retry(&key, || balance_of(provider, token, *wallet)).await {
+            Ok(new) => new,
+            Err(failure) => {
+                warn!("Skipping {} ({})", key, failure.kind);
+                // Keeps the last known balance, so the next run reports the change since then
+                if let Some(stored) = stored {
+                    balances.insert(key, stored.clone());
+                }
+                failures.push(failure);
+                continue;
+            }
+        };
+        let old = match stored {
+            Some(old) => Some(
+                old.parse::<U256>()
+                    .map_err(|e| format!("Invalid stored balance for {}: {}", key, e))?,
+            ),
+            None => None,
+        };
+        balances.insert(key.clone(), new.to_string());
+
+        if old == Some(new) {
+            unchanged += 1;
+            continue;
+        }
+        let change = signed(new)? - signed(old.unwrap_or_default())?;
+        changes.push(BalanceChange {
+            wallet: key,
+            old: old.map(|o| o.to_string()),
+            new: new.to_string(),
+            change: change.to_string(),
+        });
+    }
+
+    // A run that read no balance at all has nothing to report
+    if failures.len() == wallets.len() {
+        let failure = failures.swap_remove(0);
+        return Err(format!("Every balance read failed, the first with: {}", failure.into_error()));
+    }
+
+    // Stored before submitting, so a failed submission drops that run's deltas rather than
+    // reporting them twice
+    cache::put(&state_key, &balances)?;
+
+    Ok(WatchlistDelta {
+        token: if token.is_zero() { "ETH".to_string() } else { token.to_checksum(None) },
+        first_run: previous.is_none(),
+        previous_run_at: previous.map(|p| timestamp::Timestamp::at(p.stored_at)),
+        changes,
+        unchanged,
+        partial: !failures.is_empty(),
+        failures,
+        screening: sanctions::screen_wallets(wallets),
+    })
+}
+
+// The previous balances are the component's only state, so runs without a place to keep them
+// would report every wallet as new each time
+fn check_balance_state() -> Result<(), String> {
+    if !cache::enabled() {
+        return Err("WAVS_ENV_CACHE_DIR must be set to keep balances between runs".to_string());
+    }
+    Ok(())
+}
+
+// One state entry per token and wallet set, so editing the list starts a fresh baseline
+fn state_key(token: Address, wallets: &[Address]) -> String {
+    let wallets: Vec<String> = wallets.iter().map(|w| w.to_checksum(None)).collect();
+    hex::encode(keccak256(format!("wallet-watchlist\n{}\n{}", token, wallets.join(","))))
+}
+
+async fn balance_of(
+    provider: &RootProvider<Ethereum>,
+    token: Address,
+    wallet: Address,
+) -> Result<U256, StepError> {
+    if token.is_zero() {
+        return provider
+            .get_balance(wallet)
+            .await
+            .map_err(|e| StepError::rpc(&format!("Failed to read ETH balance of {}", wallet), &e));
+    }
+    let balance_call = IERC20::balanceOfCall { owner: wallet };
+    let tx = alloy_rpc_types::eth::TransactionRequest {
+        to: Some(TxKind::Call(token)),
+        input: TransactionInput { input: Some(balance_call.abi_encode().into()), data: None },
+        ..Default::default()
+    };
+    let result = provider
+        .call(tx)
+        .await
+        .map_err(|e| StepError::rpc(&format!("Failed to read balance of {}", wallet), &e))?;
+    IERC20::balanceOfCall::abi_decode_returns(&result)
+        .map_err(|e| StepError::other(format!("Failed to decode balance of {}: {}", wallet, e)))
+}
+
+fn signed(value: U256) -> Result<I256, String> {
+    I256::try_from(value).map_err(|_| format!("Balance {} does not fit in int256", value))
+}
@@ -0,0 +1,306 @@
+// Transfer digest mode (WAVS_ENV_WATCHLIST_MODE=transfers). Each run scans the blocks after the
+// previous run's high-water mark for Transfer events of the token that involve a watched wallet
+// and reports them. The mark is stored per token and wallet set, so consecutive digests cover
+// consecutive block ranges: a log is reported by exactly one run.
+//
+// Scans stop WAVS_ENV_REQUIRED_CONFIRMATIONS blocks behind the head, so a block is only scanned
+// once it is unlikely to be reorged and rescanning it later is never needed. A run covers at
+// most WAVS_ENV_WATCHLIST_MAX_BLOCKS blocks; a watcher that fell behind catches up over several
+// runs, each reporting `caught_up: false` until it is back at the head.
+use alloy_network::Ethereum;
+use alloy_primitives::{address, b256, Address, B256, U256};
+use alloy_provider::{Provider, RootProvider};
+use alloy_rpc_types::{Filter, Log};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use wavs_component_common::{cache, dry_run::Plan, info, reorg, rpc, sanctions};
+use wavs_wasi_utils::evm::alloy_primitives::{hex, keccak256};
+
+pub const USDT: Address = address!("dAC17F958D2ee523a2206206994597C13D831ec7");
+// keccak256("Transfer(address,address,uint256)")
+const TRANSFER_TOPIC: B256 =
+    b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+const DEFAULT_CHUNK_BLOCKS: u64 = 2_000;
+const DEFAULT_MAX_RUN_BLOCKS: u64 = 50_000;
+// Bounds the digest's size; a run that reaches it stops at the end of the current chunk
+const MAX_MATCHES: usize = 1_000;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Cursor {
+    // last block a digest covered
+    last_block: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TransferMatch {
+    block: u64,
+    tx_hash: String,
+    log_index: u64,
+    from: String,
+    to: String,
+    amount: String,
+    // "out" or "in" seen from the watch list, "internal" when both sides are watched
+    direction: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WalletTotals {
+    wallet: String,
+    sent: String,
+    received: String,
+    transfers: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ScannedRange {
+    from_block: u64,
+    to_block: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TransferDigest {
+    token: String,
+    // no high-water mark yet: this run only records where the next one starts
+    first_run: bool,
+    // null when no new confirmed blocks were due
+    range: Option<ScannedRange>,
+    // the mark the next run continues after
+    high_water_block: u64,
+    caught_up: bool,
+    transfers: Vec<TransferMatch>,
+    // watched wallets with at least one transfer in the range, in watch list order
+    wallets: Vec<WalletTotals>,
+    // `sanctioned_wallets` and `sanctions_list_version`, as in a balance delta
+    #[serde(flatten)]
+    screening: Option<sanctions::WalletsScreening>,
+}
+
+pub async fn digest(
+    provider: &RootProvider<Ethereum>,
+    token: Address,
+    wallets: &[Address],
+) -> Result<TransferDigest, String> {
+    check(token)?;
+    let state_key = state_key(token, wallets);
+    let cursor = cache::get::<Cursor>(&state_key).map(|entry| entry.value);
+
+    let head = provider
+        .get_block_number()
+        .await
+        .map_err(|e| rpc::describe("Failed to fetch block number", &e))?;
+    let safe_head = head.saturating_sub(reorg::required_confirmations());
+    let start = match &cursor {
+        Some(cursor) => Some(cursor.last_block + 1),
+        None => start_block()?,
+    };
+
+    let mut transfers = Vec::new();
+    let (range, high_water_block, caught_up) = match start {
+        // First run without a configured start: the baseline is the current safe head
+        None => (None, safe_head, true),
+        Some(start) if start > safe_head => (None, start - 1, true),
+        Some(start) => {
+            let end = safe_head.min(start.saturating_add(max_run_blocks() - 1));
+            let scanned_to = scan(provider, token, wallets, start, end, &mut transfers).await?;
+            let range = ScannedRange { from_block: start, to_block: scanned_to };
+            (Some(range), scanned_to, scanned_to == safe_head)
+        }
+    };
+    info!(
+        "Transfer digest: {} matches, high-water mark {} (safe head {})",
+        transfers.len(),
+        high_water_block,
+        safe_head
+    );
+
+    // Stored before submitting, like the balance state: a failed submission leaves a gap in the
+    // digests' block ranges rather than a range reported twice
+    cache::put(&state_key, &Cursor { last_block: high_water_block })?;
+
+    Ok(TransferDigest {
+        token: token.to_checksum(None),
+        first_run: cursor.is_none(),
+        range,
+        high_water_block,
+        caught_up,
+        wallets: totals(wallets, &transfers),
+        transfers,
+        screening: sanctions::screen_wallets(wallets),
+    })
+}
+
+// The head, then a sent and a received query per chunk of the longest range a run scans
+pub fn planned(plan: Plan, endpoint: &str, token: Address) -> Result<Plan, String> {
+    check(token)?;
+    start_block()?;
+    let chunks = max_run_blocks().div_ceil(chunk_blocks());
+    Ok(plan
+        .rpc(endpoint, 1 + 2 * chunks)
+        .note("Runs closer to the head scan fewer chunks; chunks rejected as too large are split"))
+}
+
+fn check(token: Address) -> Result<(), String> {
+    if token.is_zero() {
+        return Err("Native ETH has no Transfer events; the digest needs a token".to_string());
+    }
+    if !cache::enabled() {
+        return Err("WAVS_ENV_CACHE_DIR must be set to keep the high-water mark".to_string());
+    }
+    Ok(())
+}
+
+// Scans [start, end] chunk by chunk and returns the last block scanned, which is `end` unless
+// the digest filled up first. Chunks a provider rejects as too large are halved.
+async fn scan(
+    provider: &RootProvider<Ethereum>,
+    token: Address,
+    wallets: &[Address],
+    start: u64,
+    end: u64,
+    transfers: &mut Vec<TransferMatch>,
+) -> Result<u64, String> {
+    let mut chunk = chunk_blocks();
+    let mut from = start;
+    while from <= end {
+        let to = from.saturating_add(chunk - 1).min(end);
+        match chunk_logs(provider, token, wallets, from, to).await {
+            Ok(logs) => {
+                for log in logs.into_values() {
+                    transfers.push(transfer_match(&log, wallets)?);
+                }
+                if transfers.len() >= MAX_MATCHES {
+                    return Ok(to);
+                }
+                from = to + 1;
+            }
+            Err(e) if to > from && rpc::is_log_limit_error(&e) => chunk = (to - from).div_ceil(2),
+            Err(e) => return Err(format!("getLogs for blocks {}..={}: {}", from, to, e)),
+        }
+    }
+    Ok(end)
+}
+
+// Sent and received transfers need one query each, since topics can't be OR-ed across
+// positions. Keyed by (block, log index) so a transfer between two watched wallets is kept
+// once, and ordered as on chain.
+async fn chunk_logs(
+    provider: &RootProvider<Ethereum>,
+    token: Address,
+    wallets: &[Address],
+    from: u64,
+    to: u64,
+) -> Result<BTreeMap<(u64, u64), Log>, String> {
+    let topics: Vec<B256> = wallets.iter().map(|w| w.into_word()).collect();
+    let filter =
+        Filter::new().address(token).event_signature(TRANSFER_TOPIC).from_block(from).to_block(to);
+    let sent_filter = filter.clone().topic1(topics.clone());
+    let sent = provider
+        .get_logs(&sent_filter)
+        .await
+        .map_err(|e| rpc::describe("Failed to fetch sent transfers", &e))?;
+    let received = provider
+        .get_logs(&filter.topic2(topics))
+        .await
+        .map_err(|e| rpc::describe("Failed to fetch received transfers", &e))?;
+
+    let mut logs = BTreeMap::new();
+    for log in sent.into_iter().chain(received).filter(|log| !log.removed) {
+        let key = (
+            log.block_number.ok_or("Transfer log without a block number")?,
+            log.log_index.ok_or("Transfer log without a log index")?,
+        );
+        logs.insert(key, log);
+    }
+    Ok(logs)
+}
+
+fn transfer_match(log: &Log, wallets: &[Address]) -> Result<TransferMatch, String> {
+    let topics = log.topics();
+    let (Some(from), Some(to)) = (topics.get(1), topics.get(2)) else {
+        return Err("Transfer log without indexed from and to".to_string());
+    };
+    let (from, to) = (Address::from_word(*from), Address::from_word(*to));
+    let amount = log
+        .data()
+        .data
+        .get(..32)
+        .map(U256::from_be_slice)
+        .ok_or("Transfer log without an amount")?;
+    let direction = match (wallets.contains(&from), wallets.contains(&to)) {
+        (true, true) => "internal",
+        (true, false) => "out",
+        _ => "in",
+    };
+    Ok(TransferMatch {
+        block: log.block_number.unwrap_or_default(),
+        tx_hash: log.transaction_hash.map(|h| h.to_string()).unwrap_or_default(),
+        log_index: log.log_index.unwrap_or_default(),
+        from: from.to_checksum(None),
+        to: to.to_checksum(None),
+        amount: amount.to_string(),
+        direction: direction.to_string(),
+    })
+}
+
+fn totals(wallets: &[Address], transfers: &[TransferMatch]) -> Vec<WalletTotals> {
+    wallets
+        .iter()
+        .filter_map(|wallet| {
+            let wallet = wallet.to_checksum(None);
+            let (mut sent, mut received, mut count) = (U256::ZERO, U256::ZERO, 0);
+            for transfer in transfers {
+                let amount = transfer.amount.parse::<U256>().unwrap_or_default();
+                if transfer.from == wallet {
+                    sent = sent.saturating_add(amount);
+                }
+                if transfer.to == wallet {
+                    received = received.saturating_add(amount);
+                }
+                if transfer.from == wallet || transfer.to == wallet {
+                    count += 1;
+                }
+            }
+            (count > 0).then(|| WalletTotals {
+                wallet,
+                sent: sent.to_string(),
+                received: received.to_string(),
+                transfers: count,
+            })
+        })
+        .collect()
+}
+
+// The first run scans from WAVS_ENV_WATCHLIST_FROM_BLOCK when it is set, so a new watcher can
+// backfill; otherwise it starts at the head
+fn start_block() -> Result<Option<u64>, String> {
+    match std::env::var("WAVS_ENV_WATCHLIST_FROM_BLOCK") {
+        Ok(block) if !block.trim().is_empty() => block
+            .trim()
+            .parse::<u64>()
+            .map(Some)
+            .map_err(|e| format!("Invalid WAVS_ENV_WATCHLIST_FROM_BLOCK: {}", e)),
+        _ => Ok(None),
+    }
+}
+
+fn max_run_blocks() -> u64 {
+    env_blocks("WAVS_ENV_WATCHLIST_MAX_BLOCKS", DEFAULT_MAX_RUN_BLOCKS)
+}
+
+fn chunk_blocks() -> u64 {
+    env_blocks("WAVS_ENV_WATCHLIST_LOGS_CHUNK_BLOCKS", DEFAULT_CHUNK_BLOCKS)
+}
+
+fn env_blocks(name: &str, default: u64) -> u64 {
+    std::env::var(name)
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .filter(|blocks| *blocks > 0)
+        .unwrap_or(default)
+}
+
+// Separate from the balance state, so switching modes doesn't read the other mode's entry
+fn state_key(token: Address, wallets: &[Address]) -> String {
+    let wallets: Vec<String> = wallets.iter().map(|w| w.to_checksum(None)).collect();
+    hex::encode(keccak256(format!("wallet-watchlist-transfers\n{}\n{}", token, wallets.join(","))))
+}
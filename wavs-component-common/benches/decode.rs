@@ -0,0 +1,62 @@
+// Trigger input decode paths shared by the components: envelope detection, hex, ABI string and
+// calldata decoding. Run with `cargo bench -p wavs-component-common`.
+use alloy_sol_types::{sol, SolCall, SolValue};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use wavs_component_common::envelope;
+
+sol! {
+    function checkUsdtBalanceSince(string wallet, uint64 sinceBlock) external;
+    function search(string query, uint32 maxResults) external;
+}
+
+const WALLET: &str = "0x742d35Cc6634C0532925a3b8D84c8C0b1b39a7d0";
+
+fn decode(c: &mut Criterion) {
+    let string_input = WALLET.to_string().abi_encode();
+    let call_input =
+        checkUsdtBalanceSinceCall { wallet: WALLET.to_string(), sinceBlock: 19_000_000 }
+            .abi_encode();
+    let hex_input = alloy_primitives::hex::encode(&call_input);
+    let envelope_input =
+        format!(r#"{{"input": "0x{}", "options": {{"filter": "city == 'Denver'"}}}}"#, hex_input);
+
+    let mut abi_envelope_input = b"WAVS\x02".to_vec();
+    abi_envelope_input.extend(
+        (alloy_primitives::Bytes::from(call_input.clone()), r#"{"filter": "city == 'Denver'"}"#)
+            .abi_encode_params(),
+    );
+
+    let mut group = c.benchmark_group("decode");
+    group.bench_function("envelope_passthrough", |b| {
+        b.iter(|| envelope::unwrap(black_box(&call_input)).unwrap())
+    });
+    group.bench_function("envelope_unwrap", |b| {
+        b.iter(|| envelope::unwrap(black_box(envelope_input.as_bytes())).unwrap())
+    });
+    group.bench_function("envelope_abi", |b| {
+        b.iter(|| envelope::unwrap(black_box(&abi_envelope_input)).unwrap())
+    });
+    group.bench_function("hex", |b| {
+        b.iter(|| alloy_primitives::hex::decode(black_box(&hex_input)).unwrap())
+    });
+    group.bench_function("abi_string", |b| {
+        b.iter(|| String::abi_decode(black_box(&string_input)).unwrap())
+    });
+    group.bench_function("abi_call", |b| {
+        b.iter(|| checkUsdtBalanceSinceCall::abi_decode(black_box(&call_input)).unwrap())
+    });
+    // components try calldata first and fall back to a bare string, so a string input pays
+    // for the failed selector match too
+    group.bench_function("abi_call_fallback_to_string", |b| {
+        b.iter(|| {
+            searchCall::abi_decode(black_box(&string_input))
+                .map(|call| call.query)
+                .or_else(|_| String::abi_decode(&string_input))
+                .unwrap()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, decode);
+criterion_main!(benches);
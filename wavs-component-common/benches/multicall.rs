@@ -0,0 +1,33 @@
+// Multicall3 batch assembly: the aggregate3 calldata a batch of view calls is sent as, for a
+// single call and for a batch the size vesting-inspector sends for 16 Sablier streams. Run with
+// `cargo bench -p wavs-component-common --bench multicall`.
+use alloy_primitives::{address, U256};
+use alloy_sol_types::sol;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use wavs_component_common::multicall::Batch;
+
+sol! {
+    function streamedAmountOf(uint256 streamId) external view returns (uint128);
+}
+
+fn batch(calls: usize) -> Batch {
+    let lockup = address!("7C01AA3783577E15fD7e272443D44B92d5b21056");
+    let mut batch = Batch::new();
+    for i in 0..calls {
+        batch.add(lockup, &streamedAmountOfCall { streamId: U256::from(i) });
+    }
+    batch
+}
+
+fn multicall(c: &mut Criterion) {
+    let mut group = c.benchmark_group("multicall");
+    for calls in [1, 129] {
+        group.bench_function(format!("calldata_{}", calls), |b| {
+            b.iter(|| batch(black_box(calls)).calldata())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, multicall);
+criterion_main!(benches);
@@ -0,0 +1,74 @@
+// Batch fan-out. A trigger carrying several independent work items (wallets, prompts, zip
+// codes) runs each one in isolation and reports every item's outcome, so one bad item no longer
+// fails the whole run:
+//
+//   {"items": [{"index": 0, "status": "ok", "result": {...}},
+//              {"index": 1, "status": "err", "error": "Invalid wallet address: ..."}],
+//    "ok_count": 1, "err_count": 1}
+//
+// Items run one after another, in input order, so upstream rate limits and spend ceilings see
+// the same sequence of calls a series of single triggers would. A batch whose items all fail is
+// still a result; only malformed batches (empty, or over the size cap) fail the run.
+use crate::warn;
+use serde::Serialize;
+use serde_json::Value;
+use std::future::Future;
+
+const DEFAULT_MAX_ITEMS: usize = 16;
+const MAX_ITEMS_LIMIT: usize = 256;
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum Outcome {
+    Ok { index: usize, result: Value },
+    Err { index: usize, error: String },
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct BatchResult {
+    pub items: Vec<Outcome>,
+    pub ok_count: usize,
+    pub err_count: usize,
+}
+
+// WAVS_ENV_BATCH_MAX_ITEMS caps the items per trigger, 16 unless set
+pub fn max_items() -> usize {
+    std::env::var("WAVS_ENV_BATCH_MAX_ITEMS")
+        .ok()
+        .and_then(|v| v.parse::<usize>().ok())
+        .filter(|max| *max > 0)
+        .unwrap_or(DEFAULT_MAX_ITEMS)
+        .min(MAX_ITEMS_LIMIT)
+}
+
+pub fn check_len(len: usize) -> Result<(), String> {
+    if len == 0 {
+        return Err("Batch has no items".to_string());
+    }
+    let max = max_items();
+    if len > max {
+        return Err(format!("Batch has {} items, at most {} are allowed", len, max));
+    }
+    Ok(())
+}
+
+pub async fn run<T, F, Fut>(items: Vec<T>, mut work: F) -> Result<BatchResult, String>
+where
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = Result<Value, String>>,
+{
+    check_len(items.len())?;
+
+    let mut outcomes = Vec::with_capacity(items.len());
+    for (index, item) in items.into_iter().enumerate() {
+        outcomes.push(match work(item).await {
+            Ok(result) => Outcome::Ok { index, result },
+            Err(error) => {
+                warn!("Batch item {} failed: {}", index, error);
+                Outcome::Err { index, error }
+            }
+        });
+    }
+    let ok_count = outcomes.iter().filter(|o| matches!(o, Outcome::Ok { .. })).count();
+    Ok(BatchResult { err_count: outcomes.len() - ok_count, ok_count, items: outcomes })
+}
@@ -0,0 +1,55 @@
+// BLS12-381 signatures over result digests, next to the ECDSA attestations operators already
+// submit. Every operator signs the same digest, so the AVS aggregator can combine their
+// signatures into one and check it against the sum of their public keys.
+//
+// The key is WAVS_ENV_BLS_SECRET_KEY, a 32-byte scalar in hex. Signing needs the `bls` feature,
+// which pulls in the blst C library; a component built without it fails when the key is set
+// rather than submitting an unsigned result the operator meant to sign.
+use alloy_primitives::B256;
+use serde::{Deserialize, Serialize};
+
+// IETF ciphersuite of the signatures, reported as the scheme tag: minimal-pubkey-size keys
+// (48-byte G1) and 96-byte G2 signatures with proof of possession, the variant Ethereum
+// consensus uses. Signatures over one shared message are only safe to aggregate when every key
+// proved possession when it was registered.
+pub const SCHEME: &str = "BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BlsSignature {
+    pub scheme: String,
+    // compressed G1 point
+    pub public_key: String,
+    // compressed G2 point over the 32 digest bytes
+    pub signature: String,
+}
+
+// None when no key is configured
+pub fn sign(digest: B256) -> Result<Option<BlsSignature>, String> {
+    match std::env::var("WAVS_ENV_BLS_SECRET_KEY") {
+        Ok(key) if !key.trim().is_empty() => sign_with(key.trim(), digest).map(Some),
+        _ => Ok(None),
+    }
+}
+
+#[cfg(feature = "bls")]
+fn sign_with(key: &str, digest: B256) -> Result<BlsSignature, String> {
+    use alloy_primitives::hex;
+    use blst::min_pk::SecretKey;
+
+    let bytes = hex::decode(key.strip_prefix("0x").unwrap_or(key))
+        .map_err(|e| format!("Invalid WAVS_ENV_BLS_SECRET_KEY: {}", e))?;
+    let secret = SecretKey::from_bytes(&bytes)
+        .map_err(|e| format!("Invalid WAVS_ENV_BLS_SECRET_KEY: {:?}", e))?;
+    let signature = secret.sign(digest.as_slice(), SCHEME.as_bytes(), &[]);
+    Ok(BlsSignature {
+        scheme: SCHEME.to_string(),
+        public_key: format!("0x{}", hex::encode(secret.sk_to_pk().to_bytes())),
+        signature: format!("0x{}", hex::encode(signature.to_bytes())),
+    })
+}
+
+#[cfg(not(feature = "bls"))]
+fn sign_with(_key: &str, _digest: B256) -> Result<BlsSignature, String> {
+    Err("WAVS_ENV_BLS_SECRET_KEY is set, but this component was built without BLS signing"
+        .to_string())
+}
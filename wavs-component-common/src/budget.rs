@@ -0,0 +1,85 @@
+// Size budgets for the responses a component holds in memory. A component's heap is small and
+// an allocation failure aborts the instance without an error, so responses are counted as they
+// are read and the run fails with a structured "payload_too_large" error once a budget is hit.
+//
+// Two limits apply, both in bytes and set per component through its env:
+//   WAVS_ENV_MAX_RESPONSE_BYTES        a single response body (default 4 MiB)
+//   WAVS_ENV_MAX_TOTAL_RESPONSE_BYTES  all response bodies of one run (default 16 MiB)
+// Deserialized values usually take a small multiple of their JSON size, so the defaults leave
+// room for that within the default wasm heap.
+use serde::Serialize;
+use std::cell::Cell;
+
+const DEFAULT_MAX_RESPONSE_BYTES: u64 = 4 * 1024 * 1024;
+const DEFAULT_MAX_TOTAL_RESPONSE_BYTES: u64 = 16 * 1024 * 1024;
+
+thread_local! {
+    static USED: Cell<u64> = const { Cell::new(0) };
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PayloadTooLarge {
+    error: &'static str,
+    source: String,
+    // "response" for a single body over its limit, "total" for the run-wide budget
+    limit: &'static str,
+    limit_bytes: u64,
+    observed_bytes: u64,
+}
+
+fn too_large(source: &str, limit: &'static str, limit_bytes: u64, observed_bytes: u64) -> String {
+    let error = PayloadTooLarge {
+        error: "payload_too_large",
+        source: source.to_string(),
+        limit,
+        limit_bytes,
+        observed_bytes,
+    };
+    serde_json::to_string(&error).unwrap_or_else(|_| "payload_too_large".to_string())
+}
+
+fn env_bytes(name: &str, default: u64) -> u64 {
+    std::env::var(name).ok().and_then(|v| v.parse::<u64>().ok()).unwrap_or(default)
+}
+
+pub fn max_response_bytes() -> u64 {
+    env_bytes("WAVS_ENV_MAX_RESPONSE_BYTES", DEFAULT_MAX_RESPONSE_BYTES)
+}
+
+pub fn max_total_response_bytes() -> u64 {
+    env_bytes("WAVS_ENV_MAX_TOTAL_RESPONSE_BYTES", DEFAULT_MAX_TOTAL_RESPONSE_BYTES)
+}
+
+// Bytes charged so far in this run
+pub fn used_bytes() -> u64 {
+    USED.with(Cell::get)
+}
+
+// Checks a declared size (e.g. Content-Length) before anything is read
+pub fn check_declared(source: &str, declared_bytes: u64) -> Result<(), String> {
+    let limit = max_response_bytes();
+    if declared_bytes > limit {
+        return Err(too_large(source, "response", limit, declared_bytes));
+    }
+    let total_limit = max_total_response_bytes();
+    if used_bytes() + declared_bytes > total_limit {
+        return Err(too_large(source, "total", total_limit, used_bytes() + declared_bytes));
+    }
+    Ok(())
+}
+
+// Charges `bytes` more of the response from `source`, which has `response_bytes` read so far
+// including them
+pub fn charge(source: &str, bytes: u64, response_bytes: u64) -> Result<(), String> {
+    let limit = max_response_bytes();
+    if response_bytes > limit {
+        return Err(too_large(source, "response", limit, response_bytes));
+    }
+    let used = used_bytes() + bytes;
+    let total_limit = max_total_response_bytes();
+    if used > total_limit {
+        return Err(too_large(source, "total", total_limit, used));
+    }
+    USED.with(|u| u.set(used));
+    Ok(())
+}
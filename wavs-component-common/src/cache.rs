@@ -0,0 +1,48 @@
+// Small key-value cache for responses. The layer-trigger-world the components target does not
+// expose wasi:keyvalue, so entries are stored as JSON files in a directory preopened by the host
+// (WAVS_ENV_CACHE_DIR). Caching is disabled when no directory is configured.
+use crate::freshness::now_secs;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::path::PathBuf;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CacheEntry<T> {
+    pub stored_at: u64,
+    pub value: T,
+}
+
+impl<T> CacheEntry<T> {
+    pub fn age_secs(&self) -> u64 {
+        now_secs().saturating_sub(self.stored_at)
+    }
+}
+
+fn cache_dir() -> Option<PathBuf> {
+    std::env::var("WAVS_ENV_CACHE_DIR").ok().filter(|d| !d.is_empty()).map(PathBuf::from)
+}
+
+// For callers that rely on entries persisting between runs rather than on caching
+pub fn enabled() -> bool {
+    cache_dir().is_some()
+}
+
+// Keys are expected to be hex digests, so they are safe to use as file names
+fn entry_path(key: &str) -> Option<PathBuf> {
+    cache_dir().map(|dir| dir.join(format!("{}.json", key)))
+}
+
+pub fn get<T: DeserializeOwned>(key: &str) -> Option<CacheEntry<T>> {
+    let path = entry_path(key)?;
+    let bytes = std::fs::read(path).ok()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
+pub fn put<T: Serialize>(key: &str, value: &T) -> Result<(), String> {
+    let Some(path) = entry_path(key) else {
+        return Ok(());
+    };
+    let entry = CacheEntry { stored_at: now_secs(), value };
+    let bytes = serde_json::to_vec(&entry).map_err(|e| e.to_string())?;
+    std::fs::write(&path, bytes)
+        .map_err(|e| format!("Failed to write cache entry {}: {}", path.display(), e))
+}
@@ -0,0 +1,95 @@
+use serde::Serialize;
+use serde_json::Value;
+
+// Serializes `value` to a byte-for-byte reproducible JSON document so that every operator
+// submits the same payload for the same data:
+// - object keys are sorted by their UTF-8 bytes, regardless of struct field order
+// - no insignificant whitespace
+// - integers are written as-is; floats (e.g. coordinates) are written as strings using
+//   the shortest representation that round-trips, so no float ever reaches the payload
+pub fn to_vec<T: Serialize>(value: &T) -> Result<Vec<u8>, String> {
+    to_vec_with(value, Format::Compact)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Compact,
+    // same key order and value formatting, indented by two spaces for reading by eye
+    Pretty,
+}
+
+pub fn to_vec_with<T: Serialize>(value: &T, format: Format) -> Result<Vec<u8>, String> {
+    let value = serde_json::to_value(value)
+        .map_err(|e| format!("Failed to serialize canonical JSON: {}", e))?;
+    let mut out = Vec::new();
+    let indent = match format {
+        Format::Compact => None,
+        Format::Pretty => Some(0),
+    };
+    write_value(&value, &mut out, indent)?;
+    Ok(out)
+}
+
+// Starts a new line at `depth` levels of indentation in pretty mode
+fn newline(out: &mut Vec<u8>, indent: Option<usize>) {
+    if let Some(depth) = indent {
+        out.push(b'\n');
+        out.extend(std::iter::repeat_n(b' ', depth * 2));
+    }
+}
+
+fn write_value(value: &Value, out: &mut Vec<u8>, indent: Option<usize>) -> Result<(), String> {
+    let inner = indent.map(|depth| depth + 1);
+    match value {
+        Value::Null | Value::Bool(_) | Value::String(_) => write_scalar(value, out),
+        Value::Number(n) => match n.as_f64() {
+            Some(f) if n.is_f64() => write_scalar(&Value::String(f.to_string()), out),
+            _ => {
+                out.extend_from_slice(n.to_string().as_bytes());
+                Ok(())
+            }
+        },
+        Value::Array(items) => {
+            out.push(b'[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(b',');
+                }
+                newline(out, inner);
+                write_value(item, out, inner)?;
+            }
+            if !items.is_empty() {
+                newline(out, indent);
+            }
+            out.push(b']');
+            Ok(())
+        }
+        Value::Object(map) => {
+            let mut entries: Vec<(&String, &Value)> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.as_bytes().cmp(b.0.as_bytes()));
+            out.push(b'{');
+            for (i, (key, item)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(b',');
+                }
+                newline(out, inner);
+                write_scalar(&Value::String(key.clone()), out)?;
+                out.push(b':');
+                if indent.is_some() {
+                    out.push(b' ');
+                }
+                write_value(item, out, inner)?;
+            }
+            if !map.is_empty() {
+                newline(out, indent);
+            }
+            out.push(b'}');
+            Ok(())
+        }
+    }
+}
+
+fn write_scalar(value: &Value, out: &mut Vec<u8>) -> Result<(), String> {
+    serde_json::to_writer(&mut *out, value)
+        .map_err(|e| format!("Failed to serialize canonical JSON: {}", e))
+}
@@ -0,0 +1,68 @@
+// The chain a component reads from, resolved at run time from the host's chain configs instead
+// of a hard-coded "ethereum", so one component binary can serve deployments on many chains.
+//
+//   WAVS_ENV_CHAINS   chain names of the WAVS chain config to consider, in order of preference
+//                     (default "ethereum")
+//
+// Each listed chain is probed for a config. The trigger's own chain is used when it is listed
+// and configured, so a service deployed on Base reads Base; otherwise, as for CLI runs, the
+// first configured chain in the list. Nothing here may use the generated bindings, so
+// components pass their get_evm_chain_config in as the probe.
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_CHAINS: &str = "ethereum";
+
+// What outputs report as `chain`; the endpoint stays out of them since it may embed a key
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Chain {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chain_id: Option<u64>,
+    #[serde(skip)]
+    pub http_endpoint: Option<String>,
+}
+
+impl Chain {
+    // The chain's HTTP endpoint, or an envelope `rpc_url` override (see rpc.rs)
+    pub fn endpoint(&self, rpc_url: Option<&str>) -> Result<String, String> {
+        if rpc_url.is_none() && self.http_endpoint.is_none() {
+            return Err(format!("{} chain config has no HTTP endpoint", self.name));
+        }
+        crate::rpc::resolve_endpoint(rpc_url, self.http_endpoint.clone())
+    }
+}
+
+pub fn listed() -> Vec<String> {
+    let configured = std::env::var("WAVS_ENV_CHAINS")
+        .ok()
+        .filter(|chains| !chains.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_CHAINS.to_string());
+    let mut chains: Vec<String> = Vec::new();
+    for name in configured.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+        if !chains.iter().any(|chain| chain == name) {
+            chains.push(name.to_string());
+        }
+    }
+    chains
+}
+
+// `probe` returns the chain id and HTTP endpoint of a chain's config, or None when the host has
+// no config for it
+pub fn resolve(probe: impl Fn(&str) -> Option<(String, Option<String>)>) -> Result<Chain, String> {
+    let listed = listed();
+    let available: Vec<Chain> = listed
+        .iter()
+        .filter_map(|name| {
+            let (chain_id, http_endpoint) = probe(name)?;
+            Some(Chain { name: name.clone(), chain_id: chain_id.parse().ok(), http_endpoint })
+        })
+        .collect();
+    let trigger_chain = crate::origin::current().map(|origin| origin.chain_name);
+    let preferred = available
+        .iter()
+        .position(|chain| trigger_chain.as_deref() == Some(chain.name.as_str()))
+        .unwrap_or(0);
+    available.into_iter().nth(preferred).ok_or_else(|| {
+        format!("None of the chains in WAVS_ENV_CHAINS ({}) has a chain config", listed.join(", "))
+    })
+}
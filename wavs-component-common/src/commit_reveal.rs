@@ -0,0 +1,115 @@
+// Optional commit-reveal output, so operators can take part in commit-reveal aggregation without
+// any host support. Two triggers are involved:
+//
+//   {"input": "0x<calldata>", "options": {"commit": true}}
+//   {"input": "0x", "options": {"reveal": <trigger ID of the commit>}}
+//
+// The commit trigger runs the component as usual, but instead of the result it submits
+// ResultCommitment below, keccak256(abi.encodePacked(result, salt)) with a fresh random 32-byte
+// salt. The result is whatever would have been submitted otherwise (sealed, if encrypt_to is set
+// too). Result and salt are kept in the cache directory under the commit's trigger. The reveal
+// trigger, sent by the same trigger contract, submits them as ResultReveal without running the
+// component again; its own input is ignored. Contracts check a reveal against the commitment
+// stored for its commitTriggerId.
+//
+// Each operator draws its own salt, so their commitments differ even when their results match.
+// The pair has to survive between the two triggers, so both phases need WAVS_ENV_CACHE_DIR, and
+// both are for on-chain runs only: CLI runs have no trigger ID to tie a reveal to.
+use crate::{cache, envelope::Options, origin, output::EthereumOutput};
+use alloy_primitives::{hex, keccak256, B256};
+use alloy_sol_types::{sol, SolValue};
+use serde::{Deserialize, Serialize};
+
+sol! {
+    struct ResultCommitment {
+        bytes32 commitment;
+    }
+
+    struct ResultReveal {
+        uint64 commitTriggerId;
+        bytes result;
+        bytes32 salt;
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Committed {
+    result: String,
+    salt: String,
+}
+
+// Checks the commit and reveal options against the destination. For a reveal trigger it
+// returns the DataWithId data to submit, and the component should not run.
+pub fn reveal(
+    options: &Options,
+    component: &str,
+    on_chain: bool,
+) -> Result<Option<EthereumOutput<ResultReveal>>, String> {
+    if !options.commit && options.reveal.is_none() {
+        return Ok(None);
+    }
+    if options.commit && options.reveal.is_some() {
+        return Err("commit and reveal can't be set on the same trigger".to_string());
+    }
+    if !on_chain {
+        return Err("commit and reveal only apply to on-chain runs".to_string());
+    }
+    if !cache::enabled() {
+        return Err("commit and reveal need WAVS_ENV_CACHE_DIR to keep results between triggers"
+            .to_string());
+    }
+    let Some(commit_trigger_id) = options.reveal else {
+        return Ok(None);
+    };
+
+    let entry = cache::get::<Committed>(&storage_key(component, commit_trigger_id))
+        .ok_or_else(|| format!("No commitment stored for trigger {}", commit_trigger_id))?;
+    let decode = |value: &str| {
+        hex::decode(value).map_err(|e| format!("Stored commitment is corrupt: {}", e))
+    };
+    let reveal = ResultReveal {
+        commitTriggerId: commit_trigger_id,
+        result: decode(&entry.value.result)?.into(),
+        salt: B256::try_from(decode(&entry.value.salt)?.as_slice())
+            .map_err(|_| "Stored commitment has an invalid salt".to_string())?,
+    };
+    Ok(Some(EthereumOutput::abi(&reveal)))
+}
+
+// The DataWithId data for `result`: a commitment to it when the trigger asks for one, unchanged
+// otherwise
+pub fn commit<S>(
+    trigger_id: u64,
+    result: EthereumOutput<S>,
+    options: &Options,
+    component: &str,
+) -> Result<EthereumOutput<S>, String> {
+    if !options.commit {
+        return Ok(result);
+    }
+    result.wrap(|result| store_commitment(trigger_id, result, component))
+}
+
+fn store_commitment(trigger_id: u64, result: Vec<u8>, component: &str) -> Result<Vec<u8>, String> {
+    let mut salt = [0u8; 32];
+    wstd::rand::get_random_bytes(&mut salt);
+    let commitment = keccak256([result.as_slice(), salt.as_slice()].concat());
+
+    // Stored before anything is submitted, so a commitment can always be revealed
+    let committed = Committed { result: hex::encode(&result), salt: hex::encode(salt) };
+    cache::put(&storage_key(component, trigger_id), &committed)
+        .map_err(|e| format!("Failed to store the committed result: {}", e))?;
+    Ok(ResultCommitment { commitment }.abi_encode())
+}
+
+// Trigger IDs are only unique per trigger contract, so the key covers the event's chain and
+// contract as well as the component
+fn storage_key(component: &str, trigger_id: u64) -> String {
+    let origin = origin::current();
+    let (chain, contract) =
+        origin.as_ref().map_or(("", ""), |o| (o.chain_name.as_str(), o.contract_address.as_str()));
+    hex::encode(keccak256(format!(
+        "commit-reveal\n{}\n{}\n{}\n{}",
+        component, chain, contract, trigger_id
+    )))
+}
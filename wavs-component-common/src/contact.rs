@@ -0,0 +1,227 @@
+// Normalization of contact fields taken from third-party APIs. Downstream contracts and
+// frontends render these verbatim, so values are either brought into a strict canonical form
+// or rejected: phone numbers become E.164, websites become http(s) URLs with punycode hosts.
+
+// Calling codes for the countries OpenBreweryDB lists, keyed by its country names
+const CALLING_CODES: &[(&str, &str)] = &[
+    ("united states", "1"),
+    ("canada", "1"),
+    ("england", "44"),
+    ("scotland", "44"),
+    ("wales", "44"),
+    ("northern ireland", "44"),
+    ("united kingdom", "44"),
+    ("isle of man", "44"),
+    ("ireland", "353"),
+    ("austria", "43"),
+    ("germany", "49"),
+    ("france", "33"),
+    ("portugal", "351"),
+    ("poland", "48"),
+    ("south korea", "82"),
+    ("singapore", "65"),
+    ("australia", "61"),
+    ("new zealand", "64"),
+    ("netherlands", "31"),
+    ("belgium", "32"),
+    ("spain", "34"),
+    ("italy", "39"),
+];
+
+// Returns the number in E.164 form (+<country code><subscriber>), or None when it cannot be
+// normalized. Numbers without an international prefix are interpreted using `country`.
+pub fn normalize_phone(raw: &str, country: Option<&str>) -> Option<String> {
+    let raw = raw.trim();
+    if raw.chars().any(|c| !(c.is_ascii_digit() || " +-.()/".contains(c))) {
+        return None;
+    }
+    let digits: String = raw.chars().filter(|c| c.is_ascii_digit()).collect();
+
+    let international = if raw.starts_with('+') {
+        digits
+    } else if let Some(rest) = digits.strip_prefix("00") {
+        rest.to_string()
+    } else {
+        let country = country?.trim().to_lowercase();
+        let (_, code) = CALLING_CODES.iter().find(|(name, _)| *name == country)?;
+        if *code == "1" {
+            // North American numbering plan: 10 digits, optionally preceded by the 1
+            match digits.len() {
+                10 => format!("1{}", digits),
+                11 if digits.starts_with('1') => digits,
+                _ => return None,
+            }
+        } else {
+            // Drop the national trunk prefix
+            format!("{}{}", code, digits.strip_prefix('0').unwrap_or(&digits))
+        }
+    };
+
+    if !(8..=15).contains(&international.len()) || international.starts_with('0') {
+        return None;
+    }
+    Some(format!("+{}", international))
+}
+
+// Returns the URL with a lowercase http(s) scheme and an ASCII (punycode) host, or None when
+// it is not a plain web URL. A missing scheme is taken to mean https.
+pub fn normalize_website(raw: &str) -> Option<String> {
+    let raw = raw.trim();
+    if raw.is_empty() || raw.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return None;
+    }
+
+    let (scheme, rest) = match raw.split_once("://") {
+        Some((scheme, rest)) => (scheme.to_ascii_lowercase(), rest),
+        None if !raw.contains(':') || raw.split_once(':').is_some_and(|(_, p)| port_like(p)) => {
+            ("https".to_string(), raw)
+        }
+        None => return None,
+    };
+    if scheme != "http" && scheme != "https" {
+        return None;
+    }
+
+    let authority_end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+    let (authority, path) = rest.split_at(authority_end);
+    // Credentials in the URL are a phishing vector, never legitimate for a listing
+    if authority.contains('@') {
+        return None;
+    }
+    let (host, port) = match authority.rsplit_once(':') {
+        Some((host, port)) if port_like(port) => (host, Some(port)),
+        Some(_) => return None,
+        None => (authority, None),
+    };
+
+    let host = ascii_host(host)?;
+    let mut url = format!("{}://{}", scheme, host);
+    if let Some(port) = port {
+        url.push(':');
+        url.push_str(port);
+    }
+    for c in path.chars() {
+        if c.is_ascii() {
+            url.push(c);
+        } else {
+            let mut buf = [0u8; 4];
+            for b in c.encode_utf8(&mut buf).bytes() {
+                url.push_str(&format!("%{:02X}", b));
+            }
+        }
+    }
+    Some(url)
+}
+
+fn port_like(value: &str) -> bool {
+    let digits = value.split(['/', '?', '#']).next().unwrap_or("");
+    !digits.is_empty() && digits.len() <= 5 && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+// Lowercases the host and converts internationalized labels to their xn-- form. This covers
+// the IDNA mapping for ordinary names (lowercasing) but not the full UTS #46 table.
+fn ascii_host(host: &str) -> Option<String> {
+    let host = host.strip_suffix('.').unwrap_or(host).to_lowercase();
+    let mut labels = Vec::new();
+    for label in host.split('.') {
+        let label =
+            if label.is_ascii() { label.to_string() } else { format!("xn--{}", punycode(label)?) };
+        let valid = !label.is_empty()
+            && label.len() <= 63
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+        if !valid {
+            return None;
+        }
+        labels.push(label);
+    }
+    // Require a registrable name rather than a bare label such as "localhost"
+    if labels.len() < 2 || labels.last()?.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let host = labels.join(".");
+    (host.len() <= 253).then_some(host)
+}
+
+// Punycode encoding of a single label (RFC 3492), without the xn-- prefix
+fn punycode(label: &str) -> Option<String> {
+    const BASE: u32 = 36;
+    const T_MIN: u32 = 1;
+    const T_MAX: u32 = 26;
+    const INITIAL_BIAS: u32 = 72;
+    const INITIAL_N: u32 = 128;
+
+    let code_points: Vec<u32> = label.chars().map(|c| c as u32).collect();
+    let mut output: String = label.chars().filter(|c| c.is_ascii()).collect();
+    let basic_len = output.len() as u32;
+    if basic_len > 0 {
+        output.push('-');
+    }
+
+    let digit = |d: u32| -> char {
+        if d < 26 {
+            (b'a' + d as u8) as char
+        } else {
+            (b'0' + (d - 26) as u8) as char
+        }
+    };
+
+    let mut n = INITIAL_N;
+    let mut delta: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+    let mut handled = basic_len;
+    while (handled as usize) < code_points.len() {
+        let m = *code_points.iter().filter(|&&c| c >= n).min()?;
+        delta = delta.checked_add((m - n).checked_mul(handled + 1)?)?;
+        n = m;
+        for &c in &code_points {
+            if c < n {
+                delta = delta.checked_add(1)?;
+            }
+            if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = if k <= bias {
+                        T_MIN
+                    } else if k >= bias + T_MAX {
+                        T_MAX
+                    } else {
+                        k - bias
+                    };
+                    if q < t {
+                        break;
+                    }
+                    output.push(digit(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                output.push(digit(q));
+                bias = adapt(delta, handled + 1, handled == basic_len);
+                delta = 0;
+                handled += 1;
+            }
+        }
+        delta = delta.checked_add(1)?;
+        n += 1;
+    }
+    Some(output)
+}
+
+fn adapt(delta: u32, num_points: u32, first_time: bool) -> u32 {
+    const BASE: u32 = 36;
+    const T_MIN: u32 = 1;
+    const T_MAX: u32 = 26;
+    const SKEW: u32 = 38;
+    const DAMP: u32 = 700;
+
+    let mut delta = if first_time { delta / DAMP } else { delta / 2 };
+    delta += delta / num_points;
+    let mut k = 0;
+    while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+        delta /= BASE - T_MIN;
+        k += BASE;
+    }
+    k + (BASE - T_MIN + 1) * delta / (delta + SKEW)
+}
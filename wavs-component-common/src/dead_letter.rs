@@ -0,0 +1,35 @@
+// Dead-letter records for triggers that failed for good. A component that returns an error
+// submits nothing, so the triggering contract never learns that the trigger ID is finished.
+// With WAVS_ENV_DEAD_LETTER=true, Ethereum runs that fail submit a failure record as the
+// DataWithId payload instead, ABI-encoded like a call to
+//
+//   triggerFailed(string component, string reason)
+//
+// so contracts tell it apart from a result by its 4-byte selector and can clean up the state
+// they keep for the trigger. There is no timestamp, so operators that fail the same way
+// submit identical records.
+use crate::{log, output::EthereumOutput};
+use alloy_sol_types::sol;
+
+// Keeps the record cheap to submit; error messages can embed whole API responses
+const MAX_REASON_BYTES: usize = 256;
+
+sol! {
+    function triggerFailed(string component, string reason) external;
+}
+
+pub fn enabled() -> bool {
+    matches!(std::env::var("WAVS_ENV_DEAD_LETTER").as_deref(), Ok("1") | Ok("true"))
+}
+
+pub fn failure_record(component: &str, error: &str) -> EthereumOutput<triggerFailedCall> {
+    let mut reason = log::redact_secrets(error);
+    if reason.len() > MAX_REASON_BYTES {
+        let mut end = MAX_REASON_BYTES;
+        while !reason.is_char_boundary(end) {
+            end -= 1;
+        }
+        reason.truncate(end);
+    }
+    EthereumOutput::call(&triggerFailedCall { component: component.to_string(), reason })
+}
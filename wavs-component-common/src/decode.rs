@@ -0,0 +1,314 @@
+// Trigger input decoding. A component's input arrives as raw bytes in one of these forms:
+//
+//   - an ABI-encoded value, e.g. a bare `string`, as chain triggers and raw CLI inputs carry it
+//   - calldata of one of the component's trigger functions: a 4-byte selector, then the
+//     ABI-encoded arguments
+//   - either of the above as `0x`-prefixed hex text, as `make wasi-exec` passes an `INPUT_DATA`
+//     that starts with 0x
+//
+// input_bytes() removes the hex layer. decode_abi_string_input() handles the most common trigger
+// shape on top of that: a single string, sent bare or as the only argument of a function such as
+// `checkPeg(string symbol)`. Components with richer inputs decode their calls themselves and use
+// abi_string() for the bare string fallback. wallet() is the same fallback for inputs that name
+// one wallet, which contracts may send as an `address` or `bytes32` rather than a string.
+use crate::selector::{self, Expected};
+use alloy_primitives::{hex, Address, Bytes};
+use alloy_sol_types::SolValue;
+use serde::Serialize;
+
+// The input with a `0x` hex layer decoded. Input that isn't `0x`-prefixed text, including any
+// input that isn't UTF-8, is returned as it is.
+pub fn input_bytes(req: &[u8]) -> Result<Vec<u8>, String> {
+    match std::str::from_utf8(req).ok().and_then(|text| text.strip_prefix("0x")) {
+        Some(digits) => {
+            hex::decode(digits).map_err(|e| format!("Failed to decode hex string: {}", e))
+        }
+        None => Ok(req.to_vec()),
+    }
+}
+
+// An ABI-encoded string. The text has to be UTF-8: the lossy decode ABI decoders default to
+// would hand the component replacement characters instead of what the caller sent.
+pub fn abi_string(data: &[u8]) -> Result<String, String> {
+    if data.is_empty() {
+        return Err("Input is empty".to_string());
+    }
+    // `bytes` and `string` share their encoding, so the raw text can be checked before use
+    let bytes = <Bytes as SolValue>::abi_decode(data)
+        .map_err(|e| format!("Failed to decode input as ABI string: {}", e))?;
+    String::from_utf8(bytes.to_vec()).map_err(|e| format!("Input string is not UTF-8: {}", e))
+}
+
+// The string a single-string trigger carries, from any of the input forms above. `calls` are the
+// component's `f(string)` trigger functions: calldata for one of them yields its argument, and
+// input without one of their selectors is decoded as a bare string. With
+// WAVS_ENV_STRICT_SELECTORS the input must be calldata for one of them.
+pub fn decode_abi_string_input(req: &[u8], calls: &[Expected]) -> Result<String, String> {
+    let data = input_bytes(req)?;
+    selector::check(&data, calls)?;
+    match calls.iter().find(|call| data.get(..4) == Some(call.selector.as_slice())) {
+        Some(call) => abi_string(&data[4..])
+            .map_err(|e| format!("Invalid arguments for {}: {}", call.signature, e)),
+        None => abi_string(&data),
+    }
+}
+
+// How the trigger encoded a wallet. A wallet held as an `address` or `bytes32` arrives as one ABI
+// word, laid out as the contract converted it:
+//
+//   address   the low 20 bytes, as abi.encode(wallet) and bytes32(uint256(uint160(wallet))) give
+//   bytes32   the high 20 bytes, as bytes32(bytes20(wallet)) gives
+//
+// The left-aligned bytes32 is the same word as an address, so a bare word can only report the
+// layout; calldata of a bytes32 function reports bytes32 in either layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WalletEncoding {
+    String,
+    Address,
+    Bytes32,
+}
+
+impl WalletEncoding {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Address => "address",
+            Self::Bytes32 => "bytes32",
+        }
+    }
+}
+
+// The wallet a bare single-wallet input carries: one 32-byte word is an address in either layout,
+// and anything else is decoded as an ABI string, which is never shorter than two words.
+// Addresses from a word are returned checksummed, strings as they were sent.
+pub fn wallet(data: &[u8]) -> Result<(String, WalletEncoding), String> {
+    match <[u8; 32]>::try_from(data) {
+        Ok(word) => {
+            let (address, encoding) = wallet_word(&word)?;
+            Ok((address.to_checksum(None), encoding))
+        }
+        Err(_) => Ok((abi_string(data)?, WalletEncoding::String)),
+    }
+}
+
+// The address in a 32-byte word, from the layout whose 12 padding bytes are zero. A word with
+// neither layout isn't an address.
+pub fn wallet_word(word: &[u8; 32]) -> Result<(Address, WalletEncoding), String> {
+    if word[..12].iter().all(|b| *b == 0) {
+        return Ok((Address::from_slice(&word[12..]), WalletEncoding::Address));
+    }
+    if word[20..].iter().all(|b| *b == 0) {
+        return Ok((Address::from_slice(&word[..20]), WalletEncoding::Bytes32));
+    }
+    Err(format!(
+        "Input word 0x{} is not an address: neither its first nor its last 12 bytes are zero",
+        hex::encode(word)
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_sol_types::{sol, SolCall};
+
+    sol! {
+        function checkPeg(string symbol) external;
+        function checkStatus(string service) external;
+        function trackSupply(address token, uint64 fromBlock, uint64 toBlock) external;
+    }
+
+    const CALLS: [Expected; 2] =
+        [Expected::of::<checkPegCall>(), Expected::of::<checkStatusCall>()];
+
+    fn hex_text(data: &[u8]) -> Vec<u8> {
+        format!("0x{}", hex::encode(data)).into_bytes()
+    }
+
+    #[test]
+    fn input_bytes_passes_binary_input_through() {
+        let data = "USDC".to_string().abi_encode();
+        assert_eq!(input_bytes(&data).unwrap(), data);
+    }
+
+    #[test]
+    fn input_bytes_passes_non_utf8_input_through() {
+        let data = vec![0xff, 0xfe, b'0', b'x'];
+        assert_eq!(input_bytes(&data).unwrap(), data);
+    }
+
+    #[test]
+    fn input_bytes_decodes_hex_text() {
+        assert_eq!(input_bytes(b"0x00ff10").unwrap(), vec![0x00, 0xff, 0x10]);
+        assert_eq!(input_bytes(b"0xABcd").unwrap(), vec![0xab, 0xcd]);
+    }
+
+    #[test]
+    fn input_bytes_treats_bare_prefix_as_empty() {
+        assert_eq!(input_bytes(b"0x").unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn input_bytes_leaves_text_without_prefix_alone() {
+        assert_eq!(input_bytes(b"ff00").unwrap(), b"ff00".to_vec());
+        assert_eq!(input_bytes(b"0Xff").unwrap(), b"0Xff".to_vec());
+        assert_eq!(input_bytes(b" 0xff").unwrap(), b" 0xff".to_vec());
+    }
+
+    #[test]
+    fn input_bytes_rejects_odd_length_hex() {
+        let error = input_bytes(b"0xabc").unwrap_err();
+        assert!(error.starts_with("Failed to decode hex string"), "{}", error);
+    }
+
+    #[test]
+    fn input_bytes_rejects_invalid_hex_digits() {
+        let error = input_bytes(b"0xzz").unwrap_err();
+        assert!(error.starts_with("Failed to decode hex string"), "{}", error);
+    }
+
+    #[test]
+    fn abi_string_decodes() {
+        assert_eq!(abi_string(&"USDC".to_string().abi_encode()).unwrap(), "USDC");
+        assert_eq!(abi_string(&String::new().abi_encode()).unwrap(), "");
+        assert_eq!(abi_string(&"Zürich ☕".to_string().abi_encode()).unwrap(), "Zürich ☕");
+    }
+
+    #[test]
+    fn abi_string_rejects_empty_input() {
+        assert_eq!(abi_string(&[]).unwrap_err(), "Input is empty");
+    }
+
+    #[test]
+    fn abi_string_rejects_invalid_utf8() {
+        let data = Bytes::from(vec![b'U', 0xff, b'C']).abi_encode();
+        let error = abi_string(&data).unwrap_err();
+        assert!(error.starts_with("Input string is not UTF-8"), "{}", error);
+    }
+
+    #[test]
+    fn abi_string_rejects_truncated_encoding() {
+        let data = "a string longer than one word of thirty-two bytes".to_string().abi_encode();
+        let error = abi_string(&data[..data.len() - 32]).unwrap_err();
+        assert!(error.starts_with("Failed to decode input as ABI string"), "{}", error);
+    }
+
+    #[test]
+    fn abi_string_rejects_non_abi_bytes() {
+        assert!(abi_string(b"USDC").is_err());
+    }
+
+    #[test]
+    fn decodes_bare_string() {
+        let data = "USDC".to_string().abi_encode();
+        assert_eq!(decode_abi_string_input(&data, &CALLS).unwrap(), "USDC");
+    }
+
+    #[test]
+    fn decodes_bare_string_as_hex() {
+        let data = hex_text(&"USDC".to_string().abi_encode());
+        assert_eq!(decode_abi_string_input(&data, &CALLS).unwrap(), "USDC");
+    }
+
+    #[test]
+    fn decodes_calldata_of_each_listed_function() {
+        let peg = checkPegCall { symbol: "USDC".to_string() }.abi_encode();
+        assert_eq!(decode_abi_string_input(&peg, &CALLS).unwrap(), "USDC");
+        let status = checkStatusCall { service: "github".to_string() }.abi_encode();
+        assert_eq!(decode_abi_string_input(&status, &CALLS).unwrap(), "github");
+    }
+
+    #[test]
+    fn decodes_calldata_as_hex() {
+        let data = hex_text(&checkPegCall { symbol: "DAI".to_string() }.abi_encode());
+        assert_eq!(decode_abi_string_input(&data, &CALLS).unwrap(), "DAI");
+    }
+
+    #[test]
+    fn calldata_is_not_a_bare_string() {
+        let data = checkPegCall { symbol: "USDC".to_string() }.abi_encode();
+        assert!(decode_abi_string_input(&data, &[]).is_err());
+    }
+
+    #[test]
+    fn rejects_calldata_of_unlisted_function() {
+        let data =
+            trackSupplyCall { token: alloy_primitives::Address::ZERO, fromBlock: 1, toBlock: 2 }
+                .abi_encode();
+        assert!(decode_abi_string_input(&data, &CALLS).is_err());
+    }
+
+    #[test]
+    fn rejects_listed_selector_with_bad_arguments() {
+        let mut data = checkPegCall::SELECTOR.to_vec();
+        data.extend_from_slice(&[0u8; 31]);
+        let error = decode_abi_string_input(&data, &CALLS).unwrap_err();
+        assert!(error.starts_with("Invalid arguments for checkPeg(string)"), "{}", error);
+    }
+
+    #[test]
+    fn rejects_listed_selector_without_arguments() {
+        let error = decode_abi_string_input(&checkPegCall::SELECTOR, &CALLS).unwrap_err();
+        assert_eq!(error, "Invalid arguments for checkPeg(string): Input is empty");
+    }
+
+    #[test]
+    fn rejects_calldata_with_invalid_utf8_argument() {
+        let mut data = checkPegCall::SELECTOR.to_vec();
+        data.extend(Bytes::from(vec![0xc3, 0x28]).abi_encode());
+        let error = decode_abi_string_input(&data, &CALLS).unwrap_err();
+        assert!(error.contains("not UTF-8"), "{}", error);
+    }
+
+    #[test]
+    fn rejects_empty_input() {
+        assert_eq!(decode_abi_string_input(&[], &CALLS).unwrap_err(), "Input is empty");
+        assert_eq!(decode_abi_string_input(b"0x", &CALLS).unwrap_err(), "Input is empty");
+    }
+
+    #[test]
+    fn rejects_odd_length_hex() {
+        let mut data = hex_text(&"USDC".to_string().abi_encode());
+        data.pop();
+        let error = decode_abi_string_input(&data, &CALLS).unwrap_err();
+        assert!(error.starts_with("Failed to decode hex string"), "{}", error);
+    }
+
+    #[test]
+    fn rejects_plain_text() {
+        let error = decode_abi_string_input(b"USDC", &CALLS).unwrap_err();
+        assert!(error.starts_with("Failed to decode input as ABI string"), "{}", error);
+    }
+
+    #[test]
+    fn rejects_short_input() {
+        assert!(decode_abi_string_input(&[0x12, 0x34], &CALLS).is_err());
+    }
+
+    const WALLET: &str = "0xF977814e90dA44bFA03b6295A0616a897441aceC";
+
+    #[test]
+    fn wallet_decodes_string() {
+        let data = WALLET.to_lowercase().abi_encode();
+        assert_eq!(wallet(&data).unwrap(), (WALLET.to_lowercase(), WalletEncoding::String));
+    }
+
+    #[test]
+    fn wallet_decodes_address_word() {
+        let data = WALLET.parse::<Address>().unwrap().abi_encode();
+        assert_eq!(wallet(&data).unwrap(), (WALLET.to_string(), WalletEncoding::Address));
+    }
+
+    #[test]
+    fn wallet_decodes_right_aligned_bytes32() {
+        let mut word = [0u8; 32];
+        word[..20].copy_from_slice(WALLET.parse::<Address>().unwrap().as_slice());
+        assert_eq!(wallet(&word).unwrap(), (WALLET.to_string(), WalletEncoding::Bytes32));
+    }
+
+    #[test]
+    fn wallet_rejects_word_without_padding() {
+        let error = wallet(&[0xff; 32]).unwrap_err();
+        assert!(error.contains("is not an address"), "{}", error);
+    }
+}
@@ -0,0 +1,154 @@
+// Dry runs, asked for with `"options": {"dry_run": true}` in the trigger envelope. The component
+// decodes and validates its input and resolves its configuration the way a real run does, then
+// stops before its first upstream request and answers with a plan of the work instead:
+//
+//   {"dry_run": true, "component": "classifier", "input": {...},
+//    "requests": [{"service": "llm", "host": "api.openai.com", "count": 1}],
+//    "llm": [{"model": "gpt-4o-mini", "calls": 1, "prompt_tokens": 64, ...}],
+//    "estimated_submission_gas": 82000}
+//
+// Nothing is fetched, cached, charged to the spend ceiling or submitted. CLI runs get the plan
+// as their output. On the Ethereum destination it is only logged and the run returns no result,
+// so a contract can check that its payload decodes without anything landing on-chain.
+//
+// Request counts are what a run without failures makes; components that page, scan or fail over
+// report the most they may make. Token counts use llm::estimate_tokens, and the gas estimate is
+// only given when the size of the on-chain payload doesn't depend on upstream data.
+use crate::{canonical_json, canonical_json::Format, gas, info, llm, output::CliOutput, spend};
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(Debug, Serialize)]
+pub struct Plan {
+    dry_run: bool,
+    component: String,
+    input: Value,
+    requests: Vec<Request>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    llm: Vec<LlmEstimate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    estimated_submission_gas: Option<u64>,
+    // what the plan can't know ahead of the run, e.g. prompts built from fetched data
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    notes: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Request {
+    // "rpc", "http" or "llm"
+    service: &'static str,
+    host: String,
+    count: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct LlmEstimate {
+    model: String,
+    calls: u64,
+    // totals over all calls
+    prompt_tokens: u64,
+    completion_tokens: u64,
+    // absent when the model has no known price (see spend.rs)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    estimated_cost_usd: Option<String>,
+}
+
+impl Plan {
+    // `input` is the request as the component decoded and validated it
+    pub fn new<T: Serialize>(component: &str, input: &T) -> Result<Plan, String> {
+        Ok(Plan {
+            dry_run: true,
+            component: component.to_string(),
+            input: serde_json::to_value(input).map_err(|e| e.to_string())?,
+            requests: Vec::new(),
+            llm: Vec::new(),
+            estimated_submission_gas: None,
+            notes: Vec::new(),
+        })
+    }
+
+    pub fn rpc(self, endpoint: &str, count: u64) -> Plan {
+        self.request("rpc", endpoint, count)
+    }
+
+    pub fn http(self, url: &str, count: u64) -> Plan {
+        self.request("http", url, count)
+    }
+
+    // `calls` chat requests to `url`, each with `prompt_tokens` and at most `max_completion_tokens`
+    // (the spend ceiling's completion reserve when the request sets no limit)
+    pub fn llm(
+        mut self,
+        url: &str,
+        model: &str,
+        calls: u64,
+        prompt_tokens: u64,
+        max_completion_tokens: Option<u64>,
+    ) -> Result<Plan, String> {
+        let completion_tokens =
+            max_completion_tokens.unwrap_or_else(spend::completion_reserve_tokens);
+        let estimated_cost_usd =
+            spend::estimate_usd(model, prompt_tokens, completion_tokens, calls)?;
+        self.llm.push(LlmEstimate {
+            model: model.to_string(),
+            calls,
+            prompt_tokens: prompt_tokens.saturating_mul(calls),
+            completion_tokens: completion_tokens.saturating_mul(calls),
+            estimated_cost_usd,
+        });
+        Ok(self.request("llm", url, calls))
+    }
+
+    // `calls` requests of `messages` through the llm module, to the configured provider
+    pub fn chat(self, model: &str, messages: &[llm::Message], calls: u64) -> Result<Plan, String> {
+        let url = llm::Provider::from_env()?.chat_url();
+        let prompt_tokens = llm::estimate_prompt_tokens(messages) as u64;
+        self.llm(url, model, calls, prompt_tokens, None)
+    }
+
+    // `calls` embedding requests through the llm module. Embedding inputs are usually answers
+    // the run has yet to get, so their cost isn't estimated.
+    pub fn embeddings(self, calls: u64) -> Result<Plan, String> {
+        let url = llm::Provider::from_env()?.embeddings_url()?;
+        Ok(self.request("llm", url, calls))
+    }
+
+    // `submission` is what encode_trigger_output would return for a result of the final size
+    pub fn submission(mut self, submission: &[u8]) -> Plan {
+        self.estimated_submission_gas = Some(gas::estimate_submission_gas(submission));
+        self
+    }
+
+    pub fn note(mut self, note: impl Into<String>) -> Plan {
+        self.notes.push(note.into());
+        self
+    }
+
+    // The CLI payload, or None on-chain, where the plan is logged instead of being submitted
+    pub fn output(self, on_chain: bool, format: Format) -> Result<Option<CliOutput<Plan>>, String> {
+        if on_chain {
+            let json = canonical_json::to_vec(&self)?;
+            info!("Dry run, nothing submitted: {}", String::from_utf8_lossy(&json));
+            return Ok(None);
+        }
+        Ok(Some(CliOutput::new(self).format(format)))
+    }
+
+    // Repeated requests to one host are merged, so failover or paging adds up per host
+    fn request(mut self, service: &'static str, url: &str, count: u64) -> Plan {
+        let host = host(url);
+        match self.requests.iter_mut().find(|r| r.service == service && r.host == host) {
+            Some(request) => request.count = request.count.saturating_add(count),
+            None => self.requests.push(Request { service, host, count }),
+        }
+        self
+    }
+}
+
+// Only the host is shown: endpoint paths and queries often carry API keys
+fn host(url: &str) -> String {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host = authority.rsplit_once('@').map_or(authority, |(_, host)| host);
+    host.to_ascii_lowercase()
+}
@@ -0,0 +1,38 @@
+// EIP-712 digests of the ABI output structs, so consumers can verify operator signatures over
+// results with a standard typed-data hash instead of an ad-hoc one. The domain comes from env:
+// - WAVS_ENV_EIP712_NAME (defaults to the component name passed in)
+// - WAVS_ENV_EIP712_VERSION (defaults to "1")
+// - WAVS_ENV_EIP712_CHAIN_ID and WAVS_ENV_EIP712_VERIFYING_CONTRACT (omitted when unset)
+use alloy_primitives::{Address, B256, U256};
+use alloy_sol_types::{Eip712Domain, SolStruct};
+use std::str::FromStr;
+
+pub fn domain_from_env(default_name: &str) -> Result<Eip712Domain, String> {
+    let name = env_var("WAVS_ENV_EIP712_NAME").unwrap_or_else(|| default_name.to_string());
+    let version = env_var("WAVS_ENV_EIP712_VERSION").unwrap_or_else(|| "1".to_string());
+    let chain_id = env_var("WAVS_ENV_EIP712_CHAIN_ID")
+        .map(|v| U256::from_str(&v).map_err(|e| format!("Invalid WAVS_ENV_EIP712_CHAIN_ID: {}", e)))
+        .transpose()?;
+    let verifying_contract = env_var("WAVS_ENV_EIP712_VERIFYING_CONTRACT")
+        .map(|v| {
+            Address::from_str(&v)
+                .map_err(|e| format!("Invalid WAVS_ENV_EIP712_VERIFYING_CONTRACT: {}", e))
+        })
+        .transpose()?;
+
+    Ok(Eip712Domain::new(
+        Some(name.into()),
+        Some(version.into()),
+        chain_id,
+        verifying_contract,
+        None,
+    ))
+}
+
+pub fn digest<T: SolStruct>(value: &T, default_name: &str) -> Result<B256, String> {
+    Ok(value.eip712_signing_hash(&domain_from_env(default_name)?))
+}
+
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|v| !v.is_empty())
+}
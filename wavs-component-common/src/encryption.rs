@@ -0,0 +1,95 @@
+// Results that only one consumer should read. When a trigger's envelope names a recipient's
+// X25519 public key, the JSON result is sealed to it with HPKE (see hpke.rs) before it is
+// wrapped in DataWithId:
+//
+//   {"input": "0x<calldata>", "options": {"encrypt_to": "0x<32-byte X25519 public key>"}}
+//
+// The submitted data is then the ABI encoding of EncryptedResult below, with no associated data
+// and INFO as the HPKE info string. Nothing about the plaintext is submitted next to it.
+//
+// Operators must submit identical payloads, so with several operators the ephemeral key can't be
+// random: it is derived from HMAC-SHA256(WAVS_ENV_ENCRYPTION_SECRET, EPHEMERAL_KEY_DOMAIN,
+// recipient key, result). Operators share the secret, so they all derive the same key, while
+// anyone without it can neither derive the key nor confirm a guessed result by sealing it
+// again. Equal results sealed to the same recipient still give equal ciphertexts. Without the
+// secret the ephemeral key is random, which only suits services run by a single operator.
+//
+//   WAVS_ENV_ENCRYPTION_SECRET   ephemeral key seed shared by the operators, at least 16 bytes
+use crate::{envelope::Options, hpke, output::EthereumOutput};
+use alloy_primitives::{hex, B256};
+use alloy_sol_types::{sol, SolValue};
+use hmac::{Hmac, Mac};
+use rand_chacha::ChaCha20Rng;
+use rand_core::SeedableRng;
+use sha2::Sha256;
+
+const INFO: &[u8] = b"wavs-component-output";
+// Keeps the ephemeral key seed apart from anything else keyed by the same secret
+const EPHEMERAL_KEY_DOMAIN: &[u8] = b"wavs-component-output/ephemeral-key";
+const MIN_SECRET_LEN: usize = 16;
+
+sol! {
+    struct EncryptedResult {
+        bytes32 recipient;
+        bytes encapsulatedKey;
+        bytes ciphertext;
+    }
+}
+
+// The DataWithId data for `result`: sealed when the envelope asks for it, unchanged otherwise
+pub fn seal_result<S>(
+    result: EthereumOutput<S>,
+    options: &Options,
+) -> Result<EthereumOutput<S>, String> {
+    let Some(recipient) = options.encrypt_to.as_deref() else {
+        return Ok(result);
+    };
+    let recipient = parse_recipient(recipient)?;
+    result.wrap(|result| seal(&recipient, &result))
+}
+
+fn seal(recipient: &[u8; 32], result: &[u8]) -> Result<Vec<u8>, String> {
+    let mut rng = ChaCha20Rng::from_seed(ephemeral_seed(recipient, result)?);
+    let sealed = hpke::seal(recipient, &mut rng, INFO, b"", result)?;
+    let encrypted = EncryptedResult {
+        recipient: B256::from(*recipient),
+        encapsulatedKey: sealed.encapsulated_key.to_vec().into(),
+        ciphertext: sealed.ciphertext.into(),
+    };
+    Ok(encrypted.abi_encode())
+}
+
+// What the ephemeral key is derived from: keyed by the operators' shared secret when there is
+// one, drawn at random otherwise
+fn ephemeral_seed(recipient: &[u8; 32], result: &[u8]) -> Result<[u8; 32], String> {
+    let mut seed = [0u8; 32];
+    let secret = std::env::var("WAVS_ENV_ENCRYPTION_SECRET").unwrap_or_default();
+    let secret = secret.trim();
+    if secret.is_empty() {
+        wstd::rand::get_random_bytes(&mut seed);
+        return Ok(seed);
+    }
+    if secret.len() < MIN_SECRET_LEN {
+        return Err(format!(
+            "WAVS_ENV_ENCRYPTION_SECRET must be at least {} bytes",
+            MIN_SECRET_LEN
+        ));
+    }
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC takes any key length");
+    mac.update(EPHEMERAL_KEY_DOMAIN);
+    mac.update(recipient);
+    mac.update(result);
+    seed.copy_from_slice(&mac.finalize().into_bytes());
+    Ok(seed)
+}
+
+fn parse_recipient(key: &str) -> Result<[u8; 32], String> {
+    let key = key.trim();
+    hex::decode(key.strip_prefix("0x").unwrap_or(key))
+        .ok()
+        .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+        .ok_or_else(|| {
+            format!("encrypt_to must be a 32-byte X25519 public key in hex, not '{}'", key)
+        })
+}
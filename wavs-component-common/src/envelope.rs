@@ -0,0 +1,162 @@
+// Optional JSON envelope around a component's trigger input, carrying per-request options
+// without changing any component's input encoding:
+//
+//   {"input": "0x<abi-encoded input>", "options": {"pretty": true}}
+//
+// `input` is the component's usual input: a 0x hex string is decoded to the raw bytes it
+// stands for, anything else is passed on as text. Inputs that aren't a JSON object with an
+// `input` field pass through unchanged with default options, so existing triggers keep working.
+//
+// Envelope formats are versioned. An untagged JSON envelope is version 1 and may say so with
+// `"version": 1`. Other versions are tagged in binary: the bytes "WAVS", a version byte, then
+// that version's payload, which is what a contract can build with
+// `abi.encodePacked("WAVS", uint8(version), payload)`. VERSIONS lists the decoders this build
+// has; a tagged input with any other version is rejected instead of being read as plain input.
+//
+//   1: the JSON envelope above
+//   2: abi.encode(bytes input, string options), with options as the same JSON object; lets
+//      contracts pass raw calldata without hex-encoding it into JSON
+use crate::{canonical_json::Format, filter::Filter};
+use alloy_sol_types::SolValue;
+use serde::Deserialize;
+use serde_json::Value;
+
+const VERSION_TAG: &[u8] = b"WAVS";
+const JSON_VERSION: u8 = 1;
+
+type Decoder = fn(&[u8]) -> Result<(Vec<u8>, Options), String>;
+
+// Append new formats here; existing versions must keep decoding the way they always have
+const VERSIONS: [(u8, &str, Decoder); 2] =
+    [(JSON_VERSION, "JSON envelope", decode_json), (2, "ABI envelope", decode_abi)];
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct Options {
+    // indent CLI output for reading instead of canonical compact JSON
+    pub pretty: bool,
+    // filter expression (see filter.rs) for components that return result rows
+    pub filter: Option<String>,
+    // RPC endpoint for this request only, checked against the operator's allowlist (see rpc.rs)
+    pub rpc_url: Option<String>,
+    // CLI output shape for components that offer more than JSON, e.g. "geojson"
+    pub output_format: Option<String>,
+    // recipient X25519 public key to seal the on-chain result to (see encryption.rs)
+    pub encrypt_to: Option<String>,
+    // submit a commitment to the result instead of the result (see commit_reveal.rs)
+    pub commit: bool,
+    // trigger ID of an earlier commit whose result and salt this trigger reveals
+    pub reveal: Option<u64>,
+    // submit a JSON Patch against the previous result for the same input (see json_patch.rs)
+    pub diff: bool,
+    // validate the input and return a plan of the work instead of running it (see dry_run.rs)
+    pub dry_run: bool,
+}
+
+impl Options {
+    pub fn json_format(&self) -> Format {
+        if self.pretty {
+            Format::Pretty
+        } else {
+            Format::Compact
+        }
+    }
+
+    pub fn row_filter(&self) -> Result<Option<Filter>, String> {
+        self.filter.as_deref().map(Filter::parse).transpose()
+    }
+
+    // Errors for options set in the request that the component doesn't act on, rather than
+    // silently ignoring them
+    pub fn check_supported(&self, supported: &[&str]) -> Result<(), String> {
+        let set = [
+            ("filter", self.filter.is_some()),
+            ("rpc_url", self.rpc_url.is_some()),
+            ("output_format", self.output_format.is_some()),
+            ("encrypt_to", self.encrypt_to.is_some()),
+            ("commit", self.commit),
+            ("reveal", self.reveal.is_some()),
+            ("diff", self.diff),
+            ("dry_run", self.dry_run),
+        ];
+        // A reveal only replays a stored result, so there is nothing to plan
+        if self.dry_run && self.reveal.is_some() {
+            return Err("The dry_run and reveal options can't be combined".to_string());
+        }
+        match set.iter().find(|(name, is_set)| *is_set && !supported.contains(name)) {
+            Some((name, _)) => Err(format!("This component does not support the {} option", name)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Envelope {
+    input: String,
+    #[serde(default)]
+    options: Options,
+    version: Option<u8>,
+}
+
+pub fn unwrap(req: &[u8]) -> Result<(Vec<u8>, Options), String> {
+    if let Some(tagged) = req.strip_prefix(VERSION_TAG) {
+        let (&version, payload) = tagged
+            .split_first()
+            .ok_or_else(|| "Trigger envelope tag has no version".to_string())?;
+        let (_, name, decode) = decoder(version)?;
+        return decode(payload)
+            .map_err(|e| format!("Invalid {} (version {}): {}", name, version, e));
+    }
+    let is_envelope = req.iter().find(|b| !b.is_ascii_whitespace()) == Some(&b'{')
+        && serde_json::from_slice::<Value>(req).is_ok_and(|v| v.get("input").is_some());
+    if !is_envelope {
+        return Ok((req.to_vec(), Options::default()));
+    }
+    // From here on the input is clearly meant as an envelope, so mistakes are reported
+    // instead of falling back to treating it as plain input
+    decode_json(req).map_err(|e| format!("Invalid trigger envelope: {}", e))
+}
+
+fn decoder(version: u8) -> Result<(u8, &'static str, Decoder), String> {
+    VERSIONS.iter().find(|(v, _, _)| *v == version).copied().ok_or_else(|| {
+        let known: Vec<String> =
+            VERSIONS.iter().map(|(v, name, _)| format!("{} ({})", v, name)).collect();
+        format!(
+            "Unsupported trigger envelope version {}; this component supports {}",
+            version,
+            known.join(", ")
+        )
+    })
+}
+
+fn decode_json(payload: &[u8]) -> Result<(Vec<u8>, Options), String> {
+    let envelope: Envelope = serde_json::from_slice(payload).map_err(|e| e.to_string())?;
+    match envelope.version {
+        None | Some(JSON_VERSION) => {}
+        Some(version) => {
+            return Err(match decoder(version) {
+                Ok((_, name, _)) => {
+                    format!("version {} ({}) must be sent with the binary tag", version, name)
+                }
+                Err(e) => e,
+            })
+        }
+    }
+    let input = match envelope.input.strip_prefix("0x") {
+        Some(hex) => alloy_primitives::hex::decode(hex)
+            .map_err(|e| format!("Invalid hex input in trigger envelope: {}", e))?,
+        None => envelope.input.into_bytes(),
+    };
+    Ok((input, envelope.options))
+}
+
+fn decode_abi(payload: &[u8]) -> Result<(Vec<u8>, Options), String> {
+    let (input, options) = <(alloy_primitives::Bytes, String)>::abi_decode_params(payload)
+        .map_err(|e| format!("expected abi.encode(bytes, string): {}", e))?;
+    let options = match options.trim() {
+        "" => Options::default(),
+        json => serde_json::from_str(json).map_err(|e| format!("Invalid options: {}", e))?,
+    };
+    Ok((input.to_vec(), options))
+}
@@ -0,0 +1,261 @@
+// A small SQL-like filter language applied to result rows before they are serialized, so
+// consumers only pay to transport the rows they asked for:
+//
+//   brewery_type == 'micro' && city != 'Portland'
+//   (state == "Oregon" || state == "Washington") && !(longitude > -120)
+//
+// Rows are compared as JSON: fields are top-level keys (dots reach into nested objects) and
+// missing fields are null. `==` only matches values of the same type and `!=` is its negation, so
+// `city != 'Portland'` keeps rows without a city. Strings compare case-sensitively; `<`, `<=`,
+// `>`, `>=` order numbers numerically and strings lexicographically. `&&`/`||`/`!` can also be
+// written `and`/`or`/`not`.
+use serde::Serialize;
+use serde_json::Value;
+
+// Keeps parsing cheap for expressions that arrive in trigger input
+const MAX_EXPRESSION_LEN: usize = 512;
+const MAX_DEPTH: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone)]
+enum Expr {
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Compare { path: Vec<String>, op: Op, value: Value },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Literal(Value),
+    Op(Op),
+    And,
+    Or,
+    Not,
+    Open,
+    Close,
+}
+
+#[derive(Debug, Clone)]
+pub struct Filter {
+    expr: Expr,
+}
+
+impl Filter {
+    pub fn parse(source: &str) -> Result<Filter, String> {
+        if source.len() > MAX_EXPRESSION_LEN {
+            return Err(format!("Filter is longer than {} bytes", MAX_EXPRESSION_LEN));
+        }
+        let tokens = tokenize(source)?;
+        let mut parser = Parser { tokens, pos: 0, depth: 0 };
+        let expr = parser.or()?;
+        if parser.pos < parser.tokens.len() {
+            return Err(format!("Unexpected {:?} in filter", parser.tokens[parser.pos]));
+        }
+        Ok(Filter { expr })
+    }
+
+    pub fn matches(&self, row: &Value) -> bool {
+        eval(&self.expr, row)
+    }
+
+    pub fn retain<T: Serialize>(&self, rows: &mut Vec<T>) -> Result<(), String> {
+        let mut keep = Vec::with_capacity(rows.len());
+        for row in rows.iter() {
+            let value = serde_json::to_value(row)
+                .map_err(|e| format!("Failed to evaluate filter: {}", e))?;
+            keep.push(self.matches(&value));
+        }
+        let mut keep = keep.into_iter();
+        rows.retain(|_| keep.next().unwrap_or(false));
+        Ok(())
+    }
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = source.char_indices().peekable();
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        let rest = &source[start..];
+        let two = rest.get(..2);
+        let (token, len) = match (c, two) {
+            (_, Some("&&")) => (Token::And, 2),
+            (_, Some("||")) => (Token::Or, 2),
+            (_, Some("==")) => (Token::Op(Op::Eq), 2),
+            (_, Some("!=")) => (Token::Op(Op::Ne), 2),
+            (_, Some("<=")) => (Token::Op(Op::Le), 2),
+            (_, Some(">=")) => (Token::Op(Op::Ge), 2),
+            ('<', _) => (Token::Op(Op::Lt), 1),
+            ('>', _) => (Token::Op(Op::Gt), 1),
+            ('!', _) => (Token::Not, 1),
+            ('(', _) => (Token::Open, 1),
+            (')', _) => (Token::Close, 1),
+            ('\'' | '"', _) => {
+                let end = rest[1..]
+                    .find(c)
+                    .ok_or_else(|| format!("Unterminated string at offset {}", start))?;
+                (Token::Literal(Value::String(rest[1..1 + end].to_string())), end + 2)
+            }
+            (c, _) if c.is_ascii_digit() || c == '-' || c == '.' => {
+                let len = rest
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '+' | '.')))
+                    .unwrap_or(rest.len());
+                let number: f64 = rest[..len]
+                    .parse()
+                    .map_err(|_| format!("Invalid number '{}' in filter", &rest[..len]))?;
+                let number = serde_json::Number::from_f64(number)
+                    .ok_or_else(|| format!("Invalid number '{}' in filter", &rest[..len]))?;
+                (Token::Literal(Value::Number(number)), len)
+            }
+            (c, _) if c.is_ascii_alphabetic() || c == '_' => {
+                let len = rest
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+                    .unwrap_or(rest.len());
+                let word = &rest[..len];
+                let token = match word {
+                    "true" => Token::Literal(Value::Bool(true)),
+                    "false" => Token::Literal(Value::Bool(false)),
+                    "null" => Token::Literal(Value::Null),
+                    _ if word.eq_ignore_ascii_case("and") => Token::And,
+                    _ if word.eq_ignore_ascii_case("or") => Token::Or,
+                    _ if word.eq_ignore_ascii_case("not") => Token::Not,
+                    _ => Token::Ident(word.to_string()),
+                };
+                (token, len)
+            }
+            _ => return Err(format!("Unexpected '{}' at offset {} in filter", c, start)),
+        };
+        tokens.push(token);
+        while chars.peek().is_some_and(|&(i, _)| i < start + len) {
+            chars.next();
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    depth: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.tokens.get(self.pos) == Some(token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn or(&mut self) -> Result<Expr, String> {
+        let mut expr = self.and()?;
+        while self.eat(&Token::Or) {
+            expr = Expr::Or(Box::new(expr), Box::new(self.and()?));
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<Expr, String> {
+        let mut expr = self.unary()?;
+        while self.eat(&Token::And) {
+            expr = Expr::And(Box::new(expr), Box::new(self.unary()?));
+        }
+        Ok(expr)
+    }
+
+    fn unary(&mut self) -> Result<Expr, String> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(format!("Filter is nested deeper than {} levels", MAX_DEPTH));
+        }
+        let expr = match self.next() {
+            Some(Token::Not) => Expr::Not(Box::new(self.unary()?)),
+            Some(Token::Open) => {
+                let expr = self.or()?;
+                if !self.eat(&Token::Close) {
+                    return Err("Missing ')' in filter".to_string());
+                }
+                expr
+            }
+            Some(Token::Ident(field)) => {
+                let op = match self.next() {
+                    Some(Token::Op(op)) => op,
+                    other => {
+                        return Err(format!(
+                            "Expected a comparison after '{}', found {:?}",
+                            field, other
+                        ))
+                    }
+                };
+                let value = match self.next() {
+                    Some(Token::Literal(value)) => value,
+                    other => {
+                        return Err(format!(
+                            "Expected a value after '{}', found {:?}",
+                            field, other
+                        ))
+                    }
+                };
+                let path = field.split('.').map(str::to_string).collect();
+                Expr::Compare { path, op, value }
+            }
+            other => return Err(format!("Expected a comparison, found {:?}", other)),
+        };
+        self.depth -= 1;
+        Ok(expr)
+    }
+}
+
+fn eval(expr: &Expr, row: &Value) -> bool {
+    match expr {
+        Expr::And(a, b) => eval(a, row) && eval(b, row),
+        Expr::Or(a, b) => eval(a, row) || eval(b, row),
+        Expr::Not(e) => !eval(e, row),
+        Expr::Compare { path, op, value } => {
+            let field = path.iter().try_fold(row, |v, key| v.get(key)).unwrap_or(&Value::Null);
+            compare(field, *op, value)
+        }
+    }
+}
+
+fn compare(field: &Value, op: Op, value: &Value) -> bool {
+    if op == Op::Ne {
+        return !compare(field, Op::Eq, value);
+    }
+    let ordering = match (field, value) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64().partial_cmp(&b.as_f64()),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        (Value::Bool(a), Value::Bool(b)) if op == Op::Eq => Some(a.cmp(b)),
+        (Value::Null, Value::Null) if op == Op::Eq => Some(std::cmp::Ordering::Equal),
+        _ => None,
+    };
+    ordering.is_some_and(|ordering| match op {
+        Op::Eq | Op::Ne => ordering.is_eq(),
+        Op::Lt => ordering.is_lt(),
+        Op::Le => ordering.is_le(),
+        Op::Gt => ordering.is_gt(),
+        Op::Ge => ordering.is_ge(),
+    })
+}
@@ -0,0 +1,24 @@
+// Freshness policy for oracle results. Each component stamps its output with `expires_at`
+// (unix seconds) so consuming contracts can reject stale data. Components pass a default TTL
+// suited to what they report; operators override it with WAVS_ENV_RESULT_TTL_SECS. Data is fresh
+// while `now <= expires_at`, so a TTL of 0 marks results that are only meaningful for the
+// submission they were produced for.
+pub fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+pub fn ttl_secs(default_ttl_secs: u64) -> u64 {
+    std::env::var("WAVS_ENV_RESULT_TTL_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(default_ttl_secs)
+}
+
+// `observed_at` is when the underlying data was read, which is earlier than now for results
+// served from a cache
+pub fn expires_at(observed_at: u64, default_ttl_secs: u64) -> u64 {
+    observed_at.saturating_add(ttl_secs(default_ttl_secs))
+}
@@ -0,0 +1,58 @@
+// Rough cost of submitting a result on-chain, so operators and aggregators can decide whether a
+// submission is economical: the transaction base cost, calldata for the ABI payload
+// (16 gas per non-zero byte, 4 per zero byte) and a configurable overhead for the handler's
+// signature checks and storage writes (WAVS_ENV_SUBMISSION_GAS_OVERHEAD).
+use crate::canonical_json::{self, Format};
+use crate::timing::{self, PhaseTimings};
+use serde::Serialize;
+
+const TX_BASE_GAS: u64 = 21_000;
+const ZERO_BYTE_GAS: u64 = 4;
+const NONZERO_BYTE_GAS: u64 = 16;
+const DEFAULT_OVERHEAD_GAS: u64 = 60_000;
+
+pub fn calldata_gas(payload: &[u8]) -> u64 {
+    payload.iter().map(|b| if *b == 0 { ZERO_BYTE_GAS } else { NONZERO_BYTE_GAS }).sum()
+}
+
+fn overhead_gas() -> u64 {
+    std::env::var("WAVS_ENV_SUBMISSION_GAS_OVERHEAD")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_OVERHEAD_GAS)
+}
+
+pub fn estimate_submission_gas(payload: &[u8]) -> u64 {
+    TX_BASE_GAS + calldata_gas(payload) + overhead_gas()
+}
+
+// The largest payload whose submission stays within `gas_limit`, counting every byte as non-zero
+pub fn max_payload_bytes(gas_limit: u64) -> usize {
+    (gas_limit.saturating_sub(TX_BASE_GAS + overhead_gas()) / NONZERO_BYTE_GAS) as usize
+}
+
+#[derive(Serialize)]
+struct WithGas<'a, T> {
+    #[serde(flatten)]
+    value: &'a T,
+    estimated_submission_gas: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    phase_timings_ms: Option<PhaseTimings>,
+}
+
+// `value` with an added `estimated_submission_gas` field, plus `phase_timings_ms` within a run.
+// Only JSON objects can be annotated.
+pub fn annotate<T: Serialize>(value: &T, estimated_gas: u64) -> impl Serialize + '_ {
+    WithGas { value, estimated_submission_gas: estimated_gas, phase_timings_ms: timing::current() }
+}
+
+// Serializes the annotated `value` as canonical JSON in the requested format. Values that
+// aren't JSON objects (e.g. arrays) are serialized unchanged.
+pub fn to_json_with_estimate<T: Serialize>(
+    value: &T,
+    estimated_gas: u64,
+    format: Format,
+) -> Result<Vec<u8>, String> {
+    canonical_json::to_vec_with(&annotate(value, estimated_gas), format)
+        .or_else(|_| canonical_json::to_vec_with(value, format))
+}
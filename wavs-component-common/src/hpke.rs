@@ -0,0 +1,166 @@
+// HPKE (RFC 9180) base-mode sealing with DHKEM(X25519, HKDF-SHA256), HKDF-SHA256 and
+// ChaCha20-Poly1305, suite 0x0020/0x0001/0x0003, so recipients can open results with any HPKE
+// library. Only the sender side is here, and it is a thin call into the hpke crate; the tests
+// below pin it to RFC 9180's test vector and open what it seals.
+//
+// HPKE draws the ephemeral key's DeriveKeyPair input from `rng`; encryption.rs decides whether
+// that is random or derived from a secret the operators share.
+use hpke::{
+    aead::ChaCha20Poly1305, kdf::HkdfSha256, kem::X25519HkdfSha256, single_shot_seal,
+    Deserializable, Kem, OpModeS, Serializable,
+};
+use rand_core::{CryptoRng, RngCore};
+
+pub struct Sealed {
+    // the ephemeral public key, HPKE's `enc`
+    pub encapsulated_key: [u8; 32],
+    // ChaCha20-Poly1305 output with the 16-byte tag appended
+    pub ciphertext: Vec<u8>,
+}
+
+pub fn seal(
+    recipient: &[u8; 32],
+    rng: &mut (impl CryptoRng + RngCore),
+    info: &[u8],
+    aad: &[u8],
+    plaintext: &[u8],
+) -> Result<Sealed, String> {
+    let recipient = <X25519HkdfSha256 as Kem>::PublicKey::from_bytes(recipient)
+        .map_err(|e| format!("Recipient public key is not a valid X25519 key: {}", e))?;
+    // Fails on a low-order recipient key, whose shared secret RFC 9180 requires rejecting
+    let sealed = single_shot_seal::<ChaCha20Poly1305, HkdfSha256, X25519HkdfSha256, _>(
+        &OpModeS::Base,
+        &recipient,
+        info,
+        plaintext,
+        aad,
+        rng,
+    );
+    let (encapsulated_key, ciphertext) =
+        sealed.map_err(|e| format!("HPKE sealing failed: {}", e))?;
+    Ok(Sealed { encapsulated_key: encapsulated_key.to_bytes().into(), ciphertext })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::hex;
+    use hpke::{single_shot_open, OpModeR};
+    use rand_chacha::ChaCha20Rng;
+    use rand_core::SeedableRng;
+
+    fn bytes32(s: &str) -> [u8; 32] {
+        hex::decode(s).unwrap().try_into().unwrap()
+    }
+
+    // Hands out a fixed DeriveKeyPair input, so a test vector's ephemeral key is used
+    struct FixedIkm([u8; 32]);
+
+    impl RngCore for FixedIkm {
+        fn next_u32(&mut self) -> u32 {
+            rand_core::impls::next_u32_via_fill(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            rand_core::impls::next_u64_via_fill(self)
+        }
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for (byte, ikm) in dest.iter_mut().zip(self.0.iter().cycle()) {
+                *byte = *ikm;
+            }
+        }
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    impl CryptoRng for FixedIkm {}
+
+    // A recipient key pair, from DeriveKeyPair so the tests don't depend on randomness
+    fn recipient() -> (<X25519HkdfSha256 as Kem>::PrivateKey, [u8; 32]) {
+        let (private_key, public_key) = X25519HkdfSha256::derive_keypair(b"recipient key pair");
+        (private_key, public_key.to_bytes().into())
+    }
+
+    fn open(
+        private_key: &<X25519HkdfSha256 as Kem>::PrivateKey,
+        sealed: &Sealed,
+        info: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>, hpke::HpkeError> {
+        let encapsulated_key =
+            <X25519HkdfSha256 as Kem>::EncappedKey::from_bytes(&sealed.encapsulated_key)?;
+        single_shot_open::<ChaCha20Poly1305, HkdfSha256, X25519HkdfSha256>(
+            &OpModeR::Base,
+            private_key,
+            &encapsulated_key,
+            info,
+            &sealed.ciphertext,
+            aad,
+        )
+    }
+
+    // RFC 9180 appendix A.2.1: DHKEM(X25519, HKDF-SHA256), HKDF-SHA256, ChaCha20-Poly1305,
+    // base mode, sequence number 0
+    #[test]
+    fn seal_matches_rfc9180_base_mode_vector() {
+        let ikm_e = bytes32("909a9b35d3dc4713a5e72a4da274b55d3d3821a37e5d099e74a647db583a904b");
+        let recipient = bytes32("4310ee97d88cc1f088a5576c77ab0cf5c3ac797f3d95139c6c84b5429c59662a");
+        let sealed = seal(
+            &recipient,
+            &mut FixedIkm(ikm_e),
+            b"Ode on a Grecian Urn",
+            b"Count-0",
+            b"Beauty is truth, truth beauty",
+        )
+        .unwrap();
+        assert_eq!(
+            sealed.encapsulated_key,
+            bytes32("1afa08d3dec047a643885163f1180476fa7ddb54c6a8029ea33f95796bf2ac4a")
+        );
+        let expected = hex::decode(concat!(
+            "1c5250d8034ec2b784ba2cfd69dbdb8af406cfe3ff938e131f0def8c8b60b4db",
+            "21993c62ce81883d2dd1b51a28",
+        ))
+        .unwrap();
+        assert_eq!(sealed.ciphertext, expected);
+    }
+
+    #[test]
+    fn sealed_result_opens_with_recipient_key() {
+        let (private_key, public_key) = recipient();
+        let mut rng = ChaCha20Rng::from_seed([1u8; 32]);
+        let result = br#"{"result":1}"#;
+        let sealed = seal(&public_key, &mut rng, b"info", b"aad", result).unwrap();
+        assert_eq!(sealed.ciphertext.len(), result.len() + 16);
+        assert_eq!(open(&private_key, &sealed, b"info", b"aad").unwrap(), result);
+    }
+
+    #[test]
+    fn sealed_result_does_not_open_with_other_info_or_aad() {
+        let (private_key, public_key) = recipient();
+        let mut rng = ChaCha20Rng::from_seed([2u8; 32]);
+        let sealed = seal(&public_key, &mut rng, b"info", b"aad", b"result").unwrap();
+        assert!(open(&private_key, &sealed, b"other info", b"aad").is_err());
+        assert!(open(&private_key, &sealed, b"info", b"other aad").is_err());
+    }
+
+    #[test]
+    fn ephemeral_key_follows_rng() {
+        let (_, public_key) = recipient();
+        let seal_with = |seed: u8| {
+            let mut rng = ChaCha20Rng::from_seed([seed; 32]);
+            seal(&public_key, &mut rng, b"", b"", b"result").unwrap()
+        };
+        let (first, again, other) = (seal_with(3), seal_with(3), seal_with(4));
+        assert_eq!(first.encapsulated_key, again.encapsulated_key);
+        assert_eq!(first.ciphertext, again.ciphertext);
+        assert_ne!(first.encapsulated_key, other.encapsulated_key);
+    }
+
+    #[test]
+    fn seal_rejects_low_order_recipient_key() {
+        let mut rng = ChaCha20Rng::from_seed([5u8; 32]);
+        assert!(seal(&[0u8; 32], &mut rng, b"", b"", b"result").is_err());
+    }
+}
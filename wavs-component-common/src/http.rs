@@ -0,0 +1,114 @@
+// The client every outgoing request of a run is sent through: replay.rs's fetches, its RPC
+// transport and oauth.rs's token requests.
+//
+// Connections can't be reused from here. wasi:http has no connection handle: each request is
+// handed to the host on its own, and the host decides how to connect. The wasmtime-wasi-http
+// host WAVS runs components in opens a fresh connection, TLS handshake included, for every
+// request, and rejects Connection and Keep-Alive headers from guests. A run that reads a balance,
+// its decimals and a price therefore pays three handshakes whatever the client does, so requests
+// are counted per host in `phase_timings_ms` to show where they add up. What the client does
+// control are the wasi:http request options:
+//
+//   WAVS_ENV_HTTP_CONNECT_TIMEOUT_MS      time to connect, TLS included (unset: the host's)
+//   WAVS_ENV_HTTP_FIRST_BYTE_TIMEOUT_MS   time from sending to the first response byte
+//
+// A slow upstream then fails fast instead of taking the run's whole time budget.
+//
+// Operators in restricted networks can also limit where requests go and route them:
+//
+//   WAVS_ENV_HTTP_ALLOWED_HOSTS   hosts requests may go to, in the syntax of rpc.rs's allowlist
+//                                 (unset: any); a request to another fails before it is sent
+//   WAVS_ENV_HTTP_PROXY           http(s) origin of an egress gateway every request is sent to
+//   WAVS_ENV_HTTP_NO_PROXY        hosts, in the same syntax, that are still reached directly
+//
+// The gateway can't be a plain forward proxy: wasi:http has no CONNECT tunnel or absolute-form
+// request target, and the host rejects Host and Proxy-* headers. It gets the original path and
+// query instead, with the destination in x-forwarded-proto and x-forwarded-host, and forwards
+// there itself. The allowlist is checked against the destination, not the gateway.
+use crate::rpc;
+use std::time::Duration;
+use wstd::http::error::ErrorVariant;
+use wstd::http::{
+    body::IncomingBody, Body, Client, Error, HeaderValue, PathAndQuery, Request, Response, Result,
+    Uri,
+};
+
+fn timeout(name: &str) -> Option<Duration> {
+    let millis = std::env::var(name).ok()?.trim().parse::<u64>().ok()?;
+    Some(Duration::from_millis(millis))
+}
+
+fn client() -> Client {
+    let mut client = Client::new();
+    if let Some(timeout) = timeout("WAVS_ENV_HTTP_CONNECT_TIMEOUT_MS") {
+        client.set_connect_timeout(timeout);
+    }
+    if let Some(timeout) = timeout("WAVS_ENV_HTTP_FIRST_BYTE_TIMEOUT_MS") {
+        client.set_first_byte_timeout(timeout);
+    }
+    client
+}
+
+// Callers time the exchange with timing::record_upstream once they have read the body
+pub async fn send<B: Body>(request: Request<B>) -> Result<Response<IncomingBody>> {
+    client().send(route(request)?).await
+}
+
+fn hosts(name: &str) -> Option<String> {
+    std::env::var(name).ok().map(|v| v.trim().to_string()).filter(|v| !v.is_empty())
+}
+
+fn listed(hosts: &str, host: &str) -> bool {
+    hosts.split(',').map(str::trim).any(|pattern| rpc::host_matches(pattern, host))
+}
+
+fn denied(message: String) -> Error {
+    ErrorVariant::Other(message).into()
+}
+
+fn route<B>(mut request: Request<B>) -> Result<Request<B>> {
+    let host = request.uri().host().unwrap_or_default().to_ascii_lowercase();
+    if hosts("WAVS_ENV_HTTP_ALLOWED_HOSTS").is_some_and(|allowed| !listed(&allowed, &host)) {
+        return Err(denied(format!("Host {} is not in WAVS_ENV_HTTP_ALLOWED_HOSTS", host)));
+    }
+    let Some(proxy) = hosts("WAVS_ENV_HTTP_PROXY") else {
+        return Ok(request);
+    };
+    if hosts("WAVS_ENV_HTTP_NO_PROXY").is_some_and(|direct| listed(&direct, &host)) {
+        return Ok(request);
+    }
+
+    // Not echoed back, since a malformed URL may still carry credentials
+    let invalid = || denied("WAVS_ENV_HTTP_PROXY must be an http(s) origin".to_string());
+    let proxy = proxy.parse::<Uri>().map_err(|_| invalid())?;
+    let (Some(scheme), Some(authority)) = (proxy.scheme(), proxy.authority()) else {
+        return Err(invalid());
+    };
+    if !matches!(scheme.as_str(), "http" | "https")
+        || authority.as_str().contains('@')
+        || !matches!(proxy.path(), "" | "/")
+        || proxy.query().is_some()
+    {
+        return Err(invalid());
+    }
+
+    let original = request.uri().clone();
+    let routed = Uri::builder()
+        .scheme(scheme.clone())
+        .authority(authority.clone())
+        .path_and_query(
+            original.path_and_query().cloned().unwrap_or(PathAndQuery::from_static("/")),
+        )
+        .build()
+        .map_err(|e| denied(format!("Failed to route {} through the proxy: {}", host, e)))?;
+    *request.uri_mut() = routed;
+    let headers = request.headers_mut();
+    headers.insert(
+        "x-forwarded-proto",
+        HeaderValue::from_str(original.scheme_str().unwrap_or("https"))?,
+    );
+    if let Some(authority) = original.authority() {
+        headers.insert("x-forwarded-host", HeaderValue::from_str(authority.as_str())?);
+    }
+    Ok(request)
+}
@@ -0,0 +1,111 @@
+// RFC 6902 JSON Patch output for repeated lookups. With the `diff` option a component submits
+// the changes since its previous result for the same request instead of the full document:
+//
+//   {"input": "0x<calldata>", "options": {"diff": true}}
+//
+// The output is a ResultPatch: `patch` turns the previous result, whose canonical JSON hashes to
+// `base_hash`, into the new one, whose hash is `result_hash`. The first run for a request has no
+// base, so `base_hash` is null and the patch adds the whole document at the root. Consumers should
+// check `base_hash` against the result they hold and fetch the full document when it differs.
+//
+// Previous results are kept in the shared cache (WAVS_ENV_CACHE_DIR), keyed by the component and
+// the request input, and replaced with the new result on every run. Operators that missed a run
+// diff against an older base, so their patches differ from the others' until the next run.
+use crate::{cache, canonical_json, envelope::Options};
+use alloy_primitives::{hex, keccak256};
+use serde::Serialize;
+use serde_json::{json, Value};
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ResultPatch {
+    base_hash: Option<String>,
+    result_hash: String,
+    patch: Vec<Value>,
+}
+
+// The result to submit: `result` itself, or a ResultPatch against the previous result for
+// `request` when the trigger asks for one. The new result becomes the base either way.
+pub fn apply(
+    options: &Options,
+    component: &str,
+    request: &[u8],
+    result: Value,
+) -> Result<Value, String> {
+    if !options.diff {
+        return Ok(result);
+    }
+    if !cache::enabled() {
+        return Err("diff needs WAVS_ENV_CACHE_DIR to keep the previous result".to_string());
+    }
+    let key = storage_key(component, request);
+    let previous = cache::get::<Value>(&key).map(|entry| entry.value);
+
+    let patch = match &previous {
+        Some(previous) => diff(previous, &result),
+        None => vec![json!({"op": "add", "path": "", "value": result})],
+    };
+    let output = ResultPatch {
+        base_hash: previous.as_ref().map(hash).transpose()?,
+        result_hash: hash(&result)?,
+        patch,
+    };
+    cache::put(&key, &result).map_err(|e| format!("Failed to store the result: {}", e))?;
+    serde_json::to_value(&output).map_err(|e| e.to_string())
+}
+
+// Operations turning `from` into `to`. Object members are visited in canonical key order and
+// array elements by index; removals from an array run from its end so earlier indices stay valid.
+// Arrays are not searched for moved elements, so an insertion near the front replaces every
+// element after it.
+pub fn diff(from: &Value, to: &Value) -> Vec<Value> {
+    let mut ops = Vec::new();
+    diff_at("", from, to, &mut ops);
+    ops
+}
+
+fn diff_at(path: &str, from: &Value, to: &Value, ops: &mut Vec<Value>) {
+    if from == to {
+        return;
+    }
+    match (from, to) {
+        (Value::Object(old), Value::Object(new)) => {
+            let mut keys: Vec<&String> = old.keys().chain(new.keys()).collect();
+            keys.sort_by(|a, b| a.as_bytes().cmp(b.as_bytes()));
+            keys.dedup();
+            for key in keys {
+                let member = format!("{}/{}", path, escape(key));
+                match (old.get(key), new.get(key)) {
+                    (Some(a), Some(b)) => diff_at(&member, a, b, ops),
+                    (Some(_), None) => ops.push(json!({"op": "remove", "path": member})),
+                    (None, Some(b)) => ops.push(json!({"op": "add", "path": member, "value": b})),
+                    (None, None) => {}
+                }
+            }
+        }
+        (Value::Array(old), Value::Array(new)) => {
+            for (index, (a, b)) in old.iter().zip(new).enumerate() {
+                diff_at(&format!("{}/{}", path, index), a, b, ops);
+            }
+            for index in (new.len()..old.len()).rev() {
+                ops.push(json!({"op": "remove", "path": format!("{}/{}", path, index)}));
+            }
+            for (index, b) in new.iter().enumerate().skip(old.len()) {
+                ops.push(json!({"op": "add", "path": format!("{}/{}", path, index), "value": b}));
+            }
+        }
+        _ => ops.push(json!({"op": "replace", "path": path, "value": to})),
+    }
+}
+
+// RFC 6901 reference tokens escape "~" as "~0" and "/" as "~1"
+fn escape(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+fn hash(value: &Value) -> Result<String, String> {
+    Ok(keccak256(canonical_json::to_vec(value)?).to_string())
+}
+
+fn storage_key(component: &str, request: &[u8]) -> String {
+    hex::encode(keccak256(format!("json-patch\n{}\n{}", component, hex::encode(request))))
+}
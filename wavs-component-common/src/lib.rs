@@ -0,0 +1,47 @@
+// Helpers shared by the components in this repository. Nothing here may depend on the
+// generated bindings, since every component carries its own copy of them.
+pub mod batch;
+pub mod bls;
+pub mod budget;
+pub mod cache;
+pub mod canonical_json;
+pub mod chains;
+pub mod commit_reveal;
+pub mod contact;
+pub mod dead_letter;
+pub mod decode;
+pub mod dry_run;
+pub mod eip712;
+pub mod encryption;
+pub mod envelope;
+pub mod filter;
+pub mod freshness;
+pub mod gas;
+mod hpke;
+pub mod http;
+pub mod json_patch;
+pub mod llm;
+pub mod log;
+pub mod maintenance;
+pub mod multicall;
+pub mod oauth;
+pub mod origin;
+pub mod output;
+pub mod partial;
+pub mod price;
+pub mod privacy;
+pub mod provenance;
+pub mod reorg;
+pub mod replay;
+pub mod rpc;
+pub mod run;
+pub mod sampling;
+pub mod sanctions;
+pub mod selector;
+pub mod spend;
+pub mod timestamp;
+pub mod timing;
+pub mod truncation;
+pub mod version;
+
+pub use decode::decode_abi_string_input;
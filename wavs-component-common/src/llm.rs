@@ -0,0 +1,411 @@
+// Chat completions for components that use the model as one step of a larger run.
+// chat_json asks for strict structured output, so the answer is guaranteed to match the schema
+// and can be deserialized directly instead of being scraped out of free text. embed returns
+// embedding vectors, e.g. to score how similar two answers are with cosine_similarity.
+//
+// WAVS_ENV_LLM_PROVIDER picks the API: `openai` (default), `cohere` or `mistral`, each with its
+// own key (WAVS_ENV_OPENAI_KEY, WAVS_ENV_COHERE_KEY, WAVS_ENV_MISTRAL_KEY). Mistral speaks the
+// OpenAI wire format; Cohere goes through its v2 chat API. Providers differ in what they
+// support, see Capabilities.
+use crate::{replay, spend};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use wavs_wasi_utils::http::http_request_post_json;
+use wstd::http::{Body, HeaderValue, Request};
+
+const OPENAI_CHAT_URL: &str = "https://api.openai.com/v1/chat/completions";
+const OPENAI_EMBEDDINGS_URL: &str = "https://api.openai.com/v1/embeddings";
+const MISTRAL_CHAT_URL: &str = "https://api.mistral.ai/v1/chat/completions";
+const MISTRAL_EMBEDDINGS_URL: &str = "https://api.mistral.ai/v1/embeddings";
+const COHERE_CHAT_URL: &str = "https://api.cohere.com/v2/chat";
+// Rough size of a token for pre-flight checks; every provider's tokenizer differs
+const CHARS_PER_TOKEN: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Provider {
+    OpenAi,
+    Cohere,
+    Mistral,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Capabilities {
+    // chat_json answers are constrained to the schema by the API
+    pub json_schema: bool,
+    // embed is available; Cohere's embed API takes a different request and isn't wired up
+    pub embeddings: bool,
+    // context window of the provider's smallest current chat model, in tokens
+    pub max_context_tokens: usize,
+}
+
+impl Provider {
+    pub fn from_env() -> Result<Provider, String> {
+        match std::env::var("WAVS_ENV_LLM_PROVIDER").as_deref().map(str::trim) {
+            Err(_) | Ok("") | Ok("openai") => Ok(Provider::OpenAi),
+            Ok("cohere") => Ok(Provider::Cohere),
+            Ok("mistral") => Ok(Provider::Mistral),
+            Ok(other) => Err(format!(
+                "WAVS_ENV_LLM_PROVIDER must be openai, cohere or mistral, not '{}'",
+                other
+            )),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Provider::OpenAi => "OpenAI",
+            Provider::Cohere => "Cohere",
+            Provider::Mistral => "Mistral",
+        }
+    }
+
+    pub fn capabilities(self) -> Capabilities {
+        match self {
+            Provider::OpenAi => {
+                Capabilities { json_schema: true, embeddings: true, max_context_tokens: 128_000 }
+            }
+            Provider::Cohere => {
+                Capabilities { json_schema: true, embeddings: false, max_context_tokens: 128_000 }
+            }
+            Provider::Mistral => {
+                Capabilities { json_schema: true, embeddings: true, max_context_tokens: 32_000 }
+            }
+        }
+    }
+
+    // Used when the component's own default is an OpenAI model name
+    pub fn default_model(self) -> &'static str {
+        match self {
+            Provider::OpenAi => "gpt-4o-mini",
+            Provider::Cohere => "command-r-08-2024",
+            Provider::Mistral => "mistral-small-latest",
+        }
+    }
+
+    pub fn chat_url(self) -> &'static str {
+        match self {
+            Provider::OpenAi => OPENAI_CHAT_URL,
+            Provider::Cohere => COHERE_CHAT_URL,
+            Provider::Mistral => MISTRAL_CHAT_URL,
+        }
+    }
+
+    pub fn embeddings_url(self) -> Result<&'static str, String> {
+        match self {
+            Provider::OpenAi => Ok(OPENAI_EMBEDDINGS_URL),
+            Provider::Mistral => Ok(MISTRAL_EMBEDDINGS_URL),
+            Provider::Cohere => Err(format!("Embeddings are not supported for {}", self.name())),
+        }
+    }
+
+    fn key_var(self) -> &'static str {
+        match self {
+            Provider::OpenAi => "OPENAI_KEY",
+            Provider::Cohere => "COHERE_KEY",
+            Provider::Mistral => "MISTRAL_KEY",
+        }
+    }
+}
+
+pub fn capabilities() -> Result<Capabilities, String> {
+    Ok(Provider::from_env()?.capabilities())
+}
+
+// The configured model if there is one, otherwise `openai_default` for OpenAI and the
+// provider's own default for the others, so switching providers doesn't send gpt names to them
+pub fn resolve_model(configured: Option<String>, openai_default: &str) -> Result<String, String> {
+    if let Some(model) = configured.filter(|m| !m.trim().is_empty()) {
+        return Ok(model);
+    }
+    Ok(match Provider::from_env()? {
+        Provider::OpenAi => openai_default.to_string(),
+        provider => provider.default_model().to_string(),
+    })
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Message {
+    pub role: String,
+    pub content: String,
+}
+
+impl Message {
+    pub fn system(content: impl Into<String>) -> Message {
+        Message { role: "system".to_string(), content: content.into() }
+    }
+
+    pub fn user(content: impl Into<String>) -> Message {
+        Message { role: "user".to_string(), content: content.into() }
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct ChatRequest<'a> {
+    model: &'a str,
+    messages: &'a [Message],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_format: Option<Value>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+struct ChatResponse {
+    choices: Vec<Choice>,
+    error: Option<ApiError>,
+    // Mistral reports errors as a top-level message instead of an error object
+    message: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+struct Choice {
+    message: Option<ResponseMessage>,
+    finish_reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+struct ResponseMessage {
+    content: Option<String>,
+    // set instead of content when the model declines to answer in the schema
+    refusal: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct EmbeddingRequest<'a> {
+    model: &'a str,
+    input: &'a [&'a str],
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+struct EmbeddingResponse {
+    data: Vec<Embedding>,
+    error: Option<ApiError>,
+    message: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+struct Embedding {
+    index: usize,
+    embedding: Vec<f64>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+struct ApiError {
+    message: Option<String>,
+    code: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+struct CohereResponse {
+    message: Option<CohereMessage>,
+    finish_reason: Option<String>,
+}
+
+// Cohere uses `message` both for the reply and, as a plain string, for errors
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+enum CohereMessage {
+    Error(String),
+    Reply { content: Vec<CohereContent> },
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+struct CohereContent {
+    text: Option<String>,
+}
+
+pub async fn chat(model: &str, messages: &[Message]) -> Result<String, String> {
+    let (content, _) =
+        complete(ChatRequest { model, messages, temperature: None, response_format: None }).await?;
+    Ok(content)
+}
+
+// `schema` must follow the strict-mode rules: every property listed in `required` and
+// `additionalProperties: false` on every object. Runs at temperature 0 so operators answering
+// the same input are as likely as possible to agree.
+pub async fn chat_json(
+    model: &str,
+    messages: &[Message],
+    schema_name: &str,
+    schema: Value,
+) -> Result<Value, String> {
+    let provider = Provider::from_env()?;
+    if !provider.capabilities().json_schema {
+        return Err(format!("{} does not support schema-constrained answers", provider.name()));
+    }
+    let response_format = match provider {
+        Provider::Cohere => serde_json::json!({ "type": "json_object", "json_schema": schema }),
+        _ => serde_json::json!({
+            "type": "json_schema",
+            "json_schema": { "name": schema_name, "strict": true, "schema": schema },
+        }),
+    };
+    let (content, truncated) = complete(ChatRequest {
+        model,
+        messages,
+        temperature: Some(0.0),
+        response_format: Some(response_format),
+    })
+    .await?;
+    // A truncated structured answer is not valid JSON, so say why instead of failing to parse
+    if truncated {
+        return Err("Model output was cut off at the token limit".to_string());
+    }
+    serde_json::from_str(&content)
+        .map_err(|e| format!("Model output does not match the {} schema: {}", schema_name, e))
+}
+
+// One embedding per input, in input order. Check Capabilities::embeddings first to skip
+// embeddings on providers that don't offer them.
+pub async fn embed(model: &str, inputs: &[&str]) -> Result<Vec<Vec<f64>>, String> {
+    let provider = Provider::from_env()?;
+    let url = provider.embeddings_url()?;
+    let req = authorized_post(provider, url, &EmbeddingRequest { model, input: inputs })?;
+    let response: EmbeddingResponse =
+        replay::fetch_json(req).await.map_err(|e| format!("Failed to fetch embeddings: {}", e))?;
+    if let Some(error) = response.error {
+        return Err(api_error(provider, error));
+    }
+    if let Some(message) = response.message.filter(|_| response.data.is_empty()) {
+        return Err(format!("{} API error: {}", provider.name(), message));
+    }
+    let mut data = response.data;
+    if data.len() != inputs.len() {
+        return Err(format!("Expected {} embeddings, received {}", inputs.len(), data.len()));
+    }
+    data.sort_by_key(|e| e.index);
+    Ok(data.into_iter().map(|e| e.embedding).collect())
+}
+
+pub fn cosine_similarity(a: &[f64], b: &[f64]) -> Result<f64, String> {
+    if a.len() != b.len() || a.is_empty() {
+        return Err(format!("Cannot compare embeddings of sizes {} and {}", a.len(), b.len()));
+    }
+    let dot: f64 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm = |v: &[f64]| v.iter().map(|x| x * x).sum::<f64>().sqrt();
+    let norms = norm(a) * norm(b);
+    if norms == 0.0 {
+        return Err("Cannot compare a zero embedding".to_string());
+    }
+    Ok(dot / norms)
+}
+
+fn authorized_post(
+    provider: Provider,
+    url: &str,
+    body: &impl Serialize,
+) -> Result<Request<impl Body>, String> {
+    let api_key = std::env::var(format!("WAVS_ENV_{}", provider.key_var()))
+        .map_err(|_| format!("Failed to get {} from environment variables", provider.key_var()))?;
+    let mut req = http_request_post_json(url, body)
+        .map_err(|e| format!("Failed to create request: {}", e))?;
+    req.headers_mut().insert(
+        "Authorization",
+        HeaderValue::from_str(&format!("Bearer {}", api_key))
+            .map_err(|e| format!("Failed to create Authorization header: {}", e))?,
+    );
+    req.headers_mut().insert("Content-Type", HeaderValue::from_static("application/json"));
+    Ok(req)
+}
+
+fn api_error(provider: Provider, error: ApiError) -> String {
+    format!(
+        "{} API error ({}): {}",
+        provider.name(),
+        error.code.unwrap_or_else(|| "unknown".to_string()),
+        error.message.unwrap_or_default()
+    )
+}
+
+// Returns the answer and whether it was cut off at the token limit. Requests that clearly
+// exceed the provider's context window, or the run's spend ceiling, are refused before anything
+// is sent. The call is charged to the ceiling with estimated token counts, since usage isn't read
+// from the responses.
+async fn complete(request: ChatRequest<'_>) -> Result<(String, bool), String> {
+    let provider = Provider::from_env()?;
+    let prompt_tokens = estimate_prompt_tokens(request.messages);
+    let max_context_tokens = provider.capabilities().max_context_tokens;
+    if prompt_tokens > max_context_tokens {
+        return Err(format!(
+            "Request is about {} tokens, over {}'s {} token context",
+            prompt_tokens,
+            provider.name(),
+            max_context_tokens
+        ));
+    }
+    spend::check(request.model, prompt_tokens as u64)?;
+    let (content, truncated) = match provider {
+        Provider::OpenAi | Provider::Mistral => {
+            complete_openai(provider, provider.chat_url(), &request).await?
+        }
+        Provider::Cohere => complete_cohere(&request).await?,
+    };
+    spend::record(request.model, prompt_tokens as u64, estimate_tokens(&content) as u64);
+    Ok((content, truncated))
+}
+
+// Rough token count for pre-flight checks, for components without a tokenizer of their own
+pub fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(CHARS_PER_TOKEN)
+}
+
+pub fn estimate_prompt_tokens(messages: &[Message]) -> usize {
+    messages.iter().map(|m| estimate_tokens(&m.content)).sum()
+}
+
+async fn complete_openai(
+    provider: Provider,
+    url: &str,
+    request: &ChatRequest<'_>,
+) -> Result<(String, bool), String> {
+    let req = authorized_post(provider, url, request)?;
+    let response: ChatResponse =
+        replay::fetch_json(req).await.map_err(|e| format!("Failed to fetch data: {}", e))?;
+    if let Some(error) = response.error {
+        return Err(api_error(provider, error));
+    }
+    let no_response = || format!("No response received from {}", provider.name());
+
+    let Some(choice) = response.choices.into_iter().next() else {
+        return Err(match response.message {
+            Some(message) => format!("{} API error: {}", provider.name(), message),
+            None => no_response(),
+        });
+    };
+    let message = choice.message.unwrap_or_default();
+    if let Some(refusal) = message.refusal {
+        return Err(format!("Model refused the request: {}", refusal));
+    }
+    let truncated = choice.finish_reason.as_deref() == Some("length");
+    let content = message.content.ok_or_else(no_response)?;
+    Ok((content, truncated))
+}
+
+// The v2 chat API takes the same role/content messages; the reply is a list of content blocks
+async fn complete_cohere(request: &ChatRequest<'_>) -> Result<(String, bool), String> {
+    let req = authorized_post(Provider::Cohere, Provider::Cohere.chat_url(), request)?;
+    let response: CohereResponse =
+        replay::fetch_json(req).await.map_err(|e| format!("Failed to fetch data: {}", e))?;
+    let content = match response.message {
+        Some(CohereMessage::Reply { content }) => {
+            content.into_iter().filter_map(|c| c.text).collect::<String>()
+        }
+        Some(CohereMessage::Error(message)) => {
+            return Err(format!("Cohere API error: {}", message))
+        }
+        None => return Err("No response received from Cohere".to_string()),
+    };
+    if content.is_empty() {
+        return Err("No response received from Cohere".to_string());
+    }
+    let truncated = response.finish_reason.as_deref() == Some("MAX_TOKENS");
+    Ok((content, truncated))
+}
@@ -0,0 +1,129 @@
+// Leveled logging for components. WAVS_ENV_LOG_LEVEL picks the most verbose level printed
+// (error, warn, info or debug; default info). Every line is redacted before it is written:
+// values of secret env vars (`*_KEY`, `*_SECRET`, `*_TOKEN`, `*_PASSWORD`), OpenAI-style
+// `sk-` keys and bearer tokens are masked, and 20-byte hex addresses are middle-truncated.
+const REDACTED: &str = "[REDACTED]";
+const SECRET_SUFFIXES: [&str; 4] = ["_KEY", "_SECRET", "_TOKEN", "_PASSWORD"];
+// Short values (flags, numbers) are too likely to appear in ordinary text to mask
+const MIN_SECRET_LEN: usize = 8;
+const MIN_SK_KEY_LEN: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+}
+
+impl Level {
+    fn as_str(self) -> &'static str {
+        match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+        }
+    }
+}
+
+pub fn max_level() -> Level {
+    match std::env::var("WAVS_ENV_LOG_LEVEL").map(|v| v.to_ascii_lowercase()).as_deref() {
+        Ok("error") => Level::Error,
+        Ok("warn") => Level::Warn,
+        Ok("debug") => Level::Debug,
+        _ => Level::Info,
+    }
+}
+
+pub fn write(level: Level, message: &str) {
+    if level <= max_level() {
+        println!("[{}] {}", level.as_str(), redact(message));
+    }
+}
+
+pub fn redact(message: &str) -> String {
+    scrub(message, true)
+}
+
+// Masks secrets but leaves addresses intact, for text that must stay unambiguous
+pub fn redact_secrets(message: &str) -> String {
+    scrub(message, false)
+}
+
+fn scrub(message: &str, truncate_addresses: bool) -> String {
+    let mut out = message.to_string();
+    for (name, value) in std::env::vars() {
+        if value.len() >= MIN_SECRET_LEN && SECRET_SUFFIXES.iter().any(|s| name.ends_with(s)) {
+            out = out.replace(&value, REDACTED);
+        }
+    }
+
+    // Walk runs of token characters so patterns only match whole words
+    let mut result = String::with_capacity(out.len());
+    let mut previous_word = "";
+    let mut rest = out.as_str();
+    while !rest.is_empty() {
+        // Bearer tokens (e.g. JWTs) may contain dots, so they run to the next whitespace
+        let word_len = if previous_word.eq_ignore_ascii_case("bearer") {
+            rest.find(char::is_whitespace).unwrap_or(rest.len())
+        } else {
+            rest.find(|c: char| !is_word_char(c)).unwrap_or(rest.len())
+        };
+        if word_len == 0 {
+            let c = rest.chars().next().unwrap_or_default();
+            result.push(c);
+            rest = &rest[c.len_utf8()..];
+            if c != ' ' {
+                previous_word = "";
+            }
+            continue;
+        }
+        let word = &rest[..word_len];
+        if previous_word.eq_ignore_ascii_case("bearer") {
+            result.push_str(REDACTED);
+        } else if word.starts_with("sk-") && word.len() >= MIN_SK_KEY_LEN {
+            result.push_str("sk-");
+            result.push_str(REDACTED);
+        } else if truncate_addresses && is_address(word) {
+            result.push_str(&word[..6]);
+            result.push('…');
+            result.push_str(&word[38..]);
+        } else {
+            result.push_str(word);
+        }
+        previous_word = word;
+        rest = &rest[word_len..];
+    }
+    result
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '-'
+}
+
+fn is_address(word: &str) -> bool {
+    word.len() == 42
+        && (word.starts_with("0x") || word.starts_with("0X"))
+        && word[2..].chars().all(|c| c.is_ascii_hexdigit())
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)*) => { $crate::log::write($crate::log::Level::Error, &format!($($arg)*)) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)*) => { $crate::log::write($crate::log::Level::Warn, &format!($($arg)*)) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => { $crate::log::write($crate::log::Level::Info, &format!($($arg)*)) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => { $crate::log::write($crate::log::Level::Debug, &format!($($arg)*)) };
+}
@@ -0,0 +1,64 @@
+// Maintenance mode, the operator's kill switch. With WAVS_ENV_MAINTENANCE=true a component answers
+// every trigger with a maintenance response as soon as the trigger event is decoded: the
+// envelope isn't read and no upstream is contacted, so operators can drain traffic while they
+// rotate API keys or wait out a provider incident. WAVS_ENV_MAINTENANCE_MESSAGE says why, e.g.
+// "Rotating the Coingecko key until 14:00 UTC".
+//
+// Ethereum runs submit a maintenance record as the DataWithId payload, ABI-encoded like a call to
+//
+//   componentInMaintenance(string component, string message)
+//
+// so contracts tell it apart from a result, or a dead-letter record, by its 4-byte selector and
+// can send the trigger again later. CLI runs print the record as JSON:
+//
+//   {"maintenance": true, "message": "...", ...the versioned! fields}
+use crate::{log, output::EthereumOutput};
+use alloy_sol_types::sol;
+use serde::Serialize;
+
+const DEFAULT_MESSAGE: &str = "Component is in maintenance";
+// Same bound as dead-letter reasons
+const MAX_MESSAGE_BYTES: usize = 256;
+
+sol! {
+    function componentInMaintenance(string component, string message) external;
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct Maintenance {
+    maintenance: bool,
+    pub message: String,
+}
+
+impl Maintenance {
+    pub fn record(&self, component: &str) -> EthereumOutput<componentInMaintenanceCall> {
+        EthereumOutput::call(&componentInMaintenanceCall {
+            component: component.to_string(),
+            message: self.message.clone(),
+        })
+    }
+}
+
+// The response to give instead of running, or None when the component is in service
+pub fn current() -> Option<Maintenance> {
+    if !matches!(std::env::var("WAVS_ENV_MAINTENANCE").as_deref(), Ok("1") | Ok("true")) {
+        return None;
+    }
+    let message = std::env::var("WAVS_ENV_MAINTENANCE_MESSAGE")
+        .ok()
+        .map(|message| message.trim().to_string())
+        .filter(|message| !message.is_empty())
+        .unwrap_or_else(|| DEFAULT_MESSAGE.to_string());
+    Some(Maintenance { maintenance: true, message: truncate(log::redact_secrets(&message)) })
+}
+
+fn truncate(mut message: String) -> String {
+    if message.len() > MAX_MESSAGE_BYTES {
+        let mut end = MAX_MESSAGE_BYTES;
+        while !message.is_char_boundary(end) {
+            end -= 1;
+        }
+        message.truncate(end);
+    }
+    message
+}
@@ -0,0 +1,137 @@
+// Multicall3 batching: any number of view calls go out as one aggregate3 eth_call, so they cost
+// one round trip and are all evaluated against the same block. Each call is added with
+// allowFailure set, so a function one contract version doesn't have reverts on its own and the
+// batch still answers:
+//
+//   let mut batch = multicall::Batch::new();
+//   let owner = batch.add(wallet, &ownerCall {});
+//   let answers = batch.run(&provider, block_number).await?;
+//   let owner = answers.get(owner);   // None when ownerCall reverted or didn't decode
+//
+// Multicall3 is deployed at the same address on nearly every EVM chain;
+// WAVS_ENV_MULTICALL_ADDRESS points elsewhere on one where it isn't. The whole batch still fails
+// as one eth_call, e.g. when the node rejects it or caps eth_call gas below what it needs.
+use crate::rpc;
+use alloy_network::{Network, TransactionBuilder};
+use alloy_primitives::{address, Address};
+use alloy_provider::Provider;
+use alloy_rpc_types::BlockId;
+use alloy_sol_types::{sol, SolCall};
+use std::marker::PhantomData;
+
+pub const DEFAULT_ADDRESS: Address = address!("cA11bde05977b3631167028862bE2a173976CA11");
+
+sol! {
+    interface IMulticall3 {
+        struct Call3 {
+            address target;
+            bool allowFailure;
+            bytes callData;
+        }
+        struct Result {
+            bool success;
+            bytes returnData;
+        }
+        function aggregate3(Call3[] calldata calls) external payable returns (Result[] memory returnData);
+        function getEthBalance(address addr) external view returns (uint256 balance);
+        function getCurrentBlockTimestamp() external view returns (uint256 timestamp);
+    }
+}
+
+pub fn address() -> Result<Address, String> {
+    match std::env::var("WAVS_ENV_MULTICALL_ADDRESS").as_deref().map(str::trim) {
+        Err(_) | Ok("") => Ok(DEFAULT_ADDRESS),
+        Ok(address) => address
+            .parse::<Address>()
+            .map_err(|e| format!("Invalid WAVS_ENV_MULTICALL_ADDRESS '{}': {}", address, e)),
+    }
+}
+
+// Where one call's answer will be, typed by the call so it decodes as the call's return
+pub struct Slot<C> {
+    index: usize,
+    call: PhantomData<fn() -> C>,
+}
+
+impl<C> Clone for Slot<C> {
+    fn clone(&self) -> Slot<C> {
+        *self
+    }
+}
+
+impl<C> Copy for Slot<C> {}
+
+#[derive(Clone, Default)]
+pub struct Batch {
+    calls: Vec<IMulticall3::Call3>,
+}
+
+impl Batch {
+    pub fn new() -> Batch {
+        Batch::default()
+    }
+
+    pub fn add<C: SolCall>(&mut self, target: Address, call: &C) -> Slot<C> {
+        self.calls.push(IMulticall3::Call3 {
+            target,
+            allowFailure: true,
+            callData: call.abi_encode().into(),
+        });
+        Slot { index: self.calls.len() - 1, call: PhantomData }
+    }
+
+    // Calls of Multicall3 itself, e.g. getEthBalance
+    pub fn add_own<C: SolCall>(&mut self, call: &C) -> Result<Slot<C>, String> {
+        Ok(self.add(address()?, call))
+    }
+
+    // The aggregate3 calldata the batch is sent as
+    pub fn calldata(&self) -> Vec<u8> {
+        IMulticall3::aggregate3Call { calls: self.calls.clone() }.abi_encode()
+    }
+
+    pub async fn run<N: Network>(
+        self,
+        provider: &impl Provider<N>,
+        block_number: u64,
+    ) -> Result<Answers, String> {
+        let tx = N::TransactionRequest::default().with_to(address()?).with_input(self.calldata());
+        let data = provider
+            .call(tx)
+            .block(BlockId::number(block_number))
+            .await
+            .map_err(|e| rpc::describe("Multicall3 aggregate3 failed", &e))?;
+        if data.is_empty() {
+            return Err(format!(
+                "Multicall3 returned no data; is it deployed at {}? (WAVS_ENV_MULTICALL_ADDRESS)",
+                address()?
+            ));
+        }
+        let results = IMulticall3::aggregate3Call::abi_decode_returns(&data)
+            .map_err(|e| format!("Failed to decode the Multicall3 answer: {}", e))?;
+        if results.len() != self.calls.len() {
+            return Err(format!(
+                "Multicall3 answered {} of {} calls",
+                results.len(),
+                self.calls.len()
+            ));
+        }
+        Ok(Answers { results })
+    }
+}
+
+pub struct Answers {
+    results: Vec<IMulticall3::Result>,
+}
+
+impl Answers {
+    // None when the call reverted, returned nothing (an account without code) or returned data
+    // that doesn't decode as the call's return type
+    pub fn get<C: SolCall>(&self, slot: Slot<C>) -> Option<C::Return> {
+        let result = self.results.get(slot.index)?;
+        if !result.success || result.returnData.is_empty() {
+            return None;
+        }
+        C::abi_decode_returns(&result.returnData).ok()
+    }
+}
@@ -0,0 +1,275 @@
+// OAuth 2.0 client-credentials tokens (RFC 6749 section 4.4), for APIs that only accept a
+// short-lived bearer token, usually a JWT, minted from a client secret. A component picks an env
+// prefix and the operator configures the client under it:
+//
+//   WAVS_ENV_<PREFIX>_OAUTH_TOKEN_URL       token endpoint, https
+//   WAVS_ENV_<PREFIX>_OAUTH_CLIENT_ID
+//   WAVS_ENV_<PREFIX>_OAUTH_CLIENT_SECRET
+//   WAVS_ENV_<PREFIX>_OAUTH_SCOPE           optional, space-separated scopes
+//   WAVS_ENV_<PREFIX>_OAUTH_AUDIENCE        optional, for providers that require one (Auth0)
+//   WAVS_ENV_<PREFIX>_OAUTH_AUTH_METHOD     basic (default) sends the credentials as HTTP Basic
+//                                           auth, post sends them in the form body
+//
+// A token is reused until REFRESH_MARGIN_SECS before it expires: within the run, and across runs
+// through the cache when WAVS_ENV_CACHE_DIR is set. Its lifetime is the response's `expires_in`,
+// else the JWT's `exp` claim, else DEFAULT_TOKEN_TTL_SECS. Cache entries are keyed by a hash of
+// the whole client config, so a rotated secret never picks up a token minted with the old one.
+//
+// Token requests are not recorded in replay bundles, which would then hold a live token. Replays
+// answer the API requests from the bundle without looking at their headers, so in replay mode no
+// token is requested at all.
+use crate::{cache, dry_run::Plan, freshness::now_secs, http, info, replay, timing, warn};
+use alloy_primitives::{hex, keccak256};
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{cell::RefCell, collections::HashMap, time::Instant};
+use wstd::{
+    http::{HeaderValue, IntoBody, Request},
+    io::AsyncRead,
+};
+
+// Refreshed this long before expiry, so a token doesn't run out between here and the API
+const REFRESH_MARGIN_SECS: u64 = 60;
+const DEFAULT_TOKEN_TTL_SECS: u64 = 300;
+const MAX_ERROR_BODY_CHARS: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AuthMethod {
+    Basic,
+    Post,
+}
+
+#[derive(Debug, Clone)]
+pub struct Client {
+    token_url: String,
+    client_id: String,
+    client_secret: String,
+    scope: Option<String>,
+    audience: Option<String>,
+    auth_method: AuthMethod,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct CachedToken {
+    access_token: String,
+    expires_at: u64,
+}
+
+impl CachedToken {
+    fn is_fresh(&self) -> bool {
+        now_secs().saturating_add(REFRESH_MARGIN_SECS) < self.expires_at
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    token_type: Option<String>,
+    #[serde(default)]
+    expires_in: Option<u64>,
+}
+
+thread_local! {
+    static TOKENS: RefCell<HashMap<String, CachedToken>> = RefCell::new(HashMap::new());
+}
+
+impl Client {
+    // None when `<PREFIX>_OAUTH_TOKEN_URL` is unset, i.e. the API needs no token
+    pub fn from_env(prefix: &str) -> Result<Option<Client>, String> {
+        let var = |name: &str| {
+            std::env::var(format!("WAVS_ENV_{}_OAUTH_{}", prefix, name))
+                .ok()
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty())
+        };
+        let Some(token_url) = var("TOKEN_URL") else {
+            return Ok(None);
+        };
+        if !token_url.starts_with("https://") {
+            return Err(format!("WAVS_ENV_{}_OAUTH_TOKEN_URL must be an https URL", prefix));
+        }
+        let required = |name: &str| {
+            var(name).ok_or_else(|| {
+                format!("WAVS_ENV_{}_OAUTH_{} must be set with a token URL", prefix, name)
+            })
+        };
+        let auth_method = match var("AUTH_METHOD").as_deref() {
+            None | Some("basic") => AuthMethod::Basic,
+            Some("post") => AuthMethod::Post,
+            Some(other) => {
+                return Err(format!(
+                    "WAVS_ENV_{}_OAUTH_AUTH_METHOD must be basic or post, not '{}'",
+                    prefix, other
+                ))
+            }
+        };
+        Ok(Some(Client {
+            token_url,
+            client_id: required("CLIENT_ID")?,
+            client_secret: required("CLIENT_SECRET")?,
+            scope: var("SCOPE"),
+            audience: var("AUDIENCE"),
+            auth_method,
+        }))
+    }
+
+    // Sets the request's Authorization header, minting a token first when none is fresh
+    pub async fn authorize<B>(&self, request: &mut Request<B>) -> Result<(), String> {
+        let token = self.access_token().await?;
+        let mut value = HeaderValue::from_str(&format!("Bearer {}", token))
+            .map_err(|e| format!("Failed to create Authorization header: {}", e))?;
+        value.set_sensitive(true);
+        request.headers_mut().insert("Authorization", value);
+        Ok(())
+    }
+
+    // The token request a run would make, for dry-run plans
+    pub fn planned(&self, plan: Plan) -> Plan {
+        if replay::mode() == replay::Mode::Replay || self.cached().is_some() {
+            return plan;
+        }
+        plan.http(&self.token_url, 1)
+    }
+
+    async fn access_token(&self) -> Result<String, String> {
+        if replay::mode() == replay::Mode::Replay {
+            return Ok("replayed".to_string());
+        }
+        if let Some(token) = self.cached() {
+            return Ok(token.access_token);
+        }
+        let token = self.mint().await?;
+        let key = self.cache_key();
+        if let Err(e) = cache::put(&key, &token) {
+            warn!("Failed to cache OAuth token: {}", e);
+        }
+        TOKENS.with(|tokens| tokens.borrow_mut().insert(key, token.clone()));
+        Ok(token.access_token)
+    }
+
+    fn cached(&self) -> Option<CachedToken> {
+        let key = self.cache_key();
+        TOKENS
+            .with(|tokens| tokens.borrow().get(&key).cloned())
+            .or_else(|| cache::get::<CachedToken>(&key).map(|entry| entry.value))
+            .filter(CachedToken::is_fresh)
+    }
+
+    fn cache_key(&self) -> String {
+        let config = [
+            "oauth",
+            &self.token_url,
+            &self.client_id,
+            &self.client_secret,
+            self.scope.as_deref().unwrap_or_default(),
+            self.audience.as_deref().unwrap_or_default(),
+        ];
+        hex::encode(keccak256(config.join("\n")))
+    }
+
+    async fn mint(&self) -> Result<CachedToken, String> {
+        let mut form = vec![("grant_type", "client_credentials")];
+        if let Some(scope) = &self.scope {
+            form.push(("scope", scope));
+        }
+        if let Some(audience) = &self.audience {
+            form.push(("audience", audience));
+        }
+        if self.auth_method == AuthMethod::Post {
+            form.push(("client_id", &self.client_id));
+            form.push(("client_secret", &self.client_secret));
+        }
+        let body: Vec<String> =
+            form.iter().map(|(name, value)| format!("{}={}", name, form_encode(value))).collect();
+
+        let mut request = Request::post(&self.token_url)
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .header("Accept", "application/json")
+            .body(body.join("&").into_body())
+            .map_err(|e| format!("Failed to create token request: {}", e))?;
+        if self.auth_method == AuthMethod::Basic {
+            // RFC 6749 section 2.3.1: both halves are form-encoded before the Basic encoding
+            let credentials =
+                format!("{}:{}", form_encode(&self.client_id), form_encode(&self.client_secret));
+            let mut value = HeaderValue::from_str(&format!(
+                "Basic {}",
+                base64::engine::general_purpose::STANDARD.encode(credentials)
+            ))
+            .map_err(|e| format!("Failed to create token request: {}", e))?;
+            value.set_sensitive(true);
+            request.headers_mut().insert("Authorization", value);
+        }
+
+        let started = Instant::now();
+        let host = request.uri().host().unwrap_or_default().to_string();
+        let mut response =
+            http::send(request).await.map_err(|e| format!("Token request failed: {}", e))?;
+        let mut bytes = Vec::new();
+        response
+            .body_mut()
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(|e| format!("Failed to read token response: {}", e))?;
+        timing::record_upstream(&host, started.elapsed());
+        if !response.status().is_success() {
+            return Err(format!(
+                "Token endpoint answered {}: {}",
+                response.status().as_u16(),
+                token_error(&bytes)
+            ));
+        }
+        let token: TokenResponse =
+            serde_json::from_slice(&bytes).map_err(|e| format!("Invalid token response: {}", e))?;
+        if let Some(token_type) = token.token_type.as_deref() {
+            if !token_type.eq_ignore_ascii_case("bearer") {
+                return Err(format!("Unsupported token type '{}', expected Bearer", token_type));
+            }
+        }
+
+        let now = now_secs();
+        let expires_at = match token.expires_in {
+            Some(expires_in) => now.saturating_add(expires_in),
+            None => jwt_expiry(&token.access_token)
+                .unwrap_or_else(|| now.saturating_add(DEFAULT_TOKEN_TTL_SECS)),
+        };
+        info!("Minted an OAuth token valid for {}s", expires_at.saturating_sub(now));
+        Ok(CachedToken { access_token: token.access_token, expires_at })
+    }
+}
+
+// The OAuth error code and description when the endpoint sent them (RFC 6749 section 5.2), or
+// the start of the body. Neither carries the secret.
+fn token_error(body: &[u8]) -> String {
+    let error = serde_json::from_slice::<Value>(body).ok();
+    let field = |name: &str| error.as_ref()?.get(name)?.as_str().map(str::to_string);
+    match (field("error"), field("error_description")) {
+        (Some(code), Some(description)) => format!("{} ({})", code, description),
+        (Some(code), None) => code,
+        _ => String::from_utf8_lossy(body).chars().take(MAX_ERROR_BODY_CHARS).collect(),
+    }
+}
+
+// The `exp` claim of a JWT, read without verifying the signature: it only decides when to
+// refresh, and the API checks the token itself
+fn jwt_expiry(token: &str) -> Option<u64> {
+    let payload = token.split('.').nth(1)?;
+    let bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD.decode(payload).ok()?;
+    serde_json::from_slice::<Value>(&bytes).ok()?.get("exp")?.as_u64()
+}
+
+// application/x-www-form-urlencoded, which keeps only unreserved characters as they are
+fn form_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            b' ' => encoded.push('+'),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
@@ -0,0 +1,50 @@
+// The chain event a run was triggered by. decode_trigger_event returns it and run() records it
+// here for the rest of the run; versioned! then adds it to JSON outputs as `trigger`, so a
+// submitted result can be traced back to the event that asked for it. CLI runs have no event
+// and their outputs carry no `trigger` field.
+//
+// The layer-trigger world (0.4.0-beta.4) passes the event's chain, contract and block but not
+// its transaction hash or log index, so those can't be reported. The chain's id comes from the
+// host's chain config, and is missing when the host has none for the chain.
+use serde::Serialize;
+use std::cell::RefCell;
+
+thread_local! {
+    static CURRENT: RefCell<Option<TriggerOrigin>> = const { RefCell::new(None) };
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct TriggerOrigin {
+    pub trigger_id: u64,
+    pub chain_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chain_id: Option<u64>,
+    pub contract_address: String,
+    pub block_number: u64,
+}
+
+impl TriggerOrigin {
+    pub fn new(chain_name: String, contract_address: &[u8], block_number: u64) -> TriggerOrigin {
+        let contract_address = match alloy_primitives::Address::try_from(contract_address) {
+            Ok(address) => address.to_checksum(None),
+            Err(_) => format!("0x{}", alloy_primitives::hex::encode(contract_address)),
+        };
+        TriggerOrigin { trigger_id: 0, chain_name, chain_id: None, contract_address, block_number }
+    }
+
+    pub fn with_trigger(mut self, trigger_id: u64, chain_id: Option<u64>) -> TriggerOrigin {
+        self.trigger_id = trigger_id;
+        self.chain_id = chain_id;
+        self
+    }
+}
+
+pub fn record(origin: Option<TriggerOrigin>) {
+    crate::timestamp::start_run();
+    crate::provenance::start_run();
+    CURRENT.with(|current| *current.borrow_mut() = origin);
+}
+
+pub fn current() -> Option<TriggerOrigin> {
+    CURRENT.with(|current| current.borrow().clone())
+}
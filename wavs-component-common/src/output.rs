@@ -0,0 +1,142 @@
+// Result payloads, typed by destination. Contracts decode the DataWithId data of an Ethereum run,
+// which has to be byte-identical across operators for their submissions to aggregate. CLI runs
+// print the same result for people instead: annotated with the gas estimate, phase timings and
+// the operator's clock (see timestamp.rs), and pretty-printed when the envelope asks for it.
+// The two are different types, and each component's trigger.rs only turns an EthereumOutput
+// into a submission and a CliOutput into a CLI response, so a CLI rendering can't reach a
+// contract. An EthereumOutput holds one of:
+//
+//   EthereumOutput::abi(&value)       an ABI-encoded Solidity value, S being its type
+//   EthereumOutput::json(&value)      compact canonical JSON (see canonical_json.rs), S = Json
+//   EthereumOutput::call(&call)       calldata, for the maintenance and dead-letter records
+//
+// or one of those sealed by encryption.rs or committed by commit_reveal.rs, which keep S: it is
+// the schema of the result once decrypted or revealed.
+use crate::canonical_json::{self, Format};
+use crate::gas;
+use crate::provenance;
+use crate::timestamp;
+use alloy_sol_types::{SolCall, SolValue};
+use serde::Serialize;
+use std::marker::PhantomData;
+
+// Schema of results submitted as canonical JSON
+#[derive(Debug, Clone, Copy)]
+pub struct Json;
+
+// Schema of ABI values whose Solidity type is only known at run time
+#[derive(Debug, Clone, Copy)]
+pub struct Dynamic;
+
+#[derive(Debug, Clone)]
+pub struct EthereumOutput<S> {
+    data: Vec<u8>,
+    schema: PhantomData<fn() -> S>,
+}
+
+impl<S> EthereumOutput<S> {
+    fn new(data: Vec<u8>) -> EthereumOutput<S> {
+        EthereumOutput { data, schema: PhantomData }
+    }
+
+    // The DataWithId data
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+
+    // Records the data as the run's result for its provenance (see provenance.rs), for ABI
+    // results, which have no field to carry it
+    pub fn traced(self) -> EthereumOutput<S> {
+        provenance::record(&self.data);
+        self
+    }
+
+    // Replaces the data with what a contract opens to get it back, keeping S
+    pub(crate) fn wrap(
+        self,
+        wrap: impl FnOnce(Vec<u8>) -> Result<Vec<u8>, String>,
+    ) -> Result<EthereumOutput<S>, String> {
+        Ok(EthereumOutput::new(wrap(self.data)?))
+    }
+}
+
+impl<S: SolValue> EthereumOutput<S> {
+    pub fn abi(value: &S) -> EthereumOutput<S> {
+        EthereumOutput::new(value.abi_encode())
+    }
+
+    pub fn into_dynamic(self) -> EthereumOutput<Dynamic> {
+        EthereumOutput::new(self.data)
+    }
+}
+
+impl<C: SolCall> EthereumOutput<C> {
+    pub fn call(call: &C) -> EthereumOutput<C> {
+        EthereumOutput::new(call.abi_encode())
+    }
+}
+
+impl EthereumOutput<Json> {
+    pub fn json<T: Serialize>(value: &T) -> Result<EthereumOutput<Json>, String> {
+        Ok(EthereumOutput::new(canonical_json::to_vec(value)?))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CliOutput<T> {
+    value: T,
+    estimated_gas: Option<u64>,
+    format: Format,
+    canonical: bool,
+}
+
+impl<T: Serialize> CliOutput<T> {
+    // Compact canonical JSON of `value` unless told otherwise
+    pub fn new(value: T) -> CliOutput<T> {
+        CliOutput { value, estimated_gas: None, format: Format::Compact, canonical: true }
+    }
+
+    // Adds `estimated_submission_gas` and, within a run, `phase_timings_ms` (see gas::annotate)
+    pub fn with_estimate(mut self, estimated_gas: u64) -> CliOutput<T> {
+        self.estimated_gas = Some(estimated_gas);
+        self
+    }
+
+    pub fn format(mut self, format: Format) -> CliOutput<T> {
+        self.format = format;
+        self
+    }
+
+    // serde_json's rendering instead of canonical JSON, for formats whose floats have to stay
+    // numbers (GeoJSON coordinates)
+    pub fn plain_json(mut self) -> CliOutput<T> {
+        self.canonical = false;
+        self
+    }
+
+    pub fn to_vec(&self) -> Result<Vec<u8>, String> {
+        let mut value = serde_json::to_value(&self.value)
+            .map_err(|e| format!("Failed to serialize the output: {}", e))?;
+        timestamp::add_clock(&mut value);
+        if self.canonical {
+            return match self.estimated_gas {
+                Some(estimated_gas) => {
+                    gas::to_json_with_estimate(&value, estimated_gas, self.format)
+                }
+                None => canonical_json::to_vec_with(&value, self.format),
+            };
+        }
+        let annotated = self.estimated_gas.map(|gas| gas::annotate(&value, gas));
+        let json = match (&annotated, self.format) {
+            (Some(annotated), Format::Compact) => serde_json::to_vec(annotated),
+            (Some(annotated), Format::Pretty) => serde_json::to_vec_pretty(annotated),
+            (None, Format::Compact) => serde_json::to_vec(&value),
+            (None, Format::Pretty) => serde_json::to_vec_pretty(&value),
+        };
+        json.map_err(|e| format!("Failed to serialize the output: {}", e))
+    }
+}
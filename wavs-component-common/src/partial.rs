@@ -0,0 +1,109 @@
+// Partial results for runs made of several upstream requests, such as the pages of a listing or
+// one read per wallet. Each request is retried a few times; when it still fails, the run keeps
+// what the other requests returned and reports the failure next to it instead of failing:
+//
+//   {..., "partial": true, "failure": {"step": "page 3", "kind": "timeout"}}
+//
+// Only the step and the kind of failure are reported, in rpc.rs's categories. The error text and
+// the number of attempts depend on the operator's endpoint and settings, so they are logged
+// instead of making operators' results differ. Components decide what a step is and which
+// failures are fatal, typically the first page, without which there is nothing to report.
+//
+//   WAVS_ENV_RETRY_ATTEMPTS   tries per step, 2 unless set, at most 5
+use crate::rpc::{RpcError, RpcErrorKind};
+use crate::warn;
+use alloy_transport::TransportError;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::future::Future;
+use std::time::Duration;
+
+const DEFAULT_ATTEMPTS: u32 = 2;
+const MAX_ATTEMPTS: u32 = 5;
+// Doubled after every failed attempt
+const INITIAL_BACKOFF_MS: u64 = 250;
+
+// A failed attempt, typed so retrying doesn't depend on how the error reads
+#[derive(Debug, Clone)]
+pub struct StepError {
+    pub kind: RpcErrorKind,
+    pub message: String,
+}
+
+impl StepError {
+    pub fn rpc(context: &str, error: &TransportError) -> StepError {
+        let error = RpcError::classify(error);
+        StepError { kind: error.kind, message: format!("{} {}", context, error) }
+    }
+
+    // HTTP fetches, whose errors don't tell a down endpoint from a bad answer; both are retried
+    pub fn http(message: impl Display) -> StepError {
+        StepError { kind: RpcErrorKind::Unavailable, message: message.to_string() }
+    }
+
+    // Failures a retry can't fix, e.g. an answer that doesn't decode
+    pub fn other(message: impl Display) -> StepError {
+        StepError { kind: RpcErrorKind::Other, message: message.to_string() }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Failure {
+    // what failed, e.g. "page 3" or a wallet address
+    pub step: String,
+    pub kind: RpcErrorKind,
+    #[serde(skip)]
+    pub attempts: u32,
+    // the last attempt's error
+    #[serde(skip)]
+    pub error: String,
+}
+
+impl Failure {
+    // For steps whose failure leaves nothing to report
+    pub fn into_error(self) -> String {
+        format!("{} failed after {} attempts: {}", self.step, self.attempts, self.error)
+    }
+}
+
+pub fn attempts() -> u32 {
+    std::env::var("WAVS_ENV_RETRY_ATTEMPTS")
+        .ok()
+        .and_then(|v| v.trim().parse::<u32>().ok())
+        .unwrap_or(DEFAULT_ATTEMPTS)
+        .clamp(1, MAX_ATTEMPTS)
+}
+
+// Runs `step` until it succeeds, the attempts are used up or it fails for good
+pub async fn retry<T, F, Fut>(name: &str, mut step: F) -> Result<T, Failure>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, StepError>>,
+{
+    let attempts = attempts();
+    let mut backoff = Duration::from_millis(INITIAL_BACKOFF_MS);
+    let mut attempt = 1;
+    loop {
+        match step().await {
+            Ok(value) => return Ok(value),
+            Err(error) if attempt >= attempts || !error.kind.retriable() => {
+                warn!("{} failed after {} attempts: {}", name, attempt, error.message);
+                return Err(Failure {
+                    step: name.to_string(),
+                    kind: error.kind,
+                    attempts: attempt,
+                    error: error.message,
+                });
+            }
+            Err(error) => {
+                warn!(
+                    "{} failed (attempt {} of {}), retrying: {}",
+                    name, attempt, attempts, error.message
+                );
+                wstd::task::sleep(backoff.into()).await;
+                backoff *= 2;
+                attempt += 1;
+            }
+        }
+    }
+}
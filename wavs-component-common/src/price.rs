@@ -0,0 +1,121 @@
+// USD quotes from the HTTP price APIs. Coingecko is asked first; its free tier rate-limits
+// readily, so when it fails and WAVS_ENV_CMC_API_KEY is set the quote is taken from
+// CoinMarketCap instead. Callers record the source that served each quote alongside it.
+//
+//   WAVS_ENV_COINGECKO_API_KEY   sent as Coingecko's demo API key header (optional)
+//   WAVS_ENV_CMC_API_KEY         enables the CoinMarketCap fallback
+//
+// Quotes are returned as the decimal strings the APIs sent, so each component parses them into
+// its own fixed-point scale and no float reaches an output.
+use crate::{dry_run::Plan, replay, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use wavs_wasi_utils::http::http_request_get;
+use wstd::http::HeaderValue;
+
+const COINGECKO_URL: &str = "https://api.coingecko.com/api/v3/simple/price";
+const CMC_URL: &str = "https://pro-api.coinmarketcap.com/v2/cryptocurrency/quotes/latest";
+
+// An asset as both APIs name it
+pub struct Asset {
+    pub coingecko_id: &'static str,
+    // CoinMarketCap's numeric id, since symbols are not unique there
+    pub cmc_id: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Source {
+    Coingecko,
+    Coinmarketcap,
+}
+
+#[derive(Debug, Clone)]
+pub struct Quote {
+    pub price: String,
+    pub source: Source,
+}
+
+fn cmc_api_key() -> Option<String> {
+    std::env::var("WAVS_ENV_CMC_API_KEY").ok().filter(|key| !key.trim().is_empty())
+}
+
+fn coingecko_url(asset: &Asset) -> String {
+    format!("{}?ids={}&vs_currencies=usd", COINGECKO_URL, asset.coingecko_id)
+}
+
+fn cmc_url(asset: &Asset) -> String {
+    format!("{}?id={}&convert=USD", CMC_URL, asset.cmc_id)
+}
+
+// The Coingecko quote, and the CoinMarketCap one a failure may fall back to
+pub fn planned(plan: Plan, asset: &Asset) -> Plan {
+    let plan = plan.http(&coingecko_url(asset), 1);
+    match cmc_api_key() {
+        Some(_) => plan.http(&cmc_url(asset), 1),
+        None => plan,
+    }
+}
+
+pub async fn usd_quote(asset: &Asset) -> Result<Quote, String> {
+    let coingecko = match coingecko_price(asset).await {
+        Ok(price) => return Ok(Quote { price, source: Source::Coingecko }),
+        Err(e) => e,
+    };
+    let Some(api_key) = cmc_api_key() else {
+        return Err(coingecko);
+    };
+    warn!("{}, falling back to CoinMarketCap", coingecko);
+    let price = cmc_price(asset, &api_key)
+        .await
+        .map_err(|e| format!("{}; CoinMarketCap fallback failed: {}", coingecko, e))?;
+    Ok(Quote { price, source: Source::Coinmarketcap })
+}
+
+async fn coingecko_price(asset: &Asset) -> Result<String, String> {
+    let mut req = http_request_get(&coingecko_url(asset))
+        .map_err(|e| format!("Failed to create request: {}", e))?;
+    req.headers_mut().insert("Accept", HeaderValue::from_static("application/json"));
+    if let Ok(api_key) = std::env::var("WAVS_ENV_COINGECKO_API_KEY") {
+        req.headers_mut().insert(
+            "x-cg-demo-api-key",
+            HeaderValue::from_str(&api_key)
+                .map_err(|e| format!("Failed to set Coingecko API key header: {}", e))?,
+        );
+    }
+
+    let body: Value = replay::fetch_json(req)
+        .await
+        .map_err(|e| format!("Failed to fetch Coingecko price: {}", e))?;
+    // Some errors come back as a JSON body with the reason under `status`
+    if let Some(message) = body.pointer("/status/error_message").and_then(Value::as_str) {
+        return Err(format!("Coingecko error: {}", message));
+    }
+    body.get(asset.coingecko_id)
+        .and_then(|v| v.get("usd"))
+        .and_then(|v| v.as_number())
+        .map(|price| price.to_string())
+        .ok_or_else(|| format!("Coingecko response has no {}.usd price", asset.coingecko_id))
+}
+
+async fn cmc_price(asset: &Asset, api_key: &str) -> Result<String, String> {
+    let mut req = http_request_get(&cmc_url(asset))
+        .map_err(|e| format!("Failed to create request: {}", e))?;
+    req.headers_mut().insert("Accept", HeaderValue::from_static("application/json"));
+    req.headers_mut().insert(
+        "X-CMC_PRO_API_KEY",
+        HeaderValue::from_str(api_key)
+            .map_err(|e| format!("Failed to set CoinMarketCap API key header: {}", e))?,
+    );
+
+    let body: Value = replay::fetch_json(req)
+        .await
+        .map_err(|e| format!("Failed to fetch CoinMarketCap price: {}", e))?;
+    if let Some(message) = body.pointer("/status/error_message").and_then(Value::as_str) {
+        return Err(format!("CoinMarketCap error: {}", message));
+    }
+    body.pointer(&format!("/data/{}/quote/USD/price", asset.cmc_id))
+        .and_then(|v| v.as_number())
+        .map(|price| price.to_string())
+        .ok_or_else(|| format!("CoinMarketCap response has no USD price for id {}", asset.cmc_id))
+}
@@ -0,0 +1,98 @@
+// Differential privacy for aggregate outputs. With WAVS_ENV_DP_EPSILON set, the counts an
+// aggregate mode releases get Laplace noise of scale sensitivity / epsilon before they are
+// submitted or printed, and the output says so:
+//
+//   "privacy": {"mechanism": "laplace", "epsilon": "0.5", "sensitivity": 6}
+//
+// `sensitivity` is how much one record can move all released counts together (their L1 norm),
+// so epsilon covers the whole output rather than each count. Noisy counts are rounded and
+// clamped at zero, which doesn't weaken the guarantee.
+//
+// Operators must submit identical payloads, so the noise can't be random: each draw is derived
+// from HMAC-SHA256(WAVS_ENV_DP_SECRET, context, label, true count). Operators share the secret;
+// without it the noise can't be predicted and subtracted. The same count under the same query
+// always gets the same noise, so repeating a trigger doesn't let anyone average it away, and a
+// changed count gets fresh noise, so subtracting two releases doesn't cancel it either.
+//
+//   WAVS_ENV_DP_EPSILON   privacy budget per released output, a positive number; unset leaves
+//                         aggregates exact
+//   WAVS_ENV_DP_SECRET    noise seed shared by the operators, at least 16 bytes; required with
+//                         WAVS_ENV_DP_EPSILON
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+const MECHANISM: &str = "laplace";
+const MIN_SECRET_LEN: usize = 16;
+
+// Output metadata of a noised result
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Privacy {
+    pub mechanism: String,
+    pub epsilon: f64,
+    pub sensitivity: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Laplace {
+    epsilon: f64,
+    sensitivity: u64,
+    secret: Vec<u8>,
+    context: String,
+}
+
+impl Laplace {
+    // None when no epsilon is configured. `context` names the query (component and input), so
+    // different queries draw independent noise.
+    pub fn from_env(sensitivity: u64, context: &str) -> Result<Option<Laplace>, String> {
+        let epsilon = match std::env::var("WAVS_ENV_DP_EPSILON").as_deref().map(str::trim) {
+            Err(_) | Ok("") => return Ok(None),
+            Ok(epsilon) => {
+                epsilon.parse::<f64>().ok().filter(|e| e.is_finite() && *e > 0.0).ok_or_else(
+                    || format!("WAVS_ENV_DP_EPSILON must be a positive number, got '{}'", epsilon),
+                )?
+            }
+        };
+        let secret = std::env::var("WAVS_ENV_DP_SECRET").unwrap_or_default();
+        if secret.trim().len() < MIN_SECRET_LEN {
+            return Err(format!(
+                "WAVS_ENV_DP_EPSILON needs WAVS_ENV_DP_SECRET of at least {} bytes",
+                MIN_SECRET_LEN
+            ));
+        }
+        Ok(Some(Laplace::new(epsilon, sensitivity, secret.trim().as_bytes(), context)))
+    }
+
+    pub fn new(epsilon: f64, sensitivity: u64, secret: &[u8], context: &str) -> Laplace {
+        Laplace { epsilon, sensitivity, secret: secret.to_vec(), context: context.to_string() }
+    }
+
+    pub fn metadata(&self) -> Privacy {
+        Privacy {
+            mechanism: MECHANISM.to_string(),
+            epsilon: self.epsilon,
+            sensitivity: self.sensitivity,
+        }
+    }
+
+    // `label` tells the counts of one output apart, e.g. "by_city/San Diego"
+    pub fn count(&self, label: &str, value: u64) -> u64 {
+        let noisy = value as f64 + self.noise(&format!("{}\n{}", label, value));
+        noisy.round().max(0.0) as u64
+    }
+
+    // Inverse CDF of Laplace(0, b) at a uniform draw in (-1/2, 1/2)
+    fn noise(&self, label: &str) -> f64 {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.secret).expect("HMAC takes any key length");
+        mac.update(self.context.as_bytes());
+        mac.update(b"\n");
+        mac.update(label.as_bytes());
+        let digest = mac.finalize().into_bytes();
+        let bits = u64::from_be_bytes(digest[..8].try_into().expect("8 bytes")) >> 12;
+        // 52 bits plus a half land strictly inside (0, 1), so the log below stays finite
+        let u = (bits as f64 + 0.5) / (1u64 << 52) as f64 - 0.5;
+        let scale = self.sensitivity as f64 / self.epsilon;
+        -scale * u.signum() * (1.0 - 2.0 * u.abs()).ln()
+    }
+}
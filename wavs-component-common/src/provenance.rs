@@ -0,0 +1,55 @@
+// A watermark tying a result to the trigger that produced it:
+//
+//   provenance = keccak256(abi.encodePacked(uint64 triggerId, uint64 chainId, bytes32 payloadHash))
+//
+// payloadHash is keccak256 of the result as a contract gets it: the ABI-encoded value of an ABI
+// result, or the compact canonical JSON of a JSON result without its `provenance` member, before
+// any sealing or commitment. chainId is the trigger chain's id (see origin.rs), and both ids are 0
+// when there is no trigger or the chain has no id. Every run logs its provenance once, so a
+// result found anywhere can be looked up in operator logs, and a JSON result can be checked
+// against its own `trigger` field.
+//
+// A run has one provenance, that of the first result recorded. versioned! records and embeds it
+// for JSON results; ABI results have no room for it and record it with EthereumOutput::traced,
+// and their CLI JSON then carries the provenance of the ABI value instead of its own.
+use crate::{info, origin};
+use alloy_primitives::{keccak256, B256};
+use std::cell::Cell;
+
+thread_local! {
+    static CURRENT: Cell<Option<B256>> = const { Cell::new(None) };
+}
+
+pub fn compute(trigger_id: u64, chain_id: u64, payload: &[u8]) -> B256 {
+    let mut packed = Vec::with_capacity(48);
+    packed.extend_from_slice(&trigger_id.to_be_bytes());
+    packed.extend_from_slice(&chain_id.to_be_bytes());
+    packed.extend_from_slice(keccak256(payload).as_slice());
+    keccak256(packed)
+}
+
+// The provenance of `payload` under the run's trigger
+pub fn of(payload: &[u8]) -> B256 {
+    let (trigger_id, chain_id) = origin::current()
+        .map_or((0, 0), |origin| (origin.trigger_id, origin.chain_id.unwrap_or_default()));
+    compute(trigger_id, chain_id, payload)
+}
+
+// The run's provenance, made that of `payload` and logged unless one was recorded already
+pub fn record(payload: &[u8]) -> B256 {
+    current().unwrap_or_else(|| {
+        let provenance = of(payload);
+        CURRENT.with(|current| current.set(Some(provenance)));
+        info!("Provenance: {}", provenance);
+        provenance
+    })
+}
+
+pub fn current() -> Option<B256> {
+    CURRENT.with(Cell::get)
+}
+
+// origin::record starts each run without one
+pub(crate) fn start_run() {
+    CURRENT.with(|current| current.set(None));
+}
@@ -0,0 +1,216 @@
+// Reorg awareness for results read at the chain head. A component pins the latest block before
+// its reads, reads at that block number, and then assesses it: the block hash is fetched again
+// to make sure the block is still canonical, and the confirmations on top of it decide whether
+// the result is marked as `reorg_risk: "high"` or `"low"`.
+//
+//   WAVS_ENV_REQUIRED_CONFIRMATIONS    confirmations for `low` risk (default 12)
+//   WAVS_ENV_CONFIRMATION_WAIT_SECS    how long to wait for them before reporting (default 0,
+//                                      capped at 300)
+//
+// Both take any alloy provider, for any network type.
+use crate::rpc;
+use alloy_consensus::BlockHeader;
+use alloy_network::{primitives::HeaderResponse, BlockResponse, Network};
+use alloy_primitives::B256;
+use alloy_provider::Provider;
+use alloy_rpc_types::BlockNumberOrTag;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+const DEFAULT_REQUIRED_CONFIRMATIONS: u64 = 12;
+const MAX_WAIT_SECS: u64 = 300;
+// Roughly one Ethereum slot
+const POLL_INTERVAL_SECS: u64 = 12;
+
+#[derive(Debug, Clone, Copy)]
+pub struct PinnedBlock {
+    pub number: u64,
+    pub hash: B256,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BlockInfo {
+    block_number: u64,
+    block_hash: String,
+    confirmations: u64,
+    required_confirmations: u64,
+    reorg_risk: String,
+}
+
+fn env_u64(name: &str, default: u64) -> u64 {
+    std::env::var(name).ok().and_then(|v| v.parse::<u64>().ok()).unwrap_or(default)
+}
+
+// Also how far behind the head scans stop that must never see a block twice
+pub fn required_confirmations() -> u64 {
+    env_u64("WAVS_ENV_REQUIRED_CONFIRMATIONS", DEFAULT_REQUIRED_CONFIRMATIONS)
+}
+
+pub async fn pin_latest<N: Network>(provider: &impl Provider<N>) -> Result<PinnedBlock, String> {
+    let block = provider
+        .get_block_by_number(BlockNumberOrTag::Latest)
+        .await
+        .map_err(|e| rpc::describe("Failed to fetch latest block", &e))?
+        .ok_or_else(|| "Latest block not found".to_string())?;
+    Ok(PinnedBlock { number: block.header().number(), hash: block.header().hash() })
+}
+
+pub async fn assess<N: Network>(
+    provider: &impl Provider<N>,
+    pinned: PinnedBlock,
+) -> Result<BlockInfo, String> {
+    let required = required_confirmations();
+    let max_wait =
+        Duration::from_secs(env_u64("WAVS_ENV_CONFIRMATION_WAIT_SECS", 0).min(MAX_WAIT_SECS));
+    let poll_interval = Duration::from_secs(POLL_INTERVAL_SECS);
+    let started = Instant::now();
+
+    let confirmations = loop {
+        let latest = provider
+            .get_block_number()
+            .await
+            .map_err(|e| rpc::describe("Failed to fetch block number", &e))?;
+        let confirmations = latest.saturating_sub(pinned.number);
+        let waited = started.elapsed();
+        if confirmations >= required || waited >= max_wait {
+            break confirmations;
+        }
+        wstd::task::sleep((max_wait - waited).min(poll_interval).into()).await;
+    };
+
+    // The reads were made against this block number; if the hash behind it changed they may
+    // describe a block that is no longer part of the chain
+    let block = provider
+        .get_block_by_number(BlockNumberOrTag::Number(pinned.number))
+        .await
+        .map_err(|e| rpc::describe(&format!("Failed to fetch block {}", pinned.number), &e))?
+        .ok_or_else(|| format!("Block {} not found", pinned.number))?;
+    let hash = block.header().hash();
+    if hash != pinned.hash {
+        return Err(format!(
+            "Block {} was reorged during the run ({} replaced by {})",
+            pinned.number, pinned.hash, hash
+        ));
+    }
+
+    Ok(BlockInfo {
+        block_number: pinned.number,
+        block_hash: hash.to_string(),
+        confirmations,
+        required_confirmations: required,
+        reorg_risk: if confirmations >= required { "low" } else { "high" }.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_json_rpc::ErrorPayload;
+    use alloy_primitives::U64;
+    use alloy_provider::RootProvider;
+    use alloy_rpc_client::RpcClient;
+    use alloy_rpc_types::{Block, Header};
+    use alloy_transport::mock::Asserter;
+    use futures::executor::block_on;
+
+    fn mocked() -> (RootProvider, Asserter) {
+        let asserter = Asserter::new();
+        (RootProvider::new(RpcClient::mocked(asserter.clone())), asserter)
+    }
+
+    // Blocks at the same height with different timestamps have different hashes
+    fn block(number: u64, timestamp: u64) -> Block {
+        Block::empty(Header::new(alloy_consensus::Header {
+            number,
+            timestamp,
+            ..Default::default()
+        }))
+    }
+
+    fn pinned(block: &Block) -> PinnedBlock {
+        PinnedBlock { number: block.header.number, hash: block.header.hash }
+    }
+
+    #[test]
+    fn pin_latest_reads_number_and_hash() {
+        let (provider, asserter) = mocked();
+        let latest = block(100, 1);
+        asserter.push_success(&latest);
+
+        let pinned = block_on(pin_latest(&provider)).unwrap();
+        assert_eq!(pinned.number, 100);
+        assert_eq!(pinned.hash, latest.header.hash);
+    }
+
+    #[test]
+    fn pin_latest_fails_without_a_block() {
+        let (provider, asserter) = mocked();
+        asserter.push_success(&Option::<Block>::None);
+
+        assert_eq!(block_on(pin_latest(&provider)).unwrap_err(), "Latest block not found");
+    }
+
+    #[test]
+    fn pin_latest_reports_rpc_error_category() {
+        let (provider, asserter) = mocked();
+        asserter.push_failure(ErrorPayload {
+            code: 429,
+            message: "rate limit exceeded".into(),
+            data: None,
+        });
+
+        let error = block_on(pin_latest(&provider)).unwrap_err();
+        assert!(
+            error.starts_with("Failed to fetch latest block [rate_limited, retriable]"),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    fn assess_rates_risk_by_confirmations() {
+        for (latest, confirmations, risk) in [(105, 5, "high"), (112, 12, "low")] {
+            let (provider, asserter) = mocked();
+            let read_at = block(100, 1);
+            asserter.push_success(&U64::from(latest));
+            asserter.push_success(&read_at);
+
+            let info = block_on(assess(&provider, pinned(&read_at))).unwrap();
+            assert_eq!(info.block_number, 100);
+            assert_eq!(info.block_hash, read_at.header.hash.to_string());
+            assert_eq!(info.confirmations, confirmations);
+            assert_eq!(info.required_confirmations, DEFAULT_REQUIRED_CONFIRMATIONS);
+            assert_eq!(info.reorg_risk, risk);
+        }
+    }
+
+    #[test]
+    fn assess_fails_when_the_block_was_reorged() {
+        let (provider, asserter) = mocked();
+        let read_at = block(100, 1);
+        asserter.push_success(&U64::from(112));
+        asserter.push_success(&block(100, 2));
+
+        let error = block_on(assess(&provider, pinned(&read_at))).unwrap_err();
+        assert!(error.starts_with("Block 100 was reorged during the run"), "{}", error);
+    }
+
+    #[test]
+    fn assess_reports_rpc_error_category() {
+        let (provider, asserter) = mocked();
+        let read_at = block(100, 1);
+        asserter.push_success(&U64::from(112));
+        asserter.push_failure(ErrorPayload {
+            code: -32000,
+            message: "header not found".into(),
+            data: None,
+        });
+
+        let error = block_on(assess(&provider, pinned(&read_at))).unwrap_err();
+        assert!(
+            error.starts_with("Failed to fetch block 100 [unavailable, retriable]"),
+            "{}",
+            error
+        );
+    }
+}
@@ -0,0 +1,409 @@
+// Record/replay of the HTTP and RPC exchanges a component makes, so a run can be re-executed
+// deterministically when verifying or disputing an operator's result.
+//
+// WAVS_ENV_REPLAY_MODE=record writes every exchange to the bundle at WAVS_ENV_REPLAY_FILE (a path
+// in a preopened directory, alongside the CLI output); WAVS_ENV_REPLAY_MODE=replay answers every
+// request from that bundle and never touches the network. HTTP requests are matched by method,
+// URL and the keccak256 of their body, if they have one, so a bundle recorded for one prompt
+// can't answer another; RPC requests by JSON-RPC method and params, each in the order they were
+// made. Headers are not recorded, so API keys sent in headers never end up in a bundle, and
+// secrets in URLs are masked with the log redaction rules. Bodies are only hashed, since they
+// can carry the same kind of secrets.
+use crate::{budget, http, log, timing};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::{cell::RefCell, collections::HashMap, time::Instant};
+use wstd::{
+    http::{Body, IntoBody, Request},
+    io::AsyncRead,
+};
+
+// 2: HTTP request keys cover the body
+const BUNDLE_VERSION: u32 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Off,
+    Record,
+    Replay,
+}
+
+pub fn mode() -> Mode {
+    match std::env::var("WAVS_ENV_REPLAY_MODE").as_deref() {
+        Ok("record") => Mode::Record,
+        Ok("replay") => Mode::Replay,
+        _ => Mode::Off,
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Kind {
+    Http,
+    Rpc,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Exchange {
+    pub kind: Kind,
+    pub request: String,
+    pub response: Value,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Bundle {
+    pub version: u32,
+    pub exchanges: Vec<Exchange>,
+}
+
+#[derive(Default)]
+struct State {
+    bundle: Option<Bundle>,
+    // next occurrence to serve per (kind, request) while replaying
+    cursors: HashMap<String, usize>,
+}
+
+thread_local! {
+    static STATE: RefCell<State> = RefCell::new(State::default());
+}
+
+fn bundle_path() -> Result<String, String> {
+    std::env::var("WAVS_ENV_REPLAY_FILE")
+        .ok()
+        .filter(|p| !p.is_empty())
+        .ok_or_else(|| "WAVS_ENV_REPLAY_FILE must be set in record and replay mode".to_string())
+}
+
+fn record(kind: Kind, request: String, response: Value) -> Result<(), String> {
+    let path = bundle_path()?;
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        let bundle = state
+            .bundle
+            .get_or_insert_with(|| Bundle { version: BUNDLE_VERSION, exchanges: vec![] });
+        bundle.exchanges.push(Exchange { kind, request, response });
+        // Rewritten after every exchange so the bundle is complete even if the run fails later
+        let bytes = serde_json::to_vec_pretty(bundle).map_err(|e| e.to_string())?;
+        std::fs::write(&path, bytes)
+            .map_err(|e| format!("Failed to write replay bundle {}: {}", path, e))
+    })
+}
+
+fn replayed(kind: Kind, request: &str) -> Result<Value, String> {
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        if state.bundle.is_none() {
+            let path = bundle_path()?;
+            let bytes = std::fs::read(&path)
+                .map_err(|e| format!("Failed to read replay bundle {}: {}", path, e))?;
+            let bundle: Bundle = serde_json::from_slice(&bytes)
+                .map_err(|e| format!("Invalid replay bundle {}: {}", path, e))?;
+            if bundle.version != BUNDLE_VERSION {
+                return Err(format!("Unsupported replay bundle version {}", bundle.version));
+            }
+            state.bundle = Some(bundle);
+        }
+
+        let cursor_key = format!("{:?} {}", kind, request);
+        let occurrence = *state.cursors.get(&cursor_key).unwrap_or(&0);
+        let response = state
+            .bundle
+            .iter()
+            .flat_map(|b| b.exchanges.iter())
+            .filter(|e| e.kind == kind && e.request == request)
+            .nth(occurrence)
+            .map(|e| e.response.clone())
+            .ok_or_else(|| format!("No recorded response for {:?} request {}", kind, request))?;
+        state.cursors.insert(cursor_key, occurrence + 1);
+        Ok(response)
+    })
+}
+
+// Drop-in replacements for the wavs_wasi_utils::http fetch helpers, with response bodies
+// counted against the component's memory budget
+pub async fn fetch_bytes(request: Request<impl Body>) -> anyhow::Result<Vec<u8>> {
+    // Read out first, so the key can cover it
+    let (parts, mut body) = request.into_parts();
+    let mut bytes = Vec::new();
+    body.read_to_end(&mut bytes).await?;
+    let mut key = format!("{} {}", parts.method, log::redact_secrets(&parts.uri.to_string()));
+    if !bytes.is_empty() {
+        key.push_str(&format!(" body:{}", alloy_primitives::keccak256(&bytes)));
+    }
+    let request = Request::from_parts(parts, bytes.into_body());
+    match mode() {
+        Mode::Off => read_bounded(request, &key).await,
+        Mode::Replay => {
+            let bytes = decode_body(replayed(Kind::Http, &key).map_err(anyhow::Error::msg)?)?;
+            // Charged like a live response so a replay fails where the original run did
+            let len = bytes.len() as u64;
+            budget::charge(&key, len, len).map_err(anyhow::Error::msg)?;
+            Ok(bytes)
+        }
+        Mode::Record => {
+            let bytes = read_bounded(request, &key).await?;
+            record(Kind::Http, key, encode_body(&bytes)).map_err(anyhow::Error::msg)?;
+            Ok(bytes)
+        }
+    }
+}
+
+// Reads the body in chunks so an oversized response fails before it is held in full. Like the
+// helpers it replaces, only a 2xx body is data: an error page fails the request unread.
+async fn read_bounded(request: Request<impl Body>, source: &str) -> anyhow::Result<Vec<u8>> {
+    let started = Instant::now();
+    let host = request.uri().host().unwrap_or_default().to_string();
+    let mut response = http::send(request).await?;
+    if !response.status().is_success() {
+        anyhow::bail!("Request failed with status code: {}", response.status().as_u16());
+    }
+    let declared = response
+        .headers()
+        .get("content-length")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u64>().ok());
+    if let Some(declared) = declared {
+        budget::check_declared(source, declared).map_err(anyhow::Error::msg)?;
+    }
+
+    let body = response.body_mut();
+    let mut bytes = Vec::new();
+    let mut chunk = [0u8; 16 * 1024];
+    loop {
+        let read = body.read(&mut chunk).await?;
+        if read == 0 {
+            break;
+        }
+        budget::charge(source, read as u64, (bytes.len() + read) as u64)
+            .map_err(anyhow::Error::msg)?;
+        bytes.extend_from_slice(&chunk[..read]);
+    }
+    timing::record_upstream(&host, started.elapsed());
+    Ok(bytes)
+}
+
+pub async fn fetch_json<T: DeserializeOwned>(request: Request<impl Body>) -> anyhow::Result<T> {
+    let bytes = fetch_bytes(request).await?;
+    Ok(serde_json::from_slice(&bytes)?)
+}
+
+pub async fn fetch_string(request: Request<impl Body>) -> anyhow::Result<String> {
+    let bytes = fetch_bytes(request).await?;
+    Ok(String::from_utf8(bytes)?)
+}
+
+// Status, entity headers and redirect target of a HEAD request, for components that want to vet
+// a URL before something else fetches it. Recorded like any other HTTP exchange. Redirects are
+// not followed: a 3xx comes back with its Location as it was sent, possibly relative.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HeadInfo {
+    pub status: u16,
+    pub content_type: Option<String>,
+    pub content_length: Option<u64>,
+    // absent from bundles recorded before it was kept
+    #[serde(default)]
+    pub location: Option<String>,
+}
+
+pub async fn fetch_head(request: Request<impl Body>) -> anyhow::Result<HeadInfo> {
+    let key = format!("HEAD {}", log::redact_secrets(&request.uri().to_string()));
+    if mode() == Mode::Replay {
+        let recorded = replayed(Kind::Http, &key).map_err(anyhow::Error::msg)?;
+        return Ok(serde_json::from_value(recorded)?);
+    }
+
+    let mut request = request;
+    *request.method_mut() = wstd::http::Method::HEAD;
+    let started = Instant::now();
+    let host = request.uri().host().unwrap_or_default().to_string();
+    let response = http::send(request).await?;
+    timing::record_upstream(&host, started.elapsed());
+    let header = |name: &str| {
+        response.headers().get(name).and_then(|v| v.to_str().ok()).map(|v| v.trim().to_string())
+    };
+    let info = HeadInfo {
+        status: response.status().as_u16(),
+        content_type: header("content-type"),
+        content_length: header("content-length").and_then(|v| v.parse::<u64>().ok()),
+        location: header("location"),
+    };
+    if mode() == Mode::Record {
+        record(Kind::Http, key, serde_json::to_value(&info)?).map_err(anyhow::Error::msg)?;
+    }
+    Ok(info)
+}
+
+// Text bodies are stored as strings so bundles stay readable; anything else as hex
+fn encode_body(bytes: &[u8]) -> Value {
+    match std::str::from_utf8(bytes) {
+        Ok(text) => Value::String(text.to_string()),
+        Err(_) => serde_json::json!({ "hex": alloy_primitives::hex::encode(bytes) }),
+    }
+}
+
+fn decode_body(value: Value) -> anyhow::Result<Vec<u8>> {
+    match value {
+        Value::String(text) => Ok(text.into_bytes()),
+        other => {
+            let hex = other
+                .get("hex")
+                .and_then(Value::as_str)
+                .ok_or_else(|| anyhow::anyhow!("Invalid recorded HTTP body"))?;
+            Ok(alloy_primitives::hex::decode(hex)?)
+        }
+    }
+}
+
+pub use provider::evm_provider;
+
+#[cfg(target_arch = "wasm32")]
+mod provider {
+    use super::*;
+    use alloy_json_rpc::{RequestPacket, ResponsePacket};
+    use alloy_network::Ethereum;
+    use alloy_provider::RootProvider;
+    use alloy_rpc_client::RpcClient;
+    use alloy_transport::{
+        utils::guess_local_url, TransportError, TransportErrorKind, TransportFut,
+    };
+    use std::task;
+    use tower_service::Service;
+    use wstd::{
+        http::{IntoBody, StatusCode},
+        io::AsyncRead,
+    };
+
+    // JSON-RPC ids depend on how many requests the client made before, so they are replaced by
+    // their position in the packet before matching and restored on the way out
+    fn strip_request_ids(request: &mut Value) -> Vec<Value> {
+        let mut ids = Vec::new();
+        for_each_message(request, |message| {
+            if let Some(id) = message.as_object_mut().and_then(|m| m.remove("id")) {
+                ids.push(id);
+            }
+        });
+        ids
+    }
+
+    fn positional_response_ids(response: &mut Value, ids: &[Value]) {
+        for_each_message(response, |message| {
+            if let Some(id) = message.get_mut("id") {
+                if let Some(index) = ids.iter().position(|i| i == id) {
+                    *id = Value::from(index);
+                }
+            }
+        });
+    }
+
+    fn restore_response_ids(response: &mut Value, ids: &[Value]) {
+        for_each_message(response, |message| {
+            if let Some(id) = message.get_mut("id") {
+                if let Some(original) = id.as_u64().and_then(|i| ids.get(i as usize)) {
+                    *id = original.clone();
+                }
+            }
+        });
+    }
+
+    fn for_each_message(packet: &mut Value, mut f: impl FnMut(&mut Value)) {
+        match packet {
+            Value::Array(messages) => messages.iter_mut().for_each(f),
+            message => f(message),
+        }
+    }
+
+    // Same as wavs_wasi_utils::evm::new_evm_provider, with every exchange timed per host and
+    // going through the recorder when record or replay mode is on
+    pub fn evm_provider(endpoint: String) -> RootProvider<Ethereum> {
+        let is_local = guess_local_url(&endpoint);
+        RootProvider::new(RpcClient::new(ReplayTransport { endpoint }, is_local))
+    }
+
+    #[derive(Clone)]
+    struct ReplayTransport {
+        endpoint: String,
+    }
+
+    // Error bodies end up in error messages, so a proxy's HTML error page is cut short
+    const MAX_ERROR_BODY_CHARS: usize = 200;
+
+    fn transport_err(e: impl ToString) -> TransportError {
+        TransportError::Transport(TransportErrorKind::Custom(e.to_string().into()))
+    }
+
+    impl Service<RequestPacket> for ReplayTransport {
+        type Response = ResponsePacket;
+        type Error = TransportError;
+        type Future = TransportFut<'static>;
+
+        fn poll_ready(
+            &mut self,
+            _cx: &mut task::Context<'_>,
+        ) -> task::Poll<Result<(), Self::Error>> {
+            task::Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, packet: RequestPacket) -> Self::Future {
+            let endpoint = self.endpoint.clone();
+            Box::pin(async move {
+                let body = serde_json::to_vec(&packet).map_err(transport_err)?;
+                if mode() == Mode::Off {
+                    let response = send(&endpoint, body).await?;
+                    return serde_json::from_slice::<ResponsePacket>(&response)
+                        .map_err(transport_err);
+                }
+                let mut request: Value = serde_json::from_slice(&body).map_err(transport_err)?;
+                let ids = strip_request_ids(&mut request);
+                let key = request.to_string();
+
+                let response: Value = if mode() == Mode::Replay {
+                    let mut response = replayed(Kind::Rpc, &key).map_err(transport_err)?;
+                    restore_response_ids(&mut response, &ids);
+                    response
+                } else {
+                    let buf = send(&endpoint, body).await?;
+                    let response: Value = serde_json::from_slice(&buf).map_err(transport_err)?;
+                    let mut recorded = response.clone();
+                    positional_response_ids(&mut recorded, &ids);
+                    record(Kind::Rpc, key, recorded).map_err(transport_err)?;
+                    response
+                };
+
+                // RawValue payloads can only be deserialized from text, not from a Value
+                let bytes = serde_json::to_vec(&response).map_err(transport_err)?;
+                serde_json::from_slice::<ResponsePacket>(&bytes).map_err(transport_err)
+            })
+        }
+    }
+
+    async fn send(endpoint: &str, body: Vec<u8>) -> Result<Vec<u8>, TransportError> {
+        let started = Instant::now();
+        let request = wstd::http::Request::post(endpoint)
+            .header("content-type", "application/json")
+            .body(body.into_body())
+            .map_err(transport_err)?;
+        let host = request.uri().host().unwrap_or_default().to_string();
+        let mut res = http::send(request).await.map_err(transport_err)?;
+        let mut buf = Vec::new();
+        res.body_mut().read_to_end(&mut buf).await.map_err(transport_err)?;
+        // Typed, so rpc::RpcError can tell a rate limit from a gateway timeout
+        if res.status() != StatusCode::OK {
+            let body: String =
+                String::from_utf8_lossy(&buf).chars().take(MAX_ERROR_BODY_CHARS).collect();
+            return Err(TransportErrorKind::http_error(res.status().as_u16(), body));
+        }
+        timing::record_upstream(&host, started.elapsed());
+        Ok(buf)
+    }
+}
+
+// wavs_wasi_utils only provides a provider on wasm32; this keeps host builds compiling
+#[cfg(not(target_arch = "wasm32"))]
+mod provider {
+    use alloy_network::Ethereum;
+    use alloy_provider::RootProvider;
+
+    pub fn evm_provider(endpoint: String) -> RootProvider<Ethereum> {
+        wavs_wasi_utils::evm::new_evm_provider::<Ethereum>(endpoint)
+    }
+}
@@ -0,0 +1,227 @@
+use alloy_json_rpc::RpcError as JsonRpcError;
+use alloy_transport::{TransportError, TransportErrorKind};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// Per-request RPC endpoint overrides. A trigger envelope may carry `rpc_url` so that one task
+// can read from, say, an archive node without changing the chain config every other task uses.
+// Since the URL comes from whoever triggered the task, it is only used when the operator allows
+// its host:
+//
+//   WAVS_ENV_RPC_URL_ALLOWLIST=archive.example.com,*.alchemyapi.io
+//
+// Entries are host names, matched case-insensitively; `*.` matches any subdomain of the rest.
+// Overrides must use https, and with no allowlist every override is rejected.
+pub fn resolve_endpoint(
+    override_url: Option<&str>,
+    configured: Option<String>,
+) -> Result<String, String> {
+    let Some(url) = override_url.map(str::trim) else {
+        return configured.ok_or_else(|| "Ethereum chain config has no HTTP endpoint".to_string());
+    };
+    // Not echoed back, since a malformed URL may still carry credentials
+    let host = https_host(url)
+        .ok_or_else(|| "RPC override must be an https URL without credentials".to_string())?;
+    let allowlist = std::env::var("WAVS_ENV_RPC_URL_ALLOWLIST").unwrap_or_default();
+    if !allowlist.split(',').map(str::trim).any(|pattern| host_matches(pattern, &host)) {
+        return Err(format!("RPC override host {} is not in WAVS_ENV_RPC_URL_ALLOWLIST", host));
+    }
+    Ok(url.to_string())
+}
+
+// Lowercase host without the port, or None when the URL isn't plain https
+fn https_host(url: &str) -> Option<String> {
+    let rest = url.strip_prefix("https://")?;
+    let authority = rest.split(['/', '?', '#']).next()?;
+    if authority.contains('@') {
+        return None;
+    }
+    let host = match authority.rsplit_once(':') {
+        Some((host, port)) if port.chars().all(|c| c.is_ascii_digit()) => host,
+        _ => authority,
+    };
+    Some(host.to_ascii_lowercase()).filter(|h| !h.is_empty())
+}
+
+pub(crate) fn host_matches(pattern: &str, host: &str) -> bool {
+    let pattern = pattern.to_ascii_lowercase();
+    match pattern.strip_prefix("*.") {
+        Some(domain) => !domain.is_empty() && host.ends_with(&format!(".{}", domain)),
+        None => !pattern.is_empty() && pattern == host,
+    }
+}
+
+// Error messages providers return when an eth_getLogs query hits their result or block range
+// cap. Scans that see one retry with a smaller range instead of failing.
+const LOG_LIMIT_MARKERS: &[&str] = &[
+    "query returned more than",
+    "more than 10000 results",
+    "response size exceeded",
+    "limit exceeded",
+    "too many",
+    "block range",
+    "range is too large",
+    "-32005",
+];
+
+pub fn is_log_limit_error(error: &str) -> bool {
+    let error = error.to_lowercase();
+    LOG_LIMIT_MARKERS.iter().any(|marker| error.contains(marker))
+}
+
+// Provider failures sorted into what a caller can do about them. Every EVM read in the
+// components reports its failure through describe(), which puts the category and whether a later
+// attempt can succeed in front of the provider's message:
+//
+//   Chainlink call failed [rate_limited, retriable]: HTTP error 429 with body: ...
+//
+// so operators and dead-letter consumers can tell a flaky endpoint from a call that never works.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RpcErrorKind {
+    // HTTP 429 and the quota errors providers send as JSON-RPC errors
+    RateLimited,
+    // no answer in time, including 408 and 504 from a gateway in front of the node
+    Timeout,
+    // the endpoint couldn't be reached, answered 5xx or was missing the requested state
+    Unavailable,
+    // the call ran and reverted, so it reverts again at the same block
+    ExecutionReverted,
+    // the node rejected the arguments of the request
+    InvalidParams,
+    // an eth_getLogs query over the provider's result or block range cap
+    ResultLimit,
+    Other,
+}
+
+impl RpcErrorKind {
+    // Whether repeating the same request later may succeed; the rest need a different request
+    pub fn retriable(self) -> bool {
+        matches!(self, Self::RateLimited | Self::Timeout | Self::Unavailable)
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::RateLimited => "rate_limited",
+            Self::Timeout => "timeout",
+            Self::Unavailable => "unavailable",
+            Self::ExecutionReverted => "execution_reverted",
+            Self::InvalidParams => "invalid_params",
+            Self::ResultLimit => "result_limit",
+            Self::Other => "other",
+        }
+    }
+}
+
+impl fmt::Display for RpcErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RpcError {
+    pub kind: RpcErrorKind,
+    pub retriable: bool,
+    pub message: String,
+}
+
+impl RpcError {
+    pub fn classify(error: &TransportError) -> Self {
+        let kind = match error {
+            JsonRpcError::ErrorResp(payload) => classify_payload(payload.code, &payload.message),
+            JsonRpcError::Transport(TransportErrorKind::HttpError(http)) => {
+                classify_status(http.status)
+            }
+            JsonRpcError::Transport(TransportErrorKind::MissingBatchResponse(_))
+            | JsonRpcError::Transport(TransportErrorKind::BackendGone) => RpcErrorKind::Unavailable,
+            JsonRpcError::Transport(TransportErrorKind::Custom(e)) => {
+                classify_transport(&e.to_string())
+            }
+            JsonRpcError::NullResp => RpcErrorKind::Unavailable,
+            _ => RpcErrorKind::Other,
+        };
+        RpcError { kind, retriable: kind.retriable(), message: error.to_string() }
+    }
+}
+
+impl fmt::Display for RpcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let retriable = if self.retriable { "retriable" } else { "not retriable" };
+        write!(f, "[{}, {}]: {}", self.kind, retriable, self.message)
+    }
+}
+
+// The error text components return for a failed provider request, e.g.
+// .map_err(|e| rpc::describe("Failed to read balance", &e))
+pub fn describe(context: &str, error: &TransportError) -> String {
+    format!("{} {}", context, RpcError::classify(error))
+}
+
+fn classify_payload(code: i64, message: &str) -> RpcErrorKind {
+    let lower = message.to_lowercase();
+    // Geth sends reverts with code 3 and the revert data; others only say so in the message.
+    // Checked first, since the revert reason is free text.
+    if code == 3 || lower.contains("revert") {
+        return RpcErrorKind::ExecutionReverted;
+    }
+    // Checked before the log caps, whose markers include "too many"
+    if code == 429 || RATE_LIMIT_MARKERS.iter().any(|marker| lower.contains(marker)) {
+        return RpcErrorKind::RateLimited;
+    }
+    if is_log_limit_error(&lower) {
+        return RpcErrorKind::ResultLimit;
+    }
+    match code {
+        -32602 => RpcErrorKind::InvalidParams,
+        // Infura's code for rate limits, once its log query errors are ruled out
+        -32005 => RpcErrorKind::RateLimited,
+        _ if lower.contains("timeout") || lower.contains("timed out") => RpcErrorKind::Timeout,
+        // Load-balanced nodes that are behind the block being asked for
+        _ if lower == "header not found" || lower.contains("missing trie node") => {
+            RpcErrorKind::Unavailable
+        }
+        _ => RpcErrorKind::Other,
+    }
+}
+
+fn classify_status(status: u16) -> RpcErrorKind {
+    match status {
+        429 => RpcErrorKind::RateLimited,
+        408 | 504 => RpcErrorKind::Timeout,
+        500..=599 => RpcErrorKind::Unavailable,
+        _ => RpcErrorKind::Other,
+    }
+}
+
+// Custom transport errors only carry text: the HTTP client's failure or a bad response body
+fn classify_transport(message: &str) -> RpcErrorKind {
+    let lower = message.to_lowercase();
+    if lower.contains("429") || lower.contains("too many requests") {
+        RpcErrorKind::RateLimited
+    } else if lower.contains("timeout") || lower.contains("timed out") {
+        RpcErrorKind::Timeout
+    } else if TRANSPORT_UNAVAILABLE_MARKERS.iter().any(|marker| lower.contains(marker)) {
+        RpcErrorKind::Unavailable
+    } else {
+        RpcErrorKind::Other
+    }
+}
+
+const RATE_LIMIT_MARKERS: &[&str] = &[
+    "rate limit",
+    "rate exceeded",
+    "too many requests",
+    "credits limited",
+    "request limit reached",
+    "daily request count exceeded",
+];
+
+const TRANSPORT_UNAVAILABLE_MARKERS: &[&str] = &[
+    "connection refused",
+    "connection reset",
+    "connection terminated",
+    "dns",
+    "destination unavailable",
+    "destination not found",
+];
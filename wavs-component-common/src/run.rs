@@ -0,0 +1,96 @@
+// The steps every component's run takes around its own processing. On the way in: phase timing,
+// the trigger's origin (origin.rs), maintenance mode (maintenance.rs), the input envelope
+// (envelope.rs) and, when processing fails, the dead-letter record (dead_letter.rs). On the way
+// out: sealing (encryption.rs), committing (commit_reveal.rs) and the gas estimate (gas.rs).
+// CLI runs build the submission too, so they can report what submitting the result would cost.
+//
+// Trigger data and responses are types of each component's own bindings, so the component's
+// trigger.rs implements Codec to decode and encode them.
+use crate::envelope::{self, Options};
+use crate::origin::{self, TriggerOrigin};
+use crate::output::{CliOutput, EthereumOutput};
+use crate::version::Versioned;
+use crate::{commit_reveal, dead_letter, encryption, error, gas, info, maintenance, timing, warn};
+use serde::Serialize;
+
+pub enum Destination {
+    Ethereum,
+    CliOutput,
+}
+
+pub trait Codec {
+    // The component's name and version, as versioned! would report them
+    const NAME: &'static str;
+    const VERSION: &'static str;
+    // The bindings' TriggerData and WasmResponse
+    type TriggerData;
+    type Response;
+
+    fn decode(
+        &self,
+        data: Self::TriggerData,
+    ) -> Result<(u64, Vec<u8>, Destination, Option<TriggerOrigin>), String>;
+    fn submission<S>(&self, trigger_id: u64, output: EthereumOutput<S>) -> Self::Response;
+    fn cli<T: Serialize>(&self, output: CliOutput<T>) -> Result<Self::Response, String>;
+    fn payload<'a>(&self, response: &'a Self::Response) -> &'a [u8];
+}
+
+// Runs `process` on a trigger's input, unless the component is in maintenance
+pub fn run<C: Codec>(
+    codec: C,
+    data: C::TriggerData,
+    process: impl FnOnce(u64, Vec<u8>, Destination, Options) -> Result<Option<C::Response>, String>,
+) -> Result<Option<C::Response>, String> {
+    timing::start_run();
+    let (trigger_id, req, dest, trigger_origin) = codec.decode(data)?;
+    origin::record(trigger_origin);
+    if let Some(status) = maintenance::current() {
+        warn!("Trigger {} not processed: {}", trigger_id, status.message);
+        let response = match dest {
+            Destination::Ethereum => codec.submission(trigger_id, status.record(C::NAME)),
+            Destination::CliOutput => {
+                codec.cli(CliOutput::new(Versioned::new(C::NAME, C::VERSION, &status)))?
+            }
+        };
+        return Ok(Some(response));
+    }
+    let (req, options) = envelope::unwrap(&req)?;
+    let is_ethereum = matches!(dest, Destination::Ethereum);
+
+    let result = match process(trigger_id, req, dest, options) {
+        Err(e) if is_ethereum && dead_letter::enabled() => {
+            error!("Trigger {} failed, submitting dead-letter record: {}", trigger_id, e);
+            let record = dead_letter::failure_record(C::NAME, &e);
+            Ok(Some(codec.submission(trigger_id, record)))
+        }
+        result => result,
+    };
+    timing::warn_if_slow();
+    result
+}
+
+// Answers a run with its result: `output` sealed and committed as the envelope asks, submitted
+// on an Ethereum run, or what `cli` renders along with the gas estimate on a CLI run
+pub fn respond<C: Codec, S, T: Serialize>(
+    codec: C,
+    trigger_id: u64,
+    output: EthereumOutput<S>,
+    dest: Destination,
+    options: &Options,
+    cli: impl FnOnce() -> Result<CliOutput<T>, String>,
+) -> Result<Option<C::Response>, String> {
+    let output = encryption::seal_result(output, options)?;
+    let output = commit_reveal::commit(trigger_id, output, options, C::NAME)?;
+    let submission = codec.submission(trigger_id, output);
+    let estimated_gas = gas::estimate_submission_gas(codec.payload(&submission));
+    info!("Estimated submission gas: {}", estimated_gas);
+    timing::end_phase("encode");
+
+    let response = match dest {
+        Destination::Ethereum => submission,
+        Destination::CliOutput => {
+            codec.cli(cli()?.with_estimate(estimated_gas).format(options.json_format()))?
+        }
+    };
+    Ok(Some(response))
+}
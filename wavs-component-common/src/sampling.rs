@@ -0,0 +1,97 @@
+// Sampling parameters for the chat components. Operators answering the same trigger should get
+// the same answer, so runs whose result is submitted on-chain always use the determinism
+// profile: temperature 0, top_p 1 and a fixed seed. process() selects the profile for its
+// destination once per run and every chat request of the run sends it. OpenAI treats the seed as
+// best effort, so this makes agreement much more likely rather than certain.
+//
+//   WAVS_ENV_LLM_SEED           seed of the determinism profile (default 42)
+//   WAVS_ENV_LLM_CLI_SAMPLING   sampling for CLI runs: `default` (unset) leaves it to the provider,
+//                               `deterministic` uses the on-chain profile, and comma-separated
+//                               overrides such as temperature=0.7,top_p=0.9,seed=7 set those
+//                               parameters only
+//
+// On-chain runs have no override.
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+
+const DEFAULT_SEED: u64 = 42;
+
+thread_local! {
+    static SELECTED: Cell<Option<Sampling>> = const { Cell::new(None) };
+}
+
+// Flattened into request bodies; unset parameters are left to the provider
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(default)]
+pub struct Sampling {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+}
+
+impl Sampling {
+    pub fn deterministic() -> Result<Sampling, String> {
+        let seed = match std::env::var("WAVS_ENV_LLM_SEED").as_deref().map(str::trim) {
+            Err(_) | Ok("") => DEFAULT_SEED,
+            Ok(seed) => seed
+                .parse::<u64>()
+                .map_err(|_| format!("WAVS_ENV_LLM_SEED must be an integer, not '{}'", seed))?,
+        };
+        Ok(Sampling::profile(seed))
+    }
+
+    fn profile(seed: u64) -> Sampling {
+        Sampling { temperature: Some(0.0), top_p: Some(1.0), seed: Some(seed) }
+    }
+
+    fn for_cli() -> Result<Sampling, String> {
+        let configured = std::env::var("WAVS_ENV_LLM_CLI_SAMPLING").unwrap_or_default();
+        match configured.trim() {
+            "" | "default" => return Ok(Sampling::default()),
+            "deterministic" => return Sampling::deterministic(),
+            _ => {}
+        }
+        let mut sampling = Sampling::default();
+        for entry in configured.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let invalid = || format!("Invalid WAVS_ENV_LLM_CLI_SAMPLING entry '{}'", entry);
+            let (name, value) = entry.split_once('=').ok_or_else(invalid)?;
+            let value = value.trim();
+            match name.trim() {
+                "temperature" => {
+                    let t = value.parse::<f32>().ok().filter(|t| (0.0..=2.0).contains(t));
+                    sampling.temperature = Some(t.ok_or_else(invalid)?);
+                }
+                "top_p" => {
+                    let p = value.parse::<f32>().ok().filter(|p| *p > 0.0 && *p <= 1.0);
+                    sampling.top_p = Some(p.ok_or_else(invalid)?);
+                }
+                "seed" => sampling.seed = Some(value.parse::<u64>().map_err(|_| invalid())?),
+                _ => {
+                    return Err(format!(
+                        "{}; expected default, deterministic or temperature/top_p/seed overrides",
+                        invalid()
+                    ))
+                }
+            }
+        }
+        Ok(sampling)
+    }
+}
+
+// Picks the run's sampling for its destination
+pub fn select(on_chain: bool) -> Result<Sampling, String> {
+    let sampling = if on_chain { Sampling::deterministic()? } else { Sampling::for_cli()? };
+    SELECTED.with(|selected| selected.set(Some(sampling)));
+    Ok(sampling)
+}
+
+// The run's sampling; the determinism profile if nothing was selected, so a request can't fall
+// back to default sampling by accident
+pub fn current() -> Sampling {
+    SELECTED
+        .with(Cell::get)
+        .unwrap_or_else(|| Sampling::deterministic().unwrap_or(Sampling::profile(DEFAULT_SEED)))
+}
@@ -0,0 +1,60 @@
+// Screening of wallet addresses against the OFAC SDN list. The list is a data file embedded at
+// build time (data/sdn_eth_addresses.txt), so every result names the list version it was
+// screened against and operators running the same build agree on the answer.
+//
+// The file only counts as a list once `cargo run -p xtask -- sdn-list` has written a version
+// line into it. Until then every function here returns None and outputs leave their sanctions
+// fields out, since an empty or hand-written list would report sanctioned wallets as clean.
+use alloy_primitives::Address;
+use serde::{Deserialize, Serialize};
+
+const SDN_LIST: &str = include_str!("../data/sdn_eth_addresses.txt");
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Screening {
+    pub sanctioned: bool,
+    pub sanctions_list_version: String,
+}
+
+// Screening of a set of wallets, such as a watch list
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WalletsScreening {
+    // the listed ones, in the order given
+    pub sanctioned_wallets: Vec<String>,
+    pub sanctions_list_version: String,
+}
+
+pub fn list_version() -> Option<&'static str> {
+    SDN_LIST.lines().find_map(|line| line.trim().strip_prefix("version:")).map(str::trim)
+}
+
+fn listed_addresses() -> impl Iterator<Item = Address> {
+    SDN_LIST
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with("0x"))
+        .filter_map(|line| line.parse::<Address>().ok())
+}
+
+pub fn is_sanctioned(address: Address) -> Option<bool> {
+    list_version()?;
+    Some(listed_addresses().any(|listed| listed == address))
+}
+
+pub fn screen(address: Address) -> Option<Screening> {
+    Some(Screening {
+        sanctioned: is_sanctioned(address)?,
+        sanctions_list_version: list_version()?.to_string(),
+    })
+}
+
+pub fn screen_wallets(wallets: &[Address]) -> Option<WalletsScreening> {
+    Some(WalletsScreening {
+        sanctioned_wallets: wallets
+            .iter()
+            .filter(|w| is_sanctioned(**w) == Some(true))
+            .map(|w| w.to_checksum(None))
+            .collect(),
+        sanctions_list_version: list_version()?.to_string(),
+    })
+}
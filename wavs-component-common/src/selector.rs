@@ -0,0 +1,46 @@
+// Strict selector checks. Components accept calldata for their trigger functions as well as
+// selector-less input such as a bare ABI string, so a payload meant for another component can
+// still decode as something here. With WAVS_ENV_STRICT_SELECTORS=true, input has to start with
+// the 4-byte selector of one of the component's functions, and anything else is rejected before
+// it is decoded, naming the selector seen and the ones expected.
+use alloy_primitives::hex;
+use alloy_sol_types::SolCall;
+
+pub struct Expected {
+    pub(crate) signature: &'static str,
+    pub(crate) selector: [u8; 4],
+}
+
+impl Expected {
+    pub const fn of<C: SolCall>() -> Expected {
+        Expected { signature: C::SIGNATURE, selector: C::SELECTOR }
+    }
+}
+
+pub fn strict() -> bool {
+    matches!(std::env::var("WAVS_ENV_STRICT_SELECTORS").as_deref(), Ok("1") | Ok("true"))
+}
+
+// Ok whenever strict mode is off, so components can call it unconditionally
+pub fn check(data: &[u8], expected: &[Expected]) -> Result<(), String> {
+    if !strict() {
+        return Ok(());
+    }
+    let observed = data.get(..4);
+    if observed.is_some_and(|selector| expected.iter().any(|e| e.selector == selector)) {
+        return Ok(());
+    }
+    let observed = match observed {
+        Some(selector) => format!("0x{}", hex::encode(selector)),
+        None => format!("none ({} bytes of input)", data.len()),
+    };
+    let expected: Vec<String> = expected
+        .iter()
+        .map(|e| format!("0x{} ({})", hex::encode(e.selector), e.signature))
+        .collect();
+    Err(format!(
+        "Input selector {} is not one of this component's functions; expected {}",
+        observed,
+        expected.join(", ")
+    ))
+}
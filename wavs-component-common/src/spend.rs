@@ -0,0 +1,190 @@
+// Per-run ceiling on what a component may spend on LLM calls. Before each chat request the
+// caller passes its pre-flight prompt token count; the estimated cost of the request (the prompt
+// plus a reserve of completion tokens, at the model's list price) is added to what the run has
+// spent so far, and if that passes the ceiling the request is refused and the run fails with a
+// structured "budget_exceeded" error. After the request, its actual usage is charged.
+//
+//   WAVS_ENV_LLM_MAX_COST_USD                ceiling for one run, e.g. 0.05 (unset: no ceiling)
+//   WAVS_ENV_LLM_COMPLETION_RESERVE_TOKENS   completion tokens each estimate assumes (default 1024)
+//   WAVS_ENV_LLM_PRICES                      extra or corrected prices, USD per 1M tokens, as
+//                                            comma-separated model=input/output entries:
+//                                            gpt-4o=2.5/10,my-finetune=3/12
+//
+// Amounts are kept in micro-USD and rounded up, so estimates never come out below the real cost.
+use serde::Serialize;
+use std::cell::Cell;
+
+const DEFAULT_COMPLETION_RESERVE_TOKENS: u64 = 1024;
+const USD_DECIMALS: usize = 6;
+
+// List prices in USD per 1M input and output tokens, mid-2025. Dated snapshots (gpt-4o-2024-08-06)
+// use the price of the longest entry they start with.
+const PRICES: [(&str, &str, &str); 14] = [
+    ("gpt-4", "30", "60"),
+    ("gpt-4-turbo", "10", "30"),
+    ("gpt-4o", "2.5", "10"),
+    ("gpt-4o-mini", "0.15", "0.6"),
+    ("gpt-4.1", "2", "8"),
+    ("gpt-4.1-mini", "0.4", "1.6"),
+    ("gpt-4.1-nano", "0.1", "0.4"),
+    ("gpt-3.5-turbo", "0.5", "1.5"),
+    ("command-r-08-2024", "0.15", "0.6"),
+    ("command-r-plus-08-2024", "2.5", "10"),
+    ("command-a-03-2025", "2.5", "10"),
+    ("mistral-small-latest", "0.1", "0.3"),
+    ("mistral-medium-latest", "0.4", "2"),
+    ("mistral-large-latest", "2", "6"),
+];
+
+thread_local! {
+    static SPENT_MICRO_USD: Cell<u64> = const { Cell::new(0) };
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct BudgetExceeded {
+    error: &'static str,
+    model: String,
+    prompt_tokens: u64,
+    completion_reserve_tokens: u64,
+    estimated_cost_usd: String,
+    spent_usd: String,
+    max_cost_usd: String,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Price {
+    // micro-USD per 1M tokens
+    input: u64,
+    output: u64,
+}
+
+impl Price {
+    fn cost(self, prompt_tokens: u64, completion_tokens: u64) -> u64 {
+        let micro = |tokens: u64, price: u64| (tokens as u128 * price as u128).div_ceil(1_000_000);
+        let total = micro(prompt_tokens, self.input) + micro(completion_tokens, self.output);
+        u64::try_from(total).unwrap_or(u64::MAX)
+    }
+}
+
+fn max_cost() -> Result<Option<u64>, String> {
+    match std::env::var("WAVS_ENV_LLM_MAX_COST_USD").as_deref().map(str::trim) {
+        Err(_) | Ok("") => Ok(None),
+        Ok(value) => parse_usd(value).map(Some).ok_or_else(|| {
+            format!("WAVS_ENV_LLM_MAX_COST_USD must be a USD amount, not '{}'", value)
+        }),
+    }
+}
+
+pub fn completion_reserve_tokens() -> u64 {
+    std::env::var("WAVS_ENV_LLM_COMPLETION_RESERVE_TOKENS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_COMPLETION_RESERVE_TOKENS)
+}
+
+// Configured prices win over the built-in list
+fn price(model: &str) -> Result<Option<Price>, String> {
+    let configured = std::env::var("WAVS_ENV_LLM_PRICES").unwrap_or_default();
+    let mut entries = Vec::new();
+    for entry in configured.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        let parsed = entry.split_once('=').and_then(|(name, prices)| {
+            let (input, output) = prices.split_once('/')?;
+            Some((name.trim().to_string(), parse_usd(input.trim())?, parse_usd(output.trim())?))
+        });
+        entries.push(parsed.ok_or_else(|| {
+            format!("Invalid WAVS_ENV_LLM_PRICES entry '{}', expected model=input/output", entry)
+        })?);
+    }
+    for (name, input, output) in PRICES {
+        if let (Some(input), Some(output)) = (parse_usd(input), parse_usd(output)) {
+            entries.push((name.to_string(), input, output));
+        }
+    }
+
+    let matching = |exact: bool| {
+        entries
+            .iter()
+            .filter(|(name, _, _)| {
+                model == name
+                    || (!exact
+                        && model.strip_prefix(name.as_str()).is_some_and(|r| r.starts_with('-')))
+            })
+            .max_by_key(|(name, _, _)| name.len())
+            .map(|(_, input, output)| Price { input: *input, output: *output })
+    };
+    Ok(matching(true).or_else(|| matching(false)))
+}
+
+// Checks the request about to be sent against the ceiling. Without a ceiling nothing is
+// checked; with one, models without a known price are refused since their cost can't be bounded.
+pub fn check(model: &str, prompt_tokens: u64) -> Result<(), String> {
+    let Some(max_cost) = max_cost()? else {
+        return Ok(());
+    };
+    let price = price(model)?.ok_or_else(|| {
+        format!("No price known for model {}; add it to WAVS_ENV_LLM_PRICES", model)
+    })?;
+    let reserve = completion_reserve_tokens();
+    let estimated = price.cost(prompt_tokens, reserve);
+    let spent = spent_micro_usd();
+    if spent.saturating_add(estimated) > max_cost {
+        let error = BudgetExceeded {
+            error: "budget_exceeded",
+            model: model.to_string(),
+            prompt_tokens,
+            completion_reserve_tokens: reserve,
+            estimated_cost_usd: format_usd(estimated),
+            spent_usd: format_usd(spent),
+            max_cost_usd: format_usd(max_cost),
+        };
+        return Err(serde_json::to_string(&error).unwrap_or_else(|_| "budget_exceeded".to_string()));
+    }
+    Ok(())
+}
+
+// Cost of `calls` requests of the given size at list price, for plans made before anything is
+// sent (see dry_run.rs); None when the model has no known price. Nothing is charged.
+pub fn estimate_usd(
+    model: &str,
+    prompt_tokens: u64,
+    completion_tokens: u64,
+    calls: u64,
+) -> Result<Option<String>, String> {
+    let cost = price(model)?.map(|price| price.cost(prompt_tokens, completion_tokens));
+    Ok(cost.map(|cost| format_usd(cost.saturating_mul(calls))))
+}
+
+// Charges a finished request. Models without a known price are only possible without a ceiling,
+// where the spend isn't needed.
+pub fn record(model: &str, prompt_tokens: u64, completion_tokens: u64) {
+    if let Ok(Some(price)) = price(model) {
+        let cost = price.cost(prompt_tokens, completion_tokens);
+        SPENT_MICRO_USD.with(|spent| spent.set(spent.get().saturating_add(cost)));
+    }
+}
+
+fn spent_micro_usd() -> u64 {
+    SPENT_MICRO_USD.with(Cell::get)
+}
+
+// "0.05" -> 50000 micro-USD. More than six decimals are rejected rather than rounded.
+fn parse_usd(value: &str) -> Option<u64> {
+    let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if whole.is_empty() && fraction.is_empty()
+        || fraction.len() > USD_DECIMALS
+        || !whole.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let fraction = format!("{:0<width$}", fraction, width = USD_DECIMALS);
+    format!("{}{}", whole, fraction).parse::<u64>().ok()
+}
+
+fn format_usd(micro: u64) -> String {
+    let scale = 10u64.pow(USD_DECIMALS as u32);
+    let fraction = format!("{:0>width$}", micro % scale, width = USD_DECIMALS);
+    match fraction.trim_end_matches('0') {
+        "" => (micro / scale).to_string(),
+        fraction => format!("{}.{}", micro / scale, fraction),
+    }
+}
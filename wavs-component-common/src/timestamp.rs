@@ -0,0 +1,123 @@
+// When a result was produced. versioned! adds `timestamp` to every JSON output, and what it holds
+// depends on where the output goes. Submitted results only carry the timestamp of the block the
+// trigger event was emitted in, which every operator reports alike:
+//
+//   "timestamp": {"block_timestamp": 1760403587}
+//
+// It is only there when the component could look the block up, which needs a provider for the
+// trigger's chain; otherwise submitted results have no `timestamp`. CLI output adds the
+// operator's clock, read once per run (see CliOutput::to_vec):
+//
+//   "timestamp": {"unix_secs": 1760403600, "rfc3339": "2025-10-14T01:00:00Z"}
+//
+// Operators' clocks differ by however far their runs were apart, so a clock reading in a
+// submitted result would keep their payloads from matching. On-chain fields that stamp the result
+// (a status page's observedAt, say) use run_secs(), so a CLI run's `unix_secs` shows their value.
+use crate::{freshness, origin, rpc, warn};
+use alloy_network::Ethereum;
+use alloy_provider::{Provider, RootProvider};
+use alloy_rpc_types::BlockNumberOrTag;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::cell::Cell;
+
+thread_local! {
+    static RUN_SECS: Cell<Option<u64>> = const { Cell::new(None) };
+    static BLOCK_TIMESTAMP: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Timestamp {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unix_secs: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rfc3339: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_timestamp: Option<u64>,
+}
+
+impl Timestamp {
+    // A past moment, e.g. when a cached value was stored; the trigger block doesn't apply to it
+    pub fn at(unix_secs: u64) -> Timestamp {
+        Timestamp {
+            unix_secs: Some(unix_secs),
+            rfc3339: Some(rfc3339(unix_secs)),
+            block_timestamp: None,
+        }
+    }
+}
+
+// Second precision with a `Z` offset, e.g. "2025-10-14T01:00:00Z"
+pub fn rfc3339(unix_secs: u64) -> String {
+    i64::try_from(unix_secs)
+        .ok()
+        .and_then(|secs| chrono::DateTime::from_timestamp(secs, 0))
+        .unwrap_or_default()
+        .to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
+
+// Called by components that have a provider for `chain_name`. The lookup is skipped when the
+// run wasn't triggered on that chain or the block is already known, and a failed lookup only
+// leaves `block_timestamp` out.
+pub async fn record_trigger_block(provider: &RootProvider<Ethereum>, chain_name: &str) {
+    if BLOCK_TIMESTAMP.with(Cell::get).is_some() {
+        return;
+    }
+    let Some(origin) = origin::current().filter(|origin| origin.chain_name == chain_name) else {
+        return;
+    };
+    let block = provider.get_block_by_number(BlockNumberOrTag::Number(origin.block_number)).await;
+    match block {
+        Ok(Some(block)) => {
+            BLOCK_TIMESTAMP.with(|current| current.set(Some(block.header.timestamp)))
+        }
+        Ok(None) => warn!("Trigger block {} not found", origin.block_number),
+        Err(e) => {
+            let context = format!("Failed to fetch trigger block {}", origin.block_number);
+            warn!("{}", rpc::describe(&context, &e))
+        }
+    }
+}
+
+// The request record_trigger_block would make on `chain_name`, for dry-run plans
+pub fn trigger_block_requests(chain_name: &str) -> u64 {
+    let pending = BLOCK_TIMESTAMP.with(Cell::get).is_none()
+        && origin::current().is_some_and(|origin| origin.chain_name == chain_name);
+    pending as u64
+}
+
+// The clock reading of this run, taken on first use
+pub fn run_secs() -> u64 {
+    RUN_SECS.with(|secs| {
+        let run_secs = secs.get().unwrap_or_else(freshness::now_secs);
+        secs.set(Some(run_secs));
+        run_secs
+    })
+}
+
+// origin::record starts each run afresh, so a run never reports an earlier trigger's block
+pub(crate) fn start_run() {
+    RUN_SECS.with(|secs| secs.set(None));
+    BLOCK_TIMESTAMP.with(|current| current.set(None));
+}
+
+// The run's timestamp, as versioned! adds it: the trigger block's alone, or None when it isn't
+// known
+pub fn current() -> Option<Timestamp> {
+    let block_timestamp = BLOCK_TIMESTAMP.with(Cell::get)?;
+    Some(Timestamp { unix_secs: None, rfc3339: None, block_timestamp: Some(block_timestamp) })
+}
+
+// Adds the run's clock reading to a CLI output's `timestamp`, which it creates when the output
+// has none. Outputs that aren't JSON objects are left as they are.
+pub fn add_clock(output: &mut Value) {
+    let Some(object) = output.as_object_mut() else {
+        return;
+    };
+    let secs = run_secs();
+    let timestamp = object.entry("timestamp").or_insert_with(|| Value::Object(Default::default()));
+    if let Some(timestamp) = timestamp.as_object_mut() {
+        timestamp.insert("unix_secs".to_string(), secs.into());
+        timestamp.insert("rfc3339".to_string(), rfc3339(secs).into());
+    }
+}
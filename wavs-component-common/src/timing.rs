@@ -0,0 +1,127 @@
+// Where the time of a run goes, so operators can see which upstream is slow when a component
+// misses its latency target. run() starts the clock and process() ends each phase as it leaves
+// it: `decode` (trigger, envelope and input), `upstream` (the async part: API and RPC calls and
+// whatever the component does with their answers) and `encode` (building and sizing the
+// submission). HTTP and RPC exchanges made through `replay` are also timed and counted per host;
+// times are summed, so requests that run concurrently can add up to more than the phase took.
+//
+// CLI outputs carry the breakdown as `phase_timings_ms` next to `estimated_submission_gas`.
+// On-chain payloads never do: timings differ between operators, and their submissions must match.
+//
+//   WAVS_ENV_LATENCY_SLO_MS   runs slower than this log a warning with the breakdown (unset:
+//                             no warning)
+use crate::warn;
+use serde::Serialize;
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    time::{Duration, Instant},
+};
+
+#[derive(Default)]
+struct State {
+    started: Option<Instant>,
+    // end of the last phase
+    mark: Option<Instant>,
+    phases: BTreeMap<&'static str, Duration>,
+    hosts: BTreeMap<String, Duration>,
+    requests: BTreeMap<String, u64>,
+}
+
+thread_local! {
+    static STATE: RefCell<State> = RefCell::new(State::default());
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PhaseTimings {
+    decode: u64,
+    upstream: u64,
+    encode: u64,
+    total: u64,
+    // time spent in HTTP and RPC exchanges per upstream host
+    upstream_hosts: BTreeMap<String, u64>,
+    // requests per host, each of which opens its own connection (see http.rs)
+    upstream_requests: BTreeMap<String, u64>,
+}
+
+#[derive(Debug, Serialize)]
+struct SloExceeded<'a> {
+    warning: &'static str,
+    slo_ms: u64,
+    // the slowest host, when any upstream was called
+    slowest_upstream: Option<&'a str>,
+    #[serde(flatten)]
+    timings: &'a PhaseTimings,
+}
+
+pub fn start_run() {
+    let now = Instant::now();
+    STATE.with(|state| {
+        *state.borrow_mut() = State { started: Some(now), mark: Some(now), ..State::default() };
+    });
+}
+
+// Charges the time since the previous phase ended (or the run started) to `phase`
+pub fn end_phase(phase: &'static str) {
+    let now = Instant::now();
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        if let Some(mark) = state.mark.replace(now) {
+            *state.phases.entry(phase).or_default() += now.duration_since(mark);
+        }
+    });
+}
+
+// One call per request, once its response has been read
+pub(crate) fn record_upstream(host: &str, elapsed: Duration) {
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        *state.hosts.entry(host.to_string()).or_default() += elapsed;
+        *state.requests.entry(host.to_string()).or_default() += 1;
+    });
+}
+
+// The breakdown so far; None outside a run (e.g. in benchmarks)
+pub fn current() -> Option<PhaseTimings> {
+    STATE.with(|state| {
+        let state = state.borrow();
+        let started = state.started?;
+        let phase = |name| state.phases.get(name).map_or(0, |d: &Duration| d.as_millis() as u64);
+        Some(PhaseTimings {
+            decode: phase("decode"),
+            upstream: phase("upstream"),
+            encode: phase("encode"),
+            total: started.elapsed().as_millis() as u64,
+            upstream_hosts: state
+                .hosts
+                .iter()
+                .map(|(host, elapsed)| (host.clone(), elapsed.as_millis() as u64))
+                .collect(),
+            upstream_requests: state.requests.clone(),
+        })
+    })
+}
+
+// Called at the end of run(), whether the run succeeded or not
+pub fn warn_if_slow() {
+    let Some(slo_ms) =
+        std::env::var("WAVS_ENV_LATENCY_SLO_MS").ok().and_then(|v| v.trim().parse::<u64>().ok())
+    else {
+        return;
+    };
+    let Some(timings) = current().filter(|t| t.total > slo_ms) else {
+        return;
+    };
+    let slowest_upstream =
+        timings.upstream_hosts.iter().max_by_key(|(_, ms)| **ms).map(|(host, _)| host.as_str());
+    let record = SloExceeded {
+        warning: "latency_slo_exceeded",
+        slo_ms,
+        slowest_upstream,
+        timings: &timings,
+    };
+    match serde_json::to_string(&record) {
+        Ok(record) => warn!("{}", record),
+        Err(_) => warn!("Run took {} ms, over the {} ms latency SLO", timings.total, slo_ms),
+    }
+}
@@ -0,0 +1,77 @@
+// Size limits for list outputs. A result listing many items (breweries, transfers, batch
+// balances) can outgrow what an operator is willing to submit, so components may cap it:
+//
+//   WAVS_ENV_MAX_PAYLOAD_BYTES   most bytes a submitted result may take
+//   WAVS_ENV_MAX_SUBMISSION_GAS  most estimated submission gas (see gas.rs), as bytes
+//
+// Neither is set by default. With one or both set, the tighter one applies and the result's list
+// is cut to its first K items that fit, in the order the component ranked them, with the number
+// left out next to it:
+//
+//   {..., "breweries": [...], "omitted_count": 12}
+//
+// `omitted_count` is reported, as 0 when nothing was cut, whenever a limit is set, so consumers
+// can tell a complete list from a cut one and request the rest in a follow-up. The output's
+// version fields and ABI encoding are allowed for with a fixed reserve; a sealed result grows by
+// its encryption overhead on top.
+use crate::{canonical_json, gas, info};
+use serde_json::Value;
+
+// Room for `component_name`, `trigger`, `timestamp`, `provenance` and the ABI envelope
+const RESERVED_BYTES: usize = 1024;
+
+fn env_limit(name: &str) -> Option<u64> {
+    std::env::var(name).ok().and_then(|v| v.trim().parse::<u64>().ok())
+}
+
+// The most bytes a result's JSON may take, or None when no limit is set
+pub fn limit() -> Option<usize> {
+    let bytes = env_limit("WAVS_ENV_MAX_PAYLOAD_BYTES").map(|bytes| bytes as usize);
+    let gas = env_limit("WAVS_ENV_MAX_SUBMISSION_GAS").map(gas::max_payload_bytes);
+    let limit = match (bytes, gas) {
+        (Some(bytes), Some(gas)) => bytes.min(gas),
+        (bytes, gas) => bytes.or(gas)?,
+    };
+    Some(limit.saturating_sub(RESERVED_BYTES))
+}
+
+// Cuts the array under `list_key` to the longest prefix that keeps `result` within the limit and
+// records `omitted_count`. Results without that array, such as aggregates or a patched output
+// that dropped it, are left as they are.
+pub fn apply(result: &mut Value, list_key: &str) -> Result<(), String> {
+    let Some(limit) = limit() else {
+        return Ok(());
+    };
+    let Some(object) = result.as_object_mut() else {
+        return Ok(());
+    };
+    let Some(Value::Array(items)) = object.get(list_key).cloned() else {
+        return Ok(());
+    };
+
+    let total = items.len();
+    let size_with = |object: &mut serde_json::Map<String, Value>, kept: usize| {
+        object.insert(list_key.to_string(), Value::Array(items[..kept].to_vec()));
+        object.insert("omitted_count".to_string(), (total - kept).into());
+        canonical_json::to_vec(&*object).map(|json| json.len())
+    };
+    // Sizes only grow with the number of items kept, so the longest prefix that fits is found by
+    // bisection
+    let (mut fits, mut exceeds) = (0, total + 1);
+    if size_with(object, 0)? > limit {
+        return Err(format!("Result exceeds the payload limit even without any {}", list_key));
+    }
+    while exceeds - fits > 1 {
+        let kept = fits + (exceeds - fits) / 2;
+        if size_with(object, kept)? <= limit {
+            fits = kept;
+        } else {
+            exceeds = kept;
+        }
+    }
+    size_with(object, fits)?;
+    if fits < total {
+        info!("Kept {} of {} {} within the payload limit", fits, total, list_key);
+    }
+    Ok(())
+}
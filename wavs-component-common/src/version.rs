@@ -0,0 +1,58 @@
+// Which code produced a result. JSON outputs carry `component_name` and `component_version` next
+// to their own fields, taken from the component crate's Cargo metadata when it is built, so
+// consumers and dispute processes can tie a result to a released version. Results of chain
+// triggers also carry `trigger`, the event they answer (see origin.rs), and `timestamp` (see
+// timestamp.rs), and every output carries `provenance` (see provenance.rs). Outputs are tagged
+// through the `versioned!` macro, which has to expand in the component crate for `env!` to
+// read that crate's metadata rather than this one's.
+use crate::origin::{self, TriggerOrigin};
+use crate::timestamp::{self, Timestamp};
+use crate::{canonical_json, provenance};
+use alloy_primitives::B256;
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct Versioned<'a, T: Serialize> {
+    component_name: &'static str,
+    component_version: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trigger: Option<TriggerOrigin>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timestamp: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    provenance: Option<B256>,
+    #[serde(flatten)]
+    output: &'a T,
+}
+
+impl<'a, T: Serialize> Versioned<'a, T> {
+    pub fn new(name: &'static str, version: &'static str, output: &'a T) -> Versioned<'a, T> {
+        let mut versioned = Versioned {
+            component_name: name,
+            component_version: version,
+            trigger: origin::current(),
+            timestamp: timestamp::current(),
+            provenance: None,
+            output,
+        };
+        // Hashed while the provenance is still skipped. An output that doesn't serialize fails
+        // wherever it is rendered, so it can go without one here.
+        versioned.provenance = provenance::current().or_else(|| {
+            canonical_json::to_vec(&versioned).ok().map(|json| provenance::record(&json))
+        });
+        versioned
+    }
+}
+
+// `versioned!(&output)` wraps an output struct (anything that serializes to a JSON object);
+// `versioned!(name, &output)` reports `name` instead of the crate's, for components built under
+// another package's name
+#[macro_export]
+macro_rules! versioned {
+    ($output:expr) => {
+        $crate::version::Versioned::new(env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"), $output)
+    };
+    ($name:expr, $output:expr) => {
+        $crate::version::Versioned::new($name, env!("CARGO_PKG_VERSION"), $output)
+    };
+}
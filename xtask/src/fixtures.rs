@@ -0,0 +1,149 @@
+// Trigger fixtures: representative inputs for each component, kept as data in
+// <component>/fixtures.json and run through a built component under a WASM host, for iterating
+// on a component without deploying it to WAVS. A fixture is one of
+//
+//   {"name": "...", "abi": "<type>", "value": "..."}        a bare ABI value, e.g. a string
+//   {"name": "...", "call": "<signature>", "args": [...]}   calldata of a trigger function
+//   {"name": "...", "raw": "..."}                          text passed as it is
+//
+// optionally with "as": "dry_run" to wrap the input in a dry-run envelope (see
+// wavs-component-common/src/envelope.rs), and for calls "selector": false to leave out the
+// 4-byte selector. Signatures are canonical, e.g. `search(string,uint32)`. Arguments are strings
+// in the notation alloy's DynSolType parses, or arrays of them for array parameters. ABI values
+// and calldata become INPUT_DATA as 0x hex text, the way `make wasi-exec` takes binary input.
+use alloy_dyn_abi::{DynSolType, DynSolValue};
+use alloy_primitives::{hex, keccak256};
+use serde_json::Value;
+use std::path::Path;
+
+const FIXTURES_FILE: &str = "fixtures.json";
+
+struct Fixture {
+    name: String,
+    // INPUT_DATA for the fixture
+    input: String,
+}
+
+pub fn run(components_dir: &Path, args: &[String]) -> Result<(), String> {
+    let (args, command) = match args.iter().position(|a| a == "--") {
+        Some(i) => (&args[..i], &args[i + 1..]),
+        None => (args, &[][..]),
+    };
+    let (component, selection) =
+        args.split_first().ok_or_else(|| "fixtures needs a component name".to_string())?;
+    let selected = match selection.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["--input", data] => vec![Fixture { name: "input".to_string(), input: data.to_string() }],
+        [] => load(&components_dir.join(component))?,
+        [name] if !name.starts_with('-') => {
+            let matching: Vec<Fixture> = load(&components_dir.join(component))?
+                .into_iter()
+                .filter(|f| f.name.contains(name))
+                .collect();
+            if matching.is_empty() {
+                return Err(format!("No fixture of {} is named like '{}'", component, name));
+            }
+            matching
+        }
+        _ => return Err(format!("unknown arguments: {}", selection.join(" "))),
+    };
+
+    // Without a command the inputs are only listed, e.g. to paste into INPUT_DATA by hand
+    for fixture in &selected {
+        println!("== {} {}", component, fixture.name);
+        println!("INPUT_DATA={}", fixture.input);
+        let Some((program, program_args)) = command.split_first() else {
+            continue;
+        };
+        let status = std::process::Command::new(program)
+            .args(program_args)
+            .env("INPUT_DATA", &fixture.input)
+            .status()
+            .map_err(|e| format!("Failed to run {}: {}", program, e))?;
+        // Error fixtures are expected to fail, so a failed run doesn't stop the others
+        if !status.success() {
+            println!("exited with {}", status);
+        }
+        println!();
+    }
+    Ok(())
+}
+
+fn load(component_dir: &Path) -> Result<Vec<Fixture>, String> {
+    let path = component_dir.join(FIXTURES_FILE);
+    let text = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let entries: Vec<Value> =
+        serde_json::from_str(&text).map_err(|e| format!("Invalid {}: {}", path.display(), e))?;
+    entries
+        .iter()
+        .map(fixture)
+        .collect::<Result<_, _>>()
+        .map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn fixture(entry: &Value) -> Result<Fixture, String> {
+    let field = |key: &str| entry.get(key).and_then(Value::as_str);
+    let name = field("name").ok_or_else(|| "every fixture needs a name".to_string())?;
+    let (bytes, is_text) = if let Some(raw) = field("raw") {
+        (raw.as_bytes().to_vec(), true)
+    } else if let Some(ty) = field("abi") {
+        let value =
+            entry.get("value").ok_or_else(|| format!("fixture '{}' needs a value", name))?;
+        (sol_value(&parse_type(ty)?, value)?.abi_encode(), false)
+    } else if let Some(signature) = field("call") {
+        let args = entry.get("args").and_then(Value::as_array).map_or(&[][..], Vec::as_slice);
+        let with_selector = entry.get("selector").and_then(Value::as_bool).unwrap_or(true);
+        (calldata(signature, args, with_selector)?, false)
+    } else {
+        return Err(format!("fixture '{}' needs raw, abi or call", name));
+    };
+
+    let input = match field("as") {
+        None if is_text => String::from_utf8(bytes).expect("raw fixtures are text"),
+        None => format!("0x{}", hex::encode(&bytes)),
+        Some("dry_run") => serde_json::json!({
+            "input": format!("0x{}", hex::encode(&bytes)),
+            "options": {"dry_run": true},
+        })
+        .to_string(),
+        Some(other) => return Err(format!("fixture '{}': unknown \"as\": {}", name, other)),
+    };
+    Ok(Fixture { name: name.to_string(), input })
+}
+
+fn calldata(signature: &str, args: &[Value], with_selector: bool) -> Result<Vec<u8>, String> {
+    let params = signature
+        .find('(')
+        .map(|i| &signature[i..])
+        .ok_or_else(|| format!("'{}' is not a function signature", signature))?;
+    let DynSolType::Tuple(types) = parse_type(params)? else {
+        return Err(format!("'{}' is not a function signature", signature));
+    };
+    if args.len() != types.len() {
+        return Err(format!("{} takes {} arguments, not {}", signature, types.len(), args.len()));
+    }
+    let values =
+        types.iter().zip(args).map(|(ty, arg)| sol_value(ty, arg)).collect::<Result<_, _>>()?;
+    let mut data = Vec::new();
+    if with_selector {
+        data.extend_from_slice(&keccak256(signature)[..4]);
+    }
+    data.extend(DynSolValue::Tuple(values).abi_encode_params());
+    Ok(data)
+}
+
+fn parse_type(ty: &str) -> Result<DynSolType, String> {
+    DynSolType::parse(ty).map_err(|e| format!("Invalid ABI type '{}': {}", ty, e))
+}
+
+fn sol_value(ty: &DynSolType, value: &Value) -> Result<DynSolValue, String> {
+    match (ty, value) {
+        (DynSolType::Array(inner), Value::Array(items)) => Ok(DynSolValue::Array(
+            items.iter().map(|item| sol_value(inner, item)).collect::<Result<_, _>>()?,
+        )),
+        (_, Value::String(text)) => {
+            ty.coerce_str(text).map_err(|e| format!("'{}' is not a valid {}: {}", text, ty, e))
+        }
+        _ => Err(format!("{} is not a valid {}; write it as a string", value, ty)),
+    }
+}
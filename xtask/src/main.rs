@@ -0,0 +1,461 @@
+// Repository tasks.
+//
+//   cargo run -p xtask -- sol-outputs [--out <file>] [--check]
+//   cargo run -p xtask -- sdn-list <sdn.csv> --version <date>
+//   cargo run -p xtask -- fixtures <component> [<name> | --input <data>] [-- <command>...]
+//   cargo run -p xtask -- wasm-timing [--runs <n>] [--warmup <n>] [--save <file>]
+//                         [--baseline <file> [--max-regression <pct>]] -- <command>...
+//
+// sol-outputs generates a Solidity library per component with its output structs and decoding
+// helpers, taken from the `sol!` blocks in each component's src/trigger.rs, so on-chain
+// consumers decode exactly what the components encode. The file is written next to ITypes.sol
+// (../src/interfaces/ComponentOutputs.sol from the components directory) unless --out is given;
+// --check fails instead of writing when the file is out of date.
+//
+// sdn-list rewrites wavs-component-common/data/sdn_eth_addresses.txt, the sanctions list
+// embedded into the components, from OFAC's published sdn.csv.
+//
+// fixtures prints the trigger inputs in <component>/fixtures.json (see fixtures.rs), all of them
+// or those named like <name>, and runs the command after `--` once per input with INPUT_DATA set
+// to it. The command is usually `make wasi-exec` with COMPONENT_FILENAME set, so every fixture
+// runs against a built component with its host imports available.
+//
+// wasm-timing times a command that runs a built component under a WASM host, usually
+// `make wasi-exec` with COMPONENT_FILENAME and INPUT_DATA set, and prints min, median, p95 and
+// max wall time over the runs. The host-side criterion benches (`cargo bench`) cover the pure
+// code paths; this covers what a deployment pays, including instantiation. --save writes the
+// figures to a file, and --baseline compares the median against a saved one and fails when it
+// is more than --max-regression percent (default 10) slower.
+mod fixtures;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+const GENERATED_FILE: &str = "ComponentOutputs.sol";
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("sol-outputs") => sol_outputs(&args[1..]),
+        Some("sdn-list") => sdn_list(&args[1..]),
+        Some("fixtures") => components_dir().and_then(|dir| fixtures::run(&dir, &args[1..])),
+        Some("wasm-timing") => wasm_timing(&args[1..]),
+        _ => Err("usage: cargo run -p xtask -- sol-outputs [--out <file>] [--check]\n       \
+                  cargo run -p xtask -- sdn-list <sdn.csv> --version <date>\n       \
+                  cargo run -p xtask -- fixtures <component> [<name> | --input <data>] \
+                  [-- <command>...]\n       \
+                  cargo run -p xtask -- wasm-timing [--runs <n>] [--warmup <n>] [--save <file>] \
+                  [--baseline <file> [--max-regression <pct>]] -- <command>..."
+            .to_string()),
+    };
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
+
+fn components_dir() -> Result<PathBuf, String> {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .map(Path::to_path_buf)
+        .ok_or_else(|| "xtask must live inside the components directory".to_string())
+}
+
+fn sol_outputs(args: &[String]) -> Result<(), String> {
+    let components_dir = components_dir()?;
+    let mut out = components_dir.join("../src/interfaces").join(GENERATED_FILE);
+    let mut check = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--check" => check = true,
+            "--out" => {
+                out = PathBuf::from(args.next().ok_or_else(|| "--out needs a path".to_string())?)
+            }
+            other => return Err(format!("unknown argument: {}", other)),
+        }
+    }
+
+    let mut components = Vec::new();
+    for dir in component_dirs(&components_dir)? {
+        components.push(Component::load(&dir)?);
+    }
+    let generated = render(&components);
+
+    if check {
+        let current = std::fs::read_to_string(&out).unwrap_or_default();
+        if current != generated {
+            return Err(format!(
+                "{} is out of date; run `cargo run -p xtask -- sol-outputs`",
+                out.display()
+            ));
+        }
+        return Ok(());
+    }
+    std::fs::write(&out, generated)
+        .map_err(|e| format!("Failed to write {}: {}", out.display(), e))?;
+    println!("Wrote {} ({} components)", out.display(), components.len());
+    Ok(())
+}
+
+const SDN_LIST_FILE: &str = "wavs-component-common/data/sdn_eth_addresses.txt";
+// How the SDN list tags Ethereum addresses in an entry's remarks
+const SDN_ETH_TAG: &str = "Digital Currency Address - ETH ";
+const SDN_LIST_HEADER: &str = "\
+# OFAC SDN list: Ethereum addresses (\"Digital Currency Address - ETH\" identifiers).
+# Embedded into every component at build time. Regenerate from the published sdn.csv with
+#   cargo run -p xtask -- sdn-list <sdn.csv> --version <publication date>
+# and rebuild the components to pick up a new list.
+";
+
+fn sdn_list(args: &[String]) -> Result<(), String> {
+    let mut source = None;
+    let mut version = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--version" => {
+                version = Some(args.next().ok_or_else(|| "--version needs a value".to_string())?)
+            }
+            other if source.is_none() => source = Some(other),
+            other => return Err(format!("unknown argument: {}", other)),
+        }
+    }
+    let source = source.ok_or_else(|| "sdn-list needs the path of sdn.csv".to_string())?;
+    let version = version.ok_or_else(|| "sdn-list needs --version".to_string())?;
+
+    let csv =
+        std::fs::read_to_string(source).map_err(|e| format!("Failed to read {}: {}", source, e))?;
+    let mut addresses: Vec<String> = csv
+        .match_indices(SDN_ETH_TAG)
+        .filter_map(|(i, _)| {
+            let candidate = csv[i + SDN_ETH_TAG.len()..].get(..42)?;
+            let is_address = candidate.starts_with("0x")
+                && candidate[2..].chars().all(|c| c.is_ascii_hexdigit());
+            is_address.then(|| candidate.to_string())
+        })
+        .collect();
+    addresses.sort_by_key(|a| a.to_ascii_lowercase());
+    addresses.dedup_by_key(|a| a.to_ascii_lowercase());
+    if addresses.is_empty() {
+        return Err(format!("No Ethereum addresses found in {}", source));
+    }
+
+    let out = components_dir()?.join(SDN_LIST_FILE);
+    let mut contents = SDN_LIST_HEADER.to_string();
+    contents.push_str(&format!("version: {}\n", version));
+    for address in &addresses {
+        contents.push_str(address);
+        contents.push('\n');
+    }
+    std::fs::write(&out, contents)
+        .map_err(|e| format!("Failed to write {}: {}", out.display(), e))?;
+    println!("Wrote {} ({} addresses)", out.display(), addresses.len());
+    Ok(())
+}
+
+const DEFAULT_TIMING_RUNS: usize = 20;
+const DEFAULT_TIMING_WARMUP: usize = 2;
+const DEFAULT_MAX_REGRESSION_PCT: f64 = 10.0;
+const TIMING_KEYS: [&str; 4] = ["min_ms", "median_ms", "p95_ms", "max_ms"];
+
+fn wasm_timing(args: &[String]) -> Result<(), String> {
+    let (options, command) = match args.iter().position(|a| a == "--") {
+        Some(i) => (&args[..i], &args[i + 1..]),
+        None => return Err("wasm-timing needs the command to time after --".to_string()),
+    };
+    let (program, program_args) =
+        command.split_first().ok_or_else(|| "wasm-timing needs a command after --".to_string())?;
+
+    let mut runs = DEFAULT_TIMING_RUNS;
+    let mut warmup = DEFAULT_TIMING_WARMUP;
+    let mut save = None;
+    let mut baseline = None;
+    let mut max_regression = DEFAULT_MAX_REGRESSION_PCT;
+    let mut options = options.iter();
+    while let Some(arg) = options.next() {
+        let mut value = || options.next().ok_or_else(|| format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--runs" => runs = parse_value(arg, value()?)?,
+            "--warmup" => warmup = parse_value(arg, value()?)?,
+            "--save" => save = Some(value()?.clone()),
+            "--baseline" => baseline = Some(value()?.clone()),
+            "--max-regression" => max_regression = parse_value(arg, value()?)?,
+            other => return Err(format!("unknown argument: {}", other)),
+        }
+    }
+    if runs == 0 {
+        return Err("--runs must be at least 1".to_string());
+    }
+
+    let run_once = || -> Result<Duration, String> {
+        let start = Instant::now();
+        let output = std::process::Command::new(program)
+            .args(program_args)
+            .output()
+            .map_err(|e| format!("Failed to run {}: {}", program, e))?;
+        let elapsed = start.elapsed();
+        if !output.status.success() {
+            return Err(format!(
+                "{} exited with {}:\n{}",
+                program,
+                output.status,
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+        Ok(elapsed)
+    };
+    for _ in 0..warmup {
+        run_once()?;
+    }
+    let mut samples = (0..runs).map(|_| run_once()).collect::<Result<Vec<_>, _>>()?;
+    samples.sort();
+
+    let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+    let percentile = |p: usize| samples[(samples.len() - 1) * p / 100];
+    let figures = [ms(samples[0]), ms(percentile(50)), ms(percentile(95)), ms(percentile(100))];
+    let report: String = TIMING_KEYS
+        .iter()
+        .zip(figures)
+        .map(|(key, value)| format!("{} {:.3}\n", key, value))
+        .collect();
+    println!("{} runs ({} warmup)", runs, warmup);
+    print!("{}", report);
+
+    if let Some(save) = save {
+        std::fs::write(&save, &report).map_err(|e| format!("Failed to write {}: {}", save, e))?;
+        println!("Wrote {}", save);
+    }
+    if let Some(baseline) = baseline {
+        let saved = std::fs::read_to_string(&baseline)
+            .map_err(|e| format!("Failed to read {}: {}", baseline, e))?;
+        let saved_median = saved
+            .lines()
+            .find_map(|line| line.strip_prefix("median_ms "))
+            .and_then(|v| v.trim().parse::<f64>().ok())
+            .ok_or_else(|| format!("{} has no median_ms line", baseline))?;
+        let change_pct = (figures[1] - saved_median) / saved_median * 100.0;
+        println!("median {:+.1}% against {} ({:.3} ms)", change_pct, baseline, saved_median);
+        if change_pct > max_regression {
+            return Err(format!(
+                "Median regressed by {:.1}%, more than the allowed {}%",
+                change_pct, max_regression
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn parse_value<T: std::str::FromStr>(arg: &str, value: &str) -> Result<T, String> {
+    value.parse().map_err(|_| format!("Invalid value for {}: {}", arg, value))
+}
+
+fn component_dirs(components_dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let entries = std::fs::read_dir(components_dir)
+        .map_err(|e| format!("Failed to read {}: {}", components_dir.display(), e))?;
+    let mut dirs: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.join("src/trigger.rs").is_file())
+        .collect();
+    dirs.sort();
+    Ok(dirs)
+}
+
+struct Component {
+    name: String,
+    // struct name and its Solidity source, in declaration order
+    structs: Vec<(String, String)>,
+    functions: Vec<String>,
+    // structs returned as the whole payload instead of inside DataWithId
+    direct: Vec<String>,
+    uses_envelope: bool,
+    json_output: bool,
+}
+
+impl Component {
+    fn load(dir: &Path) -> Result<Component, String> {
+        let read = |file: &str| {
+            std::fs::read_to_string(dir.join(file))
+                .map_err(|e| format!("Failed to read {}: {}", dir.join(file).display(), e))
+        };
+        let trigger = read("src/trigger.rs")?;
+        let lib = read("src/lib.rs")?;
+
+        let mut structs = Vec::new();
+        let mut functions = Vec::new();
+        for block in sol_blocks(&trigger) {
+            let mut rest = block.as_str();
+            while let Some(start) = rest.find("struct ") {
+                let body_start = rest[start..].find('{').map(|i| start + i);
+                let Some(end) = body_start.and_then(|i| matching_brace(rest, i)) else {
+                    break;
+                };
+                let source = rest[start..=end].to_string();
+                let name = source["struct ".len()..].split(|c: char| !c.is_alphanumeric()).next();
+                structs.push((name.unwrap_or_default().to_string(), source));
+                rest = &rest[end + 1..];
+            }
+            for line in block.lines().map(str::trim) {
+                if line.starts_with("function ") {
+                    functions.push(line.to_string());
+                }
+            }
+        }
+
+        // e.g. `pub fn encode_ai_answer_output(answer: solidity::AiAnswer) -> WasmResponse`
+        let direct = structs
+            .iter()
+            .filter(|(name, _)| {
+                trigger.lines().any(|l| {
+                    l.contains("pub fn encode_") && l.contains(&format!("solidity::{})", name))
+                })
+            })
+            .map(|(name, _)| name.clone())
+            .collect();
+
+        Ok(Component {
+            name: dir.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default(),
+            structs,
+            functions,
+            direct,
+            uses_envelope: lib.contains("encode_trigger_output("),
+            json_output: lib.contains("EthereumOutput::json("),
+        })
+    }
+
+    fn is_nested(&self, name: &str) -> bool {
+        self.structs.iter().any(|(other, source)| {
+            other != name && source.split(|c: char| !c.is_alphanumeric()).any(|t| t == name)
+        })
+    }
+
+    fn library_name(&self) -> String {
+        let pascal: String = self
+            .name
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let mut chars = part.chars();
+                chars.next().map(|c| c.to_ascii_uppercase()).into_iter().chain(chars).collect()
+            })
+            .collect::<Vec<String>>()
+            .concat();
+        format!("{}Outputs", pascal)
+    }
+}
+
+// Contents of every `sol! { ... }` block; `sol!("file.sol")` imports are skipped
+fn sol_blocks(source: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut rest = source;
+    while let Some(start) = rest.find("sol! {") {
+        let open = start + "sol! ".len();
+        let Some(close) = matching_brace(rest, open) else {
+            break;
+        };
+        blocks.push(rest[open + 1..close].to_string());
+        rest = &rest[close + 1..];
+    }
+    blocks
+}
+
+fn matching_brace(source: &str, open: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in source[open..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+// Re-indents a struct copied out of a sol! block to sit one level inside a library
+fn indent_struct(source: &str) -> String {
+    let lines: Vec<&str> = source.lines().collect();
+    let inner_indent = lines
+        .iter()
+        .skip(1)
+        .filter(|l| !l.trim().is_empty() && l.trim() != "}")
+        .map(|l| l.len() - l.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| match (i, line.trim()) {
+            (0, first) => format!("    {}", first),
+            (_, "}") => "    }".to_string(),
+            (_, _) => format!("        {}", line.get(inner_indent..).unwrap_or(line.trim())),
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn render(components: &[Component]) -> String {
+    let mut out = String::new();
+    out.push_str("// SPDX-License-Identifier: MIT\n");
+    out.push_str(
+        "// Generated by `cargo run -p xtask -- sol-outputs` from the components' sol! blocks.\n",
+    );
+    out.push_str("// Do not edit by hand.\n");
+    out.push_str("pragma solidity ^0.8.22;\n\n");
+    out.push_str("import {ITypes} from \"./ITypes.sol\";\n");
+
+    for component in components {
+        let library = component.library_name();
+        out.push_str(&format!("\n// Outputs of the {} component\n", component.name));
+        out.push_str(&format!("library {} {{\n", library));
+        let mut items = Vec::new();
+        for (_, source) in &component.structs {
+            items.push(indent_struct(source));
+        }
+        for (name, _) in &component.structs {
+            // structs only used as members of other structs are never decoded on their own
+            if component.is_nested(name) {
+                continue;
+            }
+            if component.direct.contains(name) {
+                items.push(format!(
+                    "    function decode{name}(bytes memory payload) internal pure returns ({name} memory) {{\n        return abi.decode(payload, ({name}));\n    }}"
+                ));
+            } else if component.uses_envelope {
+                items.push(format!(
+                    "    function decode{name}(bytes memory payload)\n        internal\n        pure\n        returns (uint64 triggerId, {name} memory value)\n    {{\n        ITypes.DataWithId memory envelope = abi.decode(payload, (ITypes.DataWithId));\n        return (envelope.triggerId, abi.decode(envelope.data, ({name})));\n    }}"
+                ));
+            }
+        }
+        if component.uses_envelope
+            && component.structs.iter().all(|(n, _)| component.direct.contains(n))
+        {
+            let note = if component.json_output {
+                "    // `data` is the result as canonical JSON (sorted keys, no whitespace)"
+            } else {
+                "    // `data` is the ABI-encoded result"
+            };
+            items.push(format!(
+                "{note}\n    function decodeOutput(bytes memory payload)\n        internal\n        pure\n        returns (uint64 triggerId, bytes memory data)\n    {{\n        ITypes.DataWithId memory envelope = abi.decode(payload, (ITypes.DataWithId));\n        return (envelope.triggerId, envelope.data);\n    }}"
+            ));
+        }
+        out.push_str(&items.join("\n\n"));
+        out.push_str("\n}\n");
+
+        if !component.functions.is_empty() {
+            out.push_str(&format!("\n// Trigger inputs accepted by {}\n", component.name));
+            out.push_str(&format!(
+                "interface {}Triggers {{\n",
+                library.trim_end_matches("Outputs")
+            ));
+            for function in &component.functions {
+                out.push_str(&format!("    {}\n", function));
+            }
+            out.push_str("}\n");
+        }
+    }
+    out
+}